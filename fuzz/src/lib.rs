@@ -0,0 +1,413 @@
+//! Checks run by the fuzz targets, which are shared with the differential tests of `validators`.
+//!
+//! Every check feeds an input to a validator, which must never panic. Parsing must agree with validating, since they are generated separately, and the validators of grammars which have reference implementations must agree with them on whether an input is accepted.
+
+mod reference;
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    str::from_utf8,
+};
+
+use validators::{models::Host, prelude::*};
+
+/// Parse a string and make sure that validating it agrees.
+fn parse<T: ValidateString>(s: &str) -> Option<T> {
+    let result = T::parse_str(s).ok();
+
+    assert_eq!(
+        result.is_some(),
+        T::validate_str(s).is_ok(),
+        "parsing and validating disagree on {s:?}"
+    );
+
+    result
+}
+
+/// Parse bytes and make sure that validating them, and parsing them as a string if they are UTF-8, agree.
+fn parse_bytes<T: ValidateBytes + ValidateString>(data: &[u8]) -> Option<T> {
+    let result = T::parse_u8_slice(data).ok();
+
+    assert_eq!(
+        result.is_some(),
+        T::validate_u8_slice(data).is_ok(),
+        "parsing and validating disagree on {data:?}"
+    );
+
+    if let Ok(s) = from_utf8(data) {
+        assert_eq!(
+            result.is_some(),
+            parse::<T>(s).is_some(),
+            "bytes and strings disagree on {s:?}"
+        );
+    }
+
+    result
+}
+
+/// Run `f` if `data` is UTF-8.
+#[inline]
+fn with_str(data: &[u8], f: impl FnOnce(&str)) {
+    if let Ok(s) = from_utf8(data) {
+        f(s)
+    }
+}
+
+macro_rules! string_checks {
+    ($($check:ident => $validator:ident;)*) => {
+        $(
+            pub fn $check(data: &[u8]) {
+                with_str(data, |s| {
+                    parse::<$validator>(s);
+                });
+            }
+        )*
+    };
+}
+
+macro_rules! bytes_checks {
+    ($($check:ident => $validator:ident;)*) => {
+        $(
+            pub fn $check(data: &[u8]) {
+                parse_bytes::<$validator>(data);
+            }
+        )*
+    };
+}
+
+#[derive(Validator)]
+#[validator(base32)]
+pub struct Base32(pub String);
+
+#[derive(Validator)]
+#[validator(base32_decoded)]
+pub struct Base32Decoded(pub Vec<u8>);
+
+#[derive(Validator)]
+#[validator(base64)]
+pub struct Base64(pub String);
+
+#[derive(Validator)]
+#[validator(base64_decoded)]
+pub struct Base64Decoded(pub Vec<u8>);
+
+#[derive(Validator)]
+#[validator(base64_url)]
+pub struct Base64Url(pub String);
+
+#[derive(Validator)]
+#[validator(base64_url_decoded)]
+pub struct Base64UrlDecoded(pub Vec<u8>);
+
+#[derive(Validator)]
+#[validator(bit)]
+pub struct Bit(pub validators::byte_unit::Bit);
+
+#[derive(Validator)]
+#[validator(boolean)]
+pub struct Boolean(pub bool);
+
+#[derive(Validator)]
+#[validator(byte)]
+pub struct Byte(pub validators::byte_unit::Byte);
+
+#[derive(Validator)]
+#[validator(date)]
+pub struct Date(pub validators::models::Date);
+
+#[derive(Validator)]
+#[validator(datetime)]
+pub struct DateTime(pub validators::models::DateTime);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Allow), local(Allow), at_least_two_labels(Allow), port(Disallow)))]
+pub struct Domain(pub String);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), at_least_two_labels(Must), port(Allow)))]
+pub struct PublicDomain {
+    pub domain: String,
+    pub port:   Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(duration)]
+pub struct Duration(pub std::time::Duration);
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Disallow),
+    ip(Allow),
+    local(Allow),
+    at_least_two_labels(Allow),
+    non_ascii(Disallow)
+))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: Host,
+}
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Allow),
+    ip(Allow),
+    local(Allow),
+    at_least_two_labels(Allow),
+    non_ascii(Allow)
+))]
+pub struct EmailWithComments {
+    pub local_part:                 String,
+    pub need_quoted:                bool,
+    pub domain_part:                Host,
+    pub comment_before_local_part:  Option<String>,
+    pub comment_after_local_part:   Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part:  Option<String>,
+}
+
+#[derive(Validator)]
+#[validator(host(local(Allow), at_least_two_labels(Allow), port(Allow)))]
+pub struct HostWithPort {
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(http_url)]
+pub struct HttpUrl {
+    pub url:      validators::url::Url,
+    pub is_https: bool,
+}
+
+#[derive(Validator)]
+#[validator(http_ftp_url)]
+pub struct HttpFtpUrl {
+    pub url:      validators::url::Url,
+    pub protocol: validators::models::Protocol,
+}
+
+#[derive(Validator)]
+#[validator(ip(local(Allow), port(Disallow)))]
+pub struct Ip(pub IpAddr);
+
+#[derive(Validator)]
+#[validator(ip(local(Allow), port(Must)))]
+pub struct IpWithPort {
+    pub ip:   IpAddr,
+    pub port: u16,
+}
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Disallow)))]
+pub struct Ipv4(pub Ipv4Addr);
+
+#[derive(Validator)]
+#[validator(ipv6(local(Allow), port(Disallow)))]
+pub struct Ipv6(pub Ipv6Addr);
+
+#[derive(Validator)]
+#[validator(json)]
+pub struct Json(pub validators::serde_json::Value);
+
+#[derive(Validator)]
+#[validator(length(min = 1, max = 16))]
+pub struct Length(pub Vec<u8>);
+
+#[derive(Validator)]
+#[validator(line)]
+pub struct Line(pub String);
+
+#[derive(Validator)]
+#[validator(mac_address)]
+pub struct MacAddress(pub u64);
+
+#[derive(Validator)]
+#[validator(number)]
+pub struct Number(pub f64);
+
+#[derive(Validator)]
+#[validator(phone)]
+pub struct Phone(pub validators::phonenumber::PhoneNumber);
+
+#[derive(Validator)]
+#[validator(regex(regex = "^[0-9a-fA-F]+$"))]
+pub struct Regex(pub String);
+
+#[derive(Validator)]
+#[validator(semver)]
+pub struct Semver(pub validators::semver::Version);
+
+#[derive(Validator)]
+#[validator(semver_req)]
+pub struct SemverReq(pub validators::semver::VersionReq);
+
+#[derive(Validator)]
+#[validator(signed_integer)]
+pub struct SignedInteger(pub i64);
+
+#[derive(Validator)]
+#[validator(text)]
+pub struct Text(pub String);
+
+#[derive(Validator)]
+#[validator(time)]
+pub struct Time(pub validators::models::Time);
+
+#[derive(Validator)]
+#[validator(unsigned_integer)]
+pub struct UnsignedInteger(pub u64);
+
+#[derive(Validator)]
+#[validator(url)]
+pub struct Url(pub validators::url::Url);
+
+#[derive(Validator)]
+#[validator(uuid)]
+pub struct Uuid(pub u128);
+
+bytes_checks! {
+    base32 => Base32;
+    base32_decoded => Base32Decoded;
+    base64 => Base64;
+    base64_decoded => Base64Decoded;
+    base64_url => Base64Url;
+    base64_url_decoded => Base64UrlDecoded;
+}
+
+string_checks! {
+    bit => Bit;
+    boolean => Boolean;
+    byte => Byte;
+    date => Date;
+    datetime => DateTime;
+    duration => Duration;
+    http_url => HttpUrl;
+    http_ftp_url => HttpFtpUrl;
+    json => Json;
+    mac_address => MacAddress;
+    number => Number;
+    phone => Phone;
+    regex => Regex;
+    semver => Semver;
+    semver_req => SemverReq;
+    signed_integer => SignedInteger;
+    time => Time;
+    unsigned_integer => UnsignedInteger;
+    url => Url;
+    uuid => Uuid;
+}
+
+/// The `domain` validator agrees with RFC 1123.
+pub fn domain(data: &[u8]) {
+    with_str(data, |s| {
+        let accepted = parse::<Domain>(s).is_some();
+
+        if s.is_ascii() && !reference::has_punycode_label(s) {
+            assert_eq!(accepted, reference::is_domain(s), "{s:?}");
+        }
+
+        if let Some(domain) = parse::<PublicDomain>(s) {
+            assert!(reference::is_domain(&domain.domain), "{s:?}");
+        }
+    });
+}
+
+/// The `email` validator agrees with RFC 5322, in its dialect.
+pub fn email(data: &[u8]) {
+    with_str(data, |s| {
+        let accepted = parse::<Email>(s).is_some();
+
+        // internationalized domain names are converted by IDNA, which the reference does not implement
+        let domain_part_is_ascii =
+            s.rsplit_once('@').is_none_or(|(_, domain_part)| domain_part.is_ascii());
+
+        if domain_part_is_ascii && !reference::has_punycode_label(s) {
+            assert_eq!(accepted, reference::is_email(s), "{s:?}");
+        }
+
+        parse::<EmailWithComments>(s);
+    });
+}
+
+/// The hosts accepted by the `host` validator are accepted by the `url` crate as well.
+pub fn host(data: &[u8]) {
+    with_str(data, |s| {
+        if let Some(HostWithPort {
+            host, ..
+        }) = parse::<HostWithPort>(s)
+        {
+            let host = match host {
+                Host::Domain(domain) => {
+                    // a domain ending in a number is an IPv4 address for URLs
+                    let last_label = domain.trim_end_matches('.').rsplit('.').next().unwrap();
+
+                    if last_label.bytes().all(|e| e.is_ascii_digit())
+                        || last_label.get(..2).is_some_and(|e| e.eq_ignore_ascii_case("0x"))
+                    {
+                        return;
+                    }
+
+                    domain
+                },
+                Host::IPv4(ip) => ip.to_string(),
+                Host::IPv6(ip) => format!("[{ip}]"),
+            };
+
+            assert!(validators::url::Host::parse(&host).is_ok(), "{s:?}");
+        }
+    });
+}
+
+/// The `ip`, `ipv4` and `ipv6` validators agree with `std::net`, except that they accept bracketed IPv6 addresses and reject zone indices.
+pub fn ip(data: &[u8]) {
+    with_str(data, |s| {
+        let unbracketed = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')).unwrap_or(s);
+
+        assert_eq!(parse::<Ipv4>(s).is_some(), s.parse::<Ipv4Addr>().is_ok(), "{s:?}");
+        assert_eq!(parse::<Ipv6>(s).is_some(), unbracketed.parse::<Ipv6Addr>().is_ok(), "{s:?}");
+        assert_eq!(
+            parse::<Ip>(s).is_some(),
+            s.parse::<Ipv4Addr>().is_ok() || unbracketed.parse::<Ipv6Addr>().is_ok(),
+            "{s:?}"
+        );
+
+        if !s.contains('%') {
+            assert_eq!(parse::<IpWithPort>(s).is_some(), s.parse::<SocketAddr>().is_ok(), "{s:?}");
+        }
+    });
+}
+
+/// The `ipv4` validator.
+#[inline]
+pub fn ipv4(data: &[u8]) {
+    ip(data)
+}
+
+/// The `ipv6` validator.
+#[inline]
+pub fn ipv6(data: &[u8]) {
+    ip(data)
+}
+
+/// The `length` validator counts the items of collections.
+pub fn length(data: &[u8]) {
+    let accepted = Length::parse_collection(data.to_vec()).is_ok();
+
+    assert_eq!(accepted, (1..=16).contains(&data.len()));
+    assert_eq!(accepted, Length::validate_collection(&data.to_vec()).is_ok());
+}
+
+/// The `line` validator rejects control characters other than tabs.
+pub fn line(data: &[u8]) {
+    with_str(data, |s| {
+        assert_eq!(parse::<Line>(s).is_some(), reference::is_line(s), "{s:?}");
+    });
+}
+
+/// The `text` validator rejects control characters other than white spaces.
+pub fn text(data: &[u8]) {
+    with_str(data, |s| {
+        assert_eq!(parse::<Text>(s).is_some(), reference::is_text(s), "{s:?}");
+    });
+}
@@ -0,0 +1,100 @@
+//! Reference implementations of the grammars which the hand-written parsers implement.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// A domain name of RFC 1123, which consists of labels of letters, digits and hyphens not starting or ending with a hyphen. A trailing dot is allowed.
+pub fn is_domain(s: &str) -> bool {
+    let s = s.strip_suffix('.').unwrap_or(s);
+
+    !s.is_empty()
+        && s.len() <= 253
+        && s.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && label.bytes().all(|e| e.is_ascii_alphanumeric() || e == b'-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+}
+
+/// Whether a label is encoded by Punycode, which the reference implementations do not decode.
+pub fn has_punycode_label(s: &str) -> bool {
+    s.split(['.', '@']).any(|label| label.get(..4).is_some_and(|e| e.eq_ignore_ascii_case("xn--")))
+}
+
+#[inline]
+fn is_atext(e: u8) -> bool {
+    e.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&e)
+}
+
+/// The `local-part` of RFC 5322 without comments and folding white spaces, in the dialect of the `email` validator.
+///
+/// * A quoted string cannot be empty.
+/// * The specials `()<>[]:;@,` must be escaped in a quoted string.
+fn is_local_part(s: &str) -> bool {
+    if s.len() > 64 {
+        return false;
+    }
+
+    if let Some(quoted) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        let bytes = quoted.as_bytes();
+
+        if bytes.is_empty() {
+            return false;
+        }
+
+        let mut p = 0;
+
+        while p < bytes.len() {
+            match bytes[p] {
+                b'\\' => {
+                    // quoted-pair
+                    match bytes.get(p + 1) {
+                        Some(e) if *e == b' ' || *e == b'\t' || e.is_ascii_graphic() => p += 2,
+                        _ => return false,
+                    }
+                },
+                b'"' | b'(' | b')' | b'<' | b'>' | b'[' | b']' | b':' | b';' | b'@' | b',' => {
+                    return false;
+                },
+                e if e == b' ' || e == b'\t' || e.is_ascii_graphic() => p += 1,
+                _ => return false,
+            }
+        }
+
+        true
+    } else {
+        // dot-atom
+        s.split('.').all(|atom| !atom.is_empty() && atom.bytes().all(is_atext))
+    }
+}
+
+/// The `addr-spec` of RFC 5322 without comments and folding white spaces, whose domain is a domain name without a trailing dot or an address literal of RFC 5321.
+pub fn is_email(s: &str) -> bool {
+    let Some(at) = s.rfind('@') else {
+        return false;
+    };
+
+    let (local_part, domain_part) = (&s[..at], &s[(at + 1)..]);
+
+    let domain_part_is_valid = match domain_part.strip_prefix('[').and_then(|s| s.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix("IPv6:") {
+            Some(ipv6) => ipv6.parse::<Ipv6Addr>().is_ok(),
+            None => literal.parse::<Ipv4Addr>().is_ok(),
+        },
+        None => !domain_part.ends_with('.') && is_domain(domain_part),
+    };
+
+    is_local_part(local_part) && domain_part_is_valid
+}
+
+/// A text without control characters other than white spaces.
+pub fn is_text(s: &str) -> bool {
+    !s.chars().any(|c| matches!(c, '\x00'..='\x08' | '\x0C' | '\x0E'..='\x1F' | '\x7F'))
+}
+
+/// A line without control characters other than tabs.
+pub fn is_line(s: &str) -> bool {
+    !s.chars().any(|c| c.is_ascii_control() && c != '\t')
+}
@@ -0,0 +1,190 @@
+//! Generate the data embedded in `validators` from the upstream files.
+//!
+//! ```text
+//! cargo run --manifest-path tools/update-data/Cargo.toml -- psl public_suffix_list.dat
+//! cargo run --manifest-path tools/update-data/Cargo.toml -- tlds tlds-alpha-by-domain.txt
+//! ```
+//!
+//! * `psl` reads `public_suffix_list.dat` from <https://publicsuffix.org/list/public_suffix_list.dat> and writes the sorted tables of `validators/src/models/public_suffix_table.rs`.
+//! * `tlds` reads `tlds-alpha-by-domain.txt` from <https://data.iana.org/TLD/tlds-alpha-by-domain.txt>, checks it and copies it verbatim to `validators/src/models/tlds-alpha-by-domain.txt`, so that its `# Version` line tells when it was taken.
+
+use std::{env, fmt::Write, fs, path::PathBuf, process};
+
+const USAGE: &str = "usage: update-data psl <public_suffix_list.dat>\n       update-data tlds \
+                     <tlds-alpha-by-domain.txt>";
+
+/// The license notice of the list, which has to be kept with the generated tables.
+const PSL_HEADER: [&str; 7] = [
+    "//",
+    "// This Source Code Form is subject to the terms of the Mozilla Public",
+    "// License, v. 2.0. If a copy of the MPL was not distributed with this",
+    "// file, You can obtain one at https://mozilla.org/MPL/2.0/.",
+    "//",
+    "// Both the ICANN and the private sections, converted to Punycode and sorted for binary \
+     search.",
+    "",
+];
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["psl", input] => psl(input),
+        ["tlds", input] => tlds(input),
+        _ => Err(String::from(USAGE)),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{error}");
+        process::exit(1);
+    }
+}
+
+/// The path of a file in the `validators` crate.
+fn validators_path(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../validators").join(path)
+}
+
+/// Convert a domain to lowercase ASCII, with IDNs in Punycode.
+fn to_ascii(domain: &str) -> Option<String> {
+    if domain.is_ascii() {
+        Some(domain.to_ascii_lowercase())
+    } else {
+        idna::domain_to_ascii(domain).ok()
+    }
+}
+
+/// Write a sorted table of strings as a static slice.
+fn write_table(s: &mut String, doc: &str, name: &str, mut table: Vec<String>) {
+    table.sort_unstable();
+    table.dedup();
+
+    writeln!(s, "/// {doc}").unwrap();
+    writeln!(s, "#[rustfmt::skip]").unwrap();
+    writeln!(s, "pub(crate) static {name}: &[&str] = &[").unwrap();
+
+    for item in table {
+        writeln!(s, "    {item:?},").unwrap();
+    }
+
+    writeln!(s, "];").unwrap();
+}
+
+fn psl(input: &str) -> Result<(), String> {
+    let source = fs::read_to_string(input).map_err(|error| format!("{input}: {error}"))?;
+
+    let mut rules = Vec::new();
+    let mut wildcards = Vec::new();
+    let mut exceptions = Vec::new();
+
+    // the `// VERSION: ...` and `// COMMIT: ...` lines of the published list
+    let mut versions = Vec::new();
+
+    for line in source.lines() {
+        // a rule ends at the first whitespace
+        let Some(rule) = line.split_whitespace().next() else {
+            continue;
+        };
+
+        if let Some(comment) = line.strip_prefix("//") {
+            let comment = comment.trim();
+
+            if comment.starts_with("VERSION:") || comment.starts_with("COMMIT:") {
+                versions.push(String::from(comment));
+            }
+
+            continue;
+        }
+
+        let (table, rule) = if let Some(rule) = rule.strip_prefix('!') {
+            (&mut exceptions, rule)
+        } else if let Some(rule) = rule.strip_prefix("*.") {
+            (&mut wildcards, rule)
+        } else {
+            (&mut rules, rule)
+        };
+
+        match to_ascii(rule) {
+            Some(rule) => table.push(rule),
+            None => eprintln!("skip the rule which cannot be encoded by IDNA: {line}"),
+        }
+    }
+
+    if rules.is_empty() {
+        return Err(format!("{input}: no rule is found"));
+    }
+
+    let mut s = String::new();
+
+    s.push_str("// @generated by `tools/update-data` from the Public Suffix List, https://publicsuffix.org/list/public_suffix_list.dat\n");
+
+    for version in versions {
+        writeln!(s, "// {version}").unwrap();
+    }
+
+    for line in PSL_HEADER {
+        writeln!(s, "{line}").unwrap();
+    }
+
+    write_table(&mut s, "The normal rules, such as `co.uk`.", "RULES", rules);
+    s.push('\n');
+    write_table(
+        &mut s,
+        "The domains under which every label is a public suffix, such as `ck` for `*.ck`.",
+        "WILDCARDS",
+        wildcards,
+    );
+    s.push('\n');
+    write_table(
+        &mut s,
+        "The exceptions to the wildcards, such as `www.ck` for `!www.ck`.",
+        "EXCEPTIONS",
+        exceptions,
+    );
+
+    let output = validators_path("src/models/public_suffix_table.rs");
+
+    fs::write(&output, s).map_err(|error| format!("{}: {error}", output.display()))
+}
+
+fn tlds(input: &str) -> Result<(), String> {
+    let source = fs::read_to_string(input).map_err(|error| format!("{input}: {error}"))?;
+
+    let mut lines = source.lines();
+
+    // such as `# Version 2024061000, Last Updated Mon Jun 10 07:07:01 2024 UTC`
+    let version = lines
+        .next()
+        .and_then(|line| line.strip_prefix("# Version "))
+        .and_then(|line| line.split(',').next())
+        .filter(|version| version.len() == 10 && version.bytes().all(|b| b.is_ascii_digit()))
+        .ok_or_else(|| format!("{input}: the first line is not `# Version YYYYMMDDHH, ...`"))?;
+
+    let mut count = 0usize;
+
+    for line in lines {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if line.is_empty()
+            || !line.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'-')
+        {
+            return Err(format!("{input}: {line:?} is not a TLD"));
+        }
+
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(format!("{input}: no TLD is found"));
+    }
+
+    let output = validators_path("src/models/tlds-alpha-by-domain.txt");
+
+    fs::write(&output, &source).map_err(|error| format!("{}: {error}", output.display()))?;
+
+    eprintln!("{count} TLDs of version {version}");
+
+    Ok(())
+}
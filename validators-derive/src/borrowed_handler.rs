@@ -0,0 +1,388 @@
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, GenericParam, Ident, ImplItem, Item, ItemImpl,
+    Lifetime, Meta, Path, PathArguments, Type,
+};
+
+use crate::{Validator, common::custom::Custom, panic, validator_handler};
+
+/// Traits whose `parse_*` methods create the validator. They are implemented for the carrier, whose results are borrowed by the original struct.
+const VALIDATE_TRAITS: [&str; 9] = [
+    "ValidateString",
+    "ValidateBytes",
+    "ValidateChar",
+    "ValidateSignedInteger",
+    "ValidateUnsignedInteger",
+    "ValidateNumber",
+    "ValidateBoolean",
+    "ValidateLength",
+    "ValidateJsonValue",
+];
+
+/// Traits which are implemented for the carrier only, because they need an owned string.
+const CARRIER_ONLY_TRAITS: [&str; 3] = ["Deserialize", "FromFormField", "FromParam"];
+
+/// Traits of `sqlx` and `arbitrary` which are not implemented at all, because decoding and generating need an owned string.
+const UNSUPPORTED_TRAITS: [&str; 4] = ["Type", "Encode", "Decode", "Arbitrary"];
+
+/// The type of the field of a borrowing validator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum BorrowedField {
+    /// `&'a str`
+    Str,
+    /// `Cow<'a, str>`
+    Cow,
+}
+
+/// How the borrowed string is created from the input.
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum BorrowedKind {
+    /// The validated value is the input itself.
+    Whole,
+    /// The validated value is the ASCII form of the input, which can only be borrowed if it is the same as the input.
+    Domain,
+}
+
+#[inline]
+fn borrowed_kind(validator: Validator) -> Option<BorrowedKind> {
+    match validator {
+        #[cfg(feature = "base32")]
+        Validator::base32 => Some(BorrowedKind::Whole),
+        #[cfg(feature = "base64")]
+        Validator::base64 => Some(BorrowedKind::Whole),
+        #[cfg(feature = "base64_url")]
+        Validator::base64_url => Some(BorrowedKind::Whole),
+        #[cfg(feature = "line")]
+        Validator::line => Some(BorrowedKind::Whole),
+        #[cfg(feature = "regex")]
+        Validator::regex => Some(BorrowedKind::Whole),
+        #[cfg(feature = "text")]
+        Validator::text => Some(BorrowedKind::Whole),
+        #[cfg(feature = "domain")]
+        Validator::domain => Some(BorrowedKind::Domain),
+        _ => None,
+    }
+}
+
+#[inline]
+fn is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("str"))
+}
+
+#[inline]
+fn trait_name(item_impl: &ItemImpl) -> Option<String> {
+    item_impl
+        .trait_
+        .as_ref()
+        .and_then(|(path, _)| path.segments.last())
+        .map(|segment| segment.ident.to_string())
+}
+
+/// Replace every `from` identifier in the token stream with `to`.
+fn replace_ident(token_stream: TokenStream, from: &Ident, to: &Ident) -> TokenStream {
+    token_stream
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == *from => TokenTree::Ident(to.clone()),
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), replace_ident(group.stream(), from, to));
+
+                new_group.set_span(group.span());
+
+                TokenTree::Group(new_group)
+            },
+            _ => tt,
+        })
+        .collect()
+}
+
+/// Find out whether the struct is like `struct Name<'a>(&'a str)` or `struct Name<'a>(Cow<'a, str>)`.
+pub(crate) fn borrowed_field(ast: &DeriveInput) -> Option<(Lifetime, BorrowedField)> {
+    let mut params = ast.generics.params.iter();
+
+    let lifetime = match (params.next(), params.next()) {
+        (Some(GenericParam::Lifetime(param)), None) => &param.lifetime,
+        _ => return None,
+    };
+
+    let field = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match &field.ty {
+        Type::Reference(reference)
+            if reference.mutability.is_none()
+                && reference.lifetime.as_ref() == Some(lifetime)
+                && is_str(&reference.elem) =>
+        {
+            Some((lifetime.clone(), BorrowedField::Str))
+        },
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+
+            if segment.ident != "Cow" {
+                return None;
+            }
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) if arguments.args.len() == 2 => {
+                    match (&arguments.args[0], &arguments.args[1]) {
+                        (GenericArgument::Lifetime(l), GenericArgument::Type(ty))
+                            if l == lifetime && is_str(ty) =>
+                        {
+                            Some((lifetime.clone(), BorrowedField::Cow))
+                        },
+                        _ => None,
+                    }
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Handle a validator for a struct which borrows the validated string.
+///
+/// The validator itself is implemented for a hidden carrier struct which owns a `String`. The carrier only checks the input, which is then borrowed by the original struct, so parsing does not copy it.
+pub(crate) fn borrowed_handler(
+    validator: Validator,
+    ast: DeriveInput,
+    meta: Meta,
+    lifetime: Lifetime,
+    field: BorrowedField,
+    custom: Option<Path>,
+) -> syn::Result<TokenStream> {
+    let kind = match borrowed_kind(validator) {
+        Some(BorrowedKind::Domain) if field == BorrowedField::Str => {
+            return Err(panic::borrowed_str_unsupported(meta.path()));
+        },
+        Some(kind) => kind,
+        None => return Err(panic::borrowed_unsupported(meta.path())),
+    };
+
+    let name = ast.ident.clone();
+    let carrier_ident = format_ident!("__{name}BuiltinValidator");
+
+    let carrier_ast: DeriveInput = syn::parse_quote! {
+        struct #carrier_ident(validators_prelude::String);
+    };
+
+    let carrier_token_stream =
+        validator_handler(validator, carrier_ast.clone(), meta, &Custom::default())?;
+
+    let file: syn::File = syn::parse2(carrier_token_stream)?;
+
+    let generics = &ast.generics;
+    let self_ty: Type = syn::parse_quote!(#name<#lifetime>);
+
+    let mut error_type: Option<Type> = None;
+    let mut has_deserialize = false;
+
+    let mut carrier_items = TokenStream::new();
+    let mut token_stream = TokenStream::new();
+
+    for item in file.items {
+        if let Item::Impl(item_impl) = &item
+            && let Some(trait_name) = trait_name(item_impl)
+        {
+            if trait_name == "ValidateString" {
+                for item in item_impl.items.iter() {
+                    if let ImplItem::Type(ty) = item
+                        && ty.ident == "Error"
+                    {
+                        error_type = Some(ty.ty.clone());
+                    }
+                }
+            } else if trait_name == "Deserialize" {
+                has_deserialize = true;
+            } else if UNSUPPORTED_TRAITS.contains(&trait_name.as_str()) {
+                continue;
+            }
+
+            if !VALIDATE_TRAITS.contains(&trait_name.as_str())
+                && !CARRIER_ONLY_TRAITS.contains(&trait_name.as_str())
+            {
+                // other traits only read the string, so they can be moved to the original struct
+                let mut item_impl: ItemImpl = syn::parse2(replace_ident(
+                    item_impl.into_token_stream(),
+                    &carrier_ident,
+                    &name,
+                ))?;
+
+                item_impl.generics = generics.clone();
+                *item_impl.self_ty = self_ty.clone();
+
+                item_impl.to_tokens(&mut token_stream);
+
+                continue;
+            }
+        }
+
+        item.to_tokens(&mut carrier_items);
+    }
+
+    let error_type = error_type.unwrap();
+
+    let (error_type, map_builtin_error, check_custom) = match custom {
+        Some(custom) => (
+            quote! { validators_prelude::CustomizedError<#error_type> },
+            quote! { .map_err(validators_prelude::CustomizedError::Builtin) },
+            quote! {
+                if let Err(error) = #custom(&v) {
+                    return Err(validators_prelude::CustomizedError::custom(error));
+                }
+            },
+        ),
+        None => (quote! { #error_type }, quote! {}, quote! {}),
+    };
+
+    let borrow_str = match field {
+        BorrowedField::Str => quote! { s },
+        BorrowedField::Cow => quote! { validators_prelude::Cow::Borrowed(s) },
+    };
+
+    let handle_borrowed_str = match kind {
+        BorrowedKind::Whole => quote! {
+            <#carrier_ident as ValidateString>::validate_str(s)#map_builtin_error?;
+
+            let v = Self(#borrow_str);
+        },
+        BorrowedKind::Domain => quote! {
+            let #carrier_ident(domain) = <#carrier_ident as ValidateString>::parse_str(s)#map_builtin_error?;
+
+            let v = Self(if domain == s { #borrow_str } else { validators_prelude::Cow::Owned(domain) });
+        },
+    };
+
+    token_stream.extend(quote! {
+        impl #generics ValidateStr<#lifetime> for #self_ty {
+            type Error = #error_type;
+
+            #[inline]
+            fn parse_borrowed_str(s: &#lifetime str) -> Result<Self, Self::Error> {
+                #handle_borrowed_str
+
+                #check_custom
+
+                Ok(v)
+            }
+        }
+    });
+
+    if field == BorrowedField::Cow {
+        let validate_str = if check_custom.is_empty() {
+            quote! {
+                <#carrier_ident as ValidateString>::validate_str(s)
+            }
+        } else {
+            quote! {
+                <Self as ValidateString>::parse_str(s)?;
+
+                Ok(())
+            }
+        };
+
+        token_stream.extend(quote! {
+            impl #generics ValidateString for #self_ty {
+                type Error = #error_type;
+
+                #[inline]
+                fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                    let #carrier_ident(s) = <#carrier_ident as ValidateString>::parse_string(s)#map_builtin_error?;
+
+                    let v = Self(validators_prelude::Cow::Owned(s));
+
+                    #check_custom
+
+                    Ok(v)
+                }
+
+                #[inline]
+                fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                    let #carrier_ident(s) = <#carrier_ident as ValidateString>::parse_str(s)#map_builtin_error?;
+
+                    let v = Self(validators_prelude::Cow::Owned(s));
+
+                    #check_custom
+
+                    Ok(v)
+                }
+
+                #[inline]
+                fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                    #validate_str
+                }
+            }
+        });
+    }
+
+    if has_deserialize {
+        let (expect, visit_owned) = match field {
+            BorrowedField::Str => ("a borrowed string", quote! {}),
+            BorrowedField::Cow => ("a string", quote! {
+                #[inline]
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: validators_prelude::serde::de::Error, {
+                    <#self_ty as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
+                }
+
+                #[inline]
+                fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
+                where
+                    E: validators_prelude::serde::de::Error, {
+                    <#self_ty as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
+                }
+            }),
+        };
+
+        token_stream.extend(quote! {
+            impl<'de: #lifetime, #lifetime> validators_prelude::serde::Deserialize<'de> for #self_ty {
+                #[inline]
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: validators_prelude::serde::Deserializer<'de>, {
+                    struct MyVisitor<#lifetime>(::core::marker::PhantomData<&#lifetime ()>);
+
+                    impl<'de: #lifetime, #lifetime> validators_prelude::serde::de::Visitor<'de> for MyVisitor<#lifetime> {
+                        type Value = #self_ty;
+
+                        #[inline]
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str(#expect)
+                        }
+
+                        #[inline]
+                        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+                        where
+                            E: validators_prelude::serde::de::Error, {
+                            <#self_ty as ValidateStr<#lifetime>>::parse_borrowed_str(v).map_err(validators_prelude::de_error)
+                        }
+
+                        #visit_owned
+                    }
+
+                    deserializer.deserialize_str(MyVisitor(::core::marker::PhantomData))
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_camel_case_types)]
+        #carrier_ast
+
+        #carrier_items
+
+        #token_stream
+    })
+}
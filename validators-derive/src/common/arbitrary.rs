@@ -0,0 +1,36 @@
+use quote::quote;
+use syn::Ident;
+
+/// Implement `Arbitrary` of `arbitrary`. `generate` evaluates to `arbitrary::Result` of an input with `u` in scope, usually by calling a function of `validators::generators` with the parsed parameters of the validator, and `parse` validates `v`, the generated input. A rejected input, such as one failing a custom validation, is reported as `IncorrectFormat`.
+pub(crate) fn impl_arbitrary(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    generate: proc_macro2::TokenStream,
+    parse: proc_macro2::TokenStream,
+) {
+    token_stream.extend(quote! {
+        impl<'a> validators_prelude::arbitrary::Arbitrary<'a> for #name {
+            #[inline]
+            fn arbitrary(u: &mut validators_prelude::arbitrary::Unstructured<'a>) -> validators_prelude::arbitrary::Result<Self> {
+                let v = #generate?;
+
+                #parse.map_err(|_| validators_prelude::arbitrary::Error::IncorrectFormat)
+            }
+        }
+    });
+}
+
+/// Generate a string with `generate` and parse it with `ValidateString`.
+#[inline]
+pub(crate) fn impl_arbitrary_string(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    generate: proc_macro2::TokenStream,
+) {
+    impl_arbitrary(
+        token_stream,
+        name,
+        generate,
+        quote! { <Self as ValidateString>::parse_string(v) },
+    );
+}
@@ -0,0 +1,415 @@
+use quote::{ToTokens, quote};
+use syn::{Expr, Lit, Meta, Token, punctuated::Punctuated};
+
+use crate::{
+    common::{
+        allow::Allow, rocket_options::RocketOptions, serde_options::SerdeOptions,
+        tri_allow::TriAllow,
+    },
+    panic,
+};
+
+/// The kind of the values of the `range` parameter.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum DateTimeKind {
+    Date,
+    Time,
+    DateTime,
+}
+
+/// A date or a date-time literal written in the extended format of RFC 3339.
+#[derive(Debug, Clone)]
+pub(crate) struct DateTimeLiteral {
+    year:  u16,
+    month: u8,
+    day:   u8,
+    /// `(hour, minute, second, nanosecond, offset)`
+    time:  Option<(u8, u8, u8, u32, Option<i16>)>,
+}
+
+impl DateTimeLiteral {
+    fn parse(s: &str, kind: DateTimeKind) -> Option<Self> {
+        fn digits(s: &str) -> Option<u32> {
+            if s.is_empty() || !s.bytes().all(|e| e.is_ascii_digit()) {
+                return None;
+            }
+
+            s.parse().ok()
+        }
+
+        if s.len() < 10 || !s.is_char_boundary(10) {
+            return None;
+        }
+
+        let (date, rest) = s.split_at(10);
+
+        if &date[4..5] != "-" || &date[7..8] != "-" {
+            return None;
+        }
+
+        let year = digits(&date[..4])? as u16;
+        let month = digits(&date[5..7])? as u8;
+        let day = digits(&date[8..])? as u8;
+
+        let is_leap_year =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        if day == 0 || day > days_in_month {
+            return None;
+        }
+
+        let time = match kind {
+            DateTimeKind::Date if rest.is_empty() => None,
+            DateTimeKind::DateTime => {
+                let rest = rest.strip_prefix(['T', 't', ' '])?;
+
+                if rest.len() < 8 || !rest.is_char_boundary(8) {
+                    return None;
+                }
+
+                let (time, mut rest) = rest.split_at(8);
+
+                if &time[2..3] != ":" || &time[5..6] != ":" {
+                    return None;
+                }
+
+                let hour = digits(&time[..2])? as u8;
+                let minute = digits(&time[3..5])? as u8;
+                let second = digits(&time[6..])? as u8;
+
+                if hour >= 24 || minute >= 60 || second > 60 {
+                    return None;
+                }
+
+                let mut nanosecond = 0;
+
+                if let Some(fraction) = rest.strip_prefix(['.', ',']) {
+                    let length = fraction.bytes().take_while(|e| e.is_ascii_digit()).count();
+
+                    if length == 0 || length > 9 {
+                        return None;
+                    }
+
+                    nanosecond = digits(&fraction[..length])? * 10u32.pow(9 - length as u32);
+
+                    rest = &fraction[length..];
+                }
+
+                let offset = match rest {
+                    "" => None,
+                    "Z" | "z" => Some(0),
+                    _ => {
+                        let (sign, offset) = rest.split_at_checked(1)?;
+
+                        if offset.len() != 5 || &offset[2..3] != ":" {
+                            return None;
+                        }
+
+                        let hour = digits(&offset[..2])?;
+                        let minute = digits(&offset[3..])?;
+
+                        if hour >= 24 || minute >= 60 {
+                            return None;
+                        }
+
+                        let offset = (hour * 60 + minute) as i16;
+
+                        match sign {
+                            "+" => Some(offset),
+                            "-" => Some(-offset),
+                            _ => return None,
+                        }
+                    },
+                };
+
+                Some((hour, minute, second, nanosecond, offset))
+            },
+            _ => return None,
+        };
+
+        Some(Self {
+            year,
+            month,
+            day,
+            time,
+        })
+    }
+}
+
+impl ToTokens for DateTimeLiteral {
+    fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
+        let Self {
+            year,
+            month,
+            day,
+            time,
+        } = self;
+
+        let date = quote! {
+            validators_prelude::Date {
+                year: #year,
+                month: #month,
+                day: #day,
+            }
+        };
+
+        match time {
+            Some((hour, minute, second, nanosecond, offset)) => {
+                let offset = match offset {
+                    Some(offset) => quote! { Some(#offset) },
+                    None => quote! { None },
+                };
+
+                token_stream.extend(quote! {
+                    validators_prelude::DateTime {
+                        date: #date,
+                        time: validators_prelude::Time {
+                            hour: #hour,
+                            minute: #minute,
+                            second: #second,
+                            nanosecond: #nanosecond,
+                            offset: #offset,
+                        },
+                    }
+                });
+            },
+            None => token_stream.extend(date),
+        }
+    }
+}
+
+pub(crate) struct DateTimeAttribute {
+    pub(crate) basic_format:   TriAllow,
+    pub(crate) timezone:       TriAllow,
+    pub(crate) fraction:       Allow,
+    pub(crate) min:            Option<DateTimeLiteral>,
+    pub(crate) max:            Option<DateTimeLiteral>,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) serde_options:  SerdeOptions,
+    #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
+    pub(crate) rocket_options: RocketOptions,
+}
+
+impl DateTimeAttribute {
+    pub(crate) fn build_from_meta(meta: &Meta, kind: DateTimeKind) -> syn::Result<Self> {
+        let correct_parameters: &[&str] = match kind {
+            DateTimeKind::Date => &["basic_format", "range", "serde", "rocket"],
+            DateTimeKind::Time => &["basic_format", "timezone", "fraction", "serde", "rocket"],
+            DateTimeKind::DateTime => {
+                &["basic_format", "timezone", "fraction", "range", "serde", "rocket"]
+            },
+        };
+
+        let mut basic_format = TriAllow::Disallow;
+        let mut timezone = TriAllow::Allow;
+        let mut fraction = Allow::Allow;
+        let mut min = None;
+        let mut max = None;
+        let mut serde_options = SerdeOptions::default();
+        let mut rocket_options = RocketOptions::default();
+
+        match meta {
+            Meta::Path(_) => (),
+            Meta::NameValue(_) => {
+                return Err(panic::attribute_incorrect_format(meta.path()));
+            },
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+                let mut basic_format_is_set = false;
+                let mut timezone_is_set = false;
+                let mut fraction_is_set = false;
+                let mut range_is_set = false;
+                let mut serde_options_is_set = false;
+                let mut rocket_options_is_set = false;
+
+                let mut handler = |meta: &Meta| -> syn::Result<bool> {
+                    if let Some(ident) = meta.path().get_ident() {
+                        let ident_string = ident.to_string();
+
+                        if !correct_parameters.contains(&ident_string.as_str()) {
+                            return Ok(false);
+                        }
+
+                        match ident_string.as_str() {
+                            "basic_format" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if basic_format_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                basic_format_is_set = true;
+
+                                basic_format = v;
+
+                                return Ok(true);
+                            },
+                            "timezone" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if timezone_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                timezone_is_set = true;
+
+                                timezone = v;
+
+                                return Ok(true);
+                            },
+                            "fraction" => {
+                                let v = Allow::from_meta(meta)?;
+
+                                if fraction_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                fraction_is_set = true;
+
+                                fraction = v;
+
+                                return Ok(true);
+                            },
+                            "range" => {
+                                let (v_min, v_max) = range_from_meta(meta, kind)?;
+
+                                if range_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                range_is_set = true;
+
+                                min = v_min;
+                                max = v_max;
+
+                                return Ok(true);
+                            },
+                            "serde" => {
+                                let v = SerdeOptions::from_meta(meta)?;
+
+                                if serde_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                serde_options_is_set = true;
+
+                                serde_options = v;
+
+                                return Ok(true);
+                            },
+                            "rocket" => {
+                                let v = RocketOptions::from_meta(meta)?;
+
+                                if rocket_options_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                rocket_options_is_set = true;
+
+                                rocket_options = v;
+
+                                return Ok(true);
+                            },
+                            _ => (),
+                        }
+                    }
+
+                    Ok(false)
+                };
+
+                for p in result {
+                    if !handler(&p)? {
+                        return Err(panic::parameter_incorrect_format(
+                            p.path(),
+                            correct_parameters,
+                        ));
+                    }
+                }
+            },
+        }
+
+        Ok(Self {
+            basic_format,
+            timezone,
+            fraction,
+            min,
+            max,
+            serde_options,
+            rocket_options,
+        })
+    }
+}
+
+/// Parse `range(min = "...", max = "...")`. Both bounds are inclusive.
+fn range_from_meta(
+    meta: &Meta,
+    kind: DateTimeKind,
+) -> syn::Result<(Option<DateTimeLiteral>, Option<DateTimeLiteral>)> {
+    let correct_parameters = ["min", "max"];
+
+    let expect = match kind {
+        DateTimeKind::DateTime => "expected a date-time string such as \"2000-01-01T00:00:00Z\"",
+        _ => "expected a date string such as \"2000-01-01\"",
+    };
+
+    let mut min = None;
+    let mut max = None;
+
+    match meta {
+        Meta::List(list) => {
+            let result = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+            for p in result {
+                let ident = match p.path().get_ident() {
+                    Some(ident) if correct_parameters.contains(&ident.to_string().as_str()) => {
+                        ident
+                    },
+                    _ => {
+                        return Err(panic::parameter_incorrect_format(
+                            p.path(),
+                            &correct_parameters,
+                        ));
+                    },
+                };
+
+                let v = match &p {
+                    Meta::NameValue(name_value) => match &name_value.value {
+                        Expr::Lit(lit) => match &lit.lit {
+                            Lit::Str(s) => DateTimeLiteral::parse(&s.value(), kind)
+                                .ok_or_else(|| syn::Error::new_spanned(s, expect))?,
+                            _ => return Err(syn::Error::new_spanned(lit, expect)),
+                        },
+                        value => return Err(syn::Error::new_spanned(value, expect)),
+                    },
+                    _ => return Err(syn::Error::new_spanned(&p, expect)),
+                };
+
+                let target = if ident == "min" { &mut min } else { &mut max };
+
+                if target.is_some() {
+                    return Err(panic::parameter_reset(ident));
+                }
+
+                *target = Some(v);
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "expected `range(min = \"...\", max = \"...\")`",
+            ));
+        },
+    }
+
+    Ok((min, max))
+}
@@ -1,23 +1,30 @@
 use syn::{Meta, Token, punctuated::Punctuated};
 
 use crate::{
-    common::{rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow},
+    common::{
+        domain_list::DomainList, rocket_options::RocketOptions, serde_options::SerdeOptions,
+        tri_allow::TriAllow,
+    },
     panic,
 };
 
 pub(crate) struct HttpXXUrlAttribute {
-    pub(crate) local:          TriAllow,
+    pub(crate) local:           TriAllow,
+    pub(crate) allowed_domains: Option<DomainList>,
+    pub(crate) denied_domains:  Option<DomainList>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
-    pub(crate) serde_options:  SerdeOptions,
+    pub(crate) serde_options:   SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
-    pub(crate) rocket_options: RocketOptions,
+    pub(crate) rocket_options:  RocketOptions,
 }
 
 impl HttpXXUrlAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["local", "serde", "rocket"];
+        let correct_parameters = ["local", "allowed_domains", "denied_domains", "serde", "rocket"];
 
         let mut local = TriAllow::Allow;
+        let mut allowed_domains = None;
+        let mut denied_domains = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
 
@@ -31,6 +38,8 @@ impl HttpXXUrlAttribute {
                     list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
 
                 let mut local_is_set = false;
+                let mut allowed_domains_is_set = false;
+                let mut denied_domains_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
 
@@ -50,6 +59,32 @@ impl HttpXXUrlAttribute {
 
                                 return Ok(true);
                             },
+                            "allowed_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if allowed_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                allowed_domains_is_set = true;
+
+                                allowed_domains = Some(v);
+
+                                return Ok(true);
+                            },
+                            "denied_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if denied_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                denied_domains_is_set = true;
+
+                                denied_domains = Some(v);
+
+                                return Ok(true);
+                            },
                             "serde" => {
                                 let v = SerdeOptions::from_meta(meta)?;
 
@@ -96,6 +131,8 @@ impl HttpXXUrlAttribute {
 
         Ok(Self {
             local,
+            allowed_domains,
+            denied_domains,
             serde_options,
             rocket_options,
         })
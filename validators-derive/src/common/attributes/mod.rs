@@ -7,14 +7,9 @@
     feature = "base64_url_decoded",
 ))]
 pub(crate) mod base_xx_attribute;
-#[cfg(any(
-    feature = "boolean",
-    feature = "json",
-    feature = "semver",
-    feature = "semver_req",
-    feature = "url"
-))]
 pub(crate) mod basic_attribute;
+#[cfg(any(feature = "date", feature = "time", feature = "datetime"))]
+pub(crate) mod date_time_attribute;
 #[cfg(any(feature = "http_url", feature = "http_ftp_url"))]
 pub(crate) mod http_xx_url_attribute;
 #[cfg(any(feature = "ip", feature = "ipv4", feature = "ipv6"))]
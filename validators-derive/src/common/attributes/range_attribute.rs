@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use syn::{Meta, Token, punctuated::Punctuated};
 
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+use crate::common::schema::SchemaKeywords;
 use crate::{
     common::{
         range::RangedNumber,
@@ -14,6 +16,10 @@ use crate::{
 
 pub(crate) struct RangeAttribute {
     pub(crate) range:          RangeTokenStream,
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub(crate) range_schema:   SchemaKeywords,
+    #[cfg(feature = "clap")]
+    pub(crate) range_hint:     Option<String>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
@@ -27,6 +33,10 @@ impl RangeAttribute {
         let correct_parameters = ["range", "serde", "rocket"];
 
         let mut range = RangeTokenStream::Unlimited;
+        #[cfg(any(feature = "schemars", feature = "utoipa"))]
+        let mut range_schema = SchemaKeywords::default();
+        #[cfg(feature = "clap")]
+        let mut range_hint = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
 
@@ -55,6 +65,16 @@ impl RangeAttribute {
 
                                 range_is_set = true;
 
+                                #[cfg(any(feature = "schemars", feature = "utoipa"))]
+                                {
+                                    range_schema = v.to_schema_keywords();
+                                }
+
+                                #[cfg(feature = "clap")]
+                                {
+                                    range_hint = v.to_hint();
+                                }
+
                                 range = v.into();
 
                                 return Ok(true);
@@ -105,6 +125,10 @@ impl RangeAttribute {
 
         Ok(Self {
             range,
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            range_schema,
+            #[cfg(feature = "clap")]
+            range_hint,
             serde_options,
             rocket_options,
         })
@@ -0,0 +1,33 @@
+use quote::quote;
+use syn::Ident;
+
+/// Implement `ValueParserFactory` so that `#[arg(value_parser)]` picks up the validator. The `hint` is appended to the error message, and the `possible_values` with their hidden aliases are listed in the help output.
+#[inline]
+pub(crate) fn impl_value_parser_factory(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    hint: Option<&str>,
+    possible_values: &[(&str, &[&str])],
+) {
+    let hint = hint.map(|hint| quote! { .hint(#hint) });
+
+    let possible_values = if possible_values.is_empty() {
+        None
+    } else {
+        let values =
+            possible_values.iter().map(|(value, aliases)| quote! { (#value, &[#(#aliases),*]) });
+
+        Some(quote! { .possible_values(&[#(#values),*]) })
+    };
+
+    token_stream.extend(quote! {
+        impl validators_prelude::clap::builder::ValueParserFactory for #name {
+            type Parser = validators_prelude::ValidatorValueParser<Self>;
+
+            #[inline]
+            fn value_parser() -> Self::Parser {
+                validators_prelude::ValidatorValueParser::new()#hint #possible_values
+            }
+        }
+    });
+}
@@ -0,0 +1,153 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, Generics, Ident, Meta, Path, Token, punctuated::Punctuated};
+
+use crate::panic;
+
+/// Take the `custom = path::to::fn` parameter out of a validator meta, so that the rest can be handled by the validator itself.
+pub(crate) fn take_custom(meta: Meta) -> syn::Result<(Meta, Option<Path>)> {
+    let list = match meta {
+        Meta::List(list) => list,
+        _ => return Ok((meta, None)),
+    };
+
+    // leave parameters which are not metas to the validator, which reports the format error
+    let parameters = match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(parameters) => parameters,
+        Err(_) => return Ok((Meta::List(list), None)),
+    };
+
+    let mut custom: Option<Path> = None;
+    let mut rest: Punctuated<Meta, Token![,]> = Punctuated::new();
+
+    for p in parameters {
+        if let Meta::NameValue(name_value) = &p
+            && name_value.path.is_ident("custom")
+        {
+            if custom.is_some() {
+                return Err(panic::parameter_reset(name_value.path.get_ident().unwrap()));
+            }
+
+            match &name_value.value {
+                Expr::Path(path) if path.qself.is_none() => custom = Some(path.path.clone()),
+                _ => return Err(panic::custom_format_incorrect(&name_value.value)),
+            }
+        } else {
+            rest.push(p);
+        }
+    }
+
+    if custom.is_none() {
+        return Ok((Meta::List(list), None));
+    }
+
+    let path = list.path;
+
+    let meta = if rest.is_empty() { Meta::Path(path) } else { syn::parse_quote!(#path(#rest)) };
+
+    Ok((meta, custom))
+}
+
+/// The `custom = path::to::fn` parameter of a validator. The validator handlers use it in the implementations of the validating traits, so that the function is called on every parsed value.
+#[derive(Default)]
+pub(crate) struct Custom(Option<Path>);
+
+impl Custom {
+    #[inline]
+    pub(crate) fn new(custom: Option<Path>) -> Self {
+        Self(custom)
+    }
+
+    /// Determine whether there is a custom function.
+    #[allow(dead_code)] // unused if none of the validators with `const_new` is enabled
+    #[inline]
+    pub(crate) fn is_some(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// The `Error` type of the validating traits, which is wrapped by `CustomizedError` if there is a custom function.
+    #[inline]
+    pub(crate) fn error_type(&self, error_path: &Path) -> TokenStream {
+        match self.0 {
+            Some(_) => quote! { validators_prelude::CustomizedError<#error_path> },
+            None => quote! { #error_path },
+        }
+    }
+
+    /// The function which a `parse_*` method returns a created validator with, like `#ok(Self(s))`. It is `Self::v_custom` if there is a custom function, or `Ok` otherwise.
+    #[inline]
+    pub(crate) fn ok(&self) -> TokenStream {
+        match self.0 {
+            Some(_) => quote! { Self::v_custom },
+            None => quote! { Ok },
+        }
+    }
+
+    /// The body of a `validate_*` method. `builtin` only runs the built-in checks, so the validator is created by `parse` instead if there is a custom function, which needs the value.
+    #[allow(dead_code)] // unused if only the `length` validator is enabled
+    #[inline]
+    pub(crate) fn validate(&self, parse: TokenStream, builtin: TokenStream) -> TokenStream {
+        match self.0 {
+            Some(_) => quote! {
+                #parse?;
+
+                Ok(())
+            },
+            None => builtin,
+        }
+    }
+
+    /// The body of a `validate_*_detailed` method. `validate` calls the `validate_*` method, and `details` builds the details of the error of the built-in checks, which is bound to `error` by reference. An error from the custom function has no details.
+    #[allow(dead_code)] // unused if only the validators without details are enabled
+    #[inline]
+    pub(crate) fn detailed(&self, validate: TokenStream, details: TokenStream) -> TokenStream {
+        let details = match self.0 {
+            Some(_) => quote! {
+                match &error {
+                    validators_prelude::CustomizedError::Builtin(error) => #details,
+                    validators_prelude::CustomizedError::Custom(_) => validators_prelude::Vec::new(),
+                }
+            },
+            None => quote! {
+                {
+                    let error = &error;
+
+                    #details
+                }
+            },
+        };
+
+        quote! {
+            #validate.map_err(|error| {
+                let details = #details;
+
+                validators_prelude::DetailedError::new(error).with_params(details)
+            })
+        }
+    }
+
+    /// Implement `v_custom`, which `ok` refers to, if there is a custom function.
+    pub(crate) fn impl_v_custom(
+        &self,
+        token_stream: &mut TokenStream,
+        name: &Ident,
+        generics: &Generics,
+    ) {
+        if let Some(custom) = &self.0 {
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            token_stream.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #[inline]
+                    fn v_custom<E>(v: Self) -> Result<Self, validators_prelude::CustomizedError<E>> {
+                        if let Err(error) = #custom(&v) {
+                            return Err(validators_prelude::CustomizedError::custom(error));
+                        }
+
+                        Ok(v)
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use quote::quote;
+use syn::{Expr, Lit, Meta, Path, Token, punctuated::Punctuated};
+
+use crate::common::path_to_string;
+
+/// The domains of `allowed_domains` or `denied_domains`, which are literals or an expression of a `DomainSet`, such as the path to a `static`.
+pub(crate) enum DomainList {
+    Literals(Vec<String>),
+    Expr(Expr),
+}
+
+impl DomainList {
+    #[inline]
+    fn from_exprs(path: &Path, exprs: Vec<Expr>) -> syn::Result<Self> {
+        let mut literals = Vec::with_capacity(exprs.len());
+
+        for expr in exprs {
+            match expr {
+                Expr::Lit(lit) => {
+                    let Lit::Str(lit) = &lit.lit else {
+                        return Err(syn::Error::new_spanned(lit, "expected `\"domain\"`"));
+                    };
+
+                    let domain = lit.value();
+
+                    if domain.is_empty() || !domain.is_ascii() {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "a domain must be non-empty ASCII, with IDNs in Punycode (`xn--`)",
+                        ));
+                    }
+
+                    literals.push(domain);
+                },
+                expr if literals.is_empty() => return Ok(Self::Expr(expr)),
+                expr => {
+                    return Err(syn::Error::new_spanned(
+                        expr,
+                        "expected `\"domain\"`, or only one expr for a `DomainSet`",
+                    ));
+                },
+            }
+        }
+
+        if literals.is_empty() {
+            return Err(syn::Error::new_spanned(path, "at least one domain is needed"));
+        }
+
+        Ok(Self::Literals(literals))
+    }
+
+    #[inline]
+    pub(crate) fn from_meta(meta: &Meta) -> syn::Result<Self> {
+        let path = meta.path();
+
+        match meta {
+            Meta::NameValue(name_value) => {
+                return Self::from_exprs(path, vec![name_value.value.clone()]);
+            },
+            Meta::List(list) => {
+                let exprs =
+                    list.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+
+                if exprs.len() == 1 || exprs.iter().all(|expr| matches!(expr, Expr::Lit(_))) {
+                    return Self::from_exprs(path, exprs.into_iter().collect());
+                }
+            },
+            Meta::Path(_) => (),
+        }
+
+        Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "expected `{path}(\"domain\", ...)` or `{path}(DOMAIN_SET)`",
+                path = path_to_string(path)
+            ),
+        ))
+    }
+
+    #[inline]
+    fn to_set(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Literals(literals) => quote! { [#(#literals),*] },
+            Self::Expr(expr) => quote! { (#expr) },
+        }
+    }
+}
+
+/// Generate the checks of `allowed_domains` and `denied_domains` for the `domain: Option<&str>` in the scope, which is `None` for an IP. An IP is never in `allowed_domains`.
+pub(crate) fn handle_domain_lists(
+    allowed_domains: Option<&DomainList>,
+    denied_domains: Option<&DomainList>,
+    error_path: &Path,
+) -> proc_macro2::TokenStream {
+    let mut token_stream = proc_macro2::TokenStream::new();
+
+    if let Some(allowed_domains) = allowed_domains {
+        let set = allowed_domains.to_set();
+
+        token_stream.extend(quote! {
+            if !domain.is_some_and(|domain| #set.contains_domain(domain)) {
+                return Err(#error_path::DomainNotAllowed);
+            }
+        });
+    }
+
+    if let Some(denied_domains) = denied_domains {
+        let set = denied_domains.to_set();
+
+        token_stream.extend(quote! {
+            if domain.is_some_and(|domain| #set.contains_domain(domain)) {
+                return Err(#error_path::DomainDenied);
+            }
+        });
+    }
+
+    token_stream
+}
@@ -0,0 +1,17 @@
+use quote::quote;
+use syn::Path;
+
+use crate::common::tri_allow::TriAllow;
+
+/// Generate the check of `known_tld` for the `domain: Option<&str>` in the scope, which is `None` for an IP. An IP is not checked.
+pub(crate) fn handle_known_tld(known_tld: TriAllow, error_path: &Path) -> proc_macro2::TokenStream {
+    if known_tld.must() {
+        quote! {
+            if domain.is_some_and(|domain| !validators_prelude::is_known_tld(domain)) {
+                return Err(#error_path::KnownTLDMust);
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
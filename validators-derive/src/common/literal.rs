@@ -0,0 +1,38 @@
+use quote::quote;
+use syn::Ident;
+
+use crate::common::custom::Custom;
+
+/// Implement `const_new`, which parses a literal by the `v_parse_str` function generated as a `const fn`, so an invalid literal in a const context fails the build.
+///
+/// A custom function cannot be called in a const context, so there is no `const_new` if the validator has one. Otherwise, a literal which `parse_str` rejects could be created.
+#[inline]
+pub(crate) fn impl_const_new(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    custom: &Custom,
+) {
+    if custom.is_some() {
+        return;
+    }
+
+    let message = format!("the literal is not a valid `{name}`");
+
+    token_stream.extend(quote! {
+        impl #name {
+            /// Parse a literal in a const context, where an invalid literal fails the build. The `validators::literal!` macro makes sure that the literal is parsed at compile time.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the literal is invalid.
+            #[allow(dead_code)]
+            #[inline]
+            pub const fn const_new(s: &str) -> Self {
+                match Self::v_parse_str(s) {
+                    Ok(v) => Self(v),
+                    Err(_) => panic!(#message),
+                }
+            }
+        }
+    });
+}
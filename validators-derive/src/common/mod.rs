@@ -1,5 +1,6 @@
 #[allow(dead_code)]
 pub(crate) mod attributes;
+pub(crate) mod custom;
 #[allow(dead_code)]
 pub(crate) mod flag_options;
 #[allow(dead_code)]
@@ -11,25 +12,31 @@ pub(crate) mod type_enum;
 
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
+    feature = "date",
+    feature = "datetime",
     feature = "domain",
     feature = "email",
     feature = "number",
     feature = "signed_integer",
+    feature = "time",
     feature = "unsigned_integer",
 ))]
 #[allow(dead_code)]
 pub(crate) mod allow;
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
     feature = "bit",
     feature = "byte",
+    feature = "duration",
     feature = "number",
     feature = "signed_integer",
     feature = "unsigned_integer",
 ))]
 #[allow(dead_code)]
 pub(crate) mod boolean;
-#[cfg(any(feature = "test", feature = "mac_address", feature = "uuid"))]
+#[cfg(any(feature = "test", feature = "arbitrary", feature = "mac_address", feature = "uuid"))]
 #[allow(dead_code)]
 pub(crate) mod case_option;
 #[cfg(any(feature = "line", feature = "text"))]
@@ -37,8 +44,10 @@ pub(crate) mod case_option;
 pub(crate) mod length;
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
     feature = "bit",
     feature = "byte",
+    feature = "duration",
     feature = "length",
     feature = "line",
     feature = "number",
@@ -50,8 +59,10 @@ pub(crate) mod length;
 pub(crate) mod number;
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
     feature = "bit",
     feature = "byte",
+    feature = "duration",
     feature = "number",
     feature = "signed_integer",
     feature = "unsigned_integer"
@@ -60,23 +71,27 @@ pub(crate) mod number;
 pub(crate) mod range;
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
     feature = "number",
     feature = "signed_integer",
     feature = "unsigned_integer"
 ))]
 #[allow(dead_code)]
 pub(crate) mod range_option;
-#[cfg(any(feature = "test", feature = "mac_address", feature = "uuid"))]
+#[cfg(any(feature = "test", feature = "arbitrary", feature = "mac_address", feature = "uuid"))]
 #[allow(dead_code)]
 pub(crate) mod separator_option;
 #[cfg(any(
     feature = "test",
+    feature = "arbitrary",
     feature = "base32",
     feature = "base32_decoded",
     feature = "base64",
     feature = "base64_decoded",
     feature = "base64_url",
     feature = "base64_url_decoded",
+    feature = "date",
+    feature = "datetime",
     feature = "domain",
     feature = "email",
     feature = "host",
@@ -87,16 +102,53 @@ pub(crate) mod separator_option;
     feature = "ipv6",
     feature = "number",
     feature = "signed_integer",
+    feature = "time",
     feature = "unsigned_integer",
 ))]
 #[allow(dead_code)]
 pub(crate) mod tri_allow;
 
+#[cfg(any(
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "http_ftp_url",
+))]
+pub(crate) mod domain_list;
+#[cfg(any(feature = "domain", feature = "email"))]
+pub(crate) mod known_tld;
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+pub(crate) mod registrable;
+
+#[cfg(any(
+    feature = "mac_address",
+    feature = "signed_integer",
+    feature = "unsigned_integer",
+    feature = "uuid"
+))]
+pub(crate) mod literal;
+
 #[cfg(feature = "rocket")]
 #[allow(dead_code)]
 pub(crate) mod rocket;
 
-#[cfg(feature = "test")]
+#[cfg(feature = "clap")]
+pub(crate) mod clap;
+
+#[cfg(feature = "sqlx")]
+#[allow(dead_code)]
+pub(crate) mod sqlx;
+
+#[cfg(feature = "arbitrary")]
+#[allow(dead_code)]
+pub(crate) mod arbitrary;
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+#[allow(dead_code)]
+pub(crate) mod schema;
+
+#[cfg(any(feature = "test", feature = "arbitrary"))]
 pub(crate) mod test;
 
 use quote::ToTokens;
@@ -1,7 +1,10 @@
 use std::{fmt::Display, str::FromStr};
 
-use syn::Meta;
+use quote::quote;
+use syn::{Meta, Path, Type};
 
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+use crate::common::schema::SchemaKeywords;
 use crate::common::{
     path_to_string,
     range::{Range, RangedNumber, range_equal},
@@ -73,6 +76,74 @@ where
     }
 }
 
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+impl<T: RangedNumber> RangeOption<T>
+where
+    T::Err: Display,
+{
+    /// Convert the range to JSON Schema keywords. An `Outside` range becomes a `not` schema, which only `schemars` can express.
+    pub(crate) fn to_schema_keywords(self) -> SchemaKeywords {
+        let mut keywords = SchemaKeywords::default();
+
+        match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+            } => keywords.set_range(min, max, inclusive),
+            Self::Outside {
+                min,
+                max,
+                inclusive,
+            } => {
+                if min.is_some() || max.is_some() {
+                    let mut forbidden = SchemaKeywords::default();
+
+                    forbidden.set_range(min, max, inclusive);
+
+                    keywords.not = Some(Box::new(forbidden));
+                }
+            },
+            Self::Unlimited => (),
+        }
+
+        keywords
+    }
+}
+
+#[cfg(feature = "clap")]
+impl<T: RangedNumber> RangeOption<T>
+where
+    T::Err: Display,
+{
+    /// Describe the range in the syntax of Rust, such as `1..=65535`, or `not 1..=65535` for an `Outside` range.
+    pub(crate) fn to_hint(self) -> Option<String> {
+        let (prefix, min, max, inclusive) = match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+            } => ("", min, max, inclusive),
+            Self::Outside {
+                min,
+                max,
+                inclusive,
+            } => ("not ", min, max, inclusive),
+            Self::Unlimited => return None,
+        };
+
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+
+        let min = min.map(|v| v.to_string()).unwrap_or_default();
+        let max = max.map(|v| v.to_string()).unwrap_or_default();
+        let op = if inclusive && !max.is_empty() { "..=" } else { ".." };
+
+        Some(format!("{prefix}{min}{op}{max}"))
+    }
+}
+
 pub(crate) enum RangeTokenStream {
     Inside {
         min:       Option<proc_macro2::TokenStream>,
@@ -94,6 +165,82 @@ impl RangeTokenStream {
     pub(crate) fn inside(&self) -> bool {
         matches!(self, Self::Inside { .. })
     }
+
+    /// Build the body of a `v_details` function, which gives the details of the `TooSmall`, `TooLarge` and `Forbidden` errors of `error_path` for the rejected value `v` of the `wide` type. `param` is the variant of `ErrorParam` for the `wide` type.
+    ///
+    /// If `v` can be out of the range of `data_type`, the bounds of `data_type` are reported when it is, because the range was not even checked. `type_min` and `type_max` decide which bounds have to be checked.
+    pub(crate) fn details(
+        &self,
+        error_path: &Path,
+        data_type: &Type,
+        wide: proc_macro2::TokenStream,
+        param: proc_macro2::TokenStream,
+        type_min: bool,
+        type_max: bool,
+    ) -> proc_macro2::TokenStream {
+        let (min, max, inclusive) = match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+                ..
+            }
+            | Self::Outside {
+                min,
+                max,
+                inclusive,
+                ..
+            } => (min.as_ref(), max.as_ref(), *inclusive),
+            Self::Unlimited => (None, None, true),
+        };
+
+        let min = min.map(|min| quote! { (#min) as #wide });
+        let max = max.map(|max| quote! { (#max) as #wide });
+
+        let forbidden_min = min.as_ref().map(|min| quote! { details.push(("min", #param(#min))); });
+        let forbidden_max = max.as_ref().map(|max| quote! { details.push(("max", #param(#max))); });
+
+        let data_type_min = quote! { (<#data_type>::MIN as #wide) };
+        let data_type_max = quote! { (<#data_type>::MAX as #wide) };
+
+        let min = min.unwrap_or_else(|| data_type_min.clone());
+        let max = max.unwrap_or_else(|| data_type_max.clone());
+
+        let min = if type_min {
+            quote! { if v < #data_type_min { #data_type_min } else { #min } }
+        } else {
+            min
+        };
+
+        let max = if type_max {
+            quote! { if v > #data_type_max { (#data_type_max, true) } else { (#max, #inclusive) } }
+        } else {
+            quote! { (#max, #inclusive) }
+        };
+
+        quote! {
+            match error {
+                #error_path::TooSmall => validators_prelude::vec![("min", #param(#min)), ("value", #param(v))],
+                #error_path::TooLarge => {
+                    let (max, inclusive) = #max;
+
+                    validators_prelude::vec![("max", #param(max)), ("inclusive", ErrorParam::Bool(inclusive)), ("value", #param(v))]
+                },
+                #error_path::Forbidden => {
+                    let mut details = validators_prelude::Vec::with_capacity(4);
+
+                    #forbidden_min
+                    #forbidden_max
+
+                    details.push(("inclusive", ErrorParam::Bool(#inclusive)));
+                    details.push(("value", #param(v)));
+
+                    details
+                },
+                _ => validators_prelude::Vec::new(),
+            }
+        }
+    }
 }
 
 impl<T: RangedNumber> From<RangeOption<T>> for RangeTokenStream
@@ -0,0 +1,50 @@
+use proc_macro2::Ident;
+use quote::quote;
+use syn::Path;
+
+use crate::common::tri_allow::TriAllow;
+
+/// Generate the check of `registrable` for the `domain: Option<&str>` in the scope, which is `None` for an IP. An IP is never registrable.
+pub(crate) fn handle_registrable(
+    registrable: TriAllow,
+    error_path: &Path,
+) -> proc_macro2::TokenStream {
+    match registrable {
+        TriAllow::Allow => quote! {},
+        TriAllow::Must => quote! {
+            if !domain.is_some_and(validators_prelude::is_registrable_domain) {
+                return Err(#error_path::RegistrableMust);
+            }
+        },
+        TriAllow::Disallow => quote! {
+            if domain.is_some_and(validators_prelude::is_registrable_domain) {
+                return Err(#error_path::RegistrableDisallow);
+            }
+        },
+    }
+}
+
+/// Implement `RegistrableDomain` with `domain`, an expression of `Option<&str>` from `self`, which is `None` for an IP.
+pub(crate) fn impl_registrable_domain(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    domain: proc_macro2::TokenStream,
+) {
+    token_stream.extend(quote! {
+        impl RegistrableDomain for #name {
+            #[inline]
+            fn get_public_suffix(&self) -> Option<&str> {
+                let domain: Option<&str> = { #domain };
+
+                domain.and_then(validators_prelude::public_suffix)
+            }
+
+            #[inline]
+            fn get_registrable_domain(&self) -> Option<&str> {
+                let domain: Option<&str> = { #domain };
+
+                domain.and_then(validators_prelude::registrable_domain)
+            }
+        }
+    });
+}
@@ -1,5 +1,5 @@
 use quote::quote;
-use syn::{Ident, Path};
+use syn::Ident;
 
 #[inline]
 pub(crate) fn impl_from_form_field(token_stream: &mut proc_macro2::TokenStream, name: &Ident) {
@@ -17,11 +17,11 @@ pub(crate) fn impl_from_form_field(token_stream: &mut proc_macro2::TokenStream,
 pub(crate) fn impl_from_param(
     token_stream: &mut proc_macro2::TokenStream,
     name: &Ident,
-    error_path: &Path,
+    error_type: &proc_macro2::TokenStream,
 ) {
     token_stream.extend(quote! {
         impl<'r> validators_prelude::rocket::request::FromParam<'r> for #name {
-            type Error = #error_path;
+            type Error = #error_type;
 
             #[inline]
             fn from_param(v: &'r str) -> Result<Self, Self::Error> {
@@ -0,0 +1,362 @@
+use std::fmt::Display;
+
+use quote::{ToTokens, quote};
+use syn::Ident;
+
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+use crate::common::{case_option::CaseOption, separator_option::SeparatorOption};
+
+/// The keywords of a schema, collected while the macro expands. They become a `json_schema!({ ... })` object for `schemars` and a chain of builder calls for `utoipa`.
+#[derive(Default)]
+pub(crate) struct SchemaKeywords {
+    pub(crate) types:             Vec<&'static str>,
+    pub(crate) format:            Option<&'static str>,
+    pub(crate) pattern:           Option<String>,
+    pub(crate) content_encoding:  Option<&'static str>,
+    pub(crate) min_length:        Option<usize>,
+    pub(crate) max_length:        Option<usize>,
+    pub(crate) min_items:         Option<usize>,
+    pub(crate) max_items:         Option<usize>,
+    pub(crate) minimum:           Option<proc_macro2::TokenStream>,
+    pub(crate) maximum:           Option<proc_macro2::TokenStream>,
+    pub(crate) exclusive_maximum: Option<proc_macro2::TokenStream>,
+    /// Only `schemars` can express it. `utoipa` ignores it.
+    pub(crate) not:               Option<Box<SchemaKeywords>>,
+}
+
+impl SchemaKeywords {
+    #[inline]
+    pub(crate) fn new(ty: &'static str) -> Self {
+        Self::with_types(&[ty])
+    }
+
+    #[inline]
+    pub(crate) fn with_types(types: &[&'static str]) -> Self {
+        Self {
+            types: types.to_vec(),
+            ..Self::default()
+        }
+    }
+
+    /// Set `minimum` and `maximum` (or `exclusiveMaximum`). The minimum is always inclusive, just like the `range` parameters.
+    pub(crate) fn set_range<T: Display>(
+        &mut self,
+        min: Option<T>,
+        max: Option<T>,
+        inclusive: bool,
+    ) {
+        self.minimum = min.map(json_number);
+
+        if inclusive {
+            self.maximum = max.map(json_number);
+        } else {
+            self.exclusive_maximum = max.map(json_number);
+        }
+    }
+
+    /// Fill the keywords which are not set yet with the ones of `other`.
+    pub(crate) fn extend(&mut self, other: Self) {
+        if self.types.is_empty() {
+            self.types = other.types;
+        }
+
+        macro_rules! extend {
+            ($($field:ident),*) => {
+                $(
+                    if self.$field.is_none() {
+                        self.$field = other.$field;
+                    }
+                )*
+            };
+        }
+
+        extend!(
+            format,
+            pattern,
+            content_encoding,
+            min_length,
+            max_length,
+            min_items,
+            max_items,
+            minimum,
+            maximum,
+            exclusive_maximum,
+            not
+        );
+    }
+
+    /// Render the keywords as the object of `json_schema!`.
+    #[cfg(feature = "schemars")]
+    fn to_json(&self) -> proc_macro2::TokenStream {
+        let mut keys: Vec<&'static str> = Vec::new();
+        let mut values: Vec<proc_macro2::TokenStream> = Vec::new();
+
+        let mut insert = |key, value: proc_macro2::TokenStream| {
+            keys.push(key);
+            values.push(value);
+        };
+
+        match self.types.as_slice() {
+            [] => (),
+            [ty] => insert("type", ty.into_token_stream()),
+            types => insert("type", quote! { [#(#types),*] }),
+        }
+
+        if let Some(format) = self.format {
+            insert("format", format.into_token_stream());
+        }
+
+        if let Some(pattern) = self.pattern.as_ref() {
+            insert("pattern", pattern.into_token_stream());
+        }
+
+        if let Some(content_encoding) = self.content_encoding {
+            insert("contentEncoding", content_encoding.into_token_stream());
+        }
+
+        for (key, n) in [
+            ("minLength", self.min_length),
+            ("maxLength", self.max_length),
+            ("minItems", self.min_items),
+            ("maxItems", self.max_items),
+        ] {
+            if let Some(n) = n {
+                insert(key, json_number(n));
+            }
+        }
+
+        for (key, n) in [
+            ("minimum", &self.minimum),
+            ("maximum", &self.maximum),
+            ("exclusiveMaximum", &self.exclusive_maximum),
+        ] {
+            if let Some(n) = n {
+                insert(key, n.clone());
+            }
+        }
+
+        if let Some(not) = self.not.as_ref() {
+            insert("not", not.to_json());
+        }
+
+        quote! { { #(#keys: #values),* } }
+    }
+
+    /// Render the keywords as an expression of `utoipa::openapi::schema::Schema`. The `items` keywords make it an array schema.
+    #[cfg(feature = "utoipa")]
+    fn to_utoipa(&self) -> proc_macro2::TokenStream {
+        let schema = quote! { validators_prelude::utoipa::openapi::schema };
+
+        let option = |n: Option<usize>| match n {
+            Some(n) => quote! { Some(#n) },
+            None => quote! { None },
+        };
+
+        if self.min_items.is_some() || self.max_items.is_some() {
+            let min_items = option(self.min_items);
+            let max_items = option(self.max_items);
+
+            return quote! {
+                #schema::Schema::Array(
+                    #schema::ArrayBuilder::new()
+                        .items(#schema::ObjectBuilder::new().schema_type(#schema::SchemaType::AnyValue))
+                        .min_items(#min_items)
+                        .max_items(#max_items)
+                        .build()
+                )
+            };
+        }
+
+        let ty = |ty: &str| match ty {
+            "boolean" => quote! { #schema::Type::Boolean },
+            "integer" => quote! { #schema::Type::Integer },
+            "number" => quote! { #schema::Type::Number },
+            "string" => quote! { #schema::Type::String },
+            _ => unreachable!(),
+        };
+
+        let schema_type = match self.types.as_slice() {
+            [] => quote! { #schema::SchemaType::AnyValue },
+            [t] => {
+                let t = ty(t);
+
+                quote! { #schema::SchemaType::Type(#t) }
+            },
+            types => {
+                let types = types.iter().map(|t| ty(t));
+
+                quote! { #schema::SchemaType::Array(validators_prelude::Vec::from([#(#types),*])) }
+            },
+        };
+
+        let mut builder = quote! { #schema::ObjectBuilder::new().schema_type(#schema_type) };
+
+        if let Some(format) = self.format {
+            let known_format = match format {
+                "date" => Some(quote!(Date)),
+                "date-time" => Some(quote!(DateTime)),
+                "time" => Some(quote!(Time)),
+                "duration" => Some(quote!(Duration)),
+                "email" => Some(quote!(Email)),
+                "idn-email" => Some(quote!(IdnEmail)),
+                "hostname" => Some(quote!(Hostname)),
+                "idn-hostname" => Some(quote!(IdnHostname)),
+                "ipv4" => Some(quote!(Ipv4)),
+                "ipv6" => Some(quote!(Ipv6)),
+                // `uuid` and `uri` are only known to `utoipa` with its own features enabled
+                _ => None,
+            };
+
+            let format = match known_format {
+                Some(known_format) => {
+                    quote! { #schema::SchemaFormat::KnownFormat(#schema::KnownFormat::#known_format) }
+                },
+                None => {
+                    quote! { #schema::SchemaFormat::Custom(validators_prelude::String::from(#format)) }
+                },
+            };
+
+            builder.extend(quote! { .format(Some(#format)) });
+        }
+
+        if let Some(pattern) = self.pattern.as_ref() {
+            builder.extend(quote! { .pattern(Some(#pattern)) });
+        }
+
+        if let Some(content_encoding) = self.content_encoding {
+            builder.extend(quote! { .content_encoding(#content_encoding) });
+        }
+
+        if let Some(min_length) = self.min_length {
+            builder.extend(quote! { .min_length(Some(#min_length)) });
+        }
+
+        if let Some(max_length) = self.max_length {
+            builder.extend(quote! { .max_length(Some(#max_length)) });
+        }
+
+        if let Some(minimum) = self.minimum.as_ref() {
+            builder.extend(quote! { .minimum(Some(#minimum)) });
+        }
+
+        if let Some(maximum) = self.maximum.as_ref() {
+            builder.extend(quote! { .maximum(Some(#maximum)) });
+        }
+
+        if let Some(exclusive_maximum) = self.exclusive_maximum.as_ref() {
+            builder.extend(quote! { .exclusive_maximum(Some(#exclusive_maximum)) });
+        }
+
+        quote! { #schema::Schema::Object(#builder.build()) }
+    }
+}
+
+/// Write a number as a literal which both `serde_json` and `utoipa` can always represent. Integers outside the range of `u64` and `i64` become floating-point numbers.
+fn json_number<T: Display>(n: T) -> proc_macro2::TokenStream {
+    let s = n.to_string();
+
+    if let Ok(n) = s.parse::<u64>() {
+        n.into_token_stream()
+    } else if let Ok(n) = s.parse::<i64>() {
+        n.into_token_stream()
+    } else {
+        s.parse::<f64>().unwrap().into_token_stream()
+    }
+}
+
+/// Build the pattern of hexadecimal digit groups, such as `[2, 2, 2, 2, 2, 2]` for MAC addresses and `[8, 4, 4, 4, 12]` for UUIDs.
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+pub(crate) fn hex_groups_pattern(
+    groups: &[usize],
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> String {
+    let digit = match case {
+        CaseOption::Any => "[0-9A-Fa-f]",
+        CaseOption::Upper => "[0-9A-F]",
+        CaseOption::Lower => "[0-9a-f]",
+    };
+
+    let join = |separator: &str| {
+        groups.iter().map(|n| format!("{digit}{{{n}}}")).collect::<Vec<_>>().join(separator)
+    };
+
+    let escape = |c: u8| {
+        let c = c as char;
+
+        if "^$\\.*+?()[]{}|/".contains(c) { format!("\\{c}") } else { c.to_string() }
+    };
+
+    match separator {
+        SeparatorOption::Must(c) => format!("^{}$", join(&escape(c))),
+        SeparatorOption::Allow(c) => format!("^(?:{}|{})$", join(&escape(c)), join("")),
+        SeparatorOption::Disallow => format!("^{}$", join("")),
+    }
+}
+
+/// Implement the schema traits of every enabled schema feature.
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn impl_schema(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    keywords: &SchemaKeywords,
+) {
+    #[cfg(feature = "schemars")]
+    impl_json_schema(token_stream, name, &keywords.to_json());
+
+    #[cfg(feature = "utoipa")]
+    impl_to_schema(token_stream, name, &keywords.to_utoipa());
+}
+
+#[cfg(feature = "schemars")]
+#[inline]
+pub(crate) fn impl_json_schema(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    json: &proc_macro2::TokenStream,
+) {
+    // use `stringify!` instead of a string literal so that the name can be replaced along with the ident
+    token_stream.extend(quote! {
+        impl validators_prelude::schemars::JsonSchema for #name {
+            #[inline]
+            fn schema_name() -> validators_prelude::Cow<'static, str> {
+                validators_prelude::Cow::Borrowed(stringify!(#name))
+            }
+
+            #[inline]
+            fn schema_id() -> validators_prelude::Cow<'static, str> {
+                validators_prelude::Cow::Borrowed(concat!(module_path!(), "::", stringify!(#name)))
+            }
+
+            #[inline]
+            fn json_schema(_generator: &mut validators_prelude::schemars::SchemaGenerator) -> validators_prelude::schemars::Schema {
+                validators_prelude::schemars::json_schema!(#json)
+            }
+        }
+    });
+}
+
+#[cfg(feature = "utoipa")]
+#[inline]
+pub(crate) fn impl_to_schema(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    schema: &proc_macro2::TokenStream,
+) {
+    token_stream.extend(quote! {
+        impl validators_prelude::utoipa::PartialSchema for #name {
+            #[inline]
+            fn schema() -> validators_prelude::utoipa::openapi::RefOr<validators_prelude::utoipa::openapi::schema::Schema> {
+                validators_prelude::utoipa::openapi::RefOr::T(#schema)
+            }
+        }
+
+        impl validators_prelude::utoipa::ToSchema for #name {
+            #[inline]
+            fn name() -> validators_prelude::Cow<'static, str> {
+                validators_prelude::Cow::Borrowed(stringify!(#name))
+            }
+        }
+    });
+}
@@ -0,0 +1,71 @@
+use quote::quote;
+use syn::Ident;
+
+/// Implement `Type`, `Encode` and `Decode` of `sqlx` for every database supporting `repr`. `encode` converts `self` to an owned `repr`, and `decode` converts `v`, a decoded `repr`, back to `Result<Self, BoxDynError>`, which should run the validation again so that corrupted rows surface as decode errors.
+pub(crate) fn impl_sqlx(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    repr: proc_macro2::TokenStream,
+    encode: proc_macro2::TokenStream,
+    decode: proc_macro2::TokenStream,
+) {
+    token_stream.extend(quote! {
+        impl<DB: validators_prelude::sqlx::Database> validators_prelude::sqlx::Type<DB> for #name
+        where
+            #repr: validators_prelude::sqlx::Type<DB>,
+        {
+            #[inline]
+            fn type_info() -> DB::TypeInfo {
+                <#repr as validators_prelude::sqlx::Type<DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <#repr as validators_prelude::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: validators_prelude::sqlx::Database> validators_prelude::sqlx::Encode<'q, DB> for #name
+        where
+            #repr: validators_prelude::sqlx::Encode<'q, DB>,
+        {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as validators_prelude::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<validators_prelude::sqlx::encode::IsNull, validators_prelude::sqlx::error::BoxDynError> {
+                <#repr as validators_prelude::sqlx::Encode<'q, DB>>::encode(#encode, buf)
+            }
+        }
+
+        impl<'r, DB: validators_prelude::sqlx::Database> validators_prelude::sqlx::Decode<'r, DB> for #name
+        where
+            #repr: validators_prelude::sqlx::Decode<'r, DB>,
+        {
+            #[inline]
+            fn decode(
+                value: <DB as validators_prelude::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, validators_prelude::sqlx::error::BoxDynError> {
+                let v = <#repr as validators_prelude::sqlx::Decode<'r, DB>>::decode(value)?;
+
+                #decode
+            }
+        }
+    });
+}
+
+/// Store the string form of the validator, produced by `encode`, in a text column, and parse it again when decoding.
+#[inline]
+pub(crate) fn impl_sqlx_string(
+    token_stream: &mut proc_macro2::TokenStream,
+    name: &Ident,
+    encode: proc_macro2::TokenStream,
+) {
+    impl_sqlx(
+        token_stream,
+        name,
+        quote! { validators_prelude::String },
+        encode,
+        quote! { Ok(<Self as ValidateString>::parse_string(v)?) },
+    );
+}
@@ -1,8 +1,14 @@
+use std::fmt::Display;
+
 use quote::{ToTokens, quote};
 
 use crate::common::{
-    allow::Allow, case_option::CaseOption, range::Range, range_option::RangeTokenStream,
-    separator_option::SeparatorOption, tri_allow::TriAllow,
+    allow::Allow,
+    case_option::CaseOption,
+    range::{Range, RangedNumber},
+    range_option::RangeTokenStream,
+    separator_option::SeparatorOption,
+    tri_allow::TriAllow,
 };
 
 #[allow(dead_code)]
@@ -84,7 +90,10 @@ impl ToTokens for SeparatorOption {
     }
 }
 
-impl ToTokens for Range<u128> {
+impl<T: RangedNumber> ToTokens for Range<T>
+where
+    T::Err: Display,
+{
     #[inline]
     fn to_tokens(&self, token_stream: &mut proc_macro2::TokenStream) {
         let min = OptionToken(self.min.as_ref());
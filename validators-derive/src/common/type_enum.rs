@@ -27,6 +27,10 @@ pub enum TypeEnum {
     PhoneNumber,
     HashMapPhoneNumber,
     CollectionLength,
+    Date,
+    Time,
+    DateTime,
+    Duration,
 }
 
 impl TypeEnum {
@@ -61,6 +65,10 @@ impl TypeEnum {
                  crate::phonenumber::PhoneNumber>"
             },
             TypeEnum::CollectionLength => "T: crate::validators::traits::CollectionLength",
+            TypeEnum::Date => "crate::validators::models::Date",
+            TypeEnum::Time => "crate::validators::models::Time",
+            TypeEnum::DateTime => "crate::validators::models::DateTime",
+            TypeEnum::Duration => "core::time::Duration",
         }
     }
 }
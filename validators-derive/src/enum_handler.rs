@@ -0,0 +1,563 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Meta, ext::IdentExt, parse_quote};
+
+use crate::{common::attributes::basic_attribute::BasicAttribute, panic};
+
+/// Convert the name of a variant to snake case. A run of uppercase letters is an acronym, such as `HTTP` of `HTTPServer`, and a lowercase letter followed by a digit, such as `v` of `IPv4`, does not start a word.
+fn to_snake_case(ident: &Ident) -> String {
+    let s = ident.to_string();
+    let chars = s.chars().collect::<Vec<_>>();
+
+    let mut result = String::with_capacity(s.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+
+            let starts_word = if prev.is_uppercase() {
+                // the last letter of an acronym starts a word only if a word of at least two lowercase letters follows
+                chars[(i + 1)..].iter().take_while(|c| c.is_lowercase()).count() >= 2
+            } else {
+                prev.is_lowercase() || prev.is_ascii_digit()
+            };
+
+            if starts_word {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+/// Make an ident for a field of the error. A keyword becomes a raw ident such as `r#type`, and the keywords which cannot be raw get an underscore suffix.
+fn to_field_ident(name: &str, span: proc_macro2::Span) -> Ident {
+    match name {
+        "self" | "super" | "crate" => format_ident!("{name}_", span = span),
+        // reserved since Rust 2024, which `syn` does not know
+        "gen" => Ident::new_raw(name, span),
+        _ if syn::parse_str::<Ident>(name).is_err() => Ident::new_raw(name, span),
+        _ => Ident::new(name, span),
+    }
+}
+
+/// Handle an enum whose variants each wrap a validator type. The variants are tried in order.
+pub(crate) fn enum_handler(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let mut meta: Option<Meta> = None;
+
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("validator") {
+            if let Meta::List(list) = &attr.meta {
+                let m: Meta = list.parse_args()?;
+
+                if !m.path().is_ident("alternatives") {
+                    return Err(panic::parameter_incorrect_format(m.path(), &["alternatives"]));
+                }
+
+                if meta.is_some() {
+                    return Err(panic::validator_only_one_at_a_time(m.path()));
+                }
+
+                meta = Some(m);
+            } else {
+                return Err(panic::validator_format_incorrect(attr));
+            }
+        }
+    }
+
+    #[allow(unused_variables)]
+    let type_attribute = match meta.as_ref() {
+        Some(meta) => BasicAttribute::build_from_meta(meta)?,
+        None => BasicAttribute::build_from_meta(&syn::parse_quote!(alternatives))?,
+    };
+
+    let variants = match &ast.data {
+        Data::Enum(data) => &data.variants,
+        _ => unreachable!(),
+    };
+
+    if variants.is_empty() {
+        return Err(panic::alternatives_empty(&ast));
+    }
+
+    let mut variant_idents = Vec::with_capacity(variants.len());
+    let mut variant_types = Vec::with_capacity(variants.len());
+
+    for variant in variants.iter() {
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                variant_idents.push(&variant.ident);
+                variant_types.push(&fields.unnamed[0].ty);
+            },
+            _ => return Err(panic::alternatives_variant_incorrect(variant)),
+        }
+    }
+
+    let name = &ast.ident;
+    let vis = &ast.vis;
+
+    let error_name = format_ident!("{name}Error");
+    let variant_names = variant_idents.iter().map(|ident| ident.to_string()).collect::<Vec<_>>();
+
+    let mut error_fields: Vec<Ident> = Vec::with_capacity(variants.len());
+
+    for variant_ident in variant_idents.iter() {
+        let field = to_field_ident(&to_snake_case(variant_ident), variant_ident.span());
+
+        if let Some(i) = error_fields.iter().position(|e| *e == field) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!(
+                    "the variants `{}` and `{variant_ident}` have the same field `{field}` in \
+                     `{error_name}`",
+                    variant_idents[i]
+                ),
+            ));
+        }
+
+        error_fields.push(field);
+    }
+
+    let error_field_names =
+        error_fields.iter().map(|field| field.unraw().to_string()).collect::<Vec<_>>();
+
+    let error_doc = format!(
+        "Error from the `{name}` enum of alternatives. Each field tells why the corresponding \
+         variant failed."
+    );
+
+    let last = variant_idents.len() - 1;
+
+    let (init_idents, last_ident) = (&variant_idents[..last], variant_idents[last]);
+    let (init_types, last_type) = (&variant_types[..last], variant_types[last]);
+    let (init_fields, last_field) = (&error_fields[..last], &error_fields[last]);
+    let (init_names, last_name) = (&variant_names[..last], &variant_names[last]);
+
+    // every impl needs the variant types to be validators, which is trivially true unless they use the generic parameters
+    let with_bounds = |bounds: &[TokenStream]| -> Generics {
+        let mut generics = ast.generics.clone();
+
+        let where_clause = generics.make_where_clause();
+
+        for ty in variant_types.iter() {
+            where_clause.predicates.push(parse_quote!(#ty: ValidateString));
+
+            for bound in bounds {
+                where_clause.predicates.push(parse_quote!(#ty: #bound));
+            }
+        }
+
+        generics
+    };
+
+    let with_error_bounds = |bound: TokenStream| -> Generics {
+        let mut generics = with_bounds(&[]);
+
+        let where_clause = generics.make_where_clause();
+
+        for ty in variant_types.iter() {
+            where_clause.predicates.push(parse_quote!(<#ty as ValidateString>::Error: #bound));
+        }
+
+        generics
+    };
+
+    // not every error is `Clone` or `ValidatorError`, such as `CustomizedError` or the error of a hand-written validator, and the bounds mention `'v`, so they are checked only when the implementation is used
+    let with_lazy_error_bounds = |generics: &Generics, bound: TokenStream| -> Generics {
+        let mut generics = generics.clone();
+
+        let where_clause = generics.make_where_clause();
+
+        for ty in variant_types.iter() {
+            where_clause
+                .predicates
+                .push(parse_quote!(for<'v> <#ty as ValidateString>::Error: #bound));
+        }
+
+        generics
+    };
+
+    let generics = with_bounds(&[]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let debug_generics = with_error_bounds(quote!(::core::fmt::Debug));
+    let (debug_impl_generics, _, debug_where_clause) = debug_generics.split_for_impl();
+
+    let display_generics = with_error_bounds(quote!(::core::fmt::Display));
+    let (display_impl_generics, _, display_where_clause) = display_generics.split_for_impl();
+
+    let error_generics = with_error_bounds(quote!(::core::fmt::Debug + ::core::fmt::Display));
+    let (error_impl_generics, _, error_where_clause) = error_generics.split_for_impl();
+
+    let clone_generics = with_lazy_error_bounds(&generics, quote!(::core::clone::Clone));
+    let (clone_impl_generics, _, clone_where_clause) = clone_generics.split_for_impl();
+
+    let validator_error_generics = with_lazy_error_bounds(&error_generics, quote!(ValidatorError));
+    let (validator_error_impl_generics, _, validator_error_where_clause) =
+        validator_error_generics.split_for_impl();
+
+    let error_struct_generics = &generics.params;
+
+    let mut token_stream = TokenStream::new();
+
+    token_stream.extend(quote! {
+        #[doc = #error_doc]
+        #vis struct #error_name<#error_struct_generics> #where_clause {
+            #(
+                pub #error_fields: <#variant_types as ValidateString>::Error,
+            )*
+        }
+
+        impl #debug_impl_generics ::core::fmt::Debug for #error_name #ty_generics #debug_where_clause {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!(#error_name))
+                    #(.field(#error_field_names, &self.#error_fields))*
+                    .finish()
+            }
+        }
+
+        impl #display_impl_generics ::core::fmt::Display for #error_name #ty_generics #display_where_clause {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("does not match any alternative (")?;
+
+                #(
+                    f.write_str(#init_names)?;
+                    f.write_str(": ")?;
+                    ::core::fmt::Display::fmt(&self.#init_fields, f)?;
+                    f.write_str("; ")?;
+                )*
+
+                f.write_str(#last_name)?;
+                f.write_str(": ")?;
+                ::core::fmt::Display::fmt(&self.#last_field, f)?;
+
+                f.write_str(")")
+            }
+        }
+
+        impl #clone_impl_generics ::core::clone::Clone for #error_name #ty_generics #clone_where_clause {
+            #[inline]
+            fn clone(&self) -> Self {
+                #error_name {
+                    #(
+                        #error_fields: ::core::clone::Clone::clone(&self.#error_fields),
+                    )*
+                }
+            }
+        }
+
+        impl #error_impl_generics ::core::error::Error for #error_name #ty_generics #error_where_clause {}
+
+        impl #validator_error_impl_generics ValidatorError for #error_name #ty_generics #validator_error_where_clause {
+            #[inline]
+            fn validator(&self) -> &'static str {
+                "alternatives"
+            }
+
+            #[inline]
+            fn code(&self) -> &'static str {
+                "alternatives.invalid"
+            }
+
+            #[inline]
+            fn params(&self) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                validators_prelude::vec![
+                    #(
+                        (#error_field_names, ErrorParam::String(validators_prelude::String::from(ValidatorError::code(&self.#error_fields)))),
+                    )*
+                ]
+            }
+        }
+
+        impl #impl_generics ValidateString for #name #ty_generics #where_clause {
+            type Error = #error_name #ty_generics;
+
+            #[inline]
+            fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                let s = s.into();
+
+                #(
+                    let #init_fields = match <#init_types as ValidateString>::parse_str(s.as_str()) {
+                        Ok(v) => return Ok(Self::#init_idents(v)),
+                        Err(error) => error,
+                    };
+                )*
+
+                match <#last_type as ValidateString>::parse_string(s) {
+                    Ok(v) => Ok(Self::#last_ident(v)),
+                    Err(#last_field) => Err(#error_name {
+                        #(#error_fields),*
+                    }),
+                }
+            }
+
+            #[inline]
+            fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                let s = s.as_ref();
+
+                #(
+                    let #error_fields = match <#variant_types as ValidateString>::parse_str(s) {
+                        Ok(v) => return Ok(Self::#variant_idents(v)),
+                        Err(error) => error,
+                    };
+                )*
+
+                Err(#error_name {
+                    #(#error_fields),*
+                })
+            }
+
+            #[inline]
+            fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                let s = s.as_ref();
+
+                #(
+                    let #error_fields = match <#variant_types as ValidateString>::validate_str(s) {
+                        Ok(()) => return Ok(()),
+                        Err(error) => error,
+                    };
+                )*
+
+                Err(#error_name {
+                    #(#error_fields),*
+                })
+            }
+        }
+    });
+
+    #[cfg(feature = "actix-web")]
+    {
+        token_stream.extend(quote! {
+            impl #error_impl_generics validators_prelude::actix_web::ResponseError for #error_name #ty_generics #error_where_clause {
+                #[inline]
+                fn status_code(&self) -> validators_prelude::actix_web::http::StatusCode {
+                    validators_prelude::actix_web::http::StatusCode::BAD_REQUEST
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    {
+        if type_attribute.serde_options.serialize {
+            let generics = with_bounds(&[quote!(validators_prelude::serde::Serialize)]);
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+            token_stream.extend(quote! {
+                impl #impl_generics validators_prelude::serde::Serialize for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: validators_prelude::serde::Serializer, {
+                        match self {
+                            #(
+                                Self::#variant_idents(v) => validators_prelude::serde::Serialize::serialize(v, serializer),
+                            )*
+                        }
+                    }
+                }
+            });
+        }
+
+        if type_attribute.serde_options.deserialize {
+            let expect = format!("a string which matches one of {}", variant_names.join(", "));
+
+            let mut de_generics = display_generics.clone();
+            de_generics.params.insert(0, parse_quote!('de));
+            let (de_impl_generics, ..) = de_generics.split_for_impl();
+
+            let visitor_generics = &display_generics.params;
+
+            token_stream.extend(quote! {
+                impl #de_impl_generics validators_prelude::serde::Deserialize<'de> for #name #ty_generics #display_where_clause {
+                    #[inline]
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: validators_prelude::serde::Deserializer<'de>, {
+                        struct MyVisitor<#visitor_generics>(::core::marker::PhantomData<fn() -> #name #ty_generics>) #display_where_clause;
+
+                        impl #de_impl_generics validators_prelude::serde::de::Visitor<'de> for MyVisitor #ty_generics #display_where_clause {
+                            type Value = #name #ty_generics;
+
+                            #[inline]
+                            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                f.write_str(#expect)
+                            }
+
+                            #[inline]
+                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                            where
+                                E: validators_prelude::serde::de::Error, {
+                                <#name #ty_generics as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                            }
+
+                            #[inline]
+                            fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
+                            where
+                                E: validators_prelude::serde::de::Error, {
+                                <#name #ty_generics as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                            }
+                        }
+
+                        deserializer.deserialize_str(MyVisitor(::core::marker::PhantomData))
+                    }
+                }
+            });
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    {
+        let generics = with_bounds(&[quote!(validators_prelude::schemars::JsonSchema)]);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        token_stream.extend(quote! {
+            impl #impl_generics validators_prelude::schemars::JsonSchema for #name #ty_generics #where_clause {
+                #[inline]
+                fn schema_name() -> validators_prelude::Cow<'static, str> {
+                    validators_prelude::Cow::Borrowed(stringify!(#name))
+                }
+
+                #[inline]
+                fn schema_id() -> validators_prelude::Cow<'static, str> {
+                    validators_prelude::Cow::Borrowed(concat!(module_path!(), "::", stringify!(#name)))
+                }
+
+                #[inline]
+                fn json_schema(generator: &mut validators_prelude::schemars::SchemaGenerator) -> validators_prelude::schemars::Schema {
+                    // the parentheses keep the commas of generic arguments away from `json_schema!`
+                    validators_prelude::schemars::json_schema!({
+                        "anyOf": [#( (generator.subschema_for::<#variant_types>()) ),*]
+                    })
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "utoipa")]
+    {
+        let schema = quote! { validators_prelude::utoipa::openapi::schema };
+
+        let generics = with_bounds(&[quote!(validators_prelude::utoipa::ToSchema)]);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        token_stream.extend(quote! {
+            impl #impl_generics validators_prelude::utoipa::PartialSchema for #name #ty_generics #where_clause {
+                #[inline]
+                fn schema() -> validators_prelude::utoipa::openapi::RefOr<#schema::Schema> {
+                    validators_prelude::utoipa::openapi::RefOr::T(#schema::Schema::AnyOf(
+                        #schema::AnyOfBuilder::new()
+                            #(
+                                .item(validators_prelude::utoipa::openapi::Ref::from_schema_name(
+                                    <#variant_types as validators_prelude::utoipa::ToSchema>::name(),
+                                ))
+                            )*
+                            .build(),
+                    ))
+                }
+            }
+
+            impl #impl_generics validators_prelude::utoipa::ToSchema for #name #ty_generics #where_clause {
+                #[inline]
+                fn name() -> validators_prelude::Cow<'static, str> {
+                    validators_prelude::Cow::Borrowed(stringify!(#name))
+                }
+
+                #[inline]
+                fn schemas(
+                    schemas: &mut validators_prelude::Vec<(
+                        validators_prelude::String,
+                        validators_prelude::utoipa::openapi::RefOr<#schema::Schema>,
+                    )>,
+                ) {
+                    #(
+                        schemas.push((
+                            <#variant_types as validators_prelude::utoipa::ToSchema>::name().into(),
+                            <#variant_types as validators_prelude::utoipa::PartialSchema>::schema(),
+                        ));
+                        <#variant_types as validators_prelude::utoipa::ToSchema>::schemas(schemas);
+                    )*
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "arbitrary")]
+    {
+        let variant_count = variant_idents.len();
+        let variant_indexes = 0..variant_count;
+
+        // the bounds mention `'a`, so they are checked only when the implementation is used
+        let mut generics = with_bounds(&[quote!(validators_prelude::arbitrary::Arbitrary<'a>)]);
+        generics.params.insert(0, parse_quote!('a));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        token_stream.extend(quote! {
+            impl #impl_generics validators_prelude::arbitrary::Arbitrary<'a> for #name #ty_generics #where_clause {
+                #[inline]
+                fn arbitrary(u: &mut validators_prelude::arbitrary::Unstructured<'a>) -> validators_prelude::arbitrary::Result<Self> {
+                    match u.choose_index(#variant_count)? {
+                        #(#variant_indexes => Ok(Self::#variant_idents(u.arbitrary()?)),)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "clap")]
+    {
+        token_stream.extend(quote! {
+            impl #impl_generics validators_prelude::clap::builder::ValueParserFactory for #name #ty_generics #where_clause {
+                type Parser = validators_prelude::ValidatorValueParser<Self>;
+
+                #[inline]
+                fn value_parser() -> Self::Parser {
+                    validators_prelude::ValidatorValueParser::new()
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "rocket")]
+    {
+        let mut generics = error_generics.clone();
+        generics.params.insert(0, parse_quote!('r));
+        let where_clause = generics.make_where_clause();
+        where_clause.predicates.push(parse_quote!(Self: Send));
+        // `rocket::form::Error::custom` needs a sendable error
+        where_clause.predicates.push(parse_quote!(#error_name #ty_generics: Send + 'static));
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        if type_attribute.rocket_options.from_form_field {
+            token_stream.extend(quote! {
+                impl #impl_generics validators_prelude::rocket::form::FromFormField<'r> for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn from_value(v: validators_prelude::rocket::form::ValueField<'r>) -> validators_prelude::rocket::form::Result<'r, Self> {
+                        Ok(<Self as ValidateString>::parse_str(v.value).map_err(validators_prelude::rocket::form::Error::custom)?)
+                    }
+                }
+            });
+        }
+
+        if type_attribute.rocket_options.from_param {
+            token_stream.extend(quote! {
+                impl #impl_generics validators_prelude::rocket::request::FromParam<'r> for #name #ty_generics #where_clause {
+                    type Error = #error_name #ty_generics;
+
+                    #[inline]
+                    fn from_param(v: &'r str) -> Result<Self, Self::Error> {
+                        <Self as ValidateString>::parse_str(v)
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(token_stream)
+}
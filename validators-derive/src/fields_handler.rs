@@ -0,0 +1,489 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Data, DataStruct, DeriveInput, Fields, File, GenericArgument, Generics, Ident, Item, Meta,
+    PathArguments, Type, Visibility,
+};
+
+#[allow(unused_imports)]
+use crate::validator_handlers::{self, ValidatorHandler};
+use crate::{
+    Validator,
+    common::custom::{Custom, take_custom},
+    panic,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum PrimitiveType {
+    Boolean,
+    UnsignedInteger,
+    SignedInteger,
+    Number,
+}
+
+impl PrimitiveType {
+    #[inline]
+    fn from_type(ty: &Type) -> Option<Self> {
+        if let Type::Path(type_path) = ty
+            && type_path.qself.is_none()
+            && let Some(ident) = type_path.path.get_ident()
+        {
+            match ident.to_string().as_str() {
+                "bool" => Some(Self::Boolean),
+                "usize" | "u8" | "u16" | "u32" | "u64" | "u128" => Some(Self::UnsignedInteger),
+                "isize" | "i8" | "i16" | "i32" | "i64" | "i128" => Some(Self::SignedInteger),
+                "f32" | "f64" => Some(Self::Number),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns `T` if the type is `Option<T>`.
+#[inline]
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && type_path.qself.is_none()
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+        && arguments.args.len() == 1
+        && let Some(GenericArgument::Type(ty)) = arguments.args.first()
+    {
+        Some(ty)
+    } else {
+        None
+    }
+}
+
+fn carrier_handler<H: ValidatorHandler>(
+    ident: Ident,
+    meta: Meta,
+    data_type: &Type,
+) -> syn::Result<TokenStream> {
+    let fields = H::carrier_fields(&meta, data_type)?;
+
+    let semi_token = if let Fields::Unnamed(_) = fields { Some(Default::default()) } else { None };
+
+    let ast = DeriveInput {
+        attrs: Vec::new(),
+        vis: Visibility::Inherited,
+        ident,
+        generics: Generics::default(),
+        data: Data::Struct(DataStruct {
+            struct_token: Default::default(),
+            fields,
+            semi_token,
+        }),
+    };
+
+    let mut token_stream = quote! {
+        #[allow(dead_code)]
+        #ast
+    };
+
+    let file: File = syn::parse2(H::meta_handler(ast, meta, &Custom::default())?)?;
+
+    for item in file.items.into_iter().filter(|item| !is_integration(item)) {
+        token_stream.extend(item.into_token_stream());
+    }
+
+    Ok(token_stream)
+}
+
+/// The modules of `validators_prelude` whose traits the validator handlers implement for the integrations, such as `serde::Deserialize`.
+const INTEGRATIONS: [&str; 7] =
+    ["arbitrary", "clap", "rocket", "schemars", "serde", "sqlx", "utoipa"];
+
+/// Determine whether an item is an implementation of a trait of an integration. A carrier only needs the validating traits, so these implementations are dropped instead of being compiled for every field.
+#[inline]
+fn is_integration(item: &Item) -> bool {
+    if let Item::Impl(item) = item
+        && let Some((path, _)) = &item.trait_
+    {
+        let mut segments = path.segments.iter();
+
+        segments.next().is_some_and(|segment| segment.ident == "validators_prelude")
+            && segments
+                .next()
+                .is_some_and(|segment| INTEGRATIONS.iter().any(|module| segment.ident == module))
+    } else {
+        false
+    }
+}
+
+fn carrier(
+    validator: Validator,
+    ident: Ident,
+    meta: Meta,
+    data_type: &Type,
+) -> syn::Result<TokenStream> {
+    match validator {
+        #[cfg(feature = "base32")]
+        Validator::base32 => {
+            carrier_handler::<validator_handlers::base32::Base32Handler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "base32_decoded")]
+        Validator::base32_decoded => carrier_handler::<
+            validator_handlers::base32_decoded::Base32DecodedHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "base64")]
+        Validator::base64 => {
+            carrier_handler::<validator_handlers::base64::Base64Handler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "base64_decoded")]
+        Validator::base64_decoded => carrier_handler::<
+            validator_handlers::base64_decoded::Base64DecodedHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "base64_url")]
+        Validator::base64_url => {
+            carrier_handler::<validator_handlers::base64_url::Base64UrlHandler>(
+                ident, meta, data_type,
+            )
+        },
+        #[cfg(feature = "base64_url_decoded")]
+        Validator::base64_url_decoded => carrier_handler::<
+            validator_handlers::base64_url_decoded::Base64UrlDecodedHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "bit")]
+        Validator::bit => {
+            carrier_handler::<validator_handlers::bit::BitHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "boolean")]
+        Validator::boolean => {
+            carrier_handler::<validator_handlers::boolean::BooleanHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "byte")]
+        Validator::byte => {
+            carrier_handler::<validator_handlers::byte::ByteHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "date")]
+        Validator::date => {
+            carrier_handler::<validator_handlers::date::DateHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "datetime")]
+        Validator::datetime => {
+            carrier_handler::<validator_handlers::datetime::DateTimeHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "domain")]
+        Validator::domain => {
+            carrier_handler::<validator_handlers::domain::DomainHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "duration")]
+        Validator::duration => {
+            carrier_handler::<validator_handlers::duration::DurationHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "email")]
+        Validator::email => {
+            carrier_handler::<validator_handlers::email::EmailHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "host")]
+        Validator::host => {
+            carrier_handler::<validator_handlers::host::HostHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "http_url")]
+        Validator::http_url => {
+            carrier_handler::<validator_handlers::http_url::HttpUrlHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "http_ftp_url")]
+        Validator::http_ftp_url => carrier_handler::<
+            validator_handlers::http_ftp_url::HttpFtpUrlHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "ip")]
+        Validator::ip => {
+            carrier_handler::<validator_handlers::ip::IpHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "ipv4")]
+        Validator::ipv4 => {
+            carrier_handler::<validator_handlers::ipv4::Ipv4Handler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "ipv6")]
+        Validator::ipv6 => {
+            carrier_handler::<validator_handlers::ipv6::Ipv6Handler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "json")]
+        Validator::json => {
+            carrier_handler::<validator_handlers::json::JsonHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "length")]
+        Validator::length => {
+            carrier_handler::<validator_handlers::length::LengthHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "line")]
+        Validator::line => {
+            carrier_handler::<validator_handlers::line::LineHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "mac_address")]
+        Validator::mac_address => carrier_handler::<
+            validator_handlers::mac_address::MacAddressHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "number")]
+        Validator::number => {
+            carrier_handler::<validator_handlers::number::NumberHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "phone")]
+        Validator::phone => {
+            carrier_handler::<validator_handlers::phone::PhoneHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "regex")]
+        Validator::regex => {
+            carrier_handler::<validator_handlers::regex::RegexHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "semver")]
+        Validator::semver => {
+            carrier_handler::<validator_handlers::semver::SemverHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "semver_req")]
+        Validator::semver_req => {
+            carrier_handler::<validator_handlers::semver_req::SemverReqHandler>(
+                ident, meta, data_type,
+            )
+        },
+        #[cfg(feature = "signed_integer")]
+        Validator::signed_integer => carrier_handler::<
+            validator_handlers::signed_integer::SignedIntegerHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "text")]
+        Validator::text => {
+            carrier_handler::<validator_handlers::text::TextHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "time")]
+        Validator::time => {
+            carrier_handler::<validator_handlers::time::TimeHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "unsigned_integer")]
+        Validator::unsigned_integer => carrier_handler::<
+            validator_handlers::unsigned_integer::UnsignedIntegerHandler,
+        >(ident, meta, data_type),
+        #[cfg(feature = "url")]
+        Validator::url => {
+            carrier_handler::<validator_handlers::url::UrlHandler>(ident, meta, data_type)
+        },
+        #[cfg(feature = "uuid")]
+        Validator::uuid => {
+            carrier_handler::<validator_handlers::uuid::UuidHandler>(ident, meta, data_type)
+        },
+        Validator::_Nothing => unreachable!(),
+    }
+}
+
+/// Generate the expression which validates `v` (a reference to the field value) with the carrier into a `DetailedError`, so that the report gets the details of the failure. A field of a primitive type can only be validated by the validators of its kind, and an integer field by `number` only if every value of it is exactly an `f64`.
+fn check_expr(
+    validator: Validator,
+    carrier_ident: &Ident,
+    data_type: &Type,
+) -> syn::Result<TokenStream> {
+    let validator_name = format!("{validator:?}");
+
+    if validator_name == "length" {
+        return Ok(quote! {
+            <#carrier_ident as ValidateLength<#data_type>>::validate_collection_detailed(v)
+        });
+    }
+
+    let Some(primitive_type) = PrimitiveType::from_type(data_type) else {
+        return Ok(quote! {
+            <#carrier_ident as ValidateString>::validate_str_detailed(v)
+        });
+    };
+
+    let type_name = quote!(#data_type).to_string();
+
+    let is_exact_in_f64 = matches!(type_name.as_str(), "u8" | "u16" | "u32" | "i8" | "i16" | "i32");
+
+    match (validator_name.as_str(), primitive_type) {
+        ("boolean", PrimitiveType::Boolean) => {
+            return Ok(quote! {
+                <#carrier_ident as ValidateBoolean>::validate_bool(*v).map_err(validators_prelude::DetailedError::new)
+            });
+        },
+        ("number", PrimitiveType::Number) => {
+            return Ok(quote! {
+                <#carrier_ident as ValidateNumber>::validate_f64_detailed(*v as f64)
+            });
+        },
+        ("number", PrimitiveType::SignedInteger | PrimitiveType::UnsignedInteger)
+            if is_exact_in_f64 =>
+        {
+            return Ok(quote! {
+                <#carrier_ident as ValidateNumber>::validate_f64_detailed(*v as f64)
+            });
+        },
+        ("signed_integer", PrimitiveType::SignedInteger) => {
+            return Ok(quote! {
+                <#carrier_ident as ValidateSignedInteger>::validate_i128_detailed(*v as i128)
+            });
+        },
+        ("signed_integer", PrimitiveType::UnsignedInteger) if type_name != "u128" => {
+            return Ok(quote! {
+                <#carrier_ident as ValidateSignedInteger>::validate_i128_detailed(*v as i128)
+            });
+        },
+        ("unsigned_integer", PrimitiveType::UnsignedInteger) => {
+            return Ok(quote! {
+                <#carrier_ident as ValidateUnsignedInteger>::validate_u128_detailed(*v as u128)
+            });
+        },
+        _ => (),
+    }
+
+    let suitable_validators = match primitive_type {
+        PrimitiveType::Boolean => "`boolean`",
+        PrimitiveType::UnsignedInteger if type_name == "u128" => "`unsigned_integer`",
+        PrimitiveType::UnsignedInteger if !is_exact_in_f64 => {
+            "`unsigned_integer` or `signed_integer`"
+        },
+        PrimitiveType::UnsignedInteger => "`unsigned_integer`, `signed_integer` or `number`",
+        PrimitiveType::SignedInteger if !is_exact_in_f64 => "`signed_integer`",
+        PrimitiveType::SignedInteger => "`signed_integer` or `number`",
+        PrimitiveType::Number => "`number`",
+    };
+
+    Err(syn::Error::new_spanned(
+        data_type,
+        format!(
+            "the `{validator_name}` validator cannot validate a field of `{type_name}`, use \
+             {suitable_validators} instead"
+        ),
+    ))
+}
+
+pub(crate) fn fields_handler(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields), ..
+        }) => &fields.named,
+        _ => return Err(panic::validate_for_named_struct(&ast)),
+    };
+
+    let name = &ast.ident;
+
+    let mut carriers = TokenStream::new();
+    let mut checks = TokenStream::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let mut use_validator: Option<(Option<Validator>, Meta)> = None;
+
+        for attr in field.attrs.iter() {
+            let path = attr.path();
+
+            if path.is_ident("validate") {
+                if let Meta::List(list) = &attr.meta {
+                    let meta: Meta = list.parse_args()?;
+
+                    let path = meta.path();
+
+                    let validator = if let Meta::Path(_) = &meta
+                        && path.is_ident("nested")
+                    {
+                        None
+                    } else if let Some(validator) = Validator::from_path(path) {
+                        Some(validator)
+                    } else {
+                        return Err(panic::unsupported_validator(path));
+                    };
+
+                    if use_validator.is_some() {
+                        return Err(panic::validator_only_one_at_a_time(path));
+                    }
+
+                    use_validator = Some((validator, meta));
+                } else {
+                    return Err(panic::validate_format_incorrect(attr));
+                }
+            }
+        }
+
+        let (validator, meta) = match use_validator {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_name = field_ident.to_string();
+        let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name);
+
+        let validator = match validator {
+            Some(validator) => validator,
+            None => {
+                checks.extend(quote! {
+                    if let Err(e) = ValidateFields::validate(&self.#field_ident) {
+                        errors.extend_nested(#field_name, e);
+                    }
+                });
+
+                continue;
+            },
+        };
+
+        let validator_name = format!("{validator:?}");
+
+        // named by the index, because different field names, such as `foo_bar` and `fooBar`, can be converted into the same name
+        let carrier_ident = format_ident!("__Field{}Validator", index);
+
+        let (is_option, data_type) = match option_inner_type(&field.ty) {
+            Some(ty) => (true, ty),
+            None => (false, &field.ty),
+        };
+
+        let mut check = check_expr(validator, &carrier_ident, data_type)?;
+
+        let (meta, custom) = take_custom(meta)?;
+
+        carriers.extend(carrier(validator, carrier_ident.clone(), meta, data_type)?);
+
+        if let Some(custom) = custom {
+            check = quote! {
+                #check.map_err(|e| e.map(validators_prelude::CustomizedError::Builtin)).and_then(|()| {
+                    #custom(v).map_err(|e| {
+                        validators_prelude::DetailedError::new(validators_prelude::CustomizedError::custom(e))
+                    })
+                })
+            };
+        }
+
+        let push = quote! {
+            if let Err(e) = #check {
+                errors.push_detailed(#field_name, #validator_name, e);
+            }
+        };
+
+        checks.extend(if is_option {
+            quote! {
+                if let Some(v) = &self.#field_ident {
+                    #push
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let v = &self.#field_ident;
+
+                    #push
+                }
+            }
+        });
+    }
+
+    if checks.is_empty() {
+        return Err(panic::derive_attribute_not_set_up_yet(&ast));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ValidateFields for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::core::result::Result<(), validators_prelude::ValidationErrors> {
+                #carriers
+
+                let mut errors = validators_prelude::ValidationErrors::new();
+
+                #checks
+
+                errors.into_result()
+            }
+        }
+    })
+}
@@ -6,7 +6,10 @@ The provided crate offers a procedural macro for defining validators, including
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod borrowed_handler;
 mod common;
+mod enum_handler;
+mod fields_handler;
 #[allow(unused)]
 mod panic;
 mod supported_validators;
@@ -14,16 +17,159 @@ mod validator_handlers;
 
 use proc_macro::TokenStream;
 use syn::{
-    DeriveInput, Meta,
+    Data, DeriveInput, Meta,
     parse::{Parse, ParseStream},
     parse_macro_input,
 };
 #[allow(unused)]
 use validator_handlers::ValidatorHandler;
 
-use crate::supported_validators::Validator;
+use crate::{common::custom::Custom, supported_validators::Validator};
+
+fn validator_handler(
+    validator: Validator,
+    ast: DeriveInput,
+    meta: Meta,
+    custom: &Custom,
+) -> syn::Result<proc_macro2::TokenStream> {
+    match validator {
+        #[cfg(feature = "base32")]
+        Validator::base32 => {
+            validator_handlers::base32::Base32Handler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "base32_decoded")]
+        Validator::base32_decoded => {
+            validator_handlers::base32_decoded::Base32DecodedHandler::meta_handler(
+                ast, meta, custom,
+            )
+        },
+        #[cfg(feature = "base64")]
+        Validator::base64 => {
+            validator_handlers::base64::Base64Handler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "base64_decoded")]
+        Validator::base64_decoded => {
+            validator_handlers::base64_decoded::Base64DecodedHandler::meta_handler(
+                ast, meta, custom,
+            )
+        },
+        #[cfg(feature = "base64_url")]
+        Validator::base64_url => {
+            validator_handlers::base64_url::Base64UrlHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "base64_url_decoded")]
+        Validator::base64_url_decoded => {
+            validator_handlers::base64_url_decoded::Base64UrlDecodedHandler::meta_handler(
+                ast, meta, custom,
+            )
+        },
+        #[cfg(feature = "bit")]
+        Validator::bit => validator_handlers::bit::BitHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "boolean")]
+        Validator::boolean => {
+            validator_handlers::boolean::BooleanHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "byte")]
+        Validator::byte => validator_handlers::byte::ByteHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "date")]
+        Validator::date => validator_handlers::date::DateHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "datetime")]
+        Validator::datetime => {
+            validator_handlers::datetime::DateTimeHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "domain")]
+        Validator::domain => {
+            validator_handlers::domain::DomainHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "duration")]
+        Validator::duration => {
+            validator_handlers::duration::DurationHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "email")]
+        Validator::email => {
+            validator_handlers::email::EmailHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "host")]
+        Validator::host => validator_handlers::host::HostHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "http_url")]
+        Validator::http_url => {
+            validator_handlers::http_url::HttpUrlHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "http_ftp_url")]
+        Validator::http_ftp_url => {
+            validator_handlers::http_ftp_url::HttpFtpUrlHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "ip")]
+        Validator::ip => validator_handlers::ip::IpHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "ipv4")]
+        Validator::ipv4 => validator_handlers::ipv4::Ipv4Handler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "ipv6")]
+        Validator::ipv6 => validator_handlers::ipv6::Ipv6Handler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "json")]
+        Validator::json => validator_handlers::json::JsonHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "length")]
+        Validator::length => {
+            validator_handlers::length::LengthHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "line")]
+        Validator::line => validator_handlers::line::LineHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "mac_address")]
+        Validator::mac_address => {
+            validator_handlers::mac_address::MacAddressHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "number")]
+        Validator::number => {
+            validator_handlers::number::NumberHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "phone")]
+        Validator::phone => {
+            validator_handlers::phone::PhoneHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "regex")]
+        Validator::regex => {
+            validator_handlers::regex::RegexHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "semver")]
+        Validator::semver => {
+            validator_handlers::semver::SemverHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "semver_req")]
+        Validator::semver_req => {
+            validator_handlers::semver_req::SemverReqHandler::meta_handler(ast, meta, custom)
+        },
+        #[cfg(feature = "signed_integer")]
+        Validator::signed_integer => {
+            validator_handlers::signed_integer::SignedIntegerHandler::meta_handler(
+                ast, meta, custom,
+            )
+        },
+        #[cfg(feature = "text")]
+        Validator::text => validator_handlers::text::TextHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "time")]
+        Validator::time => validator_handlers::time::TimeHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "unsigned_integer")]
+        Validator::unsigned_integer => {
+            validator_handlers::unsigned_integer::UnsignedIntegerHandler::meta_handler(
+                ast, meta, custom,
+            )
+        },
+        #[cfg(feature = "url")]
+        Validator::url => validator_handlers::url::UrlHandler::meta_handler(ast, meta, custom),
+        #[cfg(feature = "uuid")]
+        Validator::uuid => validator_handlers::uuid::UuidHandler::meta_handler(ast, meta, custom),
+        Validator::_Nothing => {
+            // avoid unused warnings
+            let _ = (ast, meta);
+            unreachable!()
+        },
+    }
+}
 
 fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if let Data::Enum(_) = &ast.data {
+        return enum_handler::enum_handler(ast);
+    }
+
     let mut use_validator: Option<(Validator, Meta)> = None;
 
     for attr in ast.attrs.iter() {
@@ -51,155 +197,38 @@ fn derive_input_handler(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStrea
     }
 
     if let Some((validator, meta)) = use_validator {
-        match validator {
-            #[cfg(feature = "base32")]
-            Validator::base32 => {
-                return validator_handlers::base32::Base32Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base32_decoded")]
-            Validator::base32_decoded => {
-                return validator_handlers::base32_decoded::Base32DecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "base64")]
-            Validator::base64 => {
-                return validator_handlers::base64::Base64Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base64_decoded")]
-            Validator::base64_decoded => {
-                return validator_handlers::base64_decoded::Base64DecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "base64_url")]
-            Validator::base64_url => {
-                return validator_handlers::base64_url::Base64UrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "base64_url_decoded")]
-            Validator::base64_url_decoded => {
-                return validator_handlers::base64_url_decoded::Base64UrlDecodedHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "bit")]
-            Validator::bit => {
-                return validator_handlers::bit::BitHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "boolean")]
-            Validator::boolean => {
-                return validator_handlers::boolean::BooleanHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "byte")]
-            Validator::byte => {
-                return validator_handlers::byte::ByteHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "domain")]
-            Validator::domain => {
-                return validator_handlers::domain::DomainHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "email")]
-            Validator::email => {
-                return validator_handlers::email::EmailHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "host")]
-            Validator::host => {
-                return validator_handlers::host::HostHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "http_url")]
-            Validator::http_url => {
-                return validator_handlers::http_url::HttpUrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "http_ftp_url")]
-            Validator::http_ftp_url => {
-                return validator_handlers::http_ftp_url::HttpFtpUrlHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "ip")]
-            Validator::ip => {
-                return validator_handlers::ip::IpHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "ipv4")]
-            Validator::ipv4 => {
-                return validator_handlers::ipv4::Ipv4Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "ipv6")]
-            Validator::ipv6 => {
-                return validator_handlers::ipv6::Ipv6Handler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "json")]
-            Validator::json => {
-                return validator_handlers::json::JsonHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "length")]
-            Validator::length => {
-                return validator_handlers::length::LengthHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "line")]
-            Validator::line => {
-                return validator_handlers::line::LineHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "mac_address")]
-            Validator::mac_address => {
-                return validator_handlers::mac_address::MacAddressHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "number")]
-            Validator::number => {
-                return validator_handlers::number::NumberHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "phone")]
-            Validator::phone => {
-                return validator_handlers::phone::PhoneHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "regex")]
-            Validator::regex => {
-                return validator_handlers::regex::RegexHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "semver")]
-            Validator::semver => {
-                return validator_handlers::semver::SemverHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "semver_req")]
-            Validator::semver_req => {
-                return validator_handlers::semver_req::SemverReqHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "signed_integer")]
-            Validator::signed_integer => {
-                return validator_handlers::signed_integer::SignedIntegerHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "text")]
-            Validator::text => {
-                return validator_handlers::text::TextHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "unsigned_integer")]
-            Validator::unsigned_integer => {
-                return validator_handlers::unsigned_integer::UnsignedIntegerHandler::meta_handler(
-                    ast, meta,
-                );
-            },
-            #[cfg(feature = "url")]
-            Validator::url => {
-                return validator_handlers::url::UrlHandler::meta_handler(ast, meta);
-            },
-            #[cfg(feature = "uuid")]
-            Validator::uuid => {
-                return validator_handlers::uuid::UuidHandler::meta_handler(ast, meta);
-            },
-            Validator::_Nothing => {
-                // avoid unused warnings
-                let _ = meta;
-                unreachable!();
-            },
+        let (meta, custom) = common::custom::take_custom(meta)?;
+
+        if let Some((lifetime, field)) = borrowed_handler::borrowed_field(&ast) {
+            return borrowed_handler::borrowed_handler(
+                validator, ast, meta, lifetime, field, custom,
+            );
         }
+
+        let name = ast.ident.clone();
+        let generics = ast.generics.clone();
+        let custom = Custom::new(custom);
+
+        let mut token_stream = validator_handler(validator, ast, meta, &custom)?;
+
+        custom.impl_v_custom(&mut token_stream, &name, &generics);
+
+        return Ok(token_stream);
+    }
+
+    if let Data::Struct(data) = &ast.data
+        && data
+            .fields
+            .iter()
+            .any(|field| field.attrs.iter().any(|attr| attr.path().is_ident("validate")))
+    {
+        return fields_handler::fields_handler(ast);
     }
 
     Err(panic::derive_attribute_not_set_up_yet(&ast))
 }
 
-#[proc_macro_derive(Validator, attributes(validator))]
+#[proc_macro_derive(Validator, attributes(validator, validate))]
 pub fn validator_derive(input: TokenStream) -> TokenStream {
     struct MyDeriveInput(proc_macro2::TokenStream);
 
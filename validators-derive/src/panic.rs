@@ -104,3 +104,62 @@ pub(crate) fn parameter_incorrect_format(
         ),
     )
 }
+
+#[inline]
+pub(crate) fn validate_format_incorrect(node: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(node, "you are using an incorrect format of the `validate` attribute")
+}
+
+#[inline]
+pub(crate) fn validate_for_named_struct(ast: &DeriveInput) -> syn::Error {
+    syn::Error::new_spanned(
+        &ast.ident,
+        "the `validate` attribute can only be used on fields of a struct with named fields",
+    )
+}
+
+#[inline]
+pub(crate) fn alternatives_variant_incorrect(node: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        node,
+        "every variant of an enum of alternatives should wrap exactly one validator type, like \
+         `Variant(ValidatorType)`",
+    )
+}
+
+#[inline]
+pub(crate) fn alternatives_empty(ast: &DeriveInput) -> syn::Error {
+    syn::Error::new_spanned(&ast.ident, "an enum of alternatives should have at least one variant")
+}
+
+#[inline]
+pub(crate) fn custom_format_incorrect(node: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        node,
+        "the `custom` parameter should be a path to a function, like `custom = path::to::fn`",
+    )
+}
+
+#[inline]
+pub(crate) fn borrowed_unsupported(name: &Path) -> syn::Error {
+    syn::Error::new_spanned(
+        name,
+        format!(
+            "the `{}` validator does not support borrowed fields, available validators:\n    \
+             base32\n    base64\n    base64_url\n    domain\n    line\n    regex\n    text",
+            path_to_string(name)
+        ),
+    )
+}
+
+#[inline]
+pub(crate) fn borrowed_str_unsupported(name: &Path) -> syn::Error {
+    syn::Error::new_spanned(
+        name,
+        format!(
+            "the `{}` validator may change the input, so the field should be `Cow<'a, str>` \
+             instead of `&'a str`",
+            path_to_string(name)
+        ),
+    )
+}
@@ -8,7 +8,10 @@
     feature = "bit",
     feature = "boolean",
     feature = "byte",
+    feature = "date",
+    feature = "datetime",
     feature = "domain",
+    feature = "duration",
     feature = "email",
     feature = "host",
     feature = "http_url",
@@ -27,6 +30,7 @@
     feature = "semver_req",
     feature = "signed_integer",
     feature = "text",
+    feature = "time",
     feature = "unsigned_integer",
     feature = "url",
     feature = "uuid",
@@ -59,8 +63,14 @@ pub(crate) enum Validator {
     boolean,
     #[cfg(feature = "byte")]
     byte,
+    #[cfg(feature = "date")]
+    date,
+    #[cfg(feature = "datetime")]
+    datetime,
     #[cfg(feature = "domain")]
     domain,
+    #[cfg(feature = "duration")]
+    duration,
     #[cfg(feature = "email")]
     email,
     #[cfg(feature = "host")]
@@ -97,6 +107,8 @@ pub(crate) enum Validator {
     signed_integer,
     #[cfg(feature = "text")]
     text,
+    #[cfg(feature = "time")]
+    time,
     #[cfg(feature = "unsigned_integer")]
     unsigned_integer,
     #[cfg(feature = "url")]
@@ -131,8 +143,14 @@ impl Validator {
             "boolean" => Some(Self::boolean),
             #[cfg(feature = "byte")]
             "byte" => Some(Self::byte),
+            #[cfg(feature = "date")]
+            "date" => Some(Self::date),
+            #[cfg(feature = "datetime")]
+            "datetime" => Some(Self::datetime),
             #[cfg(feature = "domain")]
             "domain" => Some(Self::domain),
+            #[cfg(feature = "duration")]
+            "duration" => Some(Self::duration),
             #[cfg(feature = "email")]
             "email" => Some(Self::email),
             #[cfg(feature = "host")]
@@ -169,6 +187,8 @@ impl Validator {
             "signed_integer" => Some(Self::signed_integer),
             #[cfg(feature = "text")]
             "text" => Some(Self::text),
+            #[cfg(feature = "time")]
+            "time" => Some(Self::time),
             #[cfg(feature = "unsigned_integer")]
             "unsigned_integer" => Some(Self::unsigned_integer),
             #[cfg(feature = "url")]
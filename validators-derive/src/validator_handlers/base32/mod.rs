@@ -1,9 +1,9 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::base_xx_attribute::BaseXXAttribute, type_enum::TypeEnum},
+    common::{attributes::base_xx_attribute::BaseXXAttribute, custom::Custom, type_enum::TypeEnum},
     panic,
 };
 
@@ -16,7 +16,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::String);
 
 impl ValidatorHandler for Base32Handler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = BaseXXAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -29,6 +33,9 @@ impl ValidatorHandler for Base32Handler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::Base32Error }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_padding = type_attribute.padding;
@@ -138,9 +145,21 @@ impl ValidatorHandler for Base32Handler {
                 }
             });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let validate_u8_slice = custom.validate(quote! { Self::parse_u8_slice(v) }, quote! {
+                Self::v_parse_u8_slice(v.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
@@ -148,7 +167,7 @@ impl ValidatorHandler for Base32Handler {
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(Self(s))
+                                #ok(Self(s))
                             }
 
                             #[inline]
@@ -157,19 +176,17 @@ impl ValidatorHandler for Base32Handler {
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(Self(validators_prelude::String::from(s)))
+                                #ok(Self(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateBytes for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_vec_u8<V: Into<validators_prelude::Vec<u8>>>(v: V) -> Result<Self, Self::Error> {
@@ -177,7 +194,7 @@ impl ValidatorHandler for Base32Handler {
 
                                 Self::v_parse_u8_slice(v.as_slice())?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
                             }
 
                             #[inline]
@@ -186,14 +203,12 @@ impl ValidatorHandler for Base32Handler {
 
                                 Self::v_parse_u8_slice(v)?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
                             }
 
                             #[inline]
                             fn validate_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<(), Self::Error> {
-                                Self::v_parse_u8_slice(v.as_ref())?;
-
-                                Ok(())
+                                #validate_u8_slice
                             }
                         }
                     });
@@ -207,7 +222,7 @@ impl ValidatorHandler for Base32Handler {
                             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                                 where
                                     S: validators_prelude::serde::Serializer, {
-                                serializer.serialize_str(self.0.as_str())
+                                serializer.serialize_str(&self.0)
                             }
                         }
                     });
@@ -242,28 +257,28 @@ impl ValidatorHandler for Base32Handler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_byte_buf<E>(self, v: validators_prelude::Vec<u8>) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -274,6 +289,40 @@ impl ValidatorHandler for Base32Handler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.content_encoding = Some("base32");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let padding = type_attribute.padding;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::base32(u, #padding) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { self.0.clone() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -281,7 +330,7 @@ impl ValidatorHandler for Base32Handler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -290,4 +339,9 @@ impl ValidatorHandler for Base32Handler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::String) }))
+    }
 }
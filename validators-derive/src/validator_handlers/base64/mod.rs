@@ -1,9 +1,9 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::base_xx_attribute::BaseXXAttribute, type_enum::TypeEnum},
+    common::{attributes::base_xx_attribute::BaseXXAttribute, custom::Custom, type_enum::TypeEnum},
     panic,
 };
 
@@ -16,7 +16,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::String);
 
 impl ValidatorHandler for Base64Handler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = BaseXXAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -29,6 +33,9 @@ impl ValidatorHandler for Base64Handler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::Base64Error }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_padding = type_attribute.padding;
@@ -43,7 +50,7 @@ impl ValidatorHandler for Base64Handler {
             let check_last_length = if type_attribute.padding.must() {
                 quote! {
                     if last_length != 4 {
-                        return Err(#error_path::PaddingMust);
+                        return Err((#error_path::PaddingMust, length - last_length));
                     }
                 }
             } else {
@@ -52,7 +59,7 @@ impl ValidatorHandler for Base64Handler {
 
             let handle_padding = if type_attribute.padding.disallow() {
                 quote! {
-                    return Err(#error_path::PaddingDisallow);
+                    return Err((#error_path::PaddingDisallow, length - last_length + p));
                 }
             } else {
                 quote! {
@@ -60,18 +67,18 @@ impl ValidatorHandler for Base64Handler {
                         2 | 3 => {
                             if last_length != 4 {
                                 // has padding
-                                return Err(#error_path::Invalid);
+                                return Err((#error_path::Invalid, length - last_length + p));
                             }
 
-                            for e in last_bytes[p + 1..].iter().copied() {
+                            for (i, e) in last_bytes[p + 1..].iter().copied().enumerate() {
                                 if e != b'=' {
-                                    return Err(#error_path::Invalid);
+                                    return Err((#error_path::Invalid, length - last_length + p + 1 + i));
                                 }
                             }
 
                             return Ok(());
                         }
-                        _ => return Err(#error_path::Invalid),
+                        _ => return Err((#error_path::Invalid, length - last_length + p)),
                     }
                 }
             };
@@ -83,11 +90,24 @@ impl ValidatorHandler for Base64Handler {
                         Self::v_parse_u8_slice(s.as_bytes())
                     }
 
+                    #[inline]
                     fn v_parse_u8_slice(v: &[u8]) -> Result<(), #error_path> {
+                        Self::v_check_u8_slice(v).map_err(|(error, _)| error)
+                    }
+
+                    fn v_details(v: &[u8], error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match (error, Self::v_check_u8_slice(v)) {
+                            (#error_path::Invalid, Err((_, offset))) => validators_prelude::vec![("offset", ErrorParam::Unsigned(offset as u128))],
+                            _ => validators_prelude::Vec::new(),
+                        }
+                    }
+
+                    /// Check the data and find the byte offset where the error is.
+                    fn v_check_u8_slice(v: &[u8]) -> Result<(), (#error_path, usize)> {
                         let length = v.len();
 
                         if length == 0 {
-                            return Err(#error_path::Invalid);
+                            return Err((#error_path::Invalid, 0));
                         }
 
                         let last_length = {
@@ -103,10 +123,10 @@ impl ValidatorHandler for Base64Handler {
                         #check_last_length
 
                         let last_bytes = if length > 4 {
-                            for e in v.iter().copied().take(length - last_length) {
+                            for (i, e) in v.iter().copied().take(length - last_length).enumerate() {
                                 match e {
                                     b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/' => (),
-                                    _ => return Err(#error_path::Invalid),
+                                    _ => return Err((#error_path::Invalid, i)),
                                 }
                             }
 
@@ -129,7 +149,7 @@ impl ValidatorHandler for Base64Handler {
                                 b'=' => {
                                     #handle_padding
                                 }
-                                _ => return Err(#error_path::Invalid),
+                                _ => return Err((#error_path::Invalid, length - last_length + p)),
                             }
 
                             p += 1;
@@ -138,9 +158,26 @@ impl ValidatorHandler for Base64Handler {
                 }
             });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let validate_str_detailed = custom.detailed(
+                quote! { Self::validate_str(s) },
+                quote! { Self::v_details(s.as_bytes(), error) },
+            );
+
+            let validate_u8_slice = custom.validate(quote! { Self::parse_u8_slice(v) }, quote! {
+                Self::v_parse_u8_slice(v.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
@@ -148,7 +185,7 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(Self(s))
+                                #ok(Self(s))
                             }
 
                             #[inline]
@@ -157,19 +194,24 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(Self(validators_prelude::String::from(s)))
+                                #ok(Self(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
 
-                                Ok(())
+                                #validate_str_detailed
                             }
                         }
 
                         impl ValidateBytes for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_vec_u8<V: Into<validators_prelude::Vec<u8>>>(v: V) -> Result<Self, Self::Error> {
@@ -177,7 +219,7 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_u8_slice(v.as_slice())?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v) }))
                             }
 
                             #[inline]
@@ -186,14 +228,12 @@ impl ValidatorHandler for Base64Handler {
 
                                 Self::v_parse_u8_slice(v)?;
 
-                                Ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
+                                #ok(Self(unsafe { validators_prelude::String::from_utf8_unchecked(v.to_vec()) }))
                             }
 
                             #[inline]
                             fn validate_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<(), Self::Error> {
-                                Self::v_parse_u8_slice(v.as_ref())?;
-
-                                Ok(())
+                                #validate_u8_slice
                             }
                         }
                     });
@@ -207,7 +247,7 @@ impl ValidatorHandler for Base64Handler {
                             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                                 where
                                     S: validators_prelude::serde::Serializer, {
-                                serializer.serialize_str(self.0.as_str())
+                                serializer.serialize_str(&self.0)
                             }
                         }
                     });
@@ -242,28 +282,28 @@ impl ValidatorHandler for Base64Handler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_byte_buf<E>(self, v: validators_prelude::Vec<u8>) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -274,6 +314,40 @@ impl ValidatorHandler for Base64Handler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.content_encoding = Some("base64");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let padding = type_attribute.padding;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::base64(u, #padding) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { self.0.clone() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -281,7 +355,7 @@ impl ValidatorHandler for Base64Handler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -290,4 +364,9 @@ impl ValidatorHandler for Base64Handler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::String) }))
+    }
 }
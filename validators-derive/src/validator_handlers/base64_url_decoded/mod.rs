@@ -1,10 +1,11 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
     common::{
-        attributes::base_xx_attribute::BaseXXAttribute, tri_allow::TriAllow, type_enum::TypeEnum,
+        attributes::base_xx_attribute::BaseXXAttribute, custom::Custom, tri_allow::TriAllow,
+        type_enum::TypeEnum,
     },
     panic,
 };
@@ -18,7 +19,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::VecU8);
 
 impl ValidatorHandler for Base64UrlDecodedHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = BaseXXAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -32,6 +37,9 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::Base64UrlDecodedError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_padding = type_attribute.padding;
@@ -72,7 +80,7 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
                                 }
                             }
 
-                            return Ok(());
+                            break;
                         }
                         _ => return Err(#error_path::Invalid),
                     }
@@ -157,7 +165,7 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
 
                                 loop {
                                     if p == last_length {
-                                        return Ok(());
+                                        break;
                                     }
 
                                     let e = last_bytes[p];
@@ -172,50 +180,80 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
 
                                     p += 1;
                                 }
+
+                                // `p` characters of the last group are data, and the bits of the last one which do not make a whole byte must be zeros, so that the input can be decoded
+                                let mask = match p {
+                                    2 => 0b1111,
+                                    3 => 0b11,
+                                    4 => return Ok(()),
+                                    _ => return Err(#error_path::Decode),
+                                };
+
+                                let value = match last_bytes[p - 1] {
+                                    e @ b'A'..=b'Z' => e - b'A',
+                                    e @ b'a'..=b'z' => e - b'a' + 26,
+                                    e @ b'0'..=b'9' => e - b'0' + 52,
+                                    // `-` and `_` are 62 and 63, whose last two bits are not zeros
+                                    _ => return Err(#error_path::Decode),
+                                };
+
+                                if value & mask == 0 {
+                                    Ok(())
+                                } else {
+                                    Err(#error_path::Decode)
+                                }
                             }
                         }
                     });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_validate_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let validate_u8_slice = custom.validate(quote! { Self::parse_u8_slice(v) }, quote! {
+                Self::v_validate_u8_slice(v.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateBytes for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_vec_u8<V: Into<validators_prelude::Vec<u8>>>(v: V) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u8_slice(v.into().as_slice())?))
+                                #ok(Self(Self::v_parse_u8_slice(v.into().as_slice())?))
                             }
 
                             #[inline]
                             fn parse_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u8_slice(v.as_ref())?))
+                                #ok(Self(Self::v_parse_u8_slice(v.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_u8_slice<V: AsRef<[u8]>>(v: V) -> Result<(), Self::Error> {
-                                Self::v_validate_u8_slice(v.as_ref())?;
-
-                                Ok(())
+                                #validate_u8_slice
                             }
                         }
 
@@ -269,28 +307,28 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_u8_slice(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_byte_buf<E>(self, v: validators_prelude::Vec<u8>) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateBytes>::parse_vec_u8(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -301,6 +339,46 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.pattern = Some(String::from(if type_attribute.padding.disallow() {
+                    "^[0-9A-Za-z_-]*$"
+                } else {
+                    "^[0-9A-Za-z_-]*={0,2}$"
+                }));
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let padding = type_attribute.padding;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::base64_url(u, #padding) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::Vec<u8> },
+                    quote! { self.0.clone() },
+                    quote! { Ok(Self(v)) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -308,7 +386,7 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -317,4 +395,9 @@ impl ValidatorHandler for Base64UrlDecodedHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::Vec<u8>) }))
+    }
 }
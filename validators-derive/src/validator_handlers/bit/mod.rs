@@ -2,11 +2,11 @@ mod bit_attribute;
 
 use bit_attribute::BitAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{range::range_equal, type_enum::TypeEnum},
+    common::{custom::Custom, range::range_equal, type_enum::TypeEnum},
     panic,
 };
 
@@ -19,7 +19,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::Bit);
 
 impl ValidatorHandler for BitHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = BitAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -32,6 +36,9 @@ impl ValidatorHandler for BitHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::BitError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_range = &type_attribute.range;
@@ -72,7 +79,9 @@ impl ValidatorHandler for BitHandler {
                 }
 
                 if let Some(max) = type_attribute.range.max {
-                    token_stream.extend(if type_attribute.range.inclusive {
+                    let inclusive = type_attribute.range.inclusive;
+
+                    token_stream.extend(if inclusive {
                         quote! {
                             if v > #max {
                                 return Err(#error_path::TooLarge);
@@ -108,32 +117,98 @@ impl ValidatorHandler for BitHandler {
                         }
                     });
 
+            let min = type_attribute.range.min.unwrap_or(0);
+            let max = type_attribute.range.max.map_or_else(
+                || quote! { validators_prelude::byte_unit::Bit::MAX.as_u128() },
+                |max| quote! { #max },
+            );
+            let inclusive = type_attribute.range.max.is_none() || type_attribute.range.inclusive;
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(u: u128, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match error {
+                            #error_path::TooSmall => validators_prelude::vec![("min", ErrorParam::Unsigned(#min))],
+                            #error_path::TooLarge => {
+                                // the value does not even fit in `Bit`
+                                let (max, inclusive) = if u > validators_prelude::byte_unit::Bit::MAX.as_u128() {
+                                    (validators_prelude::byte_unit::Bit::MAX.as_u128(), true)
+                                } else {
+                                    (#max, #inclusive)
+                                };
+
+                                validators_prelude::vec![("max", ErrorParam::Unsigned(max)), ("inclusive", ErrorParam::Bool(inclusive))]
+                            },
+                            _ => validators_prelude::Vec::new(),
+                        }
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom.detailed(
+                quote! { Self::validate_str(s) },
+                quote! { validators_prelude::byte_unit::Bit::parse_str(s).map(|v| Self::v_details(v.as_u128(), error)).unwrap_or_default() },
+            );
+
+            let validate_u128_detailed = custom
+                .detailed(quote! { Self::validate_u128(u) }, quote! { Self::v_details(u, error) });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
 
-                                Ok(())
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
+
+                                #validate_str_detailed
                             }
                         }
                     });
 
+            let validate_u128 = custom.validate(
+                quote! { Self::parse_u128(u) },
+                quote! {
+                    let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge)?;
+
+                    Self::v_parse_v(v)?;
+
+                    Ok(())
+                },
+            );
+
+            let validate_u64 = custom.validate(quote! { Self::parse_u64(u) }, quote! {
+                let v = validators_prelude::byte_unit::Bit::from_u64(u);
+
+                Self::v_parse_v(v)?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
@@ -141,16 +216,17 @@ impl ValidatorHandler for BitHandler {
 
                                 Self::v_parse_v(v)?;
 
-                                Ok(Self(v))
+                                #ok(Self(v))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                let v = validators_prelude::byte_unit::Bit::from_u128(u).ok_or(#error_path::TooLarge)?;
-
-                                Self::v_parse_v(v)?;
+                                #validate_u128
+                            }
 
-                                Ok(())
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
                             }
 
                             #[inline]
@@ -159,16 +235,12 @@ impl ValidatorHandler for BitHandler {
 
                                 Self::v_parse_v(v)?;
 
-                                Ok(Self(v))
+                                #ok(Self(v))
                             }
 
                             #[inline]
                             fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                let v = validators_prelude::byte_unit::Bit::from_u64(u);
-
-                                Self::v_parse_v(v)?;
-
-                                Ok(())
+                                #validate_u64
                             }
                         }
                     });
@@ -197,7 +269,7 @@ impl ValidatorHandler for BitHandler {
                                         D: validators_prelude::serde::Deserializer<'de>, {
                                         let v: validators_prelude::byte_unit::Bit = validators_prelude::serde::Deserialize::deserialize(deserializer)?;
 
-                                        Self::v_parse_v(v).map_err(validators_prelude::serde::de::Error::custom)?;
+                                        Self::v_parse_v(v).map_err(validators_prelude::de_error)?;
 
                                         Ok(Self(v))
                                     }
@@ -206,6 +278,46 @@ impl ValidatorHandler for BitHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords =
+                    crate::common::schema::SchemaKeywords::with_types(&["string", "integer"]);
+
+                keywords.set_range(
+                    type_attribute.range.min,
+                    type_attribute.range.max,
+                    type_attribute.range.inclusive,
+                );
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let range = &type_attribute.range;
+
+                crate::common::arbitrary::impl_arbitrary(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::integer_within(u, #range, 0..=validators_prelude::byte_unit::Bit::MAX.as_u128()) },
+                    quote! { <Self as ValidateUnsignedInteger>::parse_u128(v) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::format!("{}", self.0) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -213,7 +325,7 @@ impl ValidatorHandler for BitHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -222,4 +334,9 @@ impl ValidatorHandler for BitHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::byte_unit::Bit) }))
+    }
 }
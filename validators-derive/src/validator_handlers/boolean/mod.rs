@@ -1,9 +1,9 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::basic_attribute::BasicAttribute, type_enum::TypeEnum},
+    common::{attributes::basic_attribute::BasicAttribute, custom::Custom, type_enum::TypeEnum},
     panic,
 };
 
@@ -16,7 +16,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::Boolean);
 
 impl ValidatorHandler for BooleanHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         #[allow(unused_variables)]
         let type_attribute = BasicAttribute::build_from_meta(&meta)?;
 
@@ -31,6 +35,9 @@ impl ValidatorHandler for BooleanHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::BooleanError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             token_stream.extend(quote! {
                         impl #name {
                             fn v_parse_str(s: &str) -> Result<bool, #error_path> {
@@ -213,89 +220,109 @@ impl ValidatorHandler for BooleanHandler {
                         }
                     });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let validate_char = custom.validate(quote! { Self::parse_char(c) }, quote! {
+                Self::v_parse_char(c)?;
+
+                Ok(())
+            });
+
+            let validate_i128 = custom.validate(quote! { Self::parse_i128(i) }, quote! {
+                Self::v_parse_i128(i)?;
+
+                Ok(())
+            });
+
+            let validate_u128 = custom.validate(quote! { Self::parse_u128(u) }, quote! {
+                Self::v_parse_u128(u)?;
+
+                Ok(())
+            });
+
+            let validate_bool = custom.validate(quote! { Self::parse_bool(b) }, quote! {
+                Self::v_parse_bool(b)?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateChar for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_char(c: char) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_char(c)?))
+                                #ok(Self(Self::v_parse_char(c)?))
                             }
 
                             #[inline]
                             fn validate_char(c: char) -> Result<(), Self::Error> {
-                                Self::v_parse_char(c)?;
-
-                                Ok(())
+                                #validate_char
                             }
                         }
 
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i128(i)?))
+                                #ok(Self(Self::v_parse_i128(i)?))
                             }
 
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                Self::v_parse_i128(i)?;
-
-                                Ok(())
+                                #validate_i128
                             }
                         }
 
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u128(u)?))
+                                #ok(Self(Self::v_parse_u128(u)?))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                Self::v_parse_u128(u)?;
-
-                                Ok(())
+                                #validate_u128
                             }
                         }
 
                         impl ValidateBoolean for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_bool(b: bool) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_bool(b)?))
+                                #ok(Self(Self::v_parse_bool(b)?))
                             }
 
                             #[inline]
                             fn validate_bool(b: bool) -> Result<(), Self::Error> {
-                                Self::v_parse_bool(b)?;
-
-                                Ok(())
+                                #validate_bool
                             }
                         }
                     });
@@ -339,14 +366,14 @@ impl ValidatorHandler for BooleanHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
@@ -359,21 +386,21 @@ impl ValidatorHandler for BooleanHandler {
                                             fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateChar>::parse_char(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateChar>::parse_char(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                 <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                 <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
@@ -381,7 +408,7 @@ impl ValidatorHandler for BooleanHandler {
                                             where
                                                 E: validators_prelude::serde::de::Error,
                                             {
-                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
@@ -389,7 +416,7 @@ impl ValidatorHandler for BooleanHandler {
                                             where
                                                 E: validators_prelude::serde::de::Error,
                                             {
-                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -400,6 +427,47 @@ impl ValidatorHandler for BooleanHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords::new("boolean");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::boolean(u) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { bool },
+                    quote! { self.0 },
+                    quote! { Ok(Self(v)) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[
+                    ("true", &["t"]),
+                    ("false", &["f"]),
+                    ("yes", &["y"]),
+                    ("no", &["n"]),
+                    ("on", &[]),
+                    ("off", &[]),
+                    ("1", &[]),
+                    ("0", &[]),
+                ]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -407,7 +475,7 @@ impl ValidatorHandler for BooleanHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -416,4 +484,9 @@ impl ValidatorHandler for BooleanHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (bool) }))
+    }
 }
@@ -0,0 +1,303 @@
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
+
+use super::ValidatorHandler;
+use crate::{
+    common::{
+        allow::Allow,
+        attributes::date_time_attribute::{DateTimeAttribute, DateTimeKind},
+        custom::Custom,
+        tri_allow::TriAllow,
+        type_enum::TypeEnum,
+    },
+    panic,
+};
+
+pub(crate) struct DateTimeHandler;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Struct(TypeEnum);
+
+const ITEM: Struct = Struct(TypeEnum::DateTime);
+
+impl ValidatorHandler for DateTimeHandler {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let type_attribute = DateTimeAttribute::build_from_meta(&meta, DateTimeKind::DateTime)?;
+
+        if let Data::Struct(data) = ast.data
+            && let Fields::Unnamed(_) = &data.fields
+            && data.fields.len() == 1
+        {
+            let mut token_stream = proc_macro2::TokenStream::new();
+
+            let name = ast.ident;
+
+            let error_path: Path =
+                syn::parse2(quote! { validators_prelude::DateTimeError }).unwrap();
+
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
+            #[cfg(feature = "test")]
+            {
+                use crate::common::test::OptionToken;
+
+                let v_basic_format = type_attribute.basic_format;
+                let v_timezone = type_attribute.timezone;
+                let v_fraction = type_attribute.fraction;
+                let v_min = OptionToken(type_attribute.min.clone());
+                let v_max = OptionToken(type_attribute.max.clone());
+
+                token_stream.extend(quote! {
+                    impl #name {
+                        pub(crate) const V_BASIC_FORMAT: validators_prelude::TriAllow = #v_basic_format;
+                        pub(crate) const V_TIMEZONE: validators_prelude::TriAllow = #v_timezone;
+                        pub(crate) const V_FRACTION: validators_prelude::TriAllow = #v_fraction;
+                        pub(crate) const V_MIN: Option<validators_prelude::DateTime> = #v_min;
+                        pub(crate) const V_MAX: Option<validators_prelude::DateTime> = #v_max;
+                    }
+                });
+            }
+
+            let check_basic_format = match type_attribute.basic_format {
+                TriAllow::Allow => quote! {},
+                TriAllow::Must => quote! {
+                    if !format.basic {
+                        return Err(#error_path::BasicFormatMust);
+                    }
+                },
+                TriAllow::Disallow => quote! {
+                    if format.basic {
+                        return Err(#error_path::BasicFormatDisallow);
+                    }
+                },
+            };
+
+            let check_timezone = match type_attribute.timezone {
+                TriAllow::Allow => quote! {},
+                TriAllow::Must => quote! {
+                    if v.time.offset.is_none() {
+                        return Err(#error_path::TimezoneMust);
+                    }
+                },
+                TriAllow::Disallow => quote! {
+                    if v.time.offset.is_some() {
+                        return Err(#error_path::TimezoneDisallow);
+                    }
+                },
+            };
+
+            let check_fraction = match type_attribute.fraction {
+                Allow::Allow => quote! {},
+                Allow::Disallow => quote! {
+                    if format.fraction {
+                        return Err(#error_path::FractionDisallow);
+                    }
+                },
+            };
+
+            let check_min = match &type_attribute.min {
+                Some(min) => quote! {
+                    if v.cmp_instant(&#min) == ::core::cmp::Ordering::Less {
+                        return Err(#error_path::TooEarly);
+                    }
+                },
+                None => quote! {},
+            };
+
+            let check_max = match &type_attribute.max {
+                Some(max) => quote! {
+                    if v.cmp_instant(&#max) == ::core::cmp::Ordering::Greater {
+                        return Err(#error_path::TooLate);
+                    }
+                },
+                None => quote! {},
+            };
+
+            token_stream.extend(quote! {
+                impl #name {
+                    #[inline]
+                    fn v_parse_str(s: &str) -> Result<validators_prelude::DateTime, #error_path> {
+                        let (v, format) = validators_prelude::parse_date_time(s).ok_or(#error_path::Invalid)?;
+
+                        #check_basic_format
+                        #check_timezone
+                        #check_fraction
+                        #check_min
+                        #check_max
+
+                        Ok(v)
+                    }
+                }
+            });
+
+            let early_details = type_attribute.min.as_ref().map(|min| {
+                quote! { #error_path::TooEarly => validators_prelude::vec![("min", ErrorParam::String(validators_prelude::format!("{}", #min)))], }
+            });
+            let late_details = type_attribute.max.as_ref().map(|max| {
+                quote! { #error_path::TooLate => validators_prelude::vec![("max", ErrorParam::String(validators_prelude::format!("{}", #max)))], }
+            });
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match error {
+                            #early_details
+                            #late_details
+                            _ => validators_prelude::Vec::new(),
+                        }
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom
+                .detailed(quote! { Self::validate_str(s) }, quote! { Self::v_details(error) });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            token_stream.extend(quote! {
+                impl ValidateString for #name {
+                    type Error = #error_type;
+
+                    #[inline]
+                    fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #ok(Self(Self::v_parse_str(s.into().as_str())?))
+                    }
+
+                    #[inline]
+                    fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #ok(Self(Self::v_parse_str(s.as_ref())?))
+                    }
+
+                    #[inline]
+                    fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                        #validate_str
+                    }
+
+                    #[inline]
+                    fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                        #validate_str_detailed
+                    }
+                }
+            });
+
+            #[cfg(feature = "serde")]
+            {
+                if type_attribute.serde_options.serialize {
+                    token_stream.extend(quote! {
+                        impl validators_prelude::serde::Serialize for #name {
+                            #[inline]
+                            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                                where
+                                    S: validators_prelude::serde::Serializer, {
+                                serializer.collect_str(&self.0)
+                            }
+                        }
+                    });
+                }
+
+                if type_attribute.serde_options.deserialize {
+                    token_stream.extend(quote! {
+                        impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
+                            #[inline]
+                            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                            where
+                                D: validators_prelude::serde::Deserializer<'de>, {
+                                struct MyVisitor;
+
+                                impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                                    type Value = #name;
+
+                                    #[inline]
+                                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                        f.write_str("a date-time string")
+                                    }
+
+                                    #[inline]
+                                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                                    where
+                                        E: validators_prelude::serde::de::Error, {
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
+                                    }
+                                }
+
+                                deserializer.deserialize_str(MyVisitor)
+                            }
+                        }
+                    });
+                }
+            }
+
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                if !type_attribute.basic_format.must() && !type_attribute.timezone.disallow() {
+                    keywords.format = Some("date-time");
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                use crate::common::test::OptionToken;
+
+                let basic_format = type_attribute.basic_format;
+                let timezone = type_attribute.timezone;
+                let fraction = type_attribute.fraction;
+                let min = OptionToken(type_attribute.min.clone());
+                let max = OptionToken(type_attribute.max.clone());
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::date_time(u, #basic_format, #timezone, #fraction, #min, #max) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::format!("{}", self.0) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
+            #[cfg(feature = "rocket")]
+            {
+                if type_attribute.rocket_options.from_form_field {
+                    crate::common::rocket::impl_from_form_field(&mut token_stream, &name);
+                }
+
+                if type_attribute.rocket_options.from_param {
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
+                }
+            }
+
+            return Ok(token_stream);
+        }
+
+        Err(panic::validator_for_specific_item(meta.path(), ITEM))
+    }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::DateTime) }))
+    }
+}
@@ -2,8 +2,8 @@ use syn::{Meta, Token, punctuated::Punctuated};
 
 use crate::{
     common::{
-        allow::Allow, rocket_options::RocketOptions, serde_options::SerdeOptions,
-        tri_allow::TriAllow,
+        allow::Allow, domain_list::DomainList, rocket_options::RocketOptions,
+        serde_options::SerdeOptions, tri_allow::TriAllow,
     },
     panic,
 };
@@ -14,6 +14,10 @@ pub(crate) struct DomainAttribute {
     pub(crate) port:                TriAllow,
     pub(crate) at_least_two_labels: TriAllow,
     pub(crate) conflict:            Allow,
+    pub(crate) registrable:         TriAllow,
+    pub(crate) known_tld:           TriAllow,
+    pub(crate) allowed_domains:     Option<DomainList>,
+    pub(crate) denied_domains:      Option<DomainList>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options:       SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
@@ -22,14 +26,29 @@ pub(crate) struct DomainAttribute {
 
 impl DomainAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters =
-            ["ipv4", "local", "port", "at_least_two_labels", "conflict", "serde", "rocket"];
+        let correct_parameters = [
+            "ipv4",
+            "local",
+            "port",
+            "at_least_two_labels",
+            "conflict",
+            "registrable",
+            "known_tld",
+            "allowed_domains",
+            "denied_domains",
+            "serde",
+            "rocket",
+        ];
 
         let mut ipv4 = TriAllow::Allow;
         let mut local = TriAllow::Allow;
         let mut port = TriAllow::Allow;
         let mut at_least_two_labels = TriAllow::Allow;
         let mut conflict = Allow::Disallow;
+        let mut registrable = TriAllow::Allow;
+        let mut known_tld = TriAllow::Allow;
+        let mut allowed_domains = None;
+        let mut denied_domains = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
 
@@ -47,6 +66,10 @@ impl DomainAttribute {
                 let mut port_is_set = false;
                 let mut at_least_two_labels_is_set = false;
                 let mut conflict_is_set = false;
+                let mut registrable_is_set = false;
+                let mut known_tld_is_set = false;
+                let mut allowed_domains_is_set = false;
+                let mut denied_domains_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
 
@@ -118,6 +141,65 @@ impl DomainAttribute {
 
                                 return Ok(true);
                             },
+                            "registrable" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if registrable_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                registrable_is_set = true;
+
+                                registrable = v;
+
+                                return Ok(true);
+                            },
+                            "known_tld" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if v.disallow() {
+                                    return Err(syn::Error::new_spanned(
+                                        meta,
+                                        "expected `known_tld(Must/Allow)`",
+                                    ));
+                                }
+
+                                if known_tld_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                known_tld_is_set = true;
+
+                                known_tld = v;
+
+                                return Ok(true);
+                            },
+                            "allowed_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if allowed_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                allowed_domains_is_set = true;
+
+                                allowed_domains = Some(v);
+
+                                return Ok(true);
+                            },
+                            "denied_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if denied_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                denied_domains_is_set = true;
+
+                                denied_domains = Some(v);
+
+                                return Ok(true);
+                            },
                             "serde" => {
                                 let v = SerdeOptions::from_meta(meta)?;
 
@@ -168,6 +250,10 @@ impl DomainAttribute {
             port,
             at_least_two_labels,
             conflict,
+            registrable,
+            known_tld,
+            allowed_domains,
+            denied_domains,
             serde_options,
             rocket_options,
         })
@@ -3,11 +3,11 @@ mod domain_attribute;
 use domain_attribute::DomainAttribute;
 use educe::Educe;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{tri_allow::TriAllow, type_enum::TypeEnum},
+    common::{custom::Custom, tri_allow::TriAllow, type_enum::TypeEnum},
     panic,
 };
 
@@ -122,7 +122,11 @@ const ITEM_ALLOW_IPV4_ALLOW_LOCAL_WITH_PORT: StructAllowIPv4AllowPortAllowLocal
     };
 
 impl ValidatorHandler for DomainHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = DomainAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data {
@@ -500,12 +504,17 @@ impl ValidatorHandler for DomainHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::DomainError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_ipv4 = type_attribute.ipv4;
                 let v_local = type_attribute.local;
                 let v_port = type_attribute.port;
                 let v_at_least_two_labels = type_attribute.at_least_two_labels;
+                let v_registrable = type_attribute.registrable;
+                let v_known_tld = type_attribute.known_tld;
 
                 token_stream.extend(quote! {
                     impl #name {
@@ -513,6 +522,8 @@ impl ValidatorHandler for DomainHandler {
                         pub(crate) const V_LOCAL: validators_prelude::TriAllow = #v_local;
                         pub(crate) const V_PORT: validators_prelude::TriAllow = #v_port;
                         pub(crate) const V_AT_LEAST_TWO_LABELS: validators_prelude::TriAllow = #v_at_least_two_labels;
+                        pub(crate) const V_REGISTRABLE: validators_prelude::TriAllow = #v_registrable;
+                        pub(crate) const V_KNOWN_TLD: validators_prelude::TriAllow = #v_known_tld;
                     }
                 });
             }
@@ -712,6 +723,52 @@ impl ValidatorHandler for DomainHandler {
                 }
             };
 
+            let handle_domain_lists = {
+                let mut checks = crate::common::known_tld::handle_known_tld(
+                    type_attribute.known_tld,
+                    &error_path,
+                );
+
+                checks.extend(crate::common::registrable::handle_registrable(
+                    type_attribute.registrable,
+                    &error_path,
+                ));
+
+                checks.extend(crate::common::domain_list::handle_domain_lists(
+                    type_attribute.allowed_domains.as_ref(),
+                    type_attribute.denied_domains.as_ref(),
+                    &error_path,
+                ));
+
+                if checks.is_empty() {
+                    quote! {
+                        Ok(#handle_domain_str_and_port_str)
+                    }
+                } else {
+                    // `is_ipv4` is always `false` if IPv4s are not parsed
+                    let is_ip = if type_attribute.ipv4 == TriAllow::Allow
+                        && type_attribute.local == TriAllow::Allow
+                        && type_attribute.at_least_two_labels == TriAllow::Allow
+                    {
+                        quote! { validators_prelude::parse_ipv4_allow_an_ended_dot(domain.as_str()).is_ok() }
+                    } else {
+                        quote! { is_ipv4 }
+                    };
+
+                    quote! {
+                        let (domain, port, is_ipv4, is_local) = #handle_domain_str_and_port_str;
+
+                        {
+                            let domain: Option<&str> = if #is_ip { None } else { Some(domain.as_str()) };
+
+                            #checks
+                        }
+
+                        Ok((domain, port, is_ipv4, is_local))
+                    }
+                }
+            };
+
             let conflict_meta = if meta_is_conflict {
                 quote! {
                     #[allow(unreachable_code)]
@@ -737,7 +794,7 @@ impl ValidatorHandler for DomainHandler {
                             }
                         };
 
-                        Ok(#handle_domain_str_and_port_str)
+                        #handle_domain_lists
                     }
                 }
             });
@@ -866,29 +923,64 @@ impl ValidatorHandler for DomainHandler {
                 }
             };
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
+
+                Ok(())
+            });
+
+            // the index of the first invalid label is found again only when the validation fails
+            let domain_part = if type_attribute.port.disallow() {
+                quote! { s }
+            } else {
+                quote! { s.rsplit_once(':').map_or(s, |(domain, _)| domain) }
+            };
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(s: &str, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match error {
+                            #error_path::Invalid => match validators_prelude::invalid_domain_label(#domain_part) {
+                                Some(label) => validators_prelude::vec![("label", ErrorParam::Unsigned(label as u128))],
+                                None => validators_prelude::Vec::new(),
+                            },
+                            _ => validators_prelude::Vec::new(),
+                        }
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom
+                .detailed(quote! { Self::validate_str(s) }, quote! { Self::v_details(s, error) });
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
                         let (domain, _port, _is_ipv4, _is_local) = Self::v_parse_str(validators_prelude::Cow::Owned(s.into()))?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
                         let (domain, _port, _is_ipv4, _is_local) = Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(validators_prelude::Cow::Borrowed(s.as_ref()))?;
+                        #validate_str
+                    }
 
-                        Ok(())
+                    #[inline]
+                    fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                        let s = s.as_ref();
+
+                        #validate_str_detailed
                     }
                 }
             });
@@ -910,7 +1002,7 @@ impl ValidatorHandler for DomainHandler {
                                 if QualifyDomain::is_fully_qualified(self) {
                                     &self.0[..(self.0.len() - 1)]
                                 } else {
-                                    self.0.as_str()
+                                    &self.0
                                 }
                             }
                         }
@@ -936,6 +1028,40 @@ impl ValidatorHandler for DomainHandler {
                 },
             );
 
+            crate::common::registrable::impl_registrable_domain(
+                &mut token_stream,
+                &name,
+                match type_attribute.ipv4 {
+                    TriAllow::Allow => {
+                        if type_attribute.local == TriAllow::Allow
+                            && type_attribute.at_least_two_labels != TriAllow::Allow
+                        {
+                            quote! {
+                                if self.is_ipv4 {
+                                    None
+                                } else {
+                                    Some(QualifyDomain::get_domain_non_fully_qualified(self))
+                                }
+                            }
+                        } else {
+                            quote! {
+                                let domain = QualifyDomain::get_domain_non_fully_qualified(self);
+
+                                if validators_prelude::parse_ipv4_allow_an_ended_dot(domain).is_ok() {
+                                    None
+                                } else {
+                                    Some(domain)
+                                }
+                            }
+                        }
+                    },
+                    TriAllow::Must => quote! { None },
+                    TriAllow::Disallow => {
+                        quote! { Some(QualifyDomain::get_domain_non_fully_qualified(self)) }
+                    },
+                },
+            );
+
             token_stream.extend(match type_attribute.port {
                 TriAllow::Allow => {
                     quote! {
@@ -1087,14 +1213,14 @@ impl ValidatorHandler for DomainHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                     }
 
                                     #[inline]
                                     fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                     }
                                 }
 
@@ -1105,6 +1231,45 @@ impl ValidatorHandler for DomainHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                if !type_attribute.port.must() && !type_attribute.ipv4.must() {
+                    keywords.format = Some("idn-hostname");
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let ipv4 = type_attribute.ipv4;
+                let local = type_attribute.local;
+                let at_least_two_labels = type_attribute.at_least_two_labels;
+                let port = type_attribute.port;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::domain(u, #ipv4, #local, #at_least_two_labels, #port) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { ToUriAuthorityString::to_uri_authority_string(self).into_owned() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -1112,7 +1277,7 @@ impl ValidatorHandler for DomainHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -1121,4 +1286,40 @@ impl ValidatorHandler for DomainHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        let type_attribute = DomainAttribute::build_from_meta(meta)?;
+
+        let mut fields = quote! {
+            domain: validators_prelude::String,
+        };
+
+        if type_attribute.local == TriAllow::Allow
+            && type_attribute.at_least_two_labels != TriAllow::Allow
+        {
+            if type_attribute.ipv4 == TriAllow::Allow {
+                fields.extend(quote! {
+                    is_ipv4: bool,
+                });
+            }
+
+            fields.extend(quote! {
+                is_local: bool,
+            });
+        } else if type_attribute.port.disallow() {
+            return Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::String) }));
+        }
+
+        match type_attribute.port {
+            TriAllow::Allow => fields.extend(quote! {
+                port: Option<u16>,
+            }),
+            TriAllow::Must => fields.extend(quote! {
+                port: u16,
+            }),
+            TriAllow::Disallow => (),
+        }
+
+        Ok(Fields::Named(syn::parse_quote! { { #fields } }))
+    }
 }
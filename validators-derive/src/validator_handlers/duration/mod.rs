@@ -0,0 +1,359 @@
+mod duration_attribute;
+
+use duration_attribute::DurationAttribute;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
+
+use super::ValidatorHandler;
+use crate::{
+    common::{custom::Custom, type_enum::TypeEnum},
+    panic,
+};
+
+pub(crate) struct DurationHandler;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Struct(TypeEnum);
+
+const ITEM: Struct = Struct(TypeEnum::Duration);
+
+/// Convert seconds to the tokens of a `Duration` constant.
+#[inline]
+fn seconds_to_duration(seconds: f64) -> proc_macro2::TokenStream {
+    let secs = seconds.trunc() as u64;
+    let nanos = (((seconds - seconds.trunc()) * 1_000_000_000.0).round() as u32).min(999_999_999);
+
+    quote! { ::core::time::Duration::new(#secs, #nanos) }
+}
+
+impl ValidatorHandler for DurationHandler {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let type_attribute = DurationAttribute::build_from_meta(&meta)?;
+
+        if let Data::Struct(data) = ast.data
+            && let Fields::Unnamed(_) = &data.fields
+            && data.fields.len() == 1
+        {
+            let mut token_stream = proc_macro2::TokenStream::new();
+
+            let name = ast.ident;
+
+            let error_path: Path =
+                syn::parse2(quote! { validators_prelude::DurationError }).unwrap();
+
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
+            #[cfg(feature = "test")]
+            {
+                let v_range = &type_attribute.range;
+                let v_ignore_case = &type_attribute.ignore_case;
+
+                token_stream.extend(quote! {
+                    impl #name {
+                        pub(crate) const V_RANGE: validators_prelude::RangeOption<f64> = #v_range;
+                        pub(crate) const V_IGNORE_CASE: bool = #v_ignore_case;
+                    }
+                });
+            }
+
+            let handle_range = {
+                let mut token_stream = proc_macro2::TokenStream::new();
+
+                if let Some(min) = type_attribute.range.min {
+                    let min = seconds_to_duration(min);
+
+                    token_stream.extend(quote! {
+                        if v < #min {
+                            return Err(#error_path::TooSmall);
+                        }
+                    });
+                }
+
+                if let Some(max) = type_attribute.range.max {
+                    let max = seconds_to_duration(max);
+
+                    token_stream.extend(if type_attribute.range.inclusive {
+                        quote! {
+                            if v > #max {
+                                return Err(#error_path::TooLarge);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            if v >= #max {
+                                return Err(#error_path::TooLarge);
+                            }
+                        }
+                    });
+                }
+
+                token_stream
+            };
+
+            let ignore_case = type_attribute.ignore_case;
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_parse_str(s: &str) -> Result<::core::time::Duration, #error_path> {
+                        let v = validators_prelude::parse_duration(s, #ignore_case)?;
+
+                        Self::v_parse_v(v)?;
+
+                        Ok(v)
+                    }
+
+                    fn v_parse_v(v: ::core::time::Duration) -> Result<(), #error_path> {
+                        #handle_range
+
+                        Ok(())
+                    }
+
+                    #[inline]
+                    fn v_secs_to_duration(u: u128) -> Result<::core::time::Duration, #error_path> {
+                        match u64::try_from(u) {
+                            Ok(secs) => Ok(::core::time::Duration::from_secs(secs)),
+                            Err(_) => Err(#error_path::TooLarge),
+                        }
+                    }
+                }
+            });
+
+            let min = type_attribute.range.min.unwrap_or(0.0);
+            let max = type_attribute.range.max.map_or_else(
+                || quote! { ::core::time::Duration::MAX.as_secs_f64() },
+                |max| quote! { #max },
+            );
+            let inclusive = type_attribute.range.max.is_none() || type_attribute.range.inclusive;
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(overflow: bool, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match error {
+                            #error_path::TooSmall => validators_prelude::vec![("min", ErrorParam::Float(#min))],
+                            #error_path::TooLarge => {
+                                // the number of seconds does not even fit in `Duration`
+                                let (max, inclusive) = if overflow {
+                                    (::core::time::Duration::MAX.as_secs_f64(), true)
+                                } else {
+                                    (#max, #inclusive)
+                                };
+
+                                validators_prelude::vec![("max", ErrorParam::Float(max)), ("inclusive", ErrorParam::Bool(inclusive))]
+                            },
+                            _ => validators_prelude::Vec::new(),
+                        }
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom.detailed(quote! { Self::validate_str(s) }, quote! {
+                Self::v_details(matches!(validators_prelude::parse_duration(s, #ignore_case), Err(#error_path::TooLarge)), error)
+            });
+
+            let validate_u128_detailed = custom.detailed(
+                quote! { Self::validate_u128(u) },
+                quote! { Self::v_details(u > u64::MAX as u128, error) },
+            );
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            token_stream.extend(quote! {
+                        impl ValidateString for #name {
+                            type Error = #error_type;
+
+                            #[inline]
+                            fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
+                            }
+
+                            #[inline]
+                            fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
+                            }
+
+                            #[inline]
+                            fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
+
+                                #validate_str_detailed
+                            }
+                        }
+                    });
+
+            let validate_u128 = custom.validate(quote! { Self::parse_u128(u) }, quote! {
+                let v = Self::v_secs_to_duration(u)?;
+
+                Self::v_parse_v(v)?;
+
+                Ok(())
+            });
+
+            token_stream.extend(quote! {
+                impl ValidateUnsignedInteger for #name {
+                    type Error = #error_type;
+
+                    #[inline]
+                    fn parse_u128(u: u128) -> Result<Self, Self::Error> {
+                        let v = Self::v_secs_to_duration(u)?;
+
+                        Self::v_parse_v(v)?;
+
+                        #ok(Self(v))
+                    }
+
+                    #[inline]
+                    fn validate_u128(u: u128) -> Result<(), Self::Error> {
+                        #validate_u128
+                    }
+
+                    #[inline]
+                    fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                        #validate_u128_detailed
+                    }
+                }
+            });
+
+            #[cfg(feature = "serde")]
+            {
+                if type_attribute.serde_options.serialize {
+                    token_stream.extend(quote! {
+                        impl validators_prelude::serde::Serialize for #name {
+                            #[inline]
+                            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                                where
+                                    S: validators_prelude::serde::Serializer, {
+                                serializer.collect_str(&validators_prelude::Iso8601Duration(self.0))
+                            }
+                        }
+                    });
+                }
+
+                if type_attribute.serde_options.deserialize {
+                    let expect = "a string such as \"PT1H30M\", \"1h30m\", \"1.5 days\", or a \
+                                  number of seconds";
+
+                    token_stream.extend(quote! {
+                                impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
+                                    #[inline]
+                                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                                    where
+                                        D: validators_prelude::serde::Deserializer<'de>, {
+                                        struct MyVisitor;
+
+                                        impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                                            type Value = #name;
+
+                                            #[inline]
+                                            fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                                f.write_str(#expect)
+                                            }
+
+                                            #[inline]
+                                            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
+                                            }
+
+                                            #[inline]
+                                            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::de_error)
+                                            }
+
+                                            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                                            where
+                                                E: validators_prelude::serde::de::Error, {
+                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::de_error)
+                                            }
+                                        }
+
+                                        if deserializer.is_human_readable() {
+                                            deserializer.deserialize_any(MyVisitor)
+                                        } else {
+                                            deserializer.deserialize_str(MyVisitor)
+                                        }
+                                    }
+                                }
+                            });
+                }
+            }
+
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords =
+                    crate::common::schema::SchemaKeywords::with_types(&["string", "integer"]);
+
+                keywords.format = Some("duration");
+                keywords.set_range(
+                    type_attribute.range.min,
+                    type_attribute.range.max,
+                    type_attribute.range.inclusive,
+                );
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let range = &type_attribute.range;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::duration(u, #range) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::format!("{}", validators_prelude::Iso8601Duration(self.0)) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
+            #[cfg(feature = "rocket")]
+            {
+                if type_attribute.rocket_options.from_form_field {
+                    crate::common::rocket::impl_from_form_field(&mut token_stream, &name);
+                }
+
+                if type_attribute.rocket_options.from_param {
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
+                }
+            }
+
+            return Ok(token_stream);
+        }
+
+        Err(panic::validator_for_specific_item(meta.path(), ITEM))
+    }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (::core::time::Duration) }))
+    }
+}
@@ -3,11 +3,11 @@ mod email_attribute;
 use educe::Educe;
 use email_attribute::EmailAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{tri_allow::TriAllow, type_enum::TypeEnum},
+    common::{custom::Custom, tri_allow::TriAllow, type_enum::TypeEnum},
     panic,
 };
 
@@ -164,12 +164,40 @@ const ITEM_ALLOW_COMMENT_ALLOW_LOCAL_MUST_IP: StructAllowCommentAllowLocal =
     };
 
 impl ValidatorHandler for EmailHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = EmailAttribute::build_from_meta(&meta)?;
 
-        if let Data::Struct(data) = ast.data {
+        if let Data::Struct(mut data) = ast.data {
             let mut meta_is_conflict = false;
 
+            // the `display_name` field is checked separately, so the rest of the fields are checked as without it
+            if !type_attribute.display_name.disallow() {
+                let Fields::Named(fields) = &mut data.fields else {
+                    return Err(panic::validator_for_specific_item(meta.path(), ITEM));
+                };
+
+                let length = fields.named.len();
+
+                fields.named = fields
+                    .named
+                    .clone()
+                    .into_iter()
+                    .filter(|field| field.ident.as_ref().unwrap() != "display_name")
+                    .collect();
+
+                if fields.named.len() == length {
+                    return Err(syn::Error::new_spanned(
+                        &meta,
+                        "`display_name(Allow)` and `display_name(Must)` need a `display_name: \
+                         Option<String>` field",
+                    ));
+                }
+            }
+
             if type_attribute.ip.must() && type_attribute.at_least_two_labels.disallow() {
                 if type_attribute.conflict.disallow() {
                     return Err(syn::Error::new_spanned(
@@ -585,6 +613,9 @@ impl ValidatorHandler for EmailHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::EmailError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_comment = type_attribute.comment;
@@ -592,6 +623,9 @@ impl ValidatorHandler for EmailHandler {
                 let v_local = type_attribute.local;
                 let v_at_least_two_labels = type_attribute.at_least_two_labels;
                 let v_non_ascii = type_attribute.non_ascii;
+                let v_display_name = type_attribute.display_name;
+                let v_registrable = type_attribute.registrable;
+                let v_known_tld = type_attribute.known_tld;
 
                 token_stream.extend(quote! {
                     impl #name {
@@ -600,6 +634,9 @@ impl ValidatorHandler for EmailHandler {
                         pub(crate) const V_LOCAL: validators_prelude::TriAllow = #v_local;
                         pub(crate) const V_AT_LEAST_TWO_LABELS: validators_prelude::TriAllow = #v_at_least_two_labels;
                         pub(crate) const V_NON_ASCII: validators_prelude::TriAllow = #v_non_ascii;
+                        pub(crate) const V_DISPLAY_NAME: validators_prelude::TriAllow = #v_display_name;
+                        pub(crate) const V_REGISTRABLE: validators_prelude::TriAllow = #v_registrable;
+                        pub(crate) const V_KNOWN_TLD: validators_prelude::TriAllow = #v_known_tld;
                     }
                 });
             }
@@ -986,10 +1023,84 @@ impl ValidatorHandler for EmailHandler {
                 }
             };
 
+            let handle_domain_lists = {
+                let mut checks = crate::common::known_tld::handle_known_tld(
+                    type_attribute.known_tld,
+                    &error_path,
+                );
+
+                checks.extend(crate::common::registrable::handle_registrable(
+                    type_attribute.registrable,
+                    &error_path,
+                ));
+
+                checks.extend(crate::common::domain_list::handle_domain_lists(
+                    type_attribute.allowed_domains.as_ref(),
+                    type_attribute.denied_domains.as_ref(),
+                    &error_path,
+                ));
+
+                if checks.is_empty() {
+                    quote! {}
+                } else {
+                    quote! {
+                        {
+                            let domain: Option<&str> = match &host {
+                                validators_prelude::Host::Domain(domain) => Some(domain.as_str()),
+                                _ => None,
+                            };
+
+                            #checks
+                        }
+                    }
+                }
+            };
+
+            let handle_display_name = {
+                let non_ascii = type_attribute.non_ascii.allow();
+                let comment = type_attribute.comment.allow();
+
+                match type_attribute.display_name {
+                    TriAllow::Allow => quote! {
+                        if s.ends_with('>') {
+                            match validators_prelude::parse_name_addr(s, #non_ascii, #comment) {
+                                Some(v) => v,
+                                None => return Err(#error_path::Invalid),
+                            }
+                        } else {
+                            (None, s)
+                        }
+                    },
+                    TriAllow::Must => quote! {
+                        if s.ends_with('>') {
+                            match validators_prelude::parse_name_addr(s, #non_ascii, #comment) {
+                                Some((Some(display_name), addr_spec)) => (Some(display_name), addr_spec),
+                                Some((None, _)) => return Err(#error_path::DisplayNameMust),
+                                None => return Err(#error_path::Invalid),
+                            }
+                        } else {
+                            return Err(#error_path::DisplayNameMust);
+                        }
+                    },
+                    TriAllow::Disallow => quote! {
+                        if s.ends_with('>') {
+                            return Err(#error_path::DisplayNameDisallow);
+                        }
+
+                        (None, s)
+                    },
+                }
+            };
+
             token_stream.extend(quote! {
                 impl #name {
                     #conflict_meta
-                    fn v_parse_str(s: &str) -> Result<(validators_prelude::String, bool, validators_prelude::Host, Option<validators_prelude::String>, Option<validators_prelude::String>, Option<validators_prelude::String>, Option<validators_prelude::String>, bool), #error_path> {
+                    fn v_parse_str(s: &str) -> Result<(validators_prelude::String, bool, validators_prelude::Host, Option<validators_prelude::String>, Option<validators_prelude::String>, Option<validators_prelude::String>, Option<validators_prelude::String>, bool, Option<validators_prelude::String>), #error_path> {
+                        // the angle brackets enclose the addr-spec
+                        let (display_name, s): (Option<validators_prelude::String>, &str) = {
+                            #handle_display_name
+                        };
+
                         let bytes = s.as_bytes();
                         let length = bytes.len();
 
@@ -1098,6 +1209,7 @@ impl ValidatorHandler for EmailHandler {
                                         }
 
                                         need_quoted = true;
+                                        escaping = false;
                                     }
                                     b'(' | b')' | b',' | b':'..=b'<' | b'>' | b'@' | b'[' | b']' => {
                                         if p == 63 || !escaping {
@@ -1202,6 +1314,11 @@ impl ValidatorHandler for EmailHandler {
                                         last_dot = true;
                                     }
                                     b'@' | b'(' => {
+                                        if last_dot {
+                                            // '.' is not allowed as the last character
+                                            return Err(#error_path::Invalid);
+                                        }
+
                                         let local_part =
                                             &s[local_part_length..p];
 
@@ -1272,6 +1389,8 @@ impl ValidatorHandler for EmailHandler {
                             None::<&str>
                         };
 
+                        #handle_domain_lists
+
                         return Ok((
                             validators_prelude::String::from(local_part),
                             need_quoted,
@@ -1281,11 +1400,18 @@ impl ValidatorHandler for EmailHandler {
                             comment_before_domain_part.map(validators_prelude::String::from),
                             comment_after_domain_part.map(validators_prelude::String::from),
                             is_local,
+                            display_name,
                         ));
                     }
                 }
             });
 
+            let display_name_field = if type_attribute.display_name.disallow() {
+                quote! {}
+            } else {
+                quote! { display_name: _display_name, }
+            };
+
             let create_instance = {
                 if type_attribute.comment.allow() {
                     match type_attribute.ip {
@@ -1295,6 +1421,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part,
@@ -1308,6 +1435,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part,
@@ -1325,6 +1453,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1344,6 +1473,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1367,6 +1497,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1385,6 +1516,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1410,6 +1542,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part,
@@ -1419,6 +1552,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part,
@@ -1432,6 +1566,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1447,6 +1582,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1466,6 +1602,7 @@ impl ValidatorHandler for EmailHandler {
                             {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1480,6 +1617,7 @@ impl ValidatorHandler for EmailHandler {
                             } else {
                                 quote! {
                                     Self {
+                                        #display_name_field
                                         local_part,
                                         need_quoted,
                                         domain_part: {
@@ -1496,38 +1634,68 @@ impl ValidatorHandler for EmailHandler {
                 }
             };
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                        let (local_part, need_quoted, domain_part, _comment_before_local_part, _comment_after_local_part, _comment_before_domain_part, _comment_after_domain_part, _is_local) = Self::v_parse_str(s.into().as_str())?;
+                        let (local_part, need_quoted, domain_part, _comment_before_local_part, _comment_after_local_part, _comment_before_domain_part, _comment_after_domain_part, _is_local, _display_name) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                        let (local_part, need_quoted, domain_part, _comment_before_local_part, _comment_after_local_part, _comment_before_domain_part, _comment_after_domain_part, _is_local) = Self::v_parse_str(s.as_ref())?;
+                        let (local_part, need_quoted, domain_part, _comment_before_local_part, _comment_after_local_part, _comment_before_domain_part, _comment_after_domain_part, _is_local, _display_name) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
 
+            let to_mailbox_string = if type_attribute.display_name.disallow() {
+                quote! {}
+            } else {
+                quote! {
+                    #[inline]
+                    fn to_mailbox_string(&self) -> validators_prelude::String {
+                        match &self.display_name {
+                            Some(display_name) => {
+                                let mut s = validators_prelude::String::with_capacity(64);
+
+                                validators_prelude::push_display_name(&mut s, display_name);
+
+                                s.push_str(" <");
+                                s.push_str(&self.to_email_string());
+                                s.push('>');
+
+                                s
+                            },
+                            None => self.to_email_string(),
+                        }
+                    }
+                }
+            };
+
             token_stream.extend(if type_attribute.comment.disallow() {
                 match type_attribute.ip {
                     TriAllow::Allow => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     let local_part = &self.local_part;
@@ -1544,6 +1712,8 @@ impl ValidatorHandler for EmailHandler {
                     TriAllow::Must => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     let local_part = &self.local_part;
@@ -1559,6 +1729,8 @@ impl ValidatorHandler for EmailHandler {
                     TriAllow::Disallow => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     let local_part = &self.local_part;
@@ -1617,6 +1789,8 @@ impl ValidatorHandler for EmailHandler {
                     TriAllow::Allow => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     #generate_s
@@ -1639,6 +1813,8 @@ impl ValidatorHandler for EmailHandler {
                     TriAllow::Must => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     #generate_s
@@ -1660,6 +1836,8 @@ impl ValidatorHandler for EmailHandler {
                     TriAllow::Disallow => {
                         quote! {
                             impl ToEmailString for #name {
+                                #to_mailbox_string
+
                                 #[inline]
                                 fn to_email_string(&self) -> validators_prelude::String {
                                     #generate_s
@@ -1676,6 +1854,51 @@ impl ValidatorHandler for EmailHandler {
                 }
             });
 
+            let canonical_email = match type_attribute.ip {
+                TriAllow::Allow => quote! {
+                    match &self.domain_part {
+                        validators_prelude::Host::IPv4(ip) => validators_prelude::canonical_email(local_part, self.need_quoted, &validators_prelude::format!("[{ip}]"), options),
+                        validators_prelude::Host::IPv6(ip) => validators_prelude::canonical_email(local_part, self.need_quoted, &validators_prelude::format!("[IPv6:{ip}]"), options),
+                        validators_prelude::Host::Domain(domain) => validators_prelude::canonical_email(local_part, self.need_quoted, domain, options),
+                    }
+                },
+                TriAllow::Must => quote! {
+                    match &self.domain_part {
+                        ::std::net::IpAddr::V4(ip) => validators_prelude::canonical_email(local_part, self.need_quoted, &validators_prelude::format!("[{ip}]"), options),
+                        ::std::net::IpAddr::V6(ip) => validators_prelude::canonical_email(local_part, self.need_quoted, &validators_prelude::format!("[IPv6:{ip}]"), options),
+                    }
+                },
+                TriAllow::Disallow => quote! {
+                    validators_prelude::canonical_email(local_part, self.need_quoted, self.domain_part.as_str(), options)
+                },
+            };
+
+            token_stream.extend(quote! {
+                impl ToCanonicalEmail for #name {
+                    #[inline]
+                    fn to_canonical_email(&self, options: &CanonicalEmailOptions) -> validators_prelude::String {
+                        let local_part = self.local_part.as_str();
+
+                        #canonical_email
+                    }
+                }
+            });
+
+            crate::common::registrable::impl_registrable_domain(
+                &mut token_stream,
+                &name,
+                match type_attribute.ip {
+                    TriAllow::Allow => quote! {
+                        match &self.domain_part {
+                            validators_prelude::Host::Domain(domain) => Some(domain.as_str()),
+                            _ => None,
+                        }
+                    },
+                    TriAllow::Must => quote! { None },
+                    TriAllow::Disallow => quote! { Some(self.domain_part.as_str()) },
+                },
+            );
+
             #[cfg(feature = "serde")]
             {
                 if type_attribute.serde_options.serialize {
@@ -1685,7 +1908,7 @@ impl ValidatorHandler for EmailHandler {
                             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                                 where
                                     S: validators_prelude::serde::Serializer, {
-                                serializer.serialize_str(&ToEmailString::to_email_string(self))
+                                serializer.serialize_str(&ToEmailString::to_mailbox_string(self))
                             }
                         }
                     });
@@ -1750,7 +1973,7 @@ impl ValidatorHandler for EmailHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                     }
                                 }
 
@@ -1761,6 +1984,50 @@ impl ValidatorHandler for EmailHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                // the formats do not accept display names
+                if type_attribute.display_name.disallow() {
+                    keywords.format =
+                        Some(if type_attribute.non_ascii.allow() { "idn-email" } else { "email" });
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let ip = type_attribute.ip;
+                let local = type_attribute.local;
+                let at_least_two_labels = type_attribute.at_least_two_labels;
+                let non_ascii = type_attribute.non_ascii;
+                let display_name = type_attribute.display_name;
+
+                crate::common::arbitrary::impl_arbitrary_string(&mut token_stream, &name, quote! {
+                    {
+                        let email = validators_prelude::generators::email(u, #ip, #local, #at_least_two_labels, #non_ascii)?;
+
+                        validators_prelude::generators::mailbox(u, email, #display_name)
+                    }
+                });
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { ToEmailString::to_mailbox_string(self) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -1768,7 +2035,7 @@ impl ValidatorHandler for EmailHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -1777,4 +2044,45 @@ impl ValidatorHandler for EmailHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM_ALLOW_COMMENT))
     }
+
+    fn carrier_fields(meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        let type_attribute = EmailAttribute::build_from_meta(meta)?;
+
+        let domain_part_type = match type_attribute.ip {
+            TriAllow::Allow => quote! { validators_prelude::Host },
+            TriAllow::Must => quote! { ::std::net::IpAddr },
+            TriAllow::Disallow => quote! { validators_prelude::String },
+        };
+
+        let mut fields = quote! {
+            local_part: validators_prelude::String,
+            need_quoted: bool,
+            domain_part: #domain_part_type,
+        };
+
+        if type_attribute.comment.allow() {
+            fields.extend(quote! {
+                comment_before_local_part: Option<validators_prelude::String>,
+                comment_after_local_part: Option<validators_prelude::String>,
+                comment_before_domain_part: Option<validators_prelude::String>,
+                comment_after_domain_part: Option<validators_prelude::String>,
+            });
+        }
+
+        if type_attribute.local == TriAllow::Allow
+            && type_attribute.at_least_two_labels != TriAllow::Allow
+        {
+            fields.extend(quote! {
+                is_local: bool,
+            });
+        }
+
+        if !type_attribute.display_name.disallow() {
+            fields.extend(quote! {
+                display_name: Option<validators_prelude::String>,
+            });
+        }
+
+        Ok(Fields::Named(syn::parse_quote! { { #fields } }))
+    }
 }
@@ -1,7 +1,10 @@
 use syn::{Meta, Token, punctuated::Punctuated};
 
 use crate::{
-    common::{rocket_options::RocketOptions, serde_options::SerdeOptions, tri_allow::TriAllow},
+    common::{
+        domain_list::DomainList, rocket_options::RocketOptions, serde_options::SerdeOptions,
+        tri_allow::TriAllow,
+    },
     panic,
 };
 
@@ -9,6 +12,9 @@ pub(crate) struct HostAttribute {
     pub(crate) local:               TriAllow,
     pub(crate) port:                TriAllow,
     pub(crate) at_least_two_labels: TriAllow,
+    pub(crate) registrable:         TriAllow,
+    pub(crate) allowed_domains:     Option<DomainList>,
+    pub(crate) denied_domains:      Option<DomainList>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options:       SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
@@ -17,11 +23,23 @@ pub(crate) struct HostAttribute {
 
 impl HostAttribute {
     pub(crate) fn build_from_meta(meta: &Meta) -> syn::Result<Self> {
-        let correct_parameters = ["local", "port", "at_least_two_labels", "serde", "rocket"];
+        let correct_parameters = [
+            "local",
+            "port",
+            "at_least_two_labels",
+            "registrable",
+            "allowed_domains",
+            "denied_domains",
+            "serde",
+            "rocket",
+        ];
 
         let mut local = TriAllow::Allow;
         let mut port = TriAllow::Allow;
         let mut at_least_two_labels = TriAllow::Allow;
+        let mut registrable = TriAllow::Allow;
+        let mut allowed_domains = None;
+        let mut denied_domains = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
 
@@ -37,6 +55,9 @@ impl HostAttribute {
                 let mut local_is_set = false;
                 let mut port_is_set = false;
                 let mut at_least_two_labels_is_set = false;
+                let mut registrable_is_set = false;
+                let mut allowed_domains_is_set = false;
+                let mut denied_domains_is_set = false;
                 let mut serde_options_is_set = false;
                 let mut rocket_options_is_set = false;
 
@@ -82,6 +103,45 @@ impl HostAttribute {
 
                                 return Ok(true);
                             },
+                            "registrable" => {
+                                let v = TriAllow::from_meta(meta)?;
+
+                                if registrable_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                registrable_is_set = true;
+
+                                registrable = v;
+
+                                return Ok(true);
+                            },
+                            "allowed_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if allowed_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                allowed_domains_is_set = true;
+
+                                allowed_domains = Some(v);
+
+                                return Ok(true);
+                            },
+                            "denied_domains" => {
+                                let v = DomainList::from_meta(meta)?;
+
+                                if denied_domains_is_set {
+                                    return Err(panic::parameter_reset(ident));
+                                }
+
+                                denied_domains_is_set = true;
+
+                                denied_domains = Some(v);
+
+                                return Ok(true);
+                            },
                             "serde" => {
                                 let v = SerdeOptions::from_meta(meta)?;
 
@@ -130,6 +190,9 @@ impl HostAttribute {
             local,
             port,
             at_least_two_labels,
+            registrable,
+            allowed_domains,
+            denied_domains,
             serde_options,
             rocket_options,
         })
@@ -3,11 +3,11 @@ mod host_attribute;
 use educe::Educe;
 use host_attribute::HostAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{tri_allow::TriAllow, type_enum::TypeEnum},
+    common::{custom::Custom, tri_allow::TriAllow, type_enum::TypeEnum},
     panic,
 };
 
@@ -67,7 +67,11 @@ const ITEM_ALLOW_LOCAL_WITH_PORT: StructAllowPortAllowLocal = StructAllowPortAll
 };
 
 impl ValidatorHandler for HostHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = HostAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data {
@@ -241,17 +245,22 @@ impl ValidatorHandler for HostHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::HostError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
                 let v_port = type_attribute.port;
                 let v_at_least_two_labels = type_attribute.at_least_two_labels;
+                let v_registrable = type_attribute.registrable;
 
                 token_stream.extend(quote! {
                     impl #name {
                         pub(crate) const V_LOCAL: validators_prelude::TriAllow = #v_local;
                         pub(crate) const V_PORT: validators_prelude::TriAllow = #v_port;
                         pub(crate) const V_AT_LEAST_TWO_LABELS: validators_prelude::TriAllow = #v_at_least_two_labels;
+                        pub(crate) const V_REGISTRABLE: validators_prelude::TriAllow = #v_registrable;
                     }
                 });
             }
@@ -519,6 +528,34 @@ impl ValidatorHandler for HostHandler {
                 }
             };
 
+            let handle_domain_lists = {
+                let mut checks = crate::common::registrable::handle_registrable(
+                    type_attribute.registrable,
+                    &error_path,
+                );
+
+                checks.extend(crate::common::domain_list::handle_domain_lists(
+                    type_attribute.allowed_domains.as_ref(),
+                    type_attribute.denied_domains.as_ref(),
+                    &error_path,
+                ));
+
+                if checks.is_empty() {
+                    quote! {}
+                } else {
+                    quote! {
+                        {
+                            let domain: Option<&str> = match &host {
+                                validators_prelude::Host::Domain(domain) => Some(domain.as_str()),
+                                _ => None,
+                            };
+
+                            #checks
+                        }
+                    }
+                }
+            };
+
             token_stream.extend(quote! {
                 impl #name {
                     fn v_parse_str(s: &str) -> Result<(validators_prelude::Host, Option<u16>, bool), #error_path> {
@@ -530,7 +567,7 @@ impl ValidatorHandler for HostHandler {
                             return Err(#error_path::Invalid);
                         }
 
-                        Ok(if bytes[0] == b'[' {
+                        let (host, port, is_local) = if bytes[0] == b'[' {
                             #handle_ipv6_bracket
                         } else {
                             match ::std::net::Ipv6Addr::from_str(s) {
@@ -555,7 +592,11 @@ impl ValidatorHandler for HostHandler {
                                     }
                                 }
                             }
-                        })
+                        };
+
+                        #handle_domain_lists
+
+                        Ok((host, port, is_local))
                     }
                 }
             });
@@ -619,33 +660,60 @@ impl ValidatorHandler for HostHandler {
                 }
             };
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
                         let (host, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
                         let (host, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
 
+            crate::common::registrable::impl_registrable_domain(
+                &mut token_stream,
+                &name,
+                if (type_attribute.local != TriAllow::Allow
+                    || type_attribute.at_least_two_labels == TriAllow::Allow)
+                    && type_attribute.port.disallow()
+                {
+                    quote! {
+                        match &self.0 {
+                            validators_prelude::Host::Domain(domain) => Some(domain.as_str()),
+                            _ => None,
+                        }
+                    }
+                } else {
+                    quote! {
+                        match &self.host {
+                            validators_prelude::Host::Domain(domain) => Some(domain.as_str()),
+                            _ => None,
+                        }
+                    }
+                },
+            );
+
             token_stream.extend(
                 if (type_attribute.local != TriAllow::Allow
                     || type_attribute.at_least_two_labels == TriAllow::Allow)
@@ -822,7 +890,7 @@ impl ValidatorHandler for HostHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                     }
                                 }
 
@@ -833,6 +901,40 @@ impl ValidatorHandler for HostHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let local = type_attribute.local;
+                let at_least_two_labels = type_attribute.at_least_two_labels;
+                let port = type_attribute.port;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::host(u, #local, #at_least_two_labels, #port) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { ToUriAuthorityString::to_uri_authority_string(self).into_owned() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -840,7 +942,7 @@ impl ValidatorHandler for HostHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -849,4 +951,34 @@ impl ValidatorHandler for HostHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        let type_attribute = HostAttribute::build_from_meta(meta)?;
+
+        let mut fields = quote! {
+            host: validators_prelude::Host,
+        };
+
+        if type_attribute.local == TriAllow::Allow
+            && type_attribute.at_least_two_labels != TriAllow::Allow
+        {
+            fields.extend(quote! {
+                is_local: bool,
+            });
+        } else if type_attribute.port.disallow() {
+            return Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::Host) }));
+        }
+
+        match type_attribute.port {
+            TriAllow::Allow => fields.extend(quote! {
+                port: Option<u16>,
+            }),
+            TriAllow::Must => fields.extend(quote! {
+                port: u16,
+            }),
+            TriAllow::Disallow => (),
+        }
+
+        Ok(Fields::Named(syn::parse_quote! { { #fields } }))
+    }
 }
@@ -1,10 +1,10 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
     common::{
-        attributes::http_xx_url_attribute::HttpXXUrlAttribute, tri_allow::TriAllow,
+        attributes::http_xx_url_attribute::HttpXXUrlAttribute, custom::Custom, tri_allow::TriAllow,
         type_enum::TypeEnum,
     },
     panic,
@@ -24,7 +24,11 @@ const ITEM: Struct = Struct {
 };
 
 impl ValidatorHandler for HttpUrlHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = HttpXXUrlAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -38,6 +42,9 @@ impl ValidatorHandler for HttpUrlHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::HttpURLError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
@@ -84,6 +91,26 @@ impl ValidatorHandler for HttpUrlHandler {
                 }
             };
 
+            let handle_domain_lists = {
+                let checks = crate::common::domain_list::handle_domain_lists(
+                    type_attribute.allowed_domains.as_ref(),
+                    type_attribute.denied_domains.as_ref(),
+                    &error_path,
+                );
+
+                if checks.is_empty() {
+                    quote! {}
+                } else {
+                    quote! {
+                        {
+                            let domain: Option<&str> = url.domain();
+
+                            #checks
+                        }
+                    }
+                }
+            };
+
             token_stream.extend(quote! {
                         impl #name {
                             fn v_parse_str(s: &str) -> Result<(validators_prelude::url::Url, bool), #error_path> {
@@ -105,20 +132,28 @@ impl ValidatorHandler for HttpUrlHandler {
 
                                 #handle_local
 
+                                #handle_domain_lists
+
                                 Ok((url, is_https))
                             }
                         }
                     });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
                                 let (url, is_https) = Self::v_parse_str(s.into().as_str())?;
 
-                                Ok(Self {
+                                #ok(Self {
                                     url,
                                     is_https,
                                 })
@@ -128,7 +163,7 @@ impl ValidatorHandler for HttpUrlHandler {
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
                                 let (url, is_https) = Self::v_parse_str(s.as_ref())?;
 
-                                Ok(Self {
+                                #ok(Self {
                                     url,
                                     is_https,
                                 })
@@ -136,9 +171,7 @@ impl ValidatorHandler for HttpUrlHandler {
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -195,7 +228,7 @@ impl ValidatorHandler for HttpUrlHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -206,6 +239,40 @@ impl ValidatorHandler for HttpUrlHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.format = Some("uri");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let local = type_attribute.local;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::http_url(u, #local) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::String::from(self.url.as_str()) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -213,7 +280,7 @@ impl ValidatorHandler for HttpUrlHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -222,4 +289,14 @@ impl ValidatorHandler for HttpUrlHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Named(syn::parse_quote! {
+            {
+                url: validators_prelude::url::Url,
+                is_https: bool,
+            }
+        }))
+    }
 }
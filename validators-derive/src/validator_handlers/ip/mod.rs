@@ -1,11 +1,12 @@
 use educe::Educe;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
     common::{
-        attributes::ip_xx_attribute::IpXXAttribute, tri_allow::TriAllow, type_enum::TypeEnum,
+        attributes::ip_xx_attribute::IpXXAttribute, custom::Custom, tri_allow::TriAllow,
+        type_enum::TypeEnum,
     },
     panic,
 };
@@ -37,7 +38,11 @@ const ITEM_WITH_PORT: StructAllowPort = StructAllowPort {
 };
 
 impl ValidatorHandler for IpHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = IpXXAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data {
@@ -117,6 +122,9 @@ impl ValidatorHandler for IpHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::IpError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_local = type_attribute.local;
@@ -360,29 +368,33 @@ impl ValidatorHandler for IpHandler {
                 }
             };
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                 impl ValidateString for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
                         let (ip, _port, _is_local) = Self::v_parse_str(s.into().as_str())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
                         let (ip, _port, _is_local) = Self::v_parse_str(s.as_ref())?;
 
-                        Ok(#create_instance)
+                        #ok(#create_instance)
                     }
 
                     #[inline]
                     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                        Self::v_parse_str(s.as_ref())?;
-
-                        Ok(())
+                        #validate_str
                     }
                 }
             });
@@ -523,7 +535,7 @@ impl ValidatorHandler for IpHandler {
                                     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                     where
                                         E: validators_prelude::serde::de::Error, {
-                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                     }
                                 }
 
@@ -534,6 +546,49 @@ impl ValidatorHandler for IpHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let local = type_attribute.local;
+                let port = type_attribute.port;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::ip(u, #local, #port) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                if type_attribute.port.disallow() {
+                    crate::common::sqlx::impl_sqlx(
+                        &mut token_stream,
+                        &name,
+                        quote! { ::std::net::IpAddr },
+                        quote! { ::std::net::IpAddr::from(self.0) },
+                        quote! { Ok(<Self as ValidateString>::parse_string(validators_prelude::format!("{v}"))?) },
+                    );
+                } else {
+                    crate::common::sqlx::impl_sqlx_string(
+                        &mut token_stream,
+                        &name,
+                        quote! { ToUriAuthorityString::to_uri_authority_string(self).into_owned() },
+                    );
+                }
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -541,7 +596,7 @@ impl ValidatorHandler for IpHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -550,4 +605,24 @@ impl ValidatorHandler for IpHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        let type_attribute = IpXXAttribute::build_from_meta(meta)?;
+
+        match type_attribute.port {
+            TriAllow::Allow => Ok(Fields::Named(syn::parse_quote! {
+                {
+                    ip: ::std::net::IpAddr,
+                    port: Option<u16>,
+                }
+            })),
+            TriAllow::Must => Ok(Fields::Named(syn::parse_quote! {
+                {
+                    ip: ::std::net::IpAddr,
+                    port: u16,
+                }
+            })),
+            TriAllow::Disallow => Ok(Fields::Unnamed(syn::parse_quote! { (::std::net::IpAddr) })),
+        }
+    }
 }
@@ -1,9 +1,9 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::basic_attribute::BasicAttribute, type_enum::TypeEnum},
+    common::{attributes::basic_attribute::BasicAttribute, custom::Custom, type_enum::TypeEnum},
     panic,
 };
 
@@ -16,7 +16,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::Serde);
 
 impl ValidatorHandler for JsonHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         #[allow(unused_variables)]
         let type_attribute = BasicAttribute::build_from_meta(&meta)?;
 
@@ -32,6 +36,9 @@ impl ValidatorHandler for JsonHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::JsonError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             token_stream.extend(quote! {
                         impl #name {
                             fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
@@ -87,129 +94,162 @@ impl ValidatorHandler for JsonHandler {
                         }
                     });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let validate_i128 = custom.validate(quote! { Self::parse_i128(i) }, quote! {
+                Self::v_parse_i128(i)?;
+
+                Ok(())
+            });
+
+            let validate_i64 = custom.validate(quote! { Self::parse_i64(i) }, quote! {
+                Self::v_parse_i64(i)?;
+
+                Ok(())
+            });
+
+            let validate_u128 = custom.validate(quote! { Self::parse_u128(u) }, quote! {
+                Self::v_parse_u128(u)?;
+
+                Ok(())
+            });
+
+            let validate_u64 = custom.validate(quote! { Self::parse_u64(u) }, quote! {
+                Self::v_parse_u64(u)?;
+
+                Ok(())
+            });
+
+            let validate_f64 = custom.validate(quote! { Self::parse_f64(f) }, quote! {
+                Self::v_parse_f64(f)?;
+
+                Ok(())
+            });
+
+            let validate_bool = custom.validate(quote! { Self::parse_bool(b) }, quote! {
+                Self::v_parse_bool(b)?;
+
+                Ok(())
+            });
+
+            let validate_json_value =
+                custom.validate(quote! { Self::parse_json_value(v) }, quote! {
+                    Self::v_parse_json_value(v)?;
+
+                    Ok(())
+                });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
 
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i128(i)?))
+                                #ok(Self(Self::v_parse_i128(i)?))
                             }
 
                             #[inline]
                             fn parse_i64(i: i64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_i64(i)?))
+                                #ok(Self(Self::v_parse_i64(i)?))
                             }
 
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                Self::v_parse_i128(i)?;
-
-                                Ok(())
+                                #validate_i128
                             }
 
                             #[inline]
                             fn validate_i64(i: i64) -> Result<(), Self::Error> {
-                                Self::v_parse_i64(i)?;
-
-                                Ok(())
+                                #validate_i64
                             }
                         }
 
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u128(u)?))
+                                #ok(Self(Self::v_parse_u128(u)?))
                             }
 
                             #[inline]
                             fn parse_u64(u: u64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_u64(u)?))
+                                #ok(Self(Self::v_parse_u64(u)?))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                Self::v_parse_u128(u)?;
-
-                                Ok(())
+                                #validate_u128
                             }
 
                             #[inline]
                             fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                Self::v_parse_u64(u)?;
-
-                                Ok(())
+                                #validate_u64
                             }
                         }
 
                         impl ValidateNumber for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_f64(f: f64) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_f64(f)?))
+                                #ok(Self(Self::v_parse_f64(f)?))
                             }
 
                             #[inline]
                             fn validate_f64(f: f64) -> Result<(), Self::Error> {
-                                Self::v_parse_f64(f)?;
-
-                                Ok(())
+                                #validate_f64
                             }
                         }
 
                         impl ValidateBoolean for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_bool(b: bool) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_bool(b)?))
+                                #ok(Self(Self::v_parse_bool(b)?))
                             }
 
                             #[inline]
                             fn validate_bool(b: bool) -> Result<(), Self::Error> {
-                                Self::v_parse_bool(b)?;
-
-                                Ok(())
+                                #validate_bool
                             }
                         }
 
                         impl ValidateJsonValue for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_json_value(v: validators_prelude::serde_json::Value) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_json_value(v)?))
+                                #ok(Self(Self::v_parse_json_value(v)?))
                             }
 
                             #[inline]
                             fn validate_json_value(v: validators_prelude::serde_json::Value) -> Result<(), Self::Error> {
-                                Self::v_parse_json_value(v)?;
-
-                                Ok(())
+                                #validate_json_value
                             }
                         }
                     });
@@ -257,6 +297,37 @@ impl ValidatorHandler for JsonHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords::default();
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                crate::common::arbitrary::impl_arbitrary(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::json(u, 2) },
+                    quote! { <Self as ValidateJsonValue>::parse_json_value(v) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { ToJsonString::to_minified_json_string(self) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -264,7 +335,7 @@ impl ValidatorHandler for JsonHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -273,4 +344,9 @@ impl ValidatorHandler for JsonHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::serde_json::Value) }))
+    }
 }
@@ -2,10 +2,13 @@ mod length_attribute;
 
 use length_attribute::LengthAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
-use crate::{common::type_enum::TypeEnum, panic};
+use crate::{
+    common::{custom::Custom, type_enum::TypeEnum},
+    panic,
+};
 
 pub(crate) struct LengthHandler;
 
@@ -16,7 +19,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::CollectionLength);
 
 impl ValidatorHandler for LengthHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = LengthAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -31,6 +38,9 @@ impl ValidatorHandler for LengthHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::LengthError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let min_expr = {
@@ -77,6 +87,8 @@ impl ValidatorHandler for LengthHandler {
                         if let Some(max) = type_attribute.max {
                             if min == max {
                                 quote! {
+                                    let length = CollectionLength::len(v);
+
                                     match ::core::cmp::Ord::cmp(&length, &#min) {
                                         ::core::cmp::Ordering::Equal => (),
                                         ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
@@ -123,6 +135,13 @@ impl ValidatorHandler for LengthHandler {
                 }
             };
 
+            let min_detail = type_attribute
+                .min
+                .map(|min| quote! { ("min", ErrorParam::Unsigned(#min as u128)), });
+            let max_detail = type_attribute
+                .max
+                .map(|max| quote! { ("max", ErrorParam::Unsigned(#max as u128)), });
+
             token_stream.extend(quote! {
                 impl #name {
                     #[allow(clippy::ptr_arg)]
@@ -132,27 +151,49 @@ impl ValidatorHandler for LengthHandler {
 
                         Ok(())
                     }
+
+                    #[allow(clippy::ptr_arg)]
+                    fn v_details(v: &#data_type, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        let length = ErrorParam::Unsigned(CollectionLength::len(v) as u128);
+
+                        match error {
+                            #error_path::TooSmall => validators_prelude::vec![#min_detail ("length", length)],
+                            #error_path::TooLarge => validators_prelude::vec![#max_detail ("length", length)],
+                        }
+                    }
                 }
             });
 
+            let validate_collection_detailed = custom.detailed(
+                quote! { Self::validate_collection(v) },
+                quote! { Self::v_details(v, error) },
+            );
+
             token_stream.extend(quote! {
                 impl ValidateLength<#data_type> for #name {
-                    type Error = #error_path;
+                    type Error = #error_type;
 
                     #[inline]
                     fn parse_collection(v: #data_type) -> Result<Self, Self::Error> {
                         Self::v_parse_v(&v)?;
 
-                        Ok(Self(v))
+                        #ok(Self(v))
                     }
 
                     #[allow(clippy::ptr_arg)]
                     #[inline]
                     fn validate_collection(v: &#data_type) -> Result<(), Self::Error> {
+                        // the custom function needs the parsed value, which cannot be created from a reference
                         Self::v_parse_v(v)?;
 
                         Ok(())
                     }
+
+                    #[allow(clippy::ptr_arg)]
+                    #[inline]
+                    fn validate_collection_detailed(v: &#data_type) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                        #validate_collection_detailed
+                    }
                 }
             });
 
@@ -180,7 +221,7 @@ impl ValidatorHandler for LengthHandler {
                                         D: validators_prelude::serde::Deserializer<'de>, {
                                         let v: #data_type = validators_prelude::serde::Deserialize::deserialize(deserializer)?;
 
-                                        Self::v_parse_v(&v).map_err(validators_prelude::serde::de::Error::custom)?;
+                                        Self::v_parse_v(&v).map_err(validators_prelude::de_error)?;
 
                                         Ok(Self(v))
                                     }
@@ -189,9 +230,49 @@ impl ValidatorHandler for LengthHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords {
+                    min_items: type_attribute.min,
+                    max_items: type_attribute.max,
+                    ..Default::default()
+                };
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                use crate::common::test::OptionToken;
+
+                let min = OptionToken(type_attribute.min);
+                let max = OptionToken(type_attribute.max);
+
+                // the bounds mention `'a`, so they are checked only when the implementation is used
+                token_stream.extend(quote! {
+                    impl<'a> validators_prelude::arbitrary::Arbitrary<'a> for #name
+                    where
+                        #data_type: IntoIterator + FromIterator<<#data_type as IntoIterator>::Item>,
+                        <#data_type as IntoIterator>::Item: validators_prelude::arbitrary::Arbitrary<'a>,
+                    {
+                        #[inline]
+                        fn arbitrary(u: &mut validators_prelude::arbitrary::Unstructured<'a>) -> validators_prelude::arbitrary::Result<Self> {
+                            let v = validators_prelude::generators::collection::<#data_type>(u, #min, #max)?;
+
+                            <Self as ValidateLength<#data_type>>::parse_collection(v).map_err(|_| validators_prelude::arbitrary::Error::IncorrectFormat)
+                        }
+                    }
+                });
+            }
+
             return Ok(token_stream);
         }
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (#data_type) }))
+    }
 }
@@ -1,9 +1,12 @@
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{attributes::utf8_attribute::Utf8Attribute, length::Length, type_enum::TypeEnum},
+    common::{
+        attributes::utf8_attribute::Utf8Attribute, custom::Custom, length::Length,
+        type_enum::TypeEnum,
+    },
     panic,
 };
 
@@ -16,7 +19,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::String);
 
 impl ValidatorHandler for LineHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = Utf8Attribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -29,6 +36,9 @@ impl ValidatorHandler for LineHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::LineError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             let (is_byte_length, min, trimmed_min, max) = if let Some((
                 is_byte_length,
                 Length {
@@ -729,9 +739,52 @@ impl ValidatorHandler for LineHandler {
                 }
             });
 
+            // the details of the errors are measured again only when the validation fails
+            let (length, trimmed_length) = if is_byte_length {
+                (quote! { s.len() }, quote! { s.trim().len() })
+            } else {
+                (quote! { s.chars().count() }, quote! { s.trim().chars().count() })
+            };
+
+            let max_detail = max.unwrap_or(usize::MAX);
+            let min_detail = min.unwrap_or(0);
+            let trimmed_min_detail = trimmed_min.unwrap_or(0);
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(s: &str, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        match error {
+                            #error_path::TooLong => validators_prelude::vec![
+                                ("max", ErrorParam::Unsigned(#max_detail as u128)),
+                                ("length", ErrorParam::Unsigned(#length as u128)),
+                            ],
+                            #error_path::TooShort => validators_prelude::vec![
+                                ("min", ErrorParam::Unsigned(#min_detail as u128)),
+                                ("trimmed_min", ErrorParam::Unsigned(#trimmed_min_detail as u128)),
+                                ("length", ErrorParam::Unsigned(#length as u128)),
+                                ("trimmed_length", ErrorParam::Unsigned(#trimmed_length as u128)),
+                            ],
+                            #error_path::Invalid => match s.find(|c: char| matches!(c, '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F')) {
+                                Some(offset) => validators_prelude::vec![("offset", ErrorParam::Unsigned(offset as u128))],
+                                None => validators_prelude::Vec::new(),
+                            },
+                        }
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom
+                .detailed(quote! { Self::validate_str(s) }, quote! { Self::v_details(s, error) });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
@@ -739,7 +792,7 @@ impl ValidatorHandler for LineHandler {
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(Self(s))
+                                #ok(Self(s))
                             }
 
                             #[inline]
@@ -748,14 +801,19 @@ impl ValidatorHandler for LineHandler {
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(Self(validators_prelude::String::from(s)))
+                                #ok(Self(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
 
-                                Ok(())
+                                #validate_str_detailed
                             }
                         }
                     });
@@ -769,7 +827,7 @@ impl ValidatorHandler for LineHandler {
                             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                                 where
                                     S: validators_prelude::serde::Serializer, {
-                                serializer.serialize_str(self.0.as_str())
+                                serializer.serialize_str(&self.0)
                             }
                         }
                     });
@@ -822,14 +880,14 @@ impl ValidatorHandler for LineHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -840,6 +898,49 @@ impl ValidatorHandler for LineHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.pattern = Some(String::from("^[^\\x00-\\x08\\x0A-\\x1F\\x7F]*$"));
+
+                if !is_byte_length {
+                    keywords.min_length = min;
+                    keywords.max_length = max;
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                use crate::common::test::OptionToken;
+
+                let v_min = OptionToken(min);
+                let v_trimmed_min = OptionToken(trimmed_min);
+                let v_max = OptionToken(max);
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::line(u, #is_byte_length, #v_min, #v_trimmed_min, #v_max) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { self.0.clone() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -847,7 +948,7 @@ impl ValidatorHandler for LineHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -856,4 +957,9 @@ impl ValidatorHandler for LineHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::String) }))
+    }
 }
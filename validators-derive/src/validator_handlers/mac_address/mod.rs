@@ -2,11 +2,14 @@ mod mac_address_attribute;
 
 use mac_address_attribute::MacAddressAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{case_option::CaseOption, separator_option::SeparatorOption, type_enum::TypeEnum},
+    common::{
+        case_option::CaseOption, custom::Custom, separator_option::SeparatorOption,
+        type_enum::TypeEnum,
+    },
     panic,
 };
 
@@ -19,7 +22,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::U64);
 
 impl ValidatorHandler for MacAddressHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = MacAddressAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -33,6 +40,9 @@ impl ValidatorHandler for MacAddressHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::MacAddressError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_case = type_attribute.case;
@@ -46,53 +56,38 @@ impl ValidatorHandler for MacAddressHandler {
                         });
             }
 
-            let handle_iter = {
+            // the positions of the separators are marked as bits, so that the parsing works in a const context
+            let handle_separators = {
                 match type_attribute.separator {
                     SeparatorOption::Allow(separator) => {
                         quote! {
-                            if !(12..=17).contains(&length) {
+                            if length < 12 || length > 17 {
                                 return Err(#error_path::Invalid);
                             }
 
-                            let first = &bytes[0..2];
-
-                            let mut no_colon_counter = if bytes[2] != #separator {
-                                1
-                            } else {
-                                0
-                            };
-
-                            let second = &bytes[(3 - no_colon_counter)..(5 - no_colon_counter)];
-
-                            if bytes[5 - no_colon_counter] != #separator {
-                                no_colon_counter += 1;
-                            }
-
-                            let third = &bytes[(6 - no_colon_counter)..(8 - no_colon_counter)];
-
-                            if bytes[8 - no_colon_counter] != #separator {
-                                no_colon_counter += 1;
-                            }
+                            let mut separators = 0u32;
+                            let mut no_colon_counter = 0;
 
-                            let forth = &bytes[(9 - no_colon_counter)..(11 - no_colon_counter)];
+                            let positions = [2, 5, 8, 11, 14];
+                            let mut p = 0;
 
-                            if bytes[11 - no_colon_counter] != #separator {
-                                no_colon_counter += 1;
-                            }
+                            while p < positions.len() {
+                                let index = positions[p] - no_colon_counter;
 
-                            let fifth = &bytes[(12 - no_colon_counter)..(14 - no_colon_counter)];
+                                if index < length && bytes[index] == #separator {
+                                    separators |= 1 << index;
+                                } else {
+                                    no_colon_counter += 1;
+                                }
 
-                            if bytes[14 - no_colon_counter] != #separator {
-                                no_colon_counter += 1;
+                                p += 1;
                             }
 
-                            let sixth = &bytes[(15 - no_colon_counter)..];
-
-                            if sixth.len() != 2 {
+                            if length != 17 - no_colon_counter {
                                 return Err(#error_path::Invalid);
                             }
 
-                            first.iter().chain(second).chain(third).chain(forth).chain(fifth).chain(sixth).copied()
+                            separators
                         }
                     },
                     SeparatorOption::Must(separator) => {
@@ -110,14 +105,7 @@ impl ValidatorHandler for MacAddressHandler {
                                 return Err(#error_path::Invalid);
                             }
 
-                            let first = &bytes[0..2];
-                            let second = &bytes[3..5];
-                            let third = &bytes[6..8];
-                            let forth = &bytes[9..11];
-                            let fifth = &bytes[12..14];
-                            let sixth = &bytes[15..];
-
-                            first.iter().chain(second).chain(third).chain(forth).chain(fifth).chain(sixth).copied()
+                            1u32 << 2 | 1 << 5 | 1 << 8 | 1 << 11 | 1 << 14
                         }
                     },
                     SeparatorOption::Disallow => {
@@ -126,7 +114,7 @@ impl ValidatorHandler for MacAddressHandler {
                                 return Err(#error_path::SeparatorDisallow);
                             }
 
-                            bytes.iter().copied()
+                            0u32
                         }
                     },
                 }
@@ -136,18 +124,29 @@ impl ValidatorHandler for MacAddressHandler {
                 match type_attribute.case {
                     CaseOption::Any => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 mac_address_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        mac_address_decoded |= u64::from(e - b'0');
+                                        mac_address_decoded |= (e - b'0') as u64;
                                     }
                                     b'a'..=b'f' => {
-                                        mac_address_decoded |= u64::from(e - (b'a' - 10));
+                                        mac_address_decoded |= (e - (b'a' - 10)) as u64;
                                     }
                                     b'A'..=b'F' => {
-                                        mac_address_decoded |= u64::from(e - (b'A' - 10));
+                                        mac_address_decoded |= (e - (b'A' - 10)) as u64;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -156,15 +155,26 @@ impl ValidatorHandler for MacAddressHandler {
                     },
                     CaseOption::Upper => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 mac_address_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        mac_address_decoded |= u64::from(e - b'0');
+                                        mac_address_decoded |= (e - b'0') as u64;
                                     }
                                     b'A'..=b'F' => {
-                                        mac_address_decoded |= u64::from(e - (b'A' - 10));
+                                        mac_address_decoded |= (e - (b'A' - 10)) as u64;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -173,15 +183,26 @@ impl ValidatorHandler for MacAddressHandler {
                     },
                     CaseOption::Lower => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 mac_address_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        mac_address_decoded |= u64::from(e - b'0');
+                                        mac_address_decoded |= (e - b'0') as u64;
                                     }
                                     b'a'..=b'f' => {
-                                        mac_address_decoded |= u64::from(e - (b'a' - 10));
+                                        mac_address_decoded |= (e - (b'a' - 10)) as u64;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -195,7 +216,18 @@ impl ValidatorHandler for MacAddressHandler {
                 match type_attribute.case {
                     CaseOption::Any => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -205,7 +237,18 @@ impl ValidatorHandler for MacAddressHandler {
                     },
                     CaseOption::Upper => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'A'..=b'F' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -215,7 +258,18 @@ impl ValidatorHandler for MacAddressHandler {
                     },
                     CaseOption::Lower => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'a'..=b'f' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -228,12 +282,12 @@ impl ValidatorHandler for MacAddressHandler {
 
             token_stream.extend(quote! {
                 impl #name {
-                    fn v_parse_str(s: &str) -> Result<u64, #error_path> {
+                    const fn v_parse_str(s: &str) -> Result<u64, #error_path> {
                         let bytes = s.as_bytes();
                         let length = bytes.len();
 
-                        let iter = {
-                            #handle_iter
+                        let separators = {
+                            #handle_separators
                         };
 
                         let mut mac_address_decoded = 0u64;
@@ -243,12 +297,12 @@ impl ValidatorHandler for MacAddressHandler {
                         Ok(mac_address_decoded)
                     }
 
-                    fn v_validate_str(s: &str) -> Result<(), #error_path> {
+                    const fn v_validate_str(s: &str) -> Result<(), #error_path> {
                         let bytes = s.as_bytes();
                         let length = bytes.len();
 
-                        let iter = {
-                            #handle_iter
+                        let separators = {
+                            #handle_separators
                         };
 
                         #handle_check
@@ -258,25 +312,31 @@ impl ValidatorHandler for MacAddressHandler {
                 }
             });
 
+            crate::common::literal::impl_const_new(&mut token_stream, &name, custom);
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_validate_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -402,7 +462,7 @@ impl ValidatorHandler for MacAddressHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -413,6 +473,51 @@ impl ValidatorHandler for MacAddressHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.pattern = Some(crate::common::schema::hex_groups_pattern(
+                    &[2, 2, 2, 2, 2, 2],
+                    type_attribute.case,
+                    type_attribute.separator,
+                ));
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let case = type_attribute.case;
+                let separator = type_attribute.separator;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::mac_address(u, #case, #separator) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { i64 },
+                    quote! { self.0 as i64 },
+                    quote! { match u64::try_from(v) {
+                        // a MAC address has 48 bits
+                        Ok(v) if v >> 48 == 0 => Ok(Self(v)),
+                        _ => Err(#error_path::Invalid.into()),
+                    } },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -420,7 +525,7 @@ impl ValidatorHandler for MacAddressHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -429,4 +534,9 @@ impl ValidatorHandler for MacAddressHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (u64) }))
+    }
 }
@@ -1,4 +1,6 @@
-use syn::{DeriveInput, Meta};
+use syn::{DeriveInput, Fields, Meta, Type};
+
+use crate::common::custom::Custom;
 
 #[cfg(feature = "base32")]
 pub(crate) mod base32;
@@ -27,9 +29,18 @@ pub(crate) mod boolean;
 #[cfg(feature = "byte")]
 pub(crate) mod byte;
 
+#[cfg(feature = "date")]
+pub(crate) mod date;
+
+#[cfg(feature = "datetime")]
+pub(crate) mod datetime;
+
 #[cfg(feature = "domain")]
 pub(crate) mod domain;
 
+#[cfg(feature = "duration")]
+pub(crate) mod duration;
+
 #[cfg(feature = "email")]
 pub(crate) mod email;
 
@@ -84,6 +95,9 @@ pub(crate) mod signed_integer;
 #[cfg(feature = "text")]
 pub(crate) mod text;
 
+#[cfg(feature = "time")]
+pub(crate) mod time;
+
 #[cfg(feature = "unsigned_integer")]
 pub(crate) mod unsigned_integer;
 
@@ -94,5 +108,13 @@ pub(crate) mod url;
 pub(crate) mod uuid;
 
 pub(crate) trait ValidatorHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream>;
+    /// Implement the validator for the struct. `custom` is used in the implementations of the validating traits.
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream>;
+
+    /// Build the fields of a hidden struct which can hold the model of this validator, so that a field typed as `data_type` can be validated by it.
+    fn carrier_fields(meta: &Meta, data_type: &Type) -> syn::Result<Fields>;
 }
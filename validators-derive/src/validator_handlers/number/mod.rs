@@ -2,11 +2,13 @@ mod number_attribute;
 
 use number_attribute::NumberAttribute;
 use quote::{ToTokens, quote};
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
-    common::{range_option::RangeTokenStream, tri_allow::TriAllow, type_enum::TypeEnum},
+    common::{
+        custom::Custom, range_option::RangeTokenStream, tri_allow::TriAllow, type_enum::TypeEnum,
+    },
     panic,
 };
 
@@ -24,7 +26,11 @@ enum NumberType {
 }
 
 impl ValidatorHandler for NumberHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         if let Data::Struct(data) = ast.data
             && let Fields::Unnamed(_) = &data.fields
             && data.fields.len() == 1
@@ -64,6 +70,9 @@ impl ValidatorHandler for NumberHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::NumberError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_nan = type_attribute.nan;
@@ -230,55 +239,106 @@ impl ValidatorHandler for NumberHandler {
                 }
             });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            let details = type_attribute.range.details(
+                &error_path,
+                &data_type,
+                quote! { f64 },
+                quote! { ErrorParam::Float },
+                false,
+                false,
+            );
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(v: f64, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        #details
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom.detailed(
+                quote! { Self::validate_str(s) },
+                quote! { s.parse::<#data_type>().map(|v| Self::v_details(v as f64, error)).unwrap_or_default() },
+            );
+
+            let validate_f64_detailed = custom
+                .detailed(quote! { Self::validate_f64(f) }, quote! { Self::v_details(f, error) });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
 
-                                Ok(())
+                                #validate_str_detailed
                             }
                         }
                     });
 
             token_stream.extend(match number_type {
                 NumberType::F64 => {
+                    let validate_f64 = custom.validate(quote! { Self::parse_f64(f) }, quote! {
+                        Self::v_parse_f(f)?;
+
+                        Ok(())
+                    });
+
                     quote! {
                         impl ValidateNumber for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_f64(f: f64) -> Result<Self, Self::Error> {
                                 Self::v_parse_f(f)?;
 
-                                Ok(Self(f))
+                                #ok(Self(f))
                             }
 
                             #[inline]
                             fn validate_f64(f: f64) -> Result<(), Self::Error> {
-                                Self::v_parse_f(f)?;
+                                #validate_f64
+                            }
 
-                                Ok(())
+                            #[inline]
+                            fn validate_f64_detailed(f: f64) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_f64_detailed
                             }
                         }
                     }
                 },
                 NumberType::F32 => {
+                    let validate_f32 = custom.validate(quote! { Self::parse_f32(f) }, quote! {
+                        Self::v_parse_f(f)?;
+
+                        Ok(())
+                    });
+
                     quote! {
                         impl ValidateNumber for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_f64(f: f64) -> Result<Self, Self::Error> {
@@ -290,18 +350,21 @@ impl ValidatorHandler for NumberHandler {
                                 Self::validate_f32(f as f32)
                             }
 
+                            #[inline]
+                            fn validate_f64_detailed(f: f64) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_f64_detailed
+                            }
+
                             #[inline]
                             fn parse_f32(f: f32) -> Result<Self, Self::Error> {
                                 Self::v_parse_f(f)?;
 
-                                Ok(Self(f))
+                                #ok(Self(f))
                             }
 
                             #[inline]
                             fn validate_f32(f: f32) -> Result<(), Self::Error> {
-                                Self::v_parse_f(f)?;
-
-                                Ok(())
+                                #validate_f32
                             }
                         }
                     }
@@ -410,28 +473,28 @@ impl ValidatorHandler for NumberHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateNumber>::parse_f64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateNumber>::parse_f64(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateNumber>::parse_f32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateNumber>::parse_f32(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -442,6 +505,49 @@ impl ValidatorHandler for NumberHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("number");
+
+                keywords.extend(type_attribute.range_schema);
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let nan = type_attribute.nan;
+                let range = &type_attribute.range;
+
+                crate::common::arbitrary::impl_arbitrary(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::number::<#data_type>(u, #nan, #range) },
+                    quote! { <Self as ValidateNumber>::parse_f64(v) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { #data_type },
+                    quote! { self.0 },
+                    quote! { Ok(<Self as ValidateNumber>::parse_f64(v.into())?) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(
+                    &mut token_stream,
+                    &name,
+                    type_attribute.range_hint.as_deref(),
+                    &[],
+                );
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -449,7 +555,7 @@ impl ValidatorHandler for NumberHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -458,4 +564,11 @@ impl ValidatorHandler for NumberHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(_meta: &Meta, data_type: &Type) -> syn::Result<Fields> {
+        match data_type.to_token_stream().to_string().as_str() {
+            "f32" | "f64" => Ok(Fields::Unnamed(syn::parse_quote! { (#data_type) })),
+            _ => Ok(Fields::Unnamed(syn::parse_quote! { (f64) })),
+        }
+    }
 }
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use syn::{Meta, Token, punctuated::Punctuated};
 
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+use crate::common::schema::SchemaKeywords;
 use crate::{
     common::{
         allow::Allow,
@@ -16,6 +18,10 @@ use crate::{
 
 pub(crate) struct NumberAttribute {
     pub(crate) range:          RangeTokenStream,
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub(crate) range_schema:   SchemaKeywords,
+    #[cfg(feature = "clap")]
+    pub(crate) range_hint:     Option<String>,
     pub(crate) nan:            TriAllow,
     pub(crate) conflict:       Allow,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
@@ -31,6 +37,10 @@ impl NumberAttribute {
         let correct_parameters = ["range", "nan", "conflict", "serde", "rocket"];
 
         let mut range = RangeTokenStream::Unlimited;
+        #[cfg(any(feature = "schemars", feature = "utoipa"))]
+        let mut range_schema = SchemaKeywords::default();
+        #[cfg(feature = "clap")]
+        let mut range_hint = None;
         let mut nan = TriAllow::Allow;
         let mut conflict = Allow::Disallow;
         let mut serde_options = SerdeOptions::default();
@@ -63,6 +73,16 @@ impl NumberAttribute {
 
                                 range_is_set = true;
 
+                                #[cfg(any(feature = "schemars", feature = "utoipa"))]
+                                {
+                                    range_schema = v.to_schema_keywords();
+                                }
+
+                                #[cfg(feature = "clap")]
+                                {
+                                    range_hint = v.to_hint();
+                                }
+
                                 range = v.into();
 
                                 return Ok(true);
@@ -139,6 +159,10 @@ impl NumberAttribute {
 
         Ok(Self {
             range,
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            range_schema,
+            #[cfg(feature = "clap")]
+            range_hint,
             nan,
             conflict,
             serde_options,
@@ -4,10 +4,13 @@ use std::str::FromStr;
 
 use phone_attribute::PhoneAttribute;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
-use crate::{common::type_enum::TypeEnum, panic};
+use crate::{
+    common::{custom::Custom, type_enum::TypeEnum},
+    panic,
+};
 
 pub(crate) struct PhoneHandler;
 
@@ -19,7 +22,11 @@ const ITEM: Struct = Struct(TypeEnum::String);
 const ITEM_MAP: Struct = Struct(TypeEnum::HashMapPhoneNumber);
 
 impl ValidatorHandler for PhoneHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = PhoneAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -32,6 +39,9 @@ impl ValidatorHandler for PhoneHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::PhoneError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let c: Vec<proc_macro2::TokenStream> = type_attribute
@@ -121,25 +131,29 @@ impl ValidatorHandler for PhoneHandler {
                         }
                     });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -196,7 +210,7 @@ impl ValidatorHandler for PhoneHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -207,6 +221,43 @@ impl ValidatorHandler for PhoneHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let c: Vec<proc_macro2::TokenStream> = type_attribute
+                    .countries
+                    .iter()
+                    .map(|id| proc_macro2::TokenStream::from_str(id.as_ref()).unwrap())
+                    .collect();
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::phone(u, &[#(validators_prelude::phonenumber::country::Id::#c),*]) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                // a map of phone numbers has no string form
+                if type_attribute.countries.len() <= 1 {
+                    crate::common::sqlx::impl_sqlx_string(&mut token_stream, &name, quote! {
+                        validators_prelude::format!("{}", self.0)
+                    });
+                }
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -214,7 +265,7 @@ impl ValidatorHandler for PhoneHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -227,4 +278,23 @@ impl ValidatorHandler for PhoneHandler {
             Err(panic::validator_for_specific_item(meta.path(), ITEM))
         }
     }
+
+    fn carrier_fields(meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        let type_attribute = PhoneAttribute::build_from_meta(meta)?;
+
+        if type_attribute.countries.len() > 1 {
+            Ok(Fields::Unnamed(syn::parse_quote! {
+                (
+                    ::std::collections::HashMap<
+                        validators_prelude::phonenumber::country::Id,
+                        validators_prelude::phonenumber::PhoneNumber,
+                    >
+                )
+            }))
+        } else {
+            Ok(Fields::Unnamed(syn::parse_quote! {
+                (validators_prelude::phonenumber::PhoneNumber)
+            }))
+        }
+    }
 }
@@ -2,10 +2,13 @@ mod regex_attribute;
 
 use quote::quote;
 use regex_attribute::RegexAttribute;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
-use crate::{common::type_enum::TypeEnum, panic};
+use crate::{
+    common::{custom::Custom, type_enum::TypeEnum},
+    panic,
+};
 
 pub(crate) struct RegexHandler;
 
@@ -16,7 +19,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::String);
 
 impl ValidatorHandler for RegexHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = RegexAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -29,6 +36,9 @@ impl ValidatorHandler for RegexHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::RegexError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             let re = type_attribute.regex;
 
             token_stream.extend(quote! {
@@ -44,9 +54,15 @@ impl ValidatorHandler for RegexHandler {
                 }
             });
 
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
@@ -54,7 +70,7 @@ impl ValidatorHandler for RegexHandler {
 
                                 Self::v_parse_str(s.as_str())?;
 
-                                Ok(#name(s))
+                                #ok(#name(s))
                             }
 
                             #[inline]
@@ -63,14 +79,12 @@ impl ValidatorHandler for RegexHandler {
 
                                 Self::v_parse_str(s)?;
 
-                                Ok(#name(validators_prelude::String::from(s)))
+                                #ok(#name(validators_prelude::String::from(s)))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -84,7 +98,7 @@ impl ValidatorHandler for RegexHandler {
                             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                                 where
                                     S: validators_prelude::serde::Serializer, {
-                                serializer.serialize_str(self.0.as_str())
+                                serializer.serialize_str(&self.0)
                             }
                         }
                     });
@@ -113,14 +127,14 @@ impl ValidatorHandler for RegexHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -131,6 +145,29 @@ impl ValidatorHandler for RegexHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.pattern = type_attribute.pattern;
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { self.0.clone() },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -138,7 +175,7 @@ impl ValidatorHandler for RegexHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -147,4 +184,9 @@ impl ValidatorHandler for RegexHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::String) }))
+    }
 }
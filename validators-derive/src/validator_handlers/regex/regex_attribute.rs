@@ -9,6 +9,9 @@ use crate::{
 
 pub(crate) struct RegexAttribute {
     pub(crate) regex:          Expr,
+    /// The source of the regular expression if it is written as a literal.
+    #[cfg_attr(not(any(feature = "schemars", feature = "utoipa")), allow(dead_code))]
+    pub(crate) pattern:        Option<String>,
     #[cfg_attr(not(feature = "serde"), allow(dead_code))]
     pub(crate) serde_options:  SerdeOptions,
     #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
@@ -20,6 +23,7 @@ impl RegexAttribute {
         let correct_parameters = ["regex", "serde", "rocket"];
 
         let mut regex = None;
+        let mut pattern = None;
         let mut serde_options = SerdeOptions::default();
         let mut rocket_options = RocketOptions::default();
 
@@ -40,7 +44,7 @@ impl RegexAttribute {
                     if let Some(ident) = meta.path().get_ident() {
                         match ident.to_string().as_str() {
                             "regex" => {
-                                let v = meta_2_regex_expr(meta)?;
+                                let (v, p) = meta_2_regex_expr(meta)?;
 
                                 if regex_is_set {
                                     return Err(panic::parameter_reset(ident));
@@ -49,6 +53,7 @@ impl RegexAttribute {
                                 regex_is_set = true;
 
                                 regex = Some(v);
+                                pattern = p;
 
                                 return Ok(true);
                             },
@@ -99,6 +104,7 @@ impl RegexAttribute {
         if let Some(regex) = regex {
             Ok(Self {
                 regex,
+                pattern,
                 serde_options,
                 rocket_options,
             })
@@ -108,7 +114,7 @@ impl RegexAttribute {
     }
 }
 
-fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<Expr> {
+fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<(Expr, Option<String>)> {
     if let Lit::Str(lit) = &lit.lit {
         let s = lit.value();
 
@@ -116,27 +122,32 @@ fn expr_lit_2_regex_expr(lit: &ExprLit) -> syn::Result<Expr> {
             return Err(syn::Error::new_spanned(lit, error));
         }
 
-        return Ok(
-            syn::parse2(quote! ( validators_prelude::regex::Regex::new(#s).unwrap() )).unwrap()
-        );
+        return Ok((
+            syn::parse2(quote! ( validators_prelude::regex::Regex::new(#s).unwrap() )).unwrap(),
+            Some(s),
+        ));
     }
 
     Err(syn::Error::new_spanned(lit, "expected `\"regex\"`"))
 }
 
-fn meta_2_regex_expr(meta: &Meta) -> syn::Result<Expr> {
+fn meta_2_regex_expr(meta: &Meta) -> syn::Result<(Expr, Option<String>)> {
     match meta {
         Meta::NameValue(name_value) => {
             return if let Expr::Lit(lit) = &name_value.value {
                 expr_lit_2_regex_expr(lit)
             } else {
-                Ok(name_value.value.clone())
+                Ok((name_value.value.clone(), None))
             };
         },
         Meta::List(list) => {
             let expr: Expr = list.parse_args()?;
 
-            return if let Expr::Lit(lit) = &expr { expr_lit_2_regex_expr(lit) } else { Ok(expr) };
+            return if let Expr::Lit(lit) = &expr {
+                expr_lit_2_regex_expr(lit)
+            } else {
+                Ok((expr, None))
+            };
         },
         Meta::Path(_) => (),
     }
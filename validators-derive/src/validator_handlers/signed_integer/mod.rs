@@ -1,11 +1,11 @@
 use quote::{ToTokens, quote};
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
     common::{
-        attributes::range_attribute::RangeAttribute, range_option::RangeTokenStream,
-        type_enum::TypeEnum,
+        attributes::range_attribute::RangeAttribute, custom::Custom,
+        range_option::RangeTokenStream, type_enum::TypeEnum,
     },
     panic,
 };
@@ -28,7 +28,11 @@ enum SignedIntegerType {
 }
 
 impl ValidatorHandler for SignedIntegerHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         if let Data::Struct(data) = ast.data
             && let Fields::Unnamed(_) = &data.fields
             && data.fields.len() == 1
@@ -67,6 +71,9 @@ impl ValidatorHandler for SignedIntegerHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::SignedIntegerError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_range = &type_attribute.range;
@@ -88,10 +95,10 @@ impl ValidatorHandler for SignedIntegerHandler {
                     } => {
                         if *equal {
                             quote! {
-                                match ::core::cmp::Ord::cmp(&i, &#min) {
-                                    ::core::cmp::Ordering::Equal => (),
-                                    ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
-                                    ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge),
+                                if i < #min {
+                                    return Err(#error_path::TooSmall);
+                                } else if i > #min {
+                                    return Err(#error_path::TooLarge);
                                 }
                             }
                         } else {
@@ -142,13 +149,13 @@ impl ValidatorHandler for SignedIntegerHandler {
                                     Some(max) => {
                                         if *inclusive {
                                             quote! {
-                                                if (#min..=#max).contains(&i) {
+                                                if i >= #min && i <= #max {
                                                     return Err(#error_path::Forbidden);
                                                 }
                                             }
                                         } else {
                                             quote! {
-                                                if (#min..#max).contains(&i) {
+                                                if i >= #min && i < #max {
                                                     return Err(#error_path::Forbidden);
                                                 }
                                             }
@@ -191,17 +198,21 @@ impl ValidatorHandler for SignedIntegerHandler {
 
             token_stream.extend(quote! {
                 impl #name {
-                    fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
-                        use ::core::str::FromStr;
-
-                        let i = FromStr::from_str(s)?;
-
-                        Self::v_parse_i(i)?;
+                    const fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
+                        // the same as `FromStr`, which cannot be called in a const context
+                        let i = match <#data_type>::from_str_radix(s, 10) {
+                            Ok(i) => i,
+                            Err(error) => return Err(#error_path::ParseIntError(error)),
+                        };
+
+                        if let Err(error) = Self::v_parse_i(i) {
+                            return Err(error);
+                        }
 
                         Ok(i)
                     }
 
-                    fn v_parse_i(i: #data_type) -> Result<(), #error_path> {
+                    const fn v_parse_i(i: #data_type) -> Result<(), #error_path> {
                         #handle_range
 
                         Ok(())
@@ -209,62 +220,121 @@ impl ValidatorHandler for SignedIntegerHandler {
                 }
             });
 
+            crate::common::literal::impl_const_new(&mut token_stream, &name, custom);
+
+            let details = type_attribute.range.details(
+                &error_path,
+                &data_type,
+                quote! { i128 },
+                quote! { ErrorParam::Signed },
+                !matches!(signed_integer_type, SignedIntegerType::I128),
+                !matches!(signed_integer_type, SignedIntegerType::I128),
+            );
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(v: i128, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        #details
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom.detailed(
+                quote! { Self::validate_str(s) },
+                quote! { s.parse::<#data_type>().map(|v| Self::v_details(v as i128, error)).unwrap_or_default() },
+            );
+
+            let validate_i128_detailed = custom
+                .detailed(quote! { Self::validate_i128(i) }, quote! { Self::v_details(i, error) });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
 
-                                Ok(())
+                                #validate_str_detailed
                             }
                         }
                     });
 
             token_stream.extend(match signed_integer_type {
                 SignedIntegerType::I128 => {
+                    let validate_i128 = custom.validate(
+                        quote! { Self::parse_i128(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
+                                #validate_i128
+                            }
 
-                                Ok(())
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
                             }
                         }
                     }
                 },
                 SignedIntegerType::I64 => {
+                    let validate_i64 = custom.validate(
+                        quote! { Self::parse_i64(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 if i > i64::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i64::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::parse_i64(i as i64)
                                 }
@@ -273,41 +343,53 @@ impl ValidatorHandler for SignedIntegerHandler {
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                 if i > i64::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i64::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::validate_i64(i as i64)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
+                            }
+
                             #[inline]
                             fn parse_i64(i: i64) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_i64(i: i64) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
-
-                                Ok(())
+                                #validate_i64
                             }
                         }
                     }
                 },
                 SignedIntegerType::I32 => {
+                    let validate_i32 = custom.validate(
+                        quote! { Self::parse_i32(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 if i > i32::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i32::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::parse_i32(i as i32)
                                 }
@@ -316,41 +398,53 @@ impl ValidatorHandler for SignedIntegerHandler {
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                 if i > i32::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i32::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::validate_i32(i as i32)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
+                            }
+
                             #[inline]
                             fn parse_i32(i: i32) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_i32(i: i32) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
-
-                                Ok(())
+                                #validate_i32
                             }
                         }
                     }
                 },
                 SignedIntegerType::I16 => {
+                    let validate_i16 = custom.validate(
+                        quote! { Self::parse_i16(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 if i > i16::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i16::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::parse_i16(i as i16)
                                 }
@@ -359,41 +453,53 @@ impl ValidatorHandler for SignedIntegerHandler {
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                 if i > i16::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i16::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::validate_i16(i as i16)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
+                            }
+
                             #[inline]
                             fn parse_i16(i: i16) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_i16(i: i16) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
-
-                                Ok(())
+                                #validate_i16
                             }
                         }
                     }
                 },
                 SignedIntegerType::I8 => {
+                    let validate_i8 = custom.validate(
+                        quote! { Self::parse_i8(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 if i > i8::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i8::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::parse_i8(i as i8)
                                 }
@@ -402,42 +508,54 @@ impl ValidatorHandler for SignedIntegerHandler {
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                 if i > i8::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < i8::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::validate_i8(i as i8)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
+                            }
+
                             #[inline]
                             fn parse_i8(i: i8) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_i8(i: i8) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
-
-                                Ok(())
+                                #validate_i8
                             }
                         }
                     }
                 },
                 SignedIntegerType::Isize => {
+                    let validate_isize = custom.validate(
+                        quote! { Self::parse_isize(i) },
+                        quote! {
+                            Self::v_parse_i(i)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateSignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[allow(unexpected_cfgs)]
                             #[inline]
                             fn parse_i128(i: i128) -> Result<Self, Self::Error> {
                                 if i > isize::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < isize::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::parse_isize(i as isize)
                                 }
@@ -447,26 +565,29 @@ impl ValidatorHandler for SignedIntegerHandler {
                             #[inline]
                             fn validate_i128(i: i128) -> Result<(), Self::Error> {
                                 if i > isize::MAX as i128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else if i < isize::MIN as i128 {
-                                    Err(#error_path::TooSmall)
+                                    Err(#error_path::TooSmall.into())
                                 } else {
                                     Self::validate_isize(i as isize)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_i128_detailed(i: i128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_i128_detailed
+                            }
+
                             #[inline]
                             fn parse_isize(i: isize) -> Result<Self, Self::Error> {
                                 Self::v_parse_i(i)?;
 
-                                Ok(Self(i))
+                                #ok(Self(i))
                             }
 
                             #[inline]
                             fn validate_isize(i: isize) -> Result<(), Self::Error> {
-                                Self::v_parse_i(i)?;
-
-                                Ok(())
+                                #validate_isize
                             }
 
                             #[cfg(target_pointer_width = "16")]
@@ -598,48 +719,48 @@ impl ValidatorHandler for SignedIntegerHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i8(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i16(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i16(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i32(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i64(v).map_err(validators_prelude::de_error)
                                             }
 
                                             fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateSignedInteger>::parse_i128(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -650,6 +771,48 @@ impl ValidatorHandler for SignedIntegerHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("integer");
+
+                keywords.extend(type_attribute.range_schema);
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let range = &type_attribute.range;
+
+                crate::common::arbitrary::impl_arbitrary(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::integer::<#data_type>(u, #range) },
+                    quote! { <Self as ValidateSignedInteger>::parse_i128(v as i128) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { #data_type },
+                    quote! { self.0 },
+                    quote! { Ok(<Self as ValidateSignedInteger>::parse_i128(v as i128)?) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(
+                    &mut token_stream,
+                    &name,
+                    type_attribute.range_hint.as_deref(),
+                    &[],
+                );
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -657,7 +820,7 @@ impl ValidatorHandler for SignedIntegerHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -666,4 +829,13 @@ impl ValidatorHandler for SignedIntegerHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(_meta: &Meta, data_type: &Type) -> syn::Result<Fields> {
+        match data_type.to_token_stream().to_string().as_str() {
+            "isize" | "i8" | "i16" | "i32" | "i64" | "i128" => {
+                Ok(Fields::Unnamed(syn::parse_quote! { (#data_type) }))
+            },
+            _ => Ok(Fields::Unnamed(syn::parse_quote! { (i128) })),
+        }
+    }
 }
@@ -0,0 +1,245 @@
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
+
+use super::ValidatorHandler;
+use crate::{
+    common::{
+        allow::Allow,
+        attributes::date_time_attribute::{DateTimeAttribute, DateTimeKind},
+        custom::Custom,
+        tri_allow::TriAllow,
+        type_enum::TypeEnum,
+    },
+    panic,
+};
+
+pub(crate) struct TimeHandler;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Struct(TypeEnum);
+
+const ITEM: Struct = Struct(TypeEnum::Time);
+
+impl ValidatorHandler for TimeHandler {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let type_attribute = DateTimeAttribute::build_from_meta(&meta, DateTimeKind::Time)?;
+
+        if let Data::Struct(data) = ast.data
+            && let Fields::Unnamed(_) = &data.fields
+            && data.fields.len() == 1
+        {
+            let mut token_stream = proc_macro2::TokenStream::new();
+
+            let name = ast.ident;
+
+            let error_path: Path = syn::parse2(quote! { validators_prelude::TimeError }).unwrap();
+
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
+            #[cfg(feature = "test")]
+            {
+                let v_basic_format = type_attribute.basic_format;
+                let v_timezone = type_attribute.timezone;
+                let v_fraction = type_attribute.fraction;
+
+                token_stream.extend(quote! {
+                    impl #name {
+                        pub(crate) const V_BASIC_FORMAT: validators_prelude::TriAllow = #v_basic_format;
+                        pub(crate) const V_TIMEZONE: validators_prelude::TriAllow = #v_timezone;
+                        pub(crate) const V_FRACTION: validators_prelude::TriAllow = #v_fraction;
+                    }
+                });
+            }
+
+            let check_basic_format = match type_attribute.basic_format {
+                TriAllow::Allow => quote! {},
+                TriAllow::Must => quote! {
+                    if !format.basic {
+                        return Err(#error_path::BasicFormatMust);
+                    }
+                },
+                TriAllow::Disallow => quote! {
+                    if format.basic {
+                        return Err(#error_path::BasicFormatDisallow);
+                    }
+                },
+            };
+
+            let check_timezone = match type_attribute.timezone {
+                TriAllow::Allow => quote! {},
+                TriAllow::Must => quote! {
+                    if v.offset.is_none() {
+                        return Err(#error_path::TimezoneMust);
+                    }
+                },
+                TriAllow::Disallow => quote! {
+                    if v.offset.is_some() {
+                        return Err(#error_path::TimezoneDisallow);
+                    }
+                },
+            };
+
+            let check_fraction = match type_attribute.fraction {
+                Allow::Allow => quote! {},
+                Allow::Disallow => quote! {
+                    if format.fraction {
+                        return Err(#error_path::FractionDisallow);
+                    }
+                },
+            };
+
+            token_stream.extend(quote! {
+                impl #name {
+                    #[inline]
+                    fn v_parse_str(s: &str) -> Result<validators_prelude::Time, #error_path> {
+                        let (v, format) = validators_prelude::parse_time(s).ok_or(#error_path::Invalid)?;
+
+                        #check_basic_format
+                        #check_timezone
+                        #check_fraction
+
+                        Ok(v)
+                    }
+                }
+            });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
+            token_stream.extend(quote! {
+                impl ValidateString for #name {
+                    type Error = #error_type;
+
+                    #[inline]
+                    fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
+                        #ok(Self(Self::v_parse_str(s.into().as_str())?))
+                    }
+
+                    #[inline]
+                    fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
+                        #ok(Self(Self::v_parse_str(s.as_ref())?))
+                    }
+
+                    #[inline]
+                    fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
+                        #validate_str
+                    }
+                }
+            });
+
+            #[cfg(feature = "serde")]
+            {
+                if type_attribute.serde_options.serialize {
+                    token_stream.extend(quote! {
+                        impl validators_prelude::serde::Serialize for #name {
+                            #[inline]
+                            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                                where
+                                    S: validators_prelude::serde::Serializer, {
+                                serializer.collect_str(&self.0)
+                            }
+                        }
+                    });
+                }
+
+                if type_attribute.serde_options.deserialize {
+                    token_stream.extend(quote! {
+                        impl<'de> validators_prelude::serde::Deserialize<'de> for #name {
+                            #[inline]
+                            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                            where
+                                D: validators_prelude::serde::Deserializer<'de>, {
+                                struct MyVisitor;
+
+                                impl<'de> validators_prelude::serde::de::Visitor<'de> for MyVisitor {
+                                    type Value = #name;
+
+                                    #[inline]
+                                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                        f.write_str("a time string")
+                                    }
+
+                                    #[inline]
+                                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                                    where
+                                        E: validators_prelude::serde::de::Error, {
+                                        <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
+                                    }
+                                }
+
+                                deserializer.deserialize_str(MyVisitor)
+                            }
+                        }
+                    });
+                }
+            }
+
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                if !type_attribute.basic_format.must() && !type_attribute.timezone.disallow() {
+                    keywords.format = Some("time");
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let basic_format = type_attribute.basic_format;
+                let timezone = type_attribute.timezone;
+                let fraction = type_attribute.fraction;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::time(u, #basic_format, #timezone, #fraction) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::format!("{}", self.0) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
+            #[cfg(feature = "rocket")]
+            {
+                if type_attribute.rocket_options.from_form_field {
+                    crate::common::rocket::impl_from_form_field(&mut token_stream, &name);
+                }
+
+                if type_attribute.rocket_options.from_param {
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
+                }
+            }
+
+            return Ok(token_stream);
+        }
+
+        Err(panic::validator_for_specific_item(meta.path(), ITEM))
+    }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (validators_prelude::Time) }))
+    }
+}
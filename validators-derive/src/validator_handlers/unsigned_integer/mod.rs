@@ -1,11 +1,11 @@
 use quote::{ToTokens, quote};
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 
 use super::ValidatorHandler;
 use crate::{
     common::{
-        attributes::range_attribute::RangeAttribute, range_option::RangeTokenStream,
-        type_enum::TypeEnum,
+        attributes::range_attribute::RangeAttribute, custom::Custom,
+        range_option::RangeTokenStream, type_enum::TypeEnum,
     },
     panic,
 };
@@ -28,7 +28,11 @@ enum UnsignedIntegerType {
 }
 
 impl ValidatorHandler for UnsignedIntegerHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         if let Data::Struct(data) = ast.data
             && let Fields::Unnamed(_) = &data.fields
             && data.fields.len() == 1
@@ -67,6 +71,9 @@ impl ValidatorHandler for UnsignedIntegerHandler {
             let error_path: Path =
                 syn::parse2(quote! { validators_prelude::UnsignedIntegerError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_range = &type_attribute.range;
@@ -88,10 +95,10 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                     } => {
                         if *equal {
                             quote! {
-                                match ::core::cmp::Ord::cmp(&u, &#min) {
-                                    ::core::cmp::Ordering::Equal => (),
-                                    ::core::cmp::Ordering::Less => return Err(#error_path::TooSmall),
-                                    ::core::cmp::Ordering::Greater => return Err(#error_path::TooLarge),
+                                if u < #min {
+                                    return Err(#error_path::TooSmall);
+                                } else if u > #min {
+                                    return Err(#error_path::TooLarge);
                                 }
                             }
                         } else {
@@ -142,13 +149,13 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                     Some(max) => {
                                         if *inclusive {
                                             quote! {
-                                                if (#min..=#max).contains(&i) {
+                                                if u >= #min && u <= #max {
                                                     return Err(#error_path::Forbidden);
                                                 }
                                             }
                                         } else {
                                             quote! {
-                                                if (#min..#max).contains(&i) {
+                                                if u >= #min && u < #max {
                                                     return Err(#error_path::Forbidden);
                                                 }
                                             }
@@ -191,17 +198,21 @@ impl ValidatorHandler for UnsignedIntegerHandler {
 
             token_stream.extend(quote! {
                 impl #name {
-                    fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
-                        use ::core::str::FromStr;
-
-                        let u = FromStr::from_str(s)?;
-
-                        Self::v_parse_u(u)?;
+                    const fn v_parse_str(s: &str) -> Result<#data_type, #error_path> {
+                        // the same as `FromStr`, which cannot be called in a const context
+                        let u = match <#data_type>::from_str_radix(s, 10) {
+                            Ok(u) => u,
+                            Err(error) => return Err(#error_path::ParseIntError(error)),
+                        };
+
+                        if let Err(error) = Self::v_parse_u(u) {
+                            return Err(error);
+                        }
 
                         Ok(u)
                     }
 
-                    fn v_parse_u(u: #data_type) -> Result<(), #error_path> {
+                    const fn v_parse_u(u: #data_type) -> Result<(), #error_path> {
                         #handle_range
 
                         Ok(())
@@ -209,60 +220,119 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                 }
             });
 
+            crate::common::literal::impl_const_new(&mut token_stream, &name, custom);
+
+            let details = type_attribute.range.details(
+                &error_path,
+                &data_type,
+                quote! { u128 },
+                quote! { ErrorParam::Unsigned },
+                false,
+                !matches!(unsigned_integer_type, UnsignedIntegerType::U128),
+            );
+
+            token_stream.extend(quote! {
+                impl #name {
+                    fn v_details(v: u128, error: &#error_path) -> validators_prelude::Vec<(&'static str, ErrorParam)> {
+                        #details
+                    }
+                }
+            });
+
+            let validate_str_detailed = custom.detailed(
+                quote! { Self::validate_str(s) },
+                quote! { s.parse::<#data_type>().map(|v| Self::v_details(v as u128, error)).unwrap_or_default() },
+            );
+
+            let validate_u128_detailed = custom
+                .detailed(quote! { Self::validate_u128(u) }, quote! { Self::v_details(u, error) });
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_parse_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_parse_str(s.as_ref())?;
+                                #validate_str
+                            }
+
+                            #[inline]
+                            fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                let s = s.as_ref();
 
-                                Ok(())
+                                #validate_str_detailed
                             }
                         }
                     });
 
             token_stream.extend(match unsigned_integer_type {
                 UnsignedIntegerType::U128 => {
+                    let validate_u128 = custom.validate(
+                        quote! { Self::parse_u128(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
+                                #validate_u128
+                            }
 
-                                Ok(())
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
                             }
                         }
                     }
                 },
                 UnsignedIntegerType::U64 => {
+                    let validate_u64 = custom.validate(
+                        quote! { Self::parse_u64(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 if u > u64::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::parse_u64(u as u64)
                                 }
@@ -271,37 +341,49 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                 if u > u64::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::validate_u64(u as u64)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
+                            }
+
                             #[inline]
                             fn parse_u64(u: u64) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_u64(u: u64) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
-
-                                Ok(())
+                                #validate_u64
                             }
                         }
                     }
                 },
                 UnsignedIntegerType::U32 => {
+                    let validate_u32 = custom.validate(
+                        quote! { Self::parse_u32(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 if u > u32::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::parse_u32(u as u32)
                                 }
@@ -310,37 +392,49 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                 if u > u32::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::validate_u32(u as u32)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
+                            }
+
                             #[inline]
                             fn parse_u32(u: u32) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_u32(u: u32) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
-
-                                Ok(())
+                                #validate_u32
                             }
                         }
                     }
                 },
                 UnsignedIntegerType::U16 => {
+                    let validate_u16 = custom.validate(
+                        quote! { Self::parse_u16(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 if u > u16::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::parse_u16(u as u16)
                                 }
@@ -349,37 +443,49 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                 if u > u16::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::validate_u16(u as u16)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
+                            }
+
                             #[inline]
                             fn parse_u16(u: u16) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_u16(u: u16) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
-
-                                Ok(())
+                                #validate_u16
                             }
                         }
                     }
                 },
                 UnsignedIntegerType::U8 => {
+                    let validate_u8 = custom.validate(
+                        quote! { Self::parse_u8(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 if u > u8::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::parse_u8(u as u8)
                                 }
@@ -388,38 +494,50 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                 if u > u8::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::validate_u8(u as u8)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
+                            }
+
                             #[inline]
                             fn parse_u8(u: u8) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_u8(u: u8) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
-
-                                Ok(())
+                                #validate_u8
                             }
                         }
                     }
                 },
                 UnsignedIntegerType::Usize => {
+                    let validate_usize = custom.validate(
+                        quote! { Self::parse_usize(u) },
+                        quote! {
+                            Self::v_parse_u(u)?;
+
+                            Ok(())
+                        },
+                    );
+
                     quote! {
                         impl ValidateUnsignedInteger for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[allow(unexpected_cfgs)]
                             #[inline]
                             fn parse_u128(u: u128) -> Result<Self, Self::Error> {
                                 if u > usize::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::parse_usize(u as usize)
                                 }
@@ -429,24 +547,27 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                             #[inline]
                             fn validate_u128(u: u128) -> Result<(), Self::Error> {
                                 if u > usize::MAX as u128 {
-                                    Err(#error_path::TooLarge)
+                                    Err(#error_path::TooLarge.into())
                                 } else {
                                     Self::validate_usize(u as usize)
                                 }
                             }
 
+                            #[inline]
+                            fn validate_u128_detailed(u: u128) -> Result<(), validators_prelude::DetailedError<Self::Error>> {
+                                #validate_u128_detailed
+                            }
+
                             #[inline]
                             fn parse_usize(u: usize) -> Result<Self, Self::Error> {
                                 Self::v_parse_u(u)?;
 
-                                Ok(Self(u))
+                                #ok(Self(u))
                             }
 
                             #[inline]
                             fn validate_usize(u: usize) -> Result<(), Self::Error> {
-                                Self::v_parse_u(u)?;
-
-                                Ok(())
+                                #validate_usize
                             }
 
                             #[cfg(target_pointer_width = "16")]
@@ -581,48 +702,48 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_string<E>(self, v: validators_prelude::String) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_string(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u8(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u8(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u16(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u16(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u32(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u32(v).map_err(validators_prelude::de_error)
                                             }
 
                                             #[inline]
                                             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u64(v).map_err(validators_prelude::de_error)
                                             }
 
                                             fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateUnsignedInteger>::parse_u128(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -633,6 +754,48 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("integer");
+
+                keywords.extend(type_attribute.range_schema);
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let range = &type_attribute.range;
+
+                crate::common::arbitrary::impl_arbitrary(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::integer::<#data_type>(u, #range) },
+                    quote! { <Self as ValidateUnsignedInteger>::parse_u128(v as u128) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { #data_type },
+                    quote! { self.0 },
+                    quote! { Ok(<Self as ValidateUnsignedInteger>::parse_u128(v as u128)?) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(
+                    &mut token_stream,
+                    &name,
+                    type_attribute.range_hint.as_deref(),
+                    &[],
+                );
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -640,7 +803,7 @@ impl ValidatorHandler for UnsignedIntegerHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -649,4 +812,13 @@ impl ValidatorHandler for UnsignedIntegerHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    fn carrier_fields(_meta: &Meta, data_type: &Type) -> syn::Result<Fields> {
+        match data_type.to_token_stream().to_string().as_str() {
+            "usize" | "u8" | "u16" | "u32" | "u64" | "u128" => {
+                Ok(Fields::Unnamed(syn::parse_quote! { (#data_type) }))
+            },
+            _ => Ok(Fields::Unnamed(syn::parse_quote! { (u128) })),
+        }
+    }
 }
@@ -1,12 +1,15 @@
 mod uuid_attribute;
 
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Meta, Path};
+use syn::{Data, DeriveInput, Fields, Meta, Path, Type};
 use uuid_attribute::UuidAttribute;
 
 use super::ValidatorHandler;
 use crate::{
-    common::{case_option::CaseOption, separator_option::SeparatorOption, type_enum::TypeEnum},
+    common::{
+        case_option::CaseOption, custom::Custom, separator_option::SeparatorOption,
+        type_enum::TypeEnum,
+    },
     panic,
 };
 
@@ -19,7 +22,11 @@ pub struct Struct(TypeEnum);
 const ITEM: Struct = Struct(TypeEnum::U128);
 
 impl ValidatorHandler for UuidHandler {
-    fn meta_handler(ast: DeriveInput, meta: Meta) -> syn::Result<proc_macro2::TokenStream> {
+    fn meta_handler(
+        ast: DeriveInput,
+        meta: Meta,
+        custom: &Custom,
+    ) -> syn::Result<proc_macro2::TokenStream> {
         let type_attribute = UuidAttribute::build_from_meta(&meta)?;
 
         if let Data::Struct(data) = ast.data
@@ -32,6 +39,9 @@ impl ValidatorHandler for UuidHandler {
 
             let error_path: Path = syn::parse2(quote! { validators_prelude::UuidError }).unwrap();
 
+            let error_type = custom.error_type(&error_path);
+            let ok = custom.ok();
+
             #[cfg(feature = "test")]
             {
                 let v_case = type_attribute.case;
@@ -45,48 +55,38 @@ impl ValidatorHandler for UuidHandler {
                         });
             }
 
-            let handle_iter = {
+            // the positions of the separators are marked as bits, so that the parsing works in a const context
+            let handle_separators = {
                 match type_attribute.separator {
                     SeparatorOption::Allow(separator) => {
                         quote! {
-                            if !(32..=36).contains(&length) {
+                            if length < 32 || length > 36 {
                                 return Err(#error_path::Invalid);
                             }
 
-                            let time_low = &bytes[0..8];
-
-                            let mut no_hyphen_counter = if bytes[8] != #separator {
-                                1
-                            } else {
-                                0
-                            };
-
-                            let time_mid = &bytes[(9 - no_hyphen_counter)..(13 - no_hyphen_counter)];
-
-                            if bytes[13 - no_hyphen_counter] != #separator {
-                                no_hyphen_counter += 1;
-                            }
+                            let mut separators = 0u64;
+                            let mut no_hyphen_counter = 0;
 
-                            let time_high_and_version =
-                                &bytes[(14 - no_hyphen_counter)..(18 - no_hyphen_counter)];
+                            let positions = [8, 13, 18, 23];
+                            let mut p = 0;
 
-                            if bytes[18 - no_hyphen_counter] != #separator {
-                                no_hyphen_counter += 1;
-                            }
+                            while p < positions.len() {
+                                let index = positions[p] - no_hyphen_counter;
 
-                            let clock_seq = &bytes[(19 - no_hyphen_counter)..(23 - no_hyphen_counter)];
+                                if bytes[index] == #separator {
+                                    separators |= 1 << index;
+                                } else {
+                                    no_hyphen_counter += 1;
+                                }
 
-                            if bytes[23 - no_hyphen_counter] != #separator {
-                                no_hyphen_counter += 1;
+                                p += 1;
                             }
 
-                            let node = &bytes[(24 - no_hyphen_counter)..];
-
-                            if node.len() != 12 {
+                            if length != 36 - no_hyphen_counter {
                                 return Err(#error_path::Invalid);
                             }
 
-                            time_low.iter().chain(time_mid).chain(time_high_and_version).chain(clock_seq).chain(node).copied()
+                            separators
                         }
                     },
                     SeparatorOption::Must(separator) => {
@@ -99,13 +99,7 @@ impl ValidatorHandler for UuidHandler {
                                 return Err(#error_path::Invalid);
                             }
 
-                            let time_low = &bytes[0..8];
-                            let time_mid = &bytes[9..13];
-                            let time_high_and_version = &bytes[14..18];
-                            let clock_seq = &bytes[19..23];
-                            let node = &bytes[24..];
-
-                            time_low.iter().chain(time_mid).chain(time_high_and_version).chain(clock_seq).chain(node).copied()
+                            1u64 << 8 | 1 << 13 | 1 << 18 | 1 << 23
                         }
                     },
                     SeparatorOption::Disallow => {
@@ -114,7 +108,7 @@ impl ValidatorHandler for UuidHandler {
                                 return Err(#error_path::SeparatorDisallow);
                             }
 
-                            bytes.iter().copied()
+                            0u64
                         }
                     },
                 }
@@ -124,18 +118,29 @@ impl ValidatorHandler for UuidHandler {
                 match type_attribute.case {
                     CaseOption::Any => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 uuid_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        uuid_decoded |= u128::from(e - b'0');
+                                        uuid_decoded |= (e - b'0') as u128;
                                     }
                                     b'a'..=b'f' => {
-                                        uuid_decoded |= u128::from(e - (b'a' - 10));
+                                        uuid_decoded |= (e - (b'a' - 10)) as u128;
                                     }
                                     b'A'..=b'F' => {
-                                        uuid_decoded |= u128::from(e - (b'A' - 10));
+                                        uuid_decoded |= (e - (b'A' - 10)) as u128;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -144,15 +149,26 @@ impl ValidatorHandler for UuidHandler {
                     },
                     CaseOption::Upper => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 uuid_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        uuid_decoded |= u128::from(e - b'0');
+                                        uuid_decoded |= (e - b'0') as u128;
                                     }
                                     b'A'..=b'F' => {
-                                        uuid_decoded |= u128::from(e - (b'A' - 10));
+                                        uuid_decoded |= (e - (b'A' - 10)) as u128;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -161,15 +177,26 @@ impl ValidatorHandler for UuidHandler {
                     },
                     CaseOption::Lower => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 uuid_decoded <<= 4;
 
                                 match e {
                                     b'0'..=b'9' => {
-                                        uuid_decoded |= u128::from(e - b'0');
+                                        uuid_decoded |= (e - b'0') as u128;
                                     }
                                     b'a'..=b'f' => {
-                                        uuid_decoded |= u128::from(e - (b'a' - 10));
+                                        uuid_decoded |= (e - (b'a' - 10)) as u128;
                                     }
                                     _ => return Err(#error_path::Invalid),
                                 }
@@ -183,7 +210,18 @@ impl ValidatorHandler for UuidHandler {
                 match type_attribute.case {
                     CaseOption::Any => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -193,7 +231,18 @@ impl ValidatorHandler for UuidHandler {
                     },
                     CaseOption::Upper => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'A'..=b'F' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -203,7 +252,18 @@ impl ValidatorHandler for UuidHandler {
                     },
                     CaseOption::Lower => {
                         quote! {
-                            for e in iter {
+                            let mut i = 0;
+
+                            while i < length {
+                                let e = bytes[i];
+                                let is_separator = separators >> i & 1 == 1;
+
+                                i += 1;
+
+                                if is_separator {
+                                    continue;
+                                }
+
                                 match e {
                                     b'0'..=b'9' | b'a'..=b'f' => (),
                                     _ => return Err(#error_path::Invalid),
@@ -216,12 +276,12 @@ impl ValidatorHandler for UuidHandler {
 
             token_stream.extend(quote! {
                 impl #name {
-                    fn v_parse_str(s: &str) -> Result<u128, #error_path> {
+                    const fn v_parse_str(s: &str) -> Result<u128, #error_path> {
                         let bytes = s.as_bytes();
                         let length = bytes.len();
 
-                        let iter = {
-                            #handle_iter
+                        let separators = {
+                            #handle_separators
                         };
 
                         let mut uuid_decoded = 0u128;
@@ -231,12 +291,12 @@ impl ValidatorHandler for UuidHandler {
                         Ok(uuid_decoded)
                     }
 
-                    fn v_validate_str(s: &str) -> Result<(), #error_path> {
+                    const fn v_validate_str(s: &str) -> Result<(), #error_path> {
                         let bytes = s.as_bytes();
                         let length = bytes.len();
 
-                        let iter = {
-                            #handle_iter
+                        let separators = {
+                            #handle_separators
                         };
 
                         #handle_check
@@ -246,25 +306,31 @@ impl ValidatorHandler for UuidHandler {
                 }
             });
 
+            crate::common::literal::impl_const_new(&mut token_stream, &name, custom);
+
+            let validate_str = custom.validate(quote! { Self::parse_str(s) }, quote! {
+                Self::v_validate_str(s.as_ref())?;
+
+                Ok(())
+            });
+
             token_stream.extend(quote! {
                         impl ValidateString for #name {
-                            type Error = #error_path;
+                            type Error = #error_type;
 
                             #[inline]
                             fn parse_string<S: Into<validators_prelude::String>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.into().as_str())?))
+                                #ok(Self(Self::v_parse_str(s.into().as_str())?))
                             }
 
                             #[inline]
                             fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error> {
-                                Ok(Self(Self::v_parse_str(s.as_ref())?))
+                                #ok(Self(Self::v_parse_str(s.as_ref())?))
                             }
 
                             #[inline]
                             fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error> {
-                                Self::v_validate_str(s.as_ref())?;
-
-                                Ok(())
+                                #validate_str
                             }
                         }
                     });
@@ -422,7 +488,7 @@ impl ValidatorHandler for UuidHandler {
                                             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
                                             where
                                                 E: validators_prelude::serde::de::Error, {
-                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::serde::de::Error::custom)
+                                                <#name as ValidateString>::parse_str(v).map_err(validators_prelude::de_error)
                                             }
                                         }
 
@@ -433,6 +499,51 @@ impl ValidatorHandler for UuidHandler {
                 }
             }
 
+            #[cfg(any(feature = "schemars", feature = "utoipa"))]
+            {
+                let mut keywords = crate::common::schema::SchemaKeywords::new("string");
+
+                keywords.pattern = Some(crate::common::schema::hex_groups_pattern(
+                    &[8, 4, 4, 4, 12],
+                    type_attribute.case,
+                    type_attribute.separator,
+                ));
+
+                if type_attribute.separator == SeparatorOption::Must(b'-') {
+                    keywords.format = Some("uuid");
+                }
+
+                crate::common::schema::impl_schema(&mut token_stream, &name, &keywords);
+            }
+
+            #[cfg(feature = "arbitrary")]
+            {
+                let case = type_attribute.case;
+                let separator = type_attribute.separator;
+
+                crate::common::arbitrary::impl_arbitrary_string(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::generators::uuid(u, #case, #separator) },
+                );
+            }
+
+            #[cfg(feature = "sqlx")]
+            {
+                crate::common::sqlx::impl_sqlx(
+                    &mut token_stream,
+                    &name,
+                    quote! { validators_prelude::sqlx::types::Uuid },
+                    quote! { validators_prelude::sqlx::types::Uuid::from_u128(self.0) },
+                    quote! { Ok(Self(v.as_u128())) },
+                );
+            }
+
+            #[cfg(feature = "clap")]
+            {
+                crate::common::clap::impl_value_parser_factory(&mut token_stream, &name, None, &[]);
+            }
+
             #[cfg(feature = "rocket")]
             {
                 if type_attribute.rocket_options.from_form_field {
@@ -440,7 +551,7 @@ impl ValidatorHandler for UuidHandler {
                 }
 
                 if type_attribute.rocket_options.from_param {
-                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_path);
+                    crate::common::rocket::impl_from_param(&mut token_stream, &name, &error_type);
                 }
             }
 
@@ -449,4 +560,9 @@ impl ValidatorHandler for UuidHandler {
 
         Err(panic::validator_for_specific_item(meta.path(), ITEM))
     }
+
+    #[inline]
+    fn carrier_fields(_meta: &Meta, _data_type: &Type) -> syn::Result<Fields> {
+        Ok(Fields::Unnamed(syn::parse_quote! { (u128) }))
+    }
 }
@@ -0,0 +1,77 @@
+use actix_web::{ResponseError, http::StatusCode};
+
+use super::*;
+
+/// Implement `ResponseError` for every validator error type so that handlers can return these errors directly with the `?` operator.
+macro_rules! impl_response_error {
+    ($(($feature:literal, $Error:ident)),* $(,)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl ResponseError for $Error {
+                #[inline]
+                fn status_code(&self) -> StatusCode {
+                    StatusCode::BAD_REQUEST
+                }
+            }
+        )*
+    };
+}
+
+impl_response_error! {
+    ("base32", Base32Error),
+    ("base32_decoded", Base32DecodedError),
+    ("base64", Base64Error),
+    ("base64_decoded", Base64DecodedError),
+    ("base64_url", Base64UrlError),
+    ("base64_url_decoded", Base64UrlDecodedError),
+    ("bit", BitError),
+    ("boolean", BooleanError),
+    ("byte", ByteError),
+    ("date", DateError),
+    ("datetime", DateTimeError),
+    ("domain", DomainError),
+    ("duration", DurationError),
+    ("email", EmailError),
+    ("host", HostError),
+    ("http_url", HttpURLError),
+    ("http_ftp_url", HttpFtpURLError),
+    ("ip", IpError),
+    ("ipv4", Ipv4Error),
+    ("ipv6", Ipv6Error),
+    ("json", JsonError),
+    ("length", LengthError),
+    ("line", LineError),
+    ("mac_address", MacAddressError),
+    ("number", NumberError),
+    ("phone", PhoneError),
+    ("regex", RegexError),
+    ("signed_integer", SignedIntegerError),
+    ("text", TextError),
+    ("time", TimeError),
+    ("unsigned_integer", UnsignedIntegerError),
+    ("url", UrlError),
+    ("uuid", UuidError),
+}
+
+// `SemverError` is shared by the `semver` and `semver_req` validators, so it cannot use the single-feature macro above.
+#[cfg(any(feature = "semver", feature = "semver_req"))]
+impl ResponseError for SemverError {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl ResponseError for ValidationErrors {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> ResponseError for CustomizedError<E> {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
@@ -30,7 +30,10 @@ impl_into_response! {
     ("bit", BitError),
     ("boolean", BooleanError),
     ("byte", ByteError),
+    ("date", DateError),
+    ("datetime", DateTimeError),
     ("domain", DomainError),
+    ("duration", DurationError),
     ("email", EmailError),
     ("host", HostError),
     ("http_url", HttpURLError),
@@ -47,6 +50,7 @@ impl_into_response! {
     ("regex", RegexError),
     ("signed_integer", SignedIntegerError),
     ("text", TextError),
+    ("time", TimeError),
     ("unsigned_integer", UnsignedIntegerError),
     ("url", UrlError),
     ("uuid", UuidError),
@@ -60,3 +64,17 @@ impl IntoResponse for SemverError {
         (StatusCode::BAD_REQUEST, self.to_string()).into_response()
     }
 }
+
+impl IntoResponse for ValidationErrors {
+    #[inline]
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+impl<E: core::fmt::Display> IntoResponse for CustomizedError<E> {
+    #[inline]
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
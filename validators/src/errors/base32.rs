@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `base32` validator.
 #[derive(Debug, Clone)]
 pub enum Base32Error {
@@ -23,3 +25,19 @@ impl Display for Base32Error {
 }
 
 impl core::error::Error for Base32Error {}
+
+impl ValidatorError for Base32Error {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "base32"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base32.invalid",
+            Self::PaddingMust => "base32.padding_must",
+            Self::PaddingDisallow => "base32.padding_disallow",
+        }
+    }
+}
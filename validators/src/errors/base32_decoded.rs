@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `base32_decoded` validator.
 #[derive(Debug, Clone)]
 pub enum Base32DecodedError {
@@ -26,3 +28,20 @@ impl Display for Base32DecodedError {
 }
 
 impl core::error::Error for Base32DecodedError {}
+
+impl ValidatorError for Base32DecodedError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "base32_decoded"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base32_decoded.invalid",
+            Self::PaddingMust => "base32_decoded.padding_must",
+            Self::PaddingDisallow => "base32_decoded.padding_disallow",
+            Self::Decode => "base32_decoded.decode",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `base64` validator.
 #[derive(Debug, Clone)]
 pub enum Base64Error {
@@ -23,3 +25,19 @@ impl Display for Base64Error {
 }
 
 impl core::error::Error for Base64Error {}
+
+impl ValidatorError for Base64Error {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "base64"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64.invalid",
+            Self::PaddingMust => "base64.padding_must",
+            Self::PaddingDisallow => "base64.padding_disallow",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `base64_decoded` validator.
 #[derive(Debug, Clone)]
 pub enum Base64DecodedError {
@@ -26,3 +28,20 @@ impl Display for Base64DecodedError {
 }
 
 impl core::error::Error for Base64DecodedError {}
+
+impl ValidatorError for Base64DecodedError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "base64_decoded"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64_decoded.invalid",
+            Self::PaddingMust => "base64_decoded.padding_must",
+            Self::PaddingDisallow => "base64_decoded.padding_disallow",
+            Self::Decode => "base64_decoded.decode",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `base64_url` validator.
 #[derive(Debug, Clone)]
 pub enum Base64UrlError {
@@ -23,3 +25,19 @@ impl Display for Base64UrlError {
 }
 
 impl core::error::Error for Base64UrlError {}
+
+impl ValidatorError for Base64UrlError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "base64_url"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "base64_url.invalid",
+            Self::PaddingMust => "base64_url.padding_must",
+            Self::PaddingDisallow => "base64_url.padding_disallow",
+        }
+    }
+}
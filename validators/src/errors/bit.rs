@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 
 use byte_unit::ParseError;
 
+use crate::traits::ValidatorError;
+
 /// Error from the `bit` validator.
 #[derive(Debug, Clone)]
 pub enum BitError {
@@ -29,3 +31,19 @@ impl Display for BitError {
 }
 
 impl core::error::Error for BitError {}
+
+impl ValidatorError for BitError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "bit"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "bit.parse_error",
+            Self::TooLarge => "bit.too_large",
+            Self::TooSmall => "bit.too_small",
+        }
+    }
+}
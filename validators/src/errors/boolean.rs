@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `boolean` validator.
 #[derive(Debug, Clone)]
 pub struct BooleanError;
@@ -12,3 +14,15 @@ impl Display for BooleanError {
 }
 
 impl core::error::Error for BooleanError {}
+
+impl ValidatorError for BooleanError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "boolean"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        "boolean.invalid"
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 
 use byte_unit::ParseError;
 
+use crate::traits::ValidatorError;
+
 /// Error from the `bit` validator.
 #[derive(Debug, Clone)]
 pub enum ByteError {
@@ -29,3 +31,19 @@ impl Display for ByteError {
 }
 
 impl core::error::Error for ByteError {}
+
+impl ValidatorError for ByteError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "byte"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "byte.parse_error",
+            Self::TooLarge => "byte.too_large",
+            Self::TooSmall => "byte.too_small",
+        }
+    }
+}
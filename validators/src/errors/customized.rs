@@ -0,0 +1,70 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::traits::{ErrorParam, ValidatorError};
+
+/// Error from a validator with the `custom` parameter. `E` is the error of the built-in validator.
+#[derive(Debug)]
+pub enum CustomizedError<E> {
+    /// The built-in checks failed.
+    Builtin(E),
+    /// The built-in checks passed, but the custom function rejected the parsed value. Use `downcast_ref` to get the error returned by the function back.
+    Custom(Box<dyn Error + Send + Sync + 'static>),
+}
+
+impl<E> CustomizedError<E> {
+    /// Wrap an error returned by a custom function.
+    #[inline]
+    pub fn custom<C: Into<Box<dyn Error + Send + Sync + 'static>>>(error: C) -> Self {
+        Self::Custom(error.into())
+    }
+}
+
+impl<E> From<E> for CustomizedError<E> {
+    #[inline]
+    fn from(error: E) -> Self {
+        Self::Builtin(error)
+    }
+}
+
+impl<E: Display> Display for CustomizedError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Builtin(error) => Display::fmt(error, f),
+            Self::Custom(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E: Error> Error for CustomizedError<E> {}
+
+/// The code of a `Custom` error is `custom`, and so is the name of its validator, because the function is not known.
+impl<E: ValidatorError> ValidatorError for CustomizedError<E> {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        match self {
+            Self::Builtin(error) => error.validator(),
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Builtin(error) => error.code(),
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    #[inline]
+    fn params(&self) -> Vec<(&'static str, ErrorParam)> {
+        match self {
+            Self::Builtin(error) => error.params(),
+            Self::Custom(_) => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::traits::ValidatorError;
+
+/// Error from the `date` validator.
+#[derive(Debug, Clone)]
+pub enum DateError {
+    /// Incorrect date data.
+    Invalid,
+    /// The date is valid, but it is written in the extended format (`YYYY-MM-DD`).
+    BasicFormatMust,
+    /// The date is valid, but it is written in the basic format (`YYYYMMDD`).
+    BasicFormatDisallow,
+    /// The date is valid, but it is earlier than the minimum.
+    TooEarly,
+    /// The date is valid, but it is later than the maximum.
+    TooLate,
+}
+
+impl Display for DateError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Invalid => f.write_str("invalid date"),
+            Self::BasicFormatMust => f.write_str("must be in the basic format"),
+            Self::BasicFormatDisallow => f.write_str("must not be in the basic format"),
+            Self::TooEarly => f.write_str("date is too early"),
+            Self::TooLate => f.write_str("date is too late"),
+        }
+    }
+}
+
+impl core::error::Error for DateError {}
+
+impl ValidatorError for DateError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "date"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "date.invalid",
+            Self::BasicFormatMust => "date.basic_format_must",
+            Self::BasicFormatDisallow => "date.basic_format_disallow",
+            Self::TooEarly => "date.too_early",
+            Self::TooLate => "date.too_late",
+        }
+    }
+}
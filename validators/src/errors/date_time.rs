@@ -0,0 +1,63 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::traits::ValidatorError;
+
+/// Error from the `datetime` validator.
+#[derive(Debug, Clone)]
+pub enum DateTimeError {
+    /// Incorrect date-time data.
+    Invalid,
+    /// The date-time is valid, but it is written in the extended format (`YYYY-MM-DDThh:mm:ss`).
+    BasicFormatMust,
+    /// The date-time is valid, but it is written in the basic format (`YYYYMMDDThhmmss`).
+    BasicFormatDisallow,
+    /// The date-time is valid, but the timezone is missing.
+    TimezoneMust,
+    /// The date-time is valid, but the timezone exists.
+    TimezoneDisallow,
+    /// The date-time is valid, but the fraction of a second exists.
+    FractionDisallow,
+    /// The date-time is valid, but it is earlier than the minimum.
+    TooEarly,
+    /// The date-time is valid, but it is later than the maximum.
+    TooLate,
+}
+
+impl Display for DateTimeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Invalid => f.write_str("invalid date-time"),
+            Self::BasicFormatMust => f.write_str("must be in the basic format"),
+            Self::BasicFormatDisallow => f.write_str("must not be in the basic format"),
+            Self::TimezoneMust => f.write_str("timezone not found"),
+            Self::TimezoneDisallow => f.write_str("timezone not allowed"),
+            Self::FractionDisallow => f.write_str("fraction of a second not allowed"),
+            Self::TooEarly => f.write_str("date-time is too early"),
+            Self::TooLate => f.write_str("date-time is too late"),
+        }
+    }
+}
+
+impl core::error::Error for DateTimeError {}
+
+impl ValidatorError for DateTimeError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "datetime"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "datetime.invalid",
+            Self::BasicFormatMust => "datetime.basic_format_must",
+            Self::BasicFormatDisallow => "datetime.basic_format_disallow",
+            Self::TimezoneMust => "datetime.timezone_must",
+            Self::TimezoneDisallow => "datetime.timezone_disallow",
+            Self::FractionDisallow => "datetime.fraction_disallow",
+            Self::TooEarly => "datetime.too_early",
+            Self::TooLate => "datetime.too_late",
+        }
+    }
+}
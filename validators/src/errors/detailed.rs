@@ -0,0 +1,160 @@
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::traits::{ErrorParam, ValidatorError};
+
+/// An error with the details of the failure, such as the configured `max` and the measured `length` of a `TextError::TooLong`, or the `offset` of the first invalid character. It is returned by the `*_detailed` methods of the validation traits, such as `ValidateString::validate_str_detailed`.
+///
+/// The details are measured again only after the validation has failed, so the errors returned by the other methods stay small.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "text"))]
+/// # {
+/// use validators::{errors::TextError, prelude::*};
+///
+/// #[derive(Debug, Validator)]
+/// #[validator(text(char_length(max = 4)))]
+/// pub struct Name(String);
+///
+/// let error = Name::validate_str_detailed("too long").unwrap_err();
+///
+/// assert!(matches!(error.error(), TextError::TooLong));
+/// assert_eq!(Some(8), error.length());
+/// assert_eq!("text.too_long", error.code());
+/// assert_eq!(
+///     vec![
+///         ("max", ErrorParam::Unsigned(4)),
+///         ("length", ErrorParam::Unsigned(8))
+///     ],
+///     error.params()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DetailedError<E> {
+    error:   E,
+    details: Vec<(&'static str, ErrorParam)>,
+}
+
+impl<E> DetailedError<E> {
+    /// Wrap an error without details.
+    #[inline]
+    pub const fn new(error: E) -> Self {
+        Self {
+            error,
+            details: Vec::new(),
+        }
+    }
+
+    /// Add details, which follow the parameters of the error itself.
+    #[inline]
+    pub fn with_params(mut self, details: Vec<(&'static str, ErrorParam)>) -> Self {
+        self.details.extend(details);
+
+        self
+    }
+
+    /// The error without the details.
+    #[inline]
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Drop the details.
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// Split the error and the details.
+    #[inline]
+    pub fn into_parts(self) -> (E, Vec<(&'static str, ErrorParam)>) {
+        (self.error, self.details)
+    }
+
+    /// Map the error and keep the details, such as wrapping it in `CustomizedError::Builtin`.
+    #[inline]
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> DetailedError<F> {
+        DetailedError {
+            error: f(self.error), details: self.details
+        }
+    }
+
+    /// The details, without the parameters of the error itself.
+    #[inline]
+    pub fn details(&self) -> &[(&'static str, ErrorParam)] {
+        self.details.as_slice()
+    }
+
+    /// Find a detail by its name, such as `max`.
+    #[inline]
+    pub fn param(&self, name: &str) -> Option<&ErrorParam> {
+        self.details.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    #[inline]
+    fn unsigned(&self, name: &str) -> Option<usize> {
+        match self.param(name) {
+            Some(ErrorParam::Unsigned(v)) => usize::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    /// The byte offset of the first invalid character, if it is known.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.unsigned("offset")
+    }
+
+    /// The index of the first invalid label of a domain, if it is known.
+    #[inline]
+    pub fn label(&self) -> Option<usize> {
+        self.unsigned("label")
+    }
+
+    /// The measured length, if the input is too long or too short.
+    #[inline]
+    pub fn length(&self) -> Option<usize> {
+        self.unsigned("length")
+    }
+}
+
+impl<E> From<E> for DetailedError<E> {
+    #[inline]
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+impl<E: Display> Display for DetailedError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: Error> Error for DetailedError<E> {}
+
+impl<E: ValidatorError> ValidatorError for DetailedError<E> {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        self.error.validator()
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    #[inline]
+    fn params(&self) -> Vec<(&'static str, ErrorParam)> {
+        let mut params = self.error.params();
+
+        params.extend(self.details.iter().cloned());
+
+        params
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `domain` validator.
 #[derive(Debug, Clone)]
 pub enum DomainError {
@@ -21,6 +23,16 @@ pub enum DomainError {
     PortMust,
     /// May not be valid, and the port part seems to exist.
     PortDisallow,
+    /// May not be valid, but it is guaranteed that the domain is not in `allowed_domains`.
+    DomainNotAllowed,
+    /// May not be valid and the domain seems to be in `denied_domains`.
+    DomainDenied,
+    /// May not be valid, but it is guaranteed that the domain is a public suffix or an IP.
+    RegistrableMust,
+    /// May not be valid and the domain seems to be under a registrable domain.
+    RegistrableDisallow,
+    /// May not be valid, but it is guaranteed that the last label of the domain is not a known TLD.
+    KnownTLDMust,
 }
 
 impl Display for DomainError {
@@ -36,8 +48,40 @@ impl Display for DomainError {
             Self::AtLeastTwoLabelsDisallow => f.write_str("must have only one label"),
             Self::PortMust => f.write_str("port not found"),
             Self::PortDisallow => f.write_str("port not allowed"),
+            Self::DomainNotAllowed => f.write_str("domain not allowed"),
+            Self::DomainDenied => f.write_str("domain denied"),
+            Self::RegistrableMust => f.write_str("must be under a registrable domain"),
+            Self::RegistrableDisallow => f.write_str("must be a public suffix"),
+            Self::KnownTLDMust => f.write_str("must use a known TLD"),
         }
     }
 }
 
 impl core::error::Error for DomainError {}
+
+impl ValidatorError for DomainError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "domain"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "domain.invalid",
+            Self::IPv4Must => "domain.ipv4_must",
+            Self::IPv4Disallow => "domain.ipv4_disallow",
+            Self::LocalMust => "domain.local_must",
+            Self::LocalDisallow => "domain.local_disallow",
+            Self::AtLeastTwoLabelsMust => "domain.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "domain.at_least_two_labels_disallow",
+            Self::PortMust => "domain.port_must",
+            Self::PortDisallow => "domain.port_disallow",
+            Self::DomainNotAllowed => "domain.domain_not_allowed",
+            Self::DomainDenied => "domain.domain_denied",
+            Self::RegistrableMust => "domain.registrable_must",
+            Self::RegistrableDisallow => "domain.registrable_disallow",
+            Self::KnownTLDMust => "domain.known_tld_must",
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::traits::ValidatorError;
+
+/// Error from the `duration` validator.
+#[derive(Debug, Clone)]
+pub enum DurationError {
+    /// Incorrect duration data.
+    Invalid,
+    /// The duration is valid, but it is too large.
+    TooLarge,
+    /// The duration is valid, but it is too small.
+    TooSmall,
+}
+
+impl Display for DurationError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Invalid => f.write_str("invalid duration"),
+            Self::TooLarge => f.write_str("duration is too large"),
+            Self::TooSmall => f.write_str("duration is too small"),
+        }
+    }
+}
+
+impl core::error::Error for DurationError {}
+
+impl ValidatorError for DurationError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "duration"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "duration.invalid",
+            Self::TooLarge => "duration.too_large",
+            Self::TooSmall => "duration.too_small",
+        }
+    }
+}
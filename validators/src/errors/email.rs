@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `email` validator.
 #[derive(Debug, Clone)]
 pub enum EmailError {
@@ -19,6 +21,20 @@ pub enum EmailError {
     AtLeastTwoLabelsDisallow,
     /// May not be valid and comments seems to exist.
     CommentDisallow,
+    /// May not be valid, but it is guaranteed that there is no display name.
+    DisplayNameMust,
+    /// May not be valid and a display name or angle brackets seem to exist.
+    DisplayNameDisallow,
+    /// May not be valid, but it is guaranteed that the domain part is not in `allowed_domains`.
+    DomainNotAllowed,
+    /// May not be valid and the domain part seems to be in `denied_domains`.
+    DomainDenied,
+    /// May not be valid, but it is guaranteed that the domain part is a public suffix or an IP.
+    RegistrableMust,
+    /// May not be valid and the domain part seems to be under a registrable domain.
+    RegistrableDisallow,
+    /// May not be valid, but it is guaranteed that the last label of the domain part is not a known TLD.
+    KnownTLDMust,
 }
 
 impl Display for EmailError {
@@ -33,8 +49,43 @@ impl Display for EmailError {
             Self::AtLeastTwoLabelsMust => f.write_str("must have at least two labels"),
             Self::AtLeastTwoLabelsDisallow => f.write_str("must have only one label"),
             Self::CommentDisallow => f.write_str("must not contain comments"),
+            Self::DisplayNameMust => f.write_str("must have a display name"),
+            Self::DisplayNameDisallow => f.write_str("must not have a display name"),
+            Self::DomainNotAllowed => f.write_str("domain not allowed"),
+            Self::DomainDenied => f.write_str("domain denied"),
+            Self::RegistrableMust => f.write_str("must be under a registrable domain"),
+            Self::RegistrableDisallow => f.write_str("must be a public suffix"),
+            Self::KnownTLDMust => f.write_str("must use a known TLD"),
         }
     }
 }
 
 impl core::error::Error for EmailError {}
+
+impl ValidatorError for EmailError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "email"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "email.invalid",
+            Self::IPMust => "email.ip_must",
+            Self::IPDisallow => "email.ip_disallow",
+            Self::LocalMust => "email.local_must",
+            Self::LocalDisallow => "email.local_disallow",
+            Self::AtLeastTwoLabelsMust => "email.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "email.at_least_two_labels_disallow",
+            Self::CommentDisallow => "email.comment_disallow",
+            Self::DisplayNameMust => "email.display_name_must",
+            Self::DisplayNameDisallow => "email.display_name_disallow",
+            Self::DomainNotAllowed => "email.domain_not_allowed",
+            Self::DomainDenied => "email.domain_denied",
+            Self::RegistrableMust => "email.registrable_must",
+            Self::RegistrableDisallow => "email.registrable_disallow",
+            Self::KnownTLDMust => "email.known_tld_must",
+        }
+    }
+}
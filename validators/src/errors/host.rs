@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `host` validator.
 #[derive(Debug, Clone)]
 pub enum HostError {
@@ -17,6 +19,14 @@ pub enum HostError {
     PortMust,
     /// May not be valid and the port part seems to exist.
     PortDisallow,
+    /// May not be valid, but it is guaranteed that the domain is not in `allowed_domains`.
+    DomainNotAllowed,
+    /// May not be valid and the domain seems to be in `denied_domains`.
+    DomainDenied,
+    /// May not be valid, but it is guaranteed that the domain is a public suffix or an IP.
+    RegistrableMust,
+    /// May not be valid and the domain seems to be under a registrable domain.
+    RegistrableDisallow,
 }
 
 impl Display for HostError {
@@ -30,8 +40,36 @@ impl Display for HostError {
             Self::AtLeastTwoLabelsDisallow => f.write_str("must have only one label"),
             Self::PortMust => f.write_str("port not found"),
             Self::PortDisallow => f.write_str("port not allowed"),
+            Self::DomainNotAllowed => f.write_str("domain not allowed"),
+            Self::DomainDenied => f.write_str("domain denied"),
+            Self::RegistrableMust => f.write_str("must be under a registrable domain"),
+            Self::RegistrableDisallow => f.write_str("must be a public suffix"),
         }
     }
 }
 
 impl core::error::Error for HostError {}
+
+impl ValidatorError for HostError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "host"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "host.invalid",
+            Self::LocalMust => "host.local_must",
+            Self::LocalDisallow => "host.local_disallow",
+            Self::AtLeastTwoLabelsMust => "host.at_least_two_labels_must",
+            Self::AtLeastTwoLabelsDisallow => "host.at_least_two_labels_disallow",
+            Self::PortMust => "host.port_must",
+            Self::PortDisallow => "host.port_disallow",
+            Self::DomainNotAllowed => "host.domain_not_allowed",
+            Self::DomainDenied => "host.domain_denied",
+            Self::RegistrableMust => "host.registrable_must",
+            Self::RegistrableDisallow => "host.registrable_disallow",
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::fmt::{self, Display, Formatter};
 
-use crate::url;
+use crate::{traits::ValidatorError, url};
 
 /// Error from the `http_ftp_url` validator.
 #[derive(Debug, Clone)]
@@ -10,6 +10,10 @@ pub enum HttpFtpURLError {
     ProtocolError,
     LocalMust,
     LocalDisallow,
+    /// May not be valid, but it is guaranteed that the host is not in `allowed_domains`.
+    DomainNotAllowed,
+    /// May not be valid and the host seems to be in `denied_domains`.
+    DomainDenied,
 }
 
 impl From<url::ParseError> for HttpFtpURLError {
@@ -29,8 +33,29 @@ impl Display for HttpFtpURLError {
             },
             Self::LocalMust => f.write_str("must be local"),
             Self::LocalDisallow => f.write_str("must not be local"),
+            Self::DomainNotAllowed => f.write_str("domain not allowed"),
+            Self::DomainDenied => f.write_str("domain denied"),
         }
     }
 }
 
 impl core::error::Error for HttpFtpURLError {}
+
+impl ValidatorError for HttpFtpURLError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "http_ftp_url"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "http_ftp_url.parse_error",
+            Self::ProtocolError => "http_ftp_url.protocol_error",
+            Self::LocalMust => "http_ftp_url.local_must",
+            Self::LocalDisallow => "http_ftp_url.local_disallow",
+            Self::DomainNotAllowed => "http_ftp_url.domain_not_allowed",
+            Self::DomainDenied => "http_ftp_url.domain_denied",
+        }
+    }
+}
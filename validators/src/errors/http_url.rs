@@ -1,6 +1,6 @@
 use core::fmt::{self, Display, Formatter};
 
-use crate::url;
+use crate::{traits::ValidatorError, url};
 
 /// Error from the `http_url` validator.
 #[derive(Debug, Clone)]
@@ -10,6 +10,10 @@ pub enum HttpURLError {
     ProtocolError,
     LocalMust,
     LocalDisallow,
+    /// May not be valid, but it is guaranteed that the host is not in `allowed_domains`.
+    DomainNotAllowed,
+    /// May not be valid and the host seems to be in `denied_domains`.
+    DomainDenied,
 }
 
 impl From<url::ParseError> for HttpURLError {
@@ -27,8 +31,29 @@ impl Display for HttpURLError {
             Self::ProtocolError => f.write_str("need to use `http` or `https` as a protocol"),
             Self::LocalMust => f.write_str("must be local"),
             Self::LocalDisallow => f.write_str("must not be local"),
+            Self::DomainNotAllowed => f.write_str("domain not allowed"),
+            Self::DomainDenied => f.write_str("domain denied"),
         }
     }
 }
 
 impl core::error::Error for HttpURLError {}
+
+impl ValidatorError for HttpURLError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "http_url"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => "http_url.parse_error",
+            Self::ProtocolError => "http_url.protocol_error",
+            Self::LocalMust => "http_url.local_must",
+            Self::LocalDisallow => "http_url.local_disallow",
+            Self::DomainNotAllowed => "http_url.domain_not_allowed",
+            Self::DomainDenied => "http_url.domain_denied",
+        }
+    }
+}
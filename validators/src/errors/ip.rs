@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `ip` validator.
 #[derive(Debug, Clone)]
 pub enum IpError {
@@ -29,3 +31,21 @@ impl Display for IpError {
 }
 
 impl core::error::Error for IpError {}
+
+impl ValidatorError for IpError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "ip"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ip.invalid",
+            Self::LocalMust => "ip.local_must",
+            Self::LocalDisallow => "ip.local_disallow",
+            Self::PortMust => "ip.port_must",
+            Self::PortDisallow => "ip.port_disallow",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `ipv4` validator.
 #[derive(Debug, Clone)]
 pub enum Ipv4Error {
@@ -29,3 +31,21 @@ impl Display for Ipv4Error {
 }
 
 impl core::error::Error for Ipv4Error {}
+
+impl ValidatorError for Ipv4Error {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "ipv4"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ipv4.invalid",
+            Self::LocalMust => "ipv4.local_must",
+            Self::LocalDisallow => "ipv4.local_disallow",
+            Self::PortMust => "ipv4.port_must",
+            Self::PortDisallow => "ipv4.port_disallow",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `ipv6` validator.
 #[derive(Debug, Clone)]
 pub enum Ipv6Error {
@@ -29,3 +31,21 @@ impl Display for Ipv6Error {
 }
 
 impl core::error::Error for Ipv6Error {}
+
+impl ValidatorError for Ipv6Error {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "ipv6"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "ipv6.invalid",
+            Self::LocalMust => "ipv6.local_must",
+            Self::LocalDisallow => "ipv6.local_disallow",
+            Self::PortMust => "ipv6.port_must",
+            Self::PortDisallow => "ipv6.port_disallow",
+        }
+    }
+}
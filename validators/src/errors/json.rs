@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `json` validator.
 #[derive(Debug)]
 pub enum JsonError {
@@ -26,3 +28,18 @@ impl Display for JsonError {
 }
 
 impl core::error::Error for JsonError {}
+
+impl ValidatorError for JsonError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "json"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::SerdeJsonError(_) => "json.serde_json_error",
+            Self::InvalidJsonValueError => "json.invalid_json_value_error",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `length` validator.
 #[derive(Debug, Clone)]
 pub enum LengthError {
@@ -18,3 +20,18 @@ impl Display for LengthError {
 }
 
 impl core::error::Error for LengthError {}
+
+impl ValidatorError for LengthError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "length"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TooLarge => "length.too_large",
+            Self::TooSmall => "length.too_small",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `line` validator.
 #[derive(Debug, Clone)]
 pub enum LineError {
@@ -22,3 +24,19 @@ impl Display for LineError {
 }
 
 impl core::error::Error for LineError {}
+
+impl ValidatorError for LineError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "line"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "line.invalid",
+            Self::TooLong => "line.too_long",
+            Self::TooShort => "line.too_short",
+        }
+    }
+}
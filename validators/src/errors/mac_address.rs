@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `mac_address` validator.
 #[derive(Debug, Clone)]
 pub enum MacAddressError {
@@ -23,3 +25,19 @@ impl Display for MacAddressError {
 }
 
 impl core::error::Error for MacAddressError {}
+
+impl ValidatorError for MacAddressError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "mac_address"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "mac_address.invalid",
+            Self::SeparatorMust => "mac_address.separator_must",
+            Self::SeparatorDisallow => "mac_address.separator_disallow",
+        }
+    }
+}
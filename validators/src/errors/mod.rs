@@ -1,6 +1,13 @@
+#[cfg(feature = "actix-web")]
+mod actix_web_traits;
 #[cfg(feature = "axum")]
 mod axum_traits;
 
+mod customized;
+mod detailed;
+pub(crate) mod validation_errors;
+pub use self::{customized::*, detailed::*, validation_errors::*};
+
 #[cfg(feature = "base32")]
 mod base32;
 #[cfg(feature = "base32")]
@@ -46,11 +53,26 @@ mod byte;
 #[cfg(feature = "byte")]
 pub use self::byte::*;
 
+#[cfg(feature = "date")]
+mod date;
+#[cfg(feature = "date")]
+pub use self::date::*;
+
+#[cfg(feature = "datetime")]
+mod date_time;
+#[cfg(feature = "datetime")]
+pub use self::date_time::*;
+
 #[cfg(feature = "domain")]
 mod domain;
 #[cfg(feature = "domain")]
 pub use self::domain::*;
 
+#[cfg(feature = "duration")]
+mod duration;
+#[cfg(feature = "duration")]
+pub use self::duration::*;
+
 #[cfg(feature = "email")]
 mod email;
 #[cfg(feature = "email")]
@@ -136,6 +158,11 @@ mod text;
 #[cfg(feature = "text")]
 pub use self::text::*;
 
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "time")]
+pub use self::time::*;
+
 #[cfg(feature = "unsigned_integer")]
 mod unsigned_integer;
 #[cfg(feature = "unsigned_integer")]
@@ -3,6 +3,8 @@ use core::{
     num::ParseFloatError,
 };
 
+use crate::traits::ValidatorError;
+
 /// Error from the `number` validator.
 #[derive(Debug, Clone)]
 pub enum NumberError {
@@ -36,3 +38,22 @@ impl Display for NumberError {
 }
 
 impl core::error::Error for NumberError {}
+
+impl ValidatorError for NumberError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "number"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseFloatError(_) => "number.parse_float_error",
+            Self::TooLarge => "number.too_large",
+            Self::TooSmall => "number.too_small",
+            Self::Forbidden => "number.forbidden",
+            Self::NaNMust => "number.nan_must",
+            Self::NaNDisallow => "number.nan_disallow",
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::fmt::{self, Display, Formatter};
 
 use phonenumber::ParseError;
 
+use crate::traits::ValidatorError;
+
 /// Error from the `phone` validator.
 #[derive(Debug)]
 pub enum PhoneError {
@@ -29,3 +31,18 @@ impl Display for PhoneError {
 }
 
 impl core::error::Error for PhoneError {}
+
+impl ValidatorError for PhoneError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "phone"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Failure(_) => "phone.failure",
+            Self::Invalid => "phone.invalid",
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `regex` validator.
 #[derive(Debug, Clone)]
 pub struct RegexError;
@@ -12,3 +14,15 @@ impl Display for RegexError {
 }
 
 impl core::error::Error for RegexError {}
+
+impl ValidatorError for RegexError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "regex"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        "regex.invalid"
+    }
+}
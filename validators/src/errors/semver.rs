@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `semver` and `semver_req` validator.
 #[derive(Debug)]
 pub struct SemverError(pub semver::Error);
@@ -19,3 +21,15 @@ impl Display for SemverError {
 }
 
 impl core::error::Error for SemverError {}
+
+impl ValidatorError for SemverError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "semver"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        "semver.invalid"
+    }
+}
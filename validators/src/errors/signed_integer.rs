@@ -3,6 +3,8 @@ use core::{
     num::ParseIntError,
 };
 
+use crate::traits::ValidatorError;
+
 /// Error from the `signed_integer` validator.
 #[derive(Debug, Clone)]
 pub enum SignedIntegerError {
@@ -32,3 +34,20 @@ impl Display for SignedIntegerError {
 }
 
 impl core::error::Error for SignedIntegerError {}
+
+impl ValidatorError for SignedIntegerError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "signed_integer"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseIntError(_) => "signed_integer.parse_int_error",
+            Self::TooLarge => "signed_integer.too_large",
+            Self::TooSmall => "signed_integer.too_small",
+            Self::Forbidden => "signed_integer.forbidden",
+        }
+    }
+}
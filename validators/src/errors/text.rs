@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `text` validator.
 #[derive(Debug, Clone)]
 pub enum TextError {
@@ -22,3 +24,19 @@ impl Display for TextError {
 }
 
 impl core::error::Error for TextError {}
+
+impl ValidatorError for TextError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "text"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "text.invalid",
+            Self::TooLong => "text.too_long",
+            Self::TooShort => "text.too_short",
+        }
+    }
+}
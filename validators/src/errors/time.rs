@@ -0,0 +1,55 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::traits::ValidatorError;
+
+/// Error from the `time` validator.
+#[derive(Debug, Clone)]
+pub enum TimeError {
+    /// Incorrect time data.
+    Invalid,
+    /// The time is valid, but it is written in the extended format (`hh:mm:ss`).
+    BasicFormatMust,
+    /// The time is valid, but it is written in the basic format (`hhmmss`).
+    BasicFormatDisallow,
+    /// The time is valid, but the timezone is missing.
+    TimezoneMust,
+    /// The time is valid, but the timezone exists.
+    TimezoneDisallow,
+    /// The time is valid, but the fraction of a second exists.
+    FractionDisallow,
+}
+
+impl Display for TimeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Invalid => f.write_str("invalid time"),
+            Self::BasicFormatMust => f.write_str("must be in the basic format"),
+            Self::BasicFormatDisallow => f.write_str("must not be in the basic format"),
+            Self::TimezoneMust => f.write_str("timezone not found"),
+            Self::TimezoneDisallow => f.write_str("timezone not allowed"),
+            Self::FractionDisallow => f.write_str("fraction of a second not allowed"),
+        }
+    }
+}
+
+impl core::error::Error for TimeError {}
+
+impl ValidatorError for TimeError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "time"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "time.invalid",
+            Self::BasicFormatMust => "time.basic_format_must",
+            Self::BasicFormatDisallow => "time.basic_format_disallow",
+            Self::TimezoneMust => "time.timezone_must",
+            Self::TimezoneDisallow => "time.timezone_disallow",
+            Self::FractionDisallow => "time.fraction_disallow",
+        }
+    }
+}
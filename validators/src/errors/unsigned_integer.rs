@@ -3,6 +3,8 @@ use core::{
     num::ParseIntError,
 };
 
+use crate::traits::ValidatorError;
+
 /// Error from the `unsigned_integer` validator.
 #[derive(Debug, Clone)]
 pub enum UnsignedIntegerError {
@@ -32,3 +34,20 @@ impl Display for UnsignedIntegerError {
 }
 
 impl core::error::Error for UnsignedIntegerError {}
+
+impl ValidatorError for UnsignedIntegerError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "unsigned_integer"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ParseIntError(_) => "unsigned_integer.parse_int_error",
+            Self::TooLarge => "unsigned_integer.too_large",
+            Self::TooSmall => "unsigned_integer.too_small",
+            Self::Forbidden => "unsigned_integer.forbidden",
+        }
+    }
+}
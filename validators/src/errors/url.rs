@@ -1,6 +1,6 @@
 use core::fmt::{self, Display, Formatter};
 
-use crate::url;
+use crate::{traits::ValidatorError, url};
 
 /// Error from the `url` validator.
 #[derive(Debug, Clone)]
@@ -21,3 +21,15 @@ impl Display for UrlError {
 }
 
 impl core::error::Error for UrlError {}
+
+impl ValidatorError for UrlError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "url"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        "url.invalid"
+    }
+}
@@ -1,5 +1,7 @@
 use core::fmt::{self, Display, Formatter};
 
+use crate::traits::ValidatorError;
+
 /// Error from the `uuid` validator.
 #[derive(Debug, Clone)]
 pub enum UuidError {
@@ -23,3 +25,19 @@ impl Display for UuidError {
 }
 
 impl core::error::Error for UuidError {}
+
+impl ValidatorError for UuidError {
+    #[inline]
+    fn validator(&self) -> &'static str {
+        "uuid"
+    }
+
+    #[inline]
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Invalid => "uuid.invalid",
+            Self::SeparatorMust => "uuid.separator_must",
+            Self::SeparatorDisallow => "uuid.separator_disallow",
+        }
+    }
+}
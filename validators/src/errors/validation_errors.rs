@@ -0,0 +1,334 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{
+    errors::DetailedError,
+    traits::{ErrorParam, ValidatorError},
+};
+
+/// A failure of one field, recorded in `ValidationErrors`.
+#[derive(Debug)]
+pub struct ValidationError {
+    path:      String,
+    validator: &'static str,
+    error:     Box<dyn ValidatorError + Send + Sync + 'static>,
+    details:   Vec<(&'static str, ErrorParam)>,
+    #[cfg(feature = "localization")]
+    message:   Option<String>,
+}
+
+impl ValidationError {
+    /// The path of the field, such as `user.addresses[2].zip`.
+    #[inline]
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// The name of the validator which rejected the field, such as `email`.
+    #[inline]
+    pub const fn validator(&self) -> &'static str {
+        self.validator
+    }
+
+    /// The error from the validator. Use `downcast_ref` to get the typed error back, such as `EmailError`.
+    #[inline]
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.error.as_ref()
+    }
+
+    /// Get the typed error if it is an `E`.
+    #[inline]
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.error().downcast_ref()
+    }
+
+    /// The stable code of the error, such as `email.invalid`.
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    /// The parameters of the error, followed by its details if it has been recorded by `push_detailed`, such as the configured `max` of `TextError::TooLong`.
+    #[inline]
+    pub fn params(&self) -> Vec<(&'static str, ErrorParam)> {
+        let mut params = self.error.params();
+
+        params.extend(self.details.iter().cloned());
+
+        params
+    }
+
+    /// The message of the error, which is translated if the report has been localized.
+    #[inline]
+    pub fn message(&self) -> String {
+        #[cfg(feature = "localization")]
+        if let Some(message) = self.message.as_ref() {
+            return message.clone();
+        }
+
+        self.error.to_string()
+    }
+}
+
+impl Display for ValidationError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        if !self.path.is_empty() {
+            f.write_str(&self.path)?;
+            f.write_str(": ")?;
+        }
+
+        #[cfg(feature = "localization")]
+        if let Some(message) = self.message.as_ref() {
+            return f.write_str(message);
+        }
+
+        Display::fmt(&self.error, f)
+    }
+}
+
+/// A report which collects every failure across several fields.
+///
+/// ```rust
+/// use validators::errors::{EmailError, LineError, ValidationErrors};
+///
+/// let mut errors = ValidationErrors::new();
+///
+/// errors.push("email", "email", EmailError::Invalid);
+///
+/// let mut address_errors = ValidationErrors::new();
+///
+/// address_errors.push("zip", "line", LineError::Invalid);
+///
+/// errors.extend_nested("addresses[2]", address_errors);
+///
+/// assert_eq!(2, errors.len());
+/// assert_eq!("addresses[2].zip", errors.iter().nth(1).unwrap().path());
+/// assert_eq!(
+///     "email: invalid Email\naddresses[2].zip: invalid line",
+///     errors.to_string()
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// Create an empty report.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            errors: Vec::new()
+        }
+    }
+
+    /// Record a failure of the field at `path`.
+    #[inline]
+    pub fn push<E: ValidatorError + Send + Sync + 'static>(
+        &mut self,
+        path: impl Into<String>,
+        validator: &'static str,
+        error: E,
+    ) {
+        self.errors.push(ValidationError {
+            path: path.into(),
+            validator,
+            error: Box::new(error),
+            details: Vec::new(),
+            #[cfg(feature = "localization")]
+            message: None,
+        });
+    }
+
+    /// Record a failure of the field at `path` with its details. The error is stored without the details, so `downcast_ref` still gets the typed error back, and the details follow its parameters.
+    #[inline]
+    pub fn push_detailed<E: ValidatorError + Send + Sync + 'static>(
+        &mut self,
+        path: impl Into<String>,
+        validator: &'static str,
+        error: DetailedError<E>,
+    ) {
+        let (error, details) = error.into_parts();
+
+        self.errors.push(ValidationError {
+            path: path.into(),
+            validator,
+            error: Box::new(error),
+            details,
+            #[cfg(feature = "localization")]
+            message: None,
+        });
+    }
+
+    /// Move the failures of a nested value into this report, prefixing their paths with `path`.
+    ///
+    /// A child path starting with `[` is appended directly (`tags` + `[0]` → `tags[0]`), others are joined with a dot (`user` + `name` → `user.name`).
+    pub fn extend_nested(&mut self, path: &str, errors: ValidationErrors) {
+        self.errors.reserve(errors.errors.len());
+
+        for mut error in errors.errors {
+            if !path.is_empty() {
+                let mut new_path = String::with_capacity(path.len() + 1 + error.path.len());
+
+                new_path.push_str(path);
+
+                if !error.path.is_empty() && !error.path.starts_with('[') {
+                    new_path.push('.');
+                }
+
+                new_path.push_str(&error.path);
+
+                error.path = new_path;
+            }
+
+            self.errors.push(error);
+        }
+    }
+
+    /// Whether there is no failure.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The number of failures.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Iterate over the failures in the order they were recorded.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, ValidationError> {
+        self.errors.iter()
+    }
+
+    /// Get the failures of the field at `path`.
+    #[inline]
+    pub fn get<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a ValidationError> + 'a {
+        self.errors.iter().filter(move |error| error.path == path)
+    }
+
+    /// Translate the messages of the failures with the bundled translations. See `Localize` for the format of `languages`.
+    #[cfg(feature = "localization")]
+    #[inline]
+    pub fn localize(&mut self, languages: &str) {
+        self.localize_with(&crate::localization::Builtin, languages)
+    }
+
+    /// Translate the messages of the failures with the given catalog. See `Localize` for the format of `languages`.
+    #[cfg(feature = "localization")]
+    pub fn localize_with<C: crate::localization::Catalog + ?Sized>(
+        &mut self,
+        catalog: &C,
+        languages: &str,
+    ) {
+        for error in self.errors.iter_mut() {
+            error.message = crate::localization::translate(
+                catalog,
+                languages,
+                error.error.code(),
+                &error.params(),
+            );
+        }
+    }
+
+    /// `Ok(())` if there is no failure, otherwise `Err(self)`.
+    #[inline]
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl Display for ValidationErrors {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let mut iter = self.errors.iter();
+
+        if let Some(error) = iter.next() {
+            Display::fmt(error, f)?;
+
+            for error in iter {
+                f.write_str("\n")?;
+                Display::fmt(error, f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {}
+
+impl IntoIterator for ValidationErrors {
+    type IntoIter = alloc::vec::IntoIter<ValidationError>;
+    type Item = ValidationError;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type IntoIter = core::slice::Iter<'a, ValidationError>;
+    type Item = &'a ValidationError;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod serde_traits {
+    use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    use super::*;
+
+    /// Serialize parameters as a map from their names to their values.
+    pub(crate) struct Params<'a>(pub(crate) &'a [(&'static str, ErrorParam)]);
+
+    impl Serialize for Params<'_> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(self.0.iter().map(|(name, value)| (name, value)))
+        }
+    }
+
+    impl Serialize for ValidationError {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let params = self.params();
+
+            let mut s = serializer
+                .serialize_struct("ValidationError", 4 + usize::from(!params.is_empty()))?;
+
+            s.serialize_field("path", &self.path)?;
+            s.serialize_field("validator", self.validator)?;
+            s.serialize_field("code", self.error.code())?;
+            s.serialize_field("message", &self.message())?;
+
+            if !params.is_empty() {
+                s.serialize_field("params", &Params(&params))?;
+            }
+
+            s.end()
+        }
+    }
+
+    impl Serialize for ValidationErrors {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.errors.iter())
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::models::{Date, DateTime, Time};
+
+/// The format of a parsed date, time or date-time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DateTimeFormat {
+    /// Whether it is written in the basic format (`YYYYMMDD`, `hhmmss`, `±hhmm`) instead of the extended format (`YYYY-MM-DD`, `hh:mm:ss`, `±hh:mm`).
+    pub basic:    bool,
+    /// Whether the fraction of a second exists.
+    pub fraction: bool,
+}
+
+#[inline]
+fn parse_digits(bytes: &[u8]) -> Option<u32> {
+    let mut n = 0u32;
+
+    for e in bytes.iter().copied() {
+        if !e.is_ascii_digit() {
+            return None;
+        }
+
+        n = n * 10 + (e - b'0') as u32;
+    }
+
+    Some(n)
+}
+
+fn parse_date_bytes(bytes: &[u8]) -> Option<(Date, bool)> {
+    let (year, month, day, basic) = match bytes.len() {
+        10 if bytes[4] == b'-' && bytes[7] == b'-' => {
+            (&bytes[..4], &bytes[5..7], &bytes[8..], false)
+        },
+        8 => (&bytes[..4], &bytes[4..6], &bytes[6..], true),
+        _ => return None,
+    };
+
+    let date = Date {
+        year:  parse_digits(year)? as u16,
+        month: parse_digits(month)? as u8,
+        day:   parse_digits(day)? as u8,
+    };
+
+    if date.is_valid() { Some((date, basic)) } else { None }
+}
+
+fn parse_time_bytes(bytes: &[u8]) -> Option<(Time, DateTimeFormat)> {
+    let (hour, minute, second, basic, mut bytes) =
+        if bytes.len() >= 8 && bytes[2] == b':' && bytes[5] == b':' {
+            (&bytes[..2], &bytes[3..5], &bytes[6..8], false, &bytes[8..])
+        } else if bytes.len() >= 6 {
+            (&bytes[..2], &bytes[2..4], &bytes[4..6], true, &bytes[6..])
+        } else {
+            return None;
+        };
+
+    let mut time = Time {
+        hour:       parse_digits(hour)? as u8,
+        minute:     parse_digits(minute)? as u8,
+        second:     parse_digits(second)? as u8,
+        nanosecond: 0,
+        offset:     None,
+    };
+
+    let mut fraction = false;
+
+    if let [b'.' | b',', rest @ ..] = bytes {
+        let length = rest.iter().take_while(|e| e.is_ascii_digit()).count();
+
+        if length == 0 || length > 9 {
+            return None;
+        }
+
+        time.nanosecond = parse_digits(&rest[..length])? * 10u32.pow(9 - length as u32);
+        fraction = true;
+
+        bytes = &rest[length..];
+    }
+
+    match bytes {
+        [] => (),
+        [b'Z' | b'z'] => time.offset = Some(0),
+        [sign @ (b'+' | b'-'), rest @ ..] => {
+            let (hour, minute) = match rest {
+                [h1, h2, b':', m1, m2] if !basic => ([*h1, *h2], [*m1, *m2]),
+                [h1, h2, m1, m2] if basic => ([*h1, *h2], [*m1, *m2]),
+                _ => return None,
+            };
+
+            let hour = parse_digits(&hour)?;
+            let minute = parse_digits(&minute)?;
+
+            if hour >= 24 || minute >= 60 {
+                return None;
+            }
+
+            let offset = (hour * 60 + minute) as i16;
+
+            time.offset = Some(if *sign == b'-' { -offset } else { offset });
+        },
+        _ => return None,
+    }
+
+    if time.is_valid() {
+        Some((time, DateTimeFormat {
+            basic,
+            fraction,
+        }))
+    } else {
+        None
+    }
+}
+
+/// Parse a date written in the extended format (`YYYY-MM-DD`) or the basic format (`YYYYMMDD`).
+#[inline]
+pub fn parse_date<S: AsRef<str>>(s: S) -> Option<(Date, DateTimeFormat)> {
+    parse_date_bytes(s.as_ref().as_bytes()).map(|(date, basic)| {
+        (date, DateTimeFormat {
+            basic,
+            fraction: false,
+        })
+    })
+}
+
+/// Parse a time written in the extended format (`hh:mm:ss[.fraction][Z|±hh:mm]`) or the basic format (`hhmmss[.fraction][Z|±hhmm]`). The second can be `60` for a leap second.
+#[inline]
+pub fn parse_time<S: AsRef<str>>(s: S) -> Option<(Time, DateTimeFormat)> {
+    parse_time_bytes(s.as_ref().as_bytes())
+}
+
+/// Parse a date-time which is a date and a time joined by `T`, `t` or a space. Both of them must be written in the same format.
+pub fn parse_date_time<S: AsRef<str>>(s: S) -> Option<(DateTime, DateTimeFormat)> {
+    let bytes = s.as_ref().as_bytes();
+
+    let date_length = if bytes.len() > 4 && bytes[4] == b'-' { 10 } else { 8 };
+
+    if bytes.len() <= date_length || !matches!(bytes[date_length], b'T' | b't' | b' ') {
+        return None;
+    }
+
+    let (date, basic) = parse_date_bytes(&bytes[..date_length])?;
+    let (time, format) = parse_time_bytes(&bytes[date_length + 1..])?;
+
+    if basic != format.basic {
+        return None;
+    }
+
+    Some((
+        DateTime {
+            date,
+            time,
+        },
+        format,
+    ))
+}
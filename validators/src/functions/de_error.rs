@@ -0,0 +1,13 @@
+use crate::traits::ValidatorError;
+
+/// Convert the error of a validator into a deserialization error. This is used by the derived `Deserialize` implementations.
+///
+/// With the `axum-problem-json` feature, the error is also recorded while `WithProblem` polls an extractor, so that the `Problem` converted from the rejection can use its code and parameters, which the message of a deserialization error cannot carry.
+#[doc(hidden)]
+#[inline]
+pub fn de_error<D: serde::de::Error, E: ValidatorError>(error: E) -> D {
+    #[cfg(feature = "axum-problem-json")]
+    crate::problem::record(&error);
+
+    D::custom(error)
+}
@@ -6,10 +6,52 @@ pub fn is_local_domain<S: AsRef<str>>(s: S) -> bool {
     s.strip_suffix('.').unwrap_or(s).eq_ignore_ascii_case("localhost")
 }
 
-/// Determine whether the input domain is has at least two labels.
+/// Determine whether the input domain is has at least two labels. A public suffix such as `co.uk` has two labels as well, so use `is_registrable_domain` to find out whether a domain can be registered.
 #[inline]
 pub fn is_at_least_two_labels_domain<S: AsRef<str>>(s: S) -> bool {
     let s = s.as_ref();
 
     s.strip_suffix('.').unwrap_or(s).contains('.')
 }
+
+/// Get the public suffix (eTLD) of the input domain by the installed `PublicSuffixList`, such as `co.uk` of `www.example.co.uk`.
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+#[inline]
+pub fn public_suffix(s: &str) -> Option<&str> {
+    crate::models::PublicSuffixList::global().public_suffix(s)
+}
+
+/// Get the registrable domain (eTLD+1) of the input domain by the installed `PublicSuffixList`, such as `example.co.uk` of `www.example.co.uk`. `None` is returned if the domain is a public suffix itself.
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+#[inline]
+pub fn registrable_domain(s: &str) -> Option<&str> {
+    crate::models::PublicSuffixList::global().registrable_domain(s)
+}
+
+/// Determine whether the input domain is or is under a registrable domain, which means it is not a public suffix.
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+#[inline]
+pub fn is_registrable_domain<S: AsRef<str>>(s: S) -> bool {
+    registrable_domain(s.as_ref()).is_some()
+}
+
+/// Determine whether the last label of the input domain, or the input TLD itself, is a TLD in the installed `TLDList`, which means `example.com` and `com` are known but `example.con` is not.
+#[cfg(any(feature = "domain", feature = "email"))]
+#[inline]
+pub fn is_known_tld<S: AsRef<str>>(s: S) -> bool {
+    crate::models::TLDList::global().contains_tld_of(s.as_ref())
+}
+
+/// Find the index of the first label of the input domain which is invalid by itself, such as an empty label or a label starting with a hyphen. A trailing dot is allowed.
+#[cfg(feature = "domain")]
+#[allow(deprecated)]
+pub fn invalid_domain_label<S: AsRef<str>>(s: S) -> Option<usize> {
+    let s = s.as_ref();
+
+    let config = idna::Config::default()
+        .use_std3_ascii_rules(true)
+        .verify_dns_length(true)
+        .check_hyphens(true);
+
+    s.strip_suffix('.').unwrap_or(s).split('.').position(|label| config.to_ascii(label).is_err())
+}
@@ -0,0 +1,241 @@
+use core::time::Duration;
+
+use crate::errors::DurationError;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+const NANOS_PER_MINUTE: u128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u128 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u128 = 24 * NANOS_PER_HOUR;
+const NANOS_PER_WEEK: u128 = 7 * NANOS_PER_DAY;
+
+/// The maximum number of fraction digits which are taken into account. Later digits are ignored.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// A decimal number which has not been multiplied by its unit yet.
+struct Decimal<'a> {
+    integer:  &'a [u8],
+    fraction: &'a [u8],
+}
+
+impl Decimal<'_> {
+    /// Multiply the number by `unit` nanoseconds. The fraction of a nanosecond is truncated. The result saturates at `u128::MAX`.
+    fn to_nanos(&self, unit: u128) -> u128 {
+        let mut integer = 0u128;
+
+        for e in self.integer.iter().copied() {
+            integer = integer.saturating_mul(10).saturating_add((e - b'0') as u128);
+        }
+
+        let mut nanos = integer.saturating_mul(unit);
+
+        if !self.fraction.is_empty() {
+            let digits = &self.fraction[..self.fraction.len().min(MAX_FRACTION_DIGITS)];
+
+            let mut fraction = 0u128;
+
+            for e in digits.iter().copied() {
+                fraction = fraction * 10 + (e - b'0') as u128;
+            }
+
+            nanos = nanos.saturating_add(fraction * unit / 10u128.pow(digits.len() as u32));
+        }
+
+        nanos
+    }
+}
+
+/// Read a decimal number like `1`, `1.5` or `1,5` at the beginning of `bytes`.
+fn read_decimal(bytes: &[u8]) -> Option<(Decimal<'_>, &[u8])> {
+    let integer_length = bytes.iter().take_while(|e| e.is_ascii_digit()).count();
+
+    if integer_length == 0 {
+        return None;
+    }
+
+    let (integer, rest) = bytes.split_at(integer_length);
+
+    if let [b'.' | b',', rest @ ..] = rest {
+        let fraction_length = rest.iter().take_while(|e| e.is_ascii_digit()).count();
+
+        if fraction_length == 0 {
+            return None;
+        }
+
+        let (fraction, rest) = rest.split_at(fraction_length);
+
+        Some((
+            Decimal {
+                integer,
+                fraction,
+            },
+            rest,
+        ))
+    } else {
+        Some((
+            Decimal {
+                integer,
+                fraction: &[],
+            },
+            rest,
+        ))
+    }
+}
+
+#[inline]
+fn nanos_to_duration(nanos: u128) -> Result<Duration, DurationError> {
+    let seconds = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| DurationError::TooLarge)?;
+
+    Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+}
+
+/// Parse an ISO 8601 duration like `P1DT12H` or `PT1.5S`. Years and months are not supported because their lengths vary.
+fn parse_iso8601_duration(bytes: &[u8], ignore_case: bool) -> Option<u128> {
+    let designator = |e: u8| if ignore_case { e.to_ascii_uppercase() } else { e };
+
+    let mut bytes = match bytes.split_first() {
+        Some((&p, rest)) if designator(p) == b'P' => rest,
+        _ => return None,
+    };
+
+    if bytes.is_empty() {
+        return None;
+    }
+
+    // the designators in the order they must appear, the time designator `T` is handled separately
+    const DATE_UNITS: [(u8, u128); 2] = [(b'W', NANOS_PER_WEEK), (b'D', NANOS_PER_DAY)];
+    const TIME_UNITS: [(u8, u128); 3] =
+        [(b'H', NANOS_PER_HOUR), (b'M', NANOS_PER_MINUTE), (b'S', NANOS_PER_SECOND)];
+
+    let mut nanos = 0u128;
+    let mut in_time = false;
+    let mut next_unit = 0;
+    let mut has_fraction = false;
+
+    loop {
+        if bytes.is_empty() {
+            break;
+        }
+
+        if !in_time && designator(bytes[0]) == b'T' {
+            in_time = true;
+            next_unit = 0;
+            bytes = &bytes[1..];
+
+            // `T` must be followed by at least one component
+            if bytes.is_empty() {
+                return None;
+            }
+
+            continue;
+        }
+
+        // only the last component can have a fraction
+        if has_fraction {
+            return None;
+        }
+
+        let (decimal, rest) = read_decimal(bytes)?;
+        let (&unit, rest) = rest.split_first()?;
+        let unit = designator(unit);
+
+        let units: &[(u8, u128)] = if in_time { &TIME_UNITS } else { &DATE_UNITS };
+
+        let index = units[next_unit..].iter().position(|(e, _)| *e == unit)? + next_unit;
+
+        nanos = nanos.saturating_add(decimal.to_nanos(units[index].1));
+
+        next_unit = index + 1;
+        has_fraction = !decimal.fraction.is_empty();
+        bytes = rest;
+    }
+
+    Some(nanos)
+}
+
+/// Get the nanoseconds of a human unit like `h`, `min` or `days`.
+fn human_unit(unit: &[u8], ignore_case: bool) -> Option<u128> {
+    let mut buffer = [0u8; 12];
+
+    if unit.len() > buffer.len() {
+        return None;
+    }
+
+    let unit = if ignore_case {
+        let buffer = &mut buffer[..unit.len()];
+
+        buffer.copy_from_slice(unit);
+        buffer.make_ascii_lowercase();
+
+        &*buffer
+    } else {
+        unit
+    };
+
+    let nanos = match unit {
+        b"ns" | b"nanosecond" | b"nanoseconds" => 1,
+        // `b"\xC2\xB5s"` is "µs"
+        b"us" | b"\xC2\xB5s" | b"microsecond" | b"microseconds" => 1_000,
+        b"ms" | b"millisecond" | b"milliseconds" => 1_000_000,
+        b"s" | b"sec" | b"secs" | b"second" | b"seconds" => NANOS_PER_SECOND,
+        b"m" | b"min" | b"mins" | b"minute" | b"minutes" => NANOS_PER_MINUTE,
+        b"h" | b"hr" | b"hrs" | b"hour" | b"hours" => NANOS_PER_HOUR,
+        b"d" | b"day" | b"days" => NANOS_PER_DAY,
+        b"w" | b"week" | b"weeks" => NANOS_PER_WEEK,
+        _ => return None,
+    };
+
+    Some(nanos)
+}
+
+/// Parse a human duration like `1h30m`, `90s`, `1.5 days` or `1h 30min`. A single number without a unit is in seconds.
+fn parse_human_duration(bytes: &[u8], ignore_case: bool) -> Option<u128> {
+    let mut nanos = 0u128;
+    let mut bytes = bytes;
+    let mut is_first = true;
+
+    loop {
+        let (decimal, rest) = read_decimal(bytes)?;
+
+        let rest = rest.trim_ascii_start();
+
+        let unit_length = rest.iter().take_while(|e| !e.is_ascii_digit() && **e != b' ').count();
+
+        if unit_length == 0 {
+            // a bare number is only allowed when it is the whole input
+            if is_first && rest.is_empty() {
+                return Some(decimal.to_nanos(NANOS_PER_SECOND));
+            }
+
+            return None;
+        }
+
+        let (unit, rest) = rest.split_at(unit_length);
+
+        nanos = nanos.saturating_add(decimal.to_nanos(human_unit(unit, ignore_case)?));
+
+        bytes = rest.trim_ascii_start();
+        is_first = false;
+
+        if bytes.is_empty() {
+            break;
+        }
+    }
+
+    Some(nanos)
+}
+
+/// Parse a duration written in the ISO 8601 format (`P1DT1H30M`, `PT0.5S`) or in a human format (`1h30m`, `90s`, `1.5 days`). Years and months are not supported.
+///
+/// If `ignore_case` is `false`, the designators of the ISO 8601 format must be upper-case, and the human units must be lower-case.
+pub fn parse_duration<S: AsRef<str>>(s: S, ignore_case: bool) -> Result<Duration, DurationError> {
+    let bytes = s.as_ref().as_bytes();
+
+    let nanos = match bytes.first() {
+        Some(b'0'..=b'9') => parse_human_duration(bytes, ignore_case),
+        Some(_) => parse_iso8601_duration(bytes, ignore_case),
+        None => None,
+    }
+    .ok_or(DurationError::Invalid)?;
+
+    nanos_to_duration(nanos)
+}
@@ -0,0 +1,364 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::traits::CanonicalEmailOptions;
+
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[inline]
+fn is_atext(c: char, non_ascii: bool) -> bool {
+    match c {
+        'A'..='Z'
+        | 'a'..='z'
+        | '0'..='9'
+        | '!'
+        | '#'..='\''
+        | '*'
+        | '+'
+        | '-'
+        | '/'
+        | '='
+        | '?'
+        | '^'..='`'
+        | '{'..='~' => true,
+        _ => non_ascii && !c.is_ascii() && !c.is_control(),
+    }
+}
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim_end_matches('=');
+
+    let mut bytes = Vec::with_capacity(s.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for e in s.bytes() {
+        buffer = buffer << 6 | BASE64_TABLE.iter().position(|&b| b == e)? as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+fn decode_q(s: &str) -> Option<Vec<u8>> {
+    let bytes = s.as_bytes();
+
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut p = 0;
+
+    while p < bytes.len() {
+        match bytes[p] {
+            b'_' => decoded.push(b' '),
+            b'=' => {
+                let hex = s.get((p + 1)..(p + 3))?;
+
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+
+                p += 2;
+            },
+            e => decoded.push(e),
+        }
+
+        p += 1;
+    }
+
+    Some(decoded)
+}
+
+/// Decode an encoded word of RFC 2047, in the UTF-8, US-ASCII or ISO-8859-1 charset. Words in other charsets, or which decode to control characters, are not decoded.
+fn decode_encoded_word(s: &str) -> Option<String> {
+    let s = s.strip_prefix("=?")?.strip_suffix("?=")?;
+
+    let mut parts = s.splitn(3, '?');
+
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let text = parts.next()?;
+
+    if text.contains('?') {
+        return None;
+    }
+
+    // RFC 2231 appends the language to the charset
+    let charset = charset.split('*').next().unwrap();
+
+    let bytes = if encoding.eq_ignore_ascii_case("B") {
+        decode_base64(text)?
+    } else if encoding.eq_ignore_ascii_case("Q") {
+        decode_q(text)?
+    } else {
+        return None;
+    };
+
+    let decoded = if charset.eq_ignore_ascii_case("utf-8")
+        || charset.eq_ignore_ascii_case("us-ascii")
+    {
+        String::from_utf8(bytes).ok()?
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") || charset.eq_ignore_ascii_case("latin1") {
+        bytes.into_iter().map(char::from).collect()
+    } else {
+        return None;
+    };
+
+    if decoded.chars().any(|c| c.is_control()) {
+        return None;
+    }
+
+    Some(decoded)
+}
+
+/// Split a `name-addr` of RFC 5322, such as `"Jane Doe" <jane@example.com>`, into its display name and the addr-spec between the angle brackets. The display name consists of atoms, quoted strings and encoded words of RFC 2047, and the words are joined by single spaces, except for adjacent encoded words which are joined directly. `None` is returned if the input is not a `name-addr`, and the display name is `None` if it is empty.
+///
+/// If `comment` is `true`, comments of RFC 5322, such as `(Doe)` in `Jane (Doe) <jane@example.com>`, are allowed between the words and before the angle brackets. They separate words like whitespace and are not part of the display name. If `non_ascii` is `false`, non-ASCII characters are only allowed in encoded words.
+pub fn parse_name_addr(s: &str, non_ascii: bool, comment: bool) -> Option<(Option<String>, &str)> {
+    let addr_spec_end = s.len().checked_sub(1).filter(|&i| s.as_bytes()[i] == b'>')?;
+
+    let mut display_name = String::new();
+    let mut last_is_encoded_word = false;
+
+    let mut chars = s.char_indices().peekable();
+
+    let angle_addr_start = loop {
+        let (i, c) = chars.next()?;
+
+        let (word, is_encoded_word) = match c {
+            ' ' | '\t' => continue,
+            '(' if comment => {
+                // comments can be nested, and the quoted pairs in them are skipped
+                let mut depth = 1usize;
+
+                while depth > 0 {
+                    match chars.next()?.1 {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        '\\' => {
+                            let c = chars.next()?.1;
+
+                            if c.is_control() && c != '\t' {
+                                return None;
+                            }
+                        },
+                        c if c.is_control() && c != '\t' => return None,
+                        c if !c.is_ascii() && !non_ascii => return None,
+                        _ => (),
+                    }
+                }
+
+                continue;
+            },
+            '<' => break i,
+            '"' => {
+                let mut word = String::new();
+
+                loop {
+                    match chars.next()?.1 {
+                        '"' => break,
+                        '\\' => match chars.next()?.1 {
+                            c @ (' ' | '\t') => word.push(c),
+                            c if is_atext(c, non_ascii) || c.is_ascii_punctuation() => word.push(c),
+                            _ => return None,
+                        },
+                        c @ (' ' | '\t') => word.push(c),
+                        c if is_atext(c, non_ascii) || c.is_ascii_punctuation() => word.push(c),
+                        _ => return None,
+                    }
+                }
+
+                (word, false)
+            },
+            c if is_atext(c, non_ascii) || c == '.' => {
+                let mut end = i + c.len_utf8();
+
+                while let Some(&(j, c)) = chars.peek() {
+                    if !is_atext(c, non_ascii) && c != '.' {
+                        break;
+                    }
+
+                    end = j + c.len_utf8();
+
+                    chars.next();
+                }
+
+                let atom = &s[i..end];
+
+                match decode_encoded_word(atom) {
+                    Some(decoded) => (decoded, true),
+                    None => (String::from(atom), false),
+                }
+            },
+            _ => return None,
+        };
+
+        // adjacent encoded words are joined without the whitespace between them
+        if !display_name.is_empty() && (!last_is_encoded_word || !is_encoded_word) {
+            display_name.push(' ');
+        }
+
+        display_name.push_str(&word);
+
+        last_is_encoded_word = is_encoded_word;
+    };
+
+    let addr_spec = &s[(angle_addr_start + 1)..addr_spec_end];
+
+    if addr_spec.is_empty() {
+        return None;
+    }
+
+    let display_name = if display_name.is_empty() { None } else { Some(display_name) };
+
+    Some((display_name, addr_spec))
+}
+
+fn encode_base64(bytes: &[u8], s: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let buffer =
+            chunk.iter().enumerate().fold(0u32, |a, (i, &e)| a | (e as u32) << (16 - i * 8));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(BASE64_TABLE[(buffer >> (18 - i * 6)) as usize & 0x3F] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+}
+
+/// Append a display name to a string as a phrase of RFC 5322. It is written as it is if it consists of atoms separated by single spaces, and as a quoted string otherwise. A display name with non-ASCII characters is written as encoded words of RFC 2047 in UTF-8, so it can be parsed regardless of the `non_ascii` option. So is a display name with control characters other than tabs, such as CR and LF, which cannot be in a quoted string and would otherwise start a new header field.
+pub fn push_display_name(s: &mut String, display_name: &str) {
+    if !display_name.is_ascii() || display_name.chars().any(|c| c.is_control() && c != '\t') {
+        let mut start = 0;
+
+        // each encoded word has at most 75 characters, so 45 bytes are encoded into 60 characters
+        while start < display_name.len() {
+            let mut end = (start + 45).min(display_name.len());
+
+            while !display_name.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            if start > 0 {
+                s.push(' ');
+            }
+
+            s.push_str("=?UTF-8?B?");
+            encode_base64(&display_name.as_bytes()[start..end], s);
+            s.push_str("?=");
+
+            start = end;
+        }
+
+        return;
+    }
+
+    let is_atoms = display_name.split(' ').all(|atom| {
+        !atom.is_empty() && !atom.starts_with("=?") && atom.chars().all(|c| is_atext(c, false))
+    });
+
+    if is_atoms {
+        s.push_str(display_name);
+    } else {
+        s.push('"');
+
+        for c in display_name.chars() {
+            if c == '"' || c == '\\' {
+                s.push('\\');
+            }
+
+            s.push(c);
+        }
+
+        s.push('"');
+    }
+}
+
+/// Build the canonical form of an email from its local part and its domain part, which is an address literal such as `[127.0.0.1]` or a domain. If `need_quoted` is `true`, the local part is the content of a quoted string and its escapes are removed. The local part is quoted again if it is not a dot-atom.
+pub fn canonical_email(
+    local_part: &str,
+    need_quoted: bool,
+    domain_part: &str,
+    options: &CanonicalEmailOptions,
+) -> String {
+    let domain_part = if domain_part.starts_with('[') {
+        String::from(domain_part)
+    } else {
+        let domain_part = domain_part.strip_suffix('.').unwrap_or(domain_part);
+
+        idna::domain_to_ascii(domain_part).unwrap_or_else(|_| domain_part.to_lowercase())
+    };
+
+    let mut local_part = if need_quoted {
+        let mut unescaped = String::with_capacity(local_part.len());
+        let mut chars = local_part.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => unescaped.extend(chars.next()),
+                _ => unescaped.push(c),
+            }
+        }
+
+        unescaped
+    } else {
+        String::from(local_part)
+    };
+
+    if options.lowercase_local_part {
+        local_part = local_part.to_lowercase();
+    }
+
+    // a tag at the beginning is kept, or nothing would be left
+    if let Some(separator) = options.sub_address_separator
+        && let Some(index) = local_part.find(separator)
+        && index > 0
+    {
+        local_part.truncate(index);
+    }
+
+    if options.dot_insensitive_domains.iter().any(|domain| {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+        domain_part.eq_ignore_ascii_case(domain)
+            || idna::domain_to_ascii(domain).is_ok_and(|domain| domain_part == domain)
+    }) {
+        let without_dots = local_part.replace('.', "");
+
+        // a local part of only dots is kept
+        if !without_dots.is_empty() {
+            local_part = without_dots;
+        }
+    }
+
+    let is_dot_atom = !local_part.is_empty()
+        && local_part
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(|c| is_atext(c, true)));
+
+    let mut s = String::with_capacity(local_part.len() + domain_part.len() + 3);
+
+    if is_dot_atom {
+        s.push_str(&local_part);
+    } else {
+        s.push('"');
+
+        for c in local_part.chars() {
+            if c == '"' || c == '\\' {
+                s.push('\\');
+            }
+
+            s.push(c);
+        }
+
+        s.push('"');
+    }
+
+    s.push('@');
+    s.push_str(&domain_part);
+
+    s
+}
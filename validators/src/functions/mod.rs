@@ -6,7 +6,8 @@
     feature = "http_ftp_url",
     feature = "ip",
     feature = "ipv4",
-    feature = "ipv6"
+    feature = "ipv6",
+    all(feature = "url", feature = "arbitrary")
 ))]
 mod ipv4;
 #[cfg(any(
@@ -17,7 +18,8 @@ mod ipv4;
     feature = "http_ftp_url",
     feature = "ip",
     feature = "ipv4",
-    feature = "ipv6"
+    feature = "ipv6",
+    all(feature = "url", feature = "arbitrary")
 ))]
 pub use ipv4::*;
 
@@ -61,3 +63,23 @@ mod domain;
     feature = "http_ftp_url",
 ))]
 pub use domain::*;
+
+#[cfg(feature = "email")]
+mod email;
+#[cfg(feature = "email")]
+pub use email::*;
+
+#[cfg(any(feature = "date", feature = "time", feature = "datetime"))]
+mod date_time;
+#[cfg(any(feature = "date", feature = "time", feature = "datetime"))]
+pub use date_time::*;
+
+#[cfg(feature = "duration")]
+mod duration;
+#[cfg(feature = "duration")]
+pub use duration::*;
+
+#[cfg(feature = "serde")]
+mod de_error;
+#[cfg(feature = "serde")]
+pub use de_error::*;
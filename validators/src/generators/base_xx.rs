@@ -0,0 +1,73 @@
+use alloc::{string::String, vec::Vec};
+
+use arbitrary::{Result, Unstructured};
+
+use super::tri_allow;
+use crate::runtime::TriAllow;
+
+/// Encode random data with `alphabet`, whose characters each hold `bits` bits. The padding fills the last block of `block` characters.
+fn encode(
+    u: &mut Unstructured<'_>,
+    alphabet: &[u8],
+    bits: u32,
+    block: usize,
+    padding: TriAllow,
+) -> Result<String> {
+    let mut data: Vec<u8> = u.arbitrary()?;
+
+    // an empty string is not accepted
+    if data.is_empty() {
+        data.push(u.arbitrary()?);
+    }
+
+    let padding = tri_allow(u, padding)?;
+
+    let mut s = String::with_capacity(data.len() * 8 / bits as usize + block);
+
+    let mut buffer = 0u32;
+    let mut buffer_bits = 0;
+
+    for e in data {
+        buffer = (buffer << 8) | e as u32;
+        buffer_bits += 8;
+
+        while buffer_bits >= bits {
+            buffer_bits -= bits;
+
+            s.push(alphabet[((buffer >> buffer_bits) & ((1 << bits) - 1)) as usize] as char);
+        }
+    }
+
+    if buffer_bits > 0 {
+        s.push(alphabet[((buffer << (bits - buffer_bits)) & ((1 << bits) - 1)) as usize] as char);
+    }
+
+    if padding {
+        while !s.len().is_multiple_of(block) {
+            s.push('=');
+        }
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `base32` and `base32_decoded` validators.
+#[cfg(any(feature = "base32", feature = "base32_decoded"))]
+#[inline]
+pub fn base32(u: &mut Unstructured<'_>, padding: TriAllow) -> Result<String> {
+    encode(u, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", 5, 8, padding)
+}
+
+/// Generate an input of the `base64` and `base64_decoded` validators.
+#[cfg(any(feature = "base64", feature = "base64_decoded"))]
+#[inline]
+pub fn base64(u: &mut Unstructured<'_>, padding: TriAllow) -> Result<String> {
+    encode(u, b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/", 6, 4, padding)
+}
+
+/// Generate an input of the `base64_url` and `base64_url_decoded` validators.
+#[cfg(any(feature = "base64_url", feature = "base64_url_decoded"))]
+#[inline]
+pub fn base64_url(u: &mut Unstructured<'_>, padding: TriAllow) -> Result<String> {
+    encode(u, b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_", 6, 4, padding)
+}
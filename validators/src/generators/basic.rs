@@ -0,0 +1,78 @@
+#[cfg(feature = "boolean")]
+use alloc::string::String;
+
+#[cfg(feature = "length")]
+use arbitrary::Arbitrary;
+use arbitrary::{Result, Unstructured};
+
+/// The words accepted by the `boolean` validator, in any letter case.
+#[cfg(feature = "boolean")]
+const BOOLEAN_WORDS: [&str; 12] =
+    ["true", "false", "t", "f", "yes", "no", "y", "n", "on", "off", "1", "0"];
+
+/// Generate an input of the `boolean` validator, with random letter cases.
+#[cfg(feature = "boolean")]
+pub fn boolean(u: &mut Unstructured<'_>) -> Result<String> {
+    let word = *u.choose(&BOOLEAN_WORDS)?;
+
+    let mut s = String::with_capacity(word.len());
+
+    for c in word.chars() {
+        s.push(if u.arbitrary()? { c.to_ascii_uppercase() } else { c });
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `json` validator. Arrays and objects are nested up to `depth` levels.
+#[cfg(feature = "json")]
+pub fn json(u: &mut Unstructured<'_>, depth: usize) -> Result<serde_json::Value> {
+    use serde_json::{Map, Number, Value};
+
+    let kinds = if depth == 0 { 4 } else { 6 };
+
+    Ok(match u.choose_index(kinds)? {
+        0 => Value::Null,
+        1 => Value::Bool(u.arbitrary()?),
+        2 => match u.int_in_range(0..=2u8)? {
+            0 => Value::Number(Number::from(u.arbitrary::<u64>()?)),
+            1 => Value::Number(Number::from(u.arbitrary::<i64>()?)),
+            _ => Number::from_f64(u.arbitrary()?).map(Value::Number).unwrap_or(Value::Null),
+        },
+        3 => Value::String(u.arbitrary()?),
+        4 => Value::Array(
+            (0..u.int_in_range(0..=4u8)?).map(|_| json(u, depth - 1)).collect::<Result<_>>()?,
+        ),
+        _ => {
+            let mut map = Map::new();
+
+            for _ in 0..u.int_in_range(0..=4u8)? {
+                map.insert(u.arbitrary()?, json(u, depth - 1)?);
+            }
+
+            Value::Object(map)
+        },
+    })
+}
+
+/// Generate an input of the `length` validator, which is a collection of arbitrary items. Sets and maps may end up shorter than `min` because of duplicated items.
+#[cfg(feature = "length")]
+pub fn collection<'a, T>(
+    u: &mut Unstructured<'a>,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<T>
+where
+    T: IntoIterator + FromIterator<<T as IntoIterator>::Item>,
+    <T as IntoIterator>::Item: Arbitrary<'a>, {
+    let min = min.unwrap_or(0);
+    let max = max.unwrap_or(usize::MAX).min(min.saturating_add(16));
+
+    if min > max {
+        return Err(arbitrary::Error::IncorrectFormat);
+    }
+
+    let length = u.int_in_range(min..=max)?;
+
+    (0..length).map(|_| u.arbitrary()).collect()
+}
@@ -0,0 +1,177 @@
+use alloc::{format, string::String};
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+use arbitrary::Error;
+use arbitrary::{Result, Unstructured};
+
+use super::tri_allow;
+#[cfg(feature = "datetime")]
+use crate::models::DateTime;
+use crate::{
+    models::{Date, Time},
+    runtime::TriAllow,
+};
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+const MIN_DAYS: i64 = Date {
+    year: 0, month: 1, day: 1
+}
+.days_since_unix_epoch();
+
+#[cfg(any(feature = "date", feature = "datetime"))]
+const MAX_DAYS: i64 = Date {
+    year: 9999, month: 12, day: 31
+}
+.days_since_unix_epoch();
+
+/// Convert the number of days since `1970-01-01` to a date.
+#[allow(dead_code)]
+fn date_from_days(days: i64) -> Date {
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    Date {
+        year: year as u16, month: month as u8, day: day as u8
+    }
+}
+
+#[allow(dead_code)]
+fn format_date(date: &Date, basic: bool) -> String {
+    if basic {
+        format!("{:04}{:02}{:02}", date.year, date.month, date.day)
+    } else {
+        format!("{date}")
+    }
+}
+
+#[allow(dead_code)]
+fn format_time(time: &Time, basic: bool) -> String {
+    if basic {
+        // the basic format is the extended format without colons, including the ones of the offset
+        format!("{time}").replace(':', "")
+    } else {
+        format!("{time}")
+    }
+}
+
+#[allow(dead_code)]
+fn offset(u: &mut Unstructured<'_>, timezone: TriAllow) -> Result<Option<i16>> {
+    if tri_allow(u, timezone)? { Ok(Some(u.int_in_range(-1439..=1439)?)) } else { Ok(None) }
+}
+
+/// Generate an input of the `date` validator between `min` and `max`, which are inclusive.
+#[cfg(feature = "date")]
+pub fn date(
+    u: &mut Unstructured<'_>,
+    basic_format: TriAllow,
+    min: Option<Date>,
+    max: Option<Date>,
+) -> Result<String> {
+    let min = min.map_or(MIN_DAYS, |min| min.days_since_unix_epoch()).max(MIN_DAYS);
+    let max = max.map_or(MAX_DAYS, |max| max.days_since_unix_epoch()).min(MAX_DAYS);
+
+    if min > max {
+        return Err(Error::IncorrectFormat);
+    }
+
+    let date = date_from_days(u.int_in_range(min..=max)?);
+
+    Ok(format_date(&date, tri_allow(u, basic_format)?))
+}
+
+/// Generate an input of the `time` validator.
+#[cfg(feature = "time")]
+pub fn time(
+    u: &mut Unstructured<'_>,
+    basic_format: TriAllow,
+    timezone: TriAllow,
+    fraction: TriAllow,
+) -> Result<String> {
+    let time = Time {
+        hour:       u.int_in_range(0..=23)?,
+        minute:     u.int_in_range(0..=59)?,
+        second:     u.int_in_range(0..=59)?,
+        nanosecond: if tri_allow(u, fraction)? { u.int_in_range(0..=999_999_999)? } else { 0 },
+        offset:     offset(u, timezone)?,
+    };
+
+    Ok(format_time(&time, tri_allow(u, basic_format)?))
+}
+
+/// Generate an input of the `datetime` validator between the instants `min` and `max`, which are inclusive.
+#[cfg(feature = "datetime")]
+pub fn date_time(
+    u: &mut Unstructured<'_>,
+    basic_format: TriAllow,
+    timezone: TriAllow,
+    fraction: TriAllow,
+    min: Option<DateTime>,
+    max: Option<DateTime>,
+) -> Result<String> {
+    let offset = offset(u, timezone)?;
+    let fraction = tri_allow(u, fraction)?;
+
+    // keep a day of margin, so that the local date never exceeds the years from 0 to 9999
+    let (mut min_second, mut min_nanosecond) = match min {
+        Some(min) => (min.unix_timestamp(), min.time.nanosecond),
+        None => ((MIN_DAYS + 1) * 86400, 0),
+    };
+    let (max_second, max_nanosecond) = match max {
+        Some(max) => (max.unix_timestamp(), max.time.nanosecond),
+        None => (MAX_DAYS * 86400 - 1, 999_999_999),
+    };
+
+    if !fraction && min_nanosecond > 0 {
+        min_second += 1;
+        min_nanosecond = 0;
+    }
+
+    let min_second = min_second.max((MIN_DAYS + 1) * 86400);
+    let max_second = max_second.min(MAX_DAYS * 86400 - 1);
+
+    if min_second > max_second {
+        return Err(Error::IncorrectFormat);
+    }
+
+    let second = u.int_in_range(min_second..=max_second)?;
+
+    let nanosecond = if fraction {
+        let min = if second == min_second { min_nanosecond } else { 0 };
+        let max = if second == max_second { max_nanosecond } else { 999_999_999 };
+
+        if min > max {
+            return Err(Error::IncorrectFormat);
+        }
+
+        u.int_in_range(min..=max)?
+    } else {
+        0
+    };
+
+    let local_second = second + offset.unwrap_or(0) as i64 * 60;
+    let second_of_day = local_second.rem_euclid(86400) as u32;
+
+    let date_time = DateTime {
+        date: date_from_days(local_second.div_euclid(86400)),
+        time: Time {
+            hour: (second_of_day / 3600) as u8,
+            minute: (second_of_day / 60 % 60) as u8,
+            second: (second_of_day % 60) as u8,
+            nanosecond,
+            offset,
+        },
+    };
+
+    let basic = tri_allow(u, basic_format)?;
+
+    Ok(format!("{}T{}", format_date(&date_time.date, basic), format_time(&date_time.time, basic)))
+}
@@ -0,0 +1,73 @@
+use alloc::string::String;
+
+use arbitrary::{Result, Unstructured};
+
+use crate::runtime::{CaseOption, SeparatorOption};
+
+/// Write random hexadecimal digits in groups of the given lengths.
+fn hex_groups(
+    u: &mut Unstructured<'_>,
+    groups: &[usize],
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> Result<String> {
+    let separator = match separator {
+        SeparatorOption::Must(c) => Some(c),
+        SeparatorOption::Allow(c) => {
+            if u.arbitrary()? {
+                Some(c)
+            } else {
+                None
+            }
+        },
+        SeparatorOption::Disallow => None,
+    };
+
+    let mut s = String::with_capacity(groups.iter().sum::<usize>() + groups.len());
+
+    for (i, length) in groups.iter().copied().enumerate() {
+        if i > 0
+            && let Some(separator) = separator
+        {
+            s.push(separator as char);
+        }
+
+        for _ in 0..length {
+            let digit = u.int_in_range(0..=15u8)?;
+
+            let upper = match case {
+                CaseOption::Any => u.arbitrary()?,
+                CaseOption::Upper => true,
+                CaseOption::Lower => false,
+            };
+
+            let c = char::from_digit(digit as u32, 16).unwrap();
+
+            s.push(if upper { c.to_ascii_uppercase() } else { c });
+        }
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `mac_address` validator.
+#[cfg(feature = "mac_address")]
+#[inline]
+pub fn mac_address(
+    u: &mut Unstructured<'_>,
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> Result<String> {
+    hex_groups(u, &[2; 6], case, separator)
+}
+
+/// Generate an input of the `uuid` validator.
+#[cfg(feature = "uuid")]
+#[inline]
+pub fn uuid(
+    u: &mut Unstructured<'_>,
+    case: CaseOption,
+    separator: SeparatorOption,
+) -> Result<String> {
+    hex_groups(u, &[8, 4, 4, 4, 12], case, separator)
+}
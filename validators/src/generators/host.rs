@@ -0,0 +1,330 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use arbitrary::{Result, Unstructured};
+
+use super::tri_allow;
+use crate::runtime::TriAllow;
+
+/// Generate a domain which is `localhost` if `local` is `true`, or consists of random labels otherwise.
+#[allow(dead_code)]
+fn domain_name(
+    u: &mut Unstructured<'_>,
+    local: bool,
+    at_least_two_labels: TriAllow,
+) -> Result<String> {
+    if local {
+        return Ok(String::from("localhost"));
+    }
+
+    let labels = match at_least_two_labels {
+        TriAllow::Must => u.int_in_range(2..=4u8)?,
+        TriAllow::Allow => u.int_in_range(1..=4u8)?,
+        TriAllow::Disallow => 1,
+    };
+
+    let mut s = String::new();
+
+    for i in 0..labels {
+        if i > 0 {
+            s.push('.');
+        }
+
+        // starting with a letter, so that the domain never looks like an IPv4 address
+        s.push(u.int_in_range(b'a'..=b'z')? as char);
+
+        for _ in 0..u.int_in_range(0..=10u8)? {
+            s.push(*u.choose(b"abcdefghijklmnopqrstuvwxyz0123456789-")? as char);
+        }
+
+        if s.ends_with('-') {
+            s.pop();
+            s.push('0');
+        }
+    }
+
+    if s == "localhost" {
+        s.push('0');
+    }
+
+    Ok(s)
+}
+
+#[allow(dead_code)]
+fn ipv4_addr(u: &mut Unstructured<'_>, local: bool) -> Result<Ipv4Addr> {
+    let [a, b, c, d] = u.arbitrary::<[u8; 4]>()?;
+
+    Ok(if local {
+        match u.int_in_range(0..=4u8)? {
+            0 => Ipv4Addr::new(127, b, c, d),
+            1 => Ipv4Addr::new(10, b, c, d),
+            2 => Ipv4Addr::new(172, 16 | (b & 0x0F), c, d),
+            3 => Ipv4Addr::new(192, 168, c, d),
+            _ => Ipv4Addr::new(169, 254, c, d),
+        }
+    } else {
+        let addr = Ipv4Addr::new(a, b, c, d);
+
+        if crate::functions::is_local_ipv4(addr) {
+            // none of the local addresses starts with these numbers
+            Ipv4Addr::new(u.int_in_range(11..=126)?, b, c, d)
+        } else {
+            addr
+        }
+    })
+}
+
+#[allow(dead_code)]
+fn ipv6_addr(u: &mut Unstructured<'_>, local: bool) -> Result<Ipv6Addr> {
+    let mut segments = u.arbitrary::<[u16; 8]>()?;
+
+    if local {
+        match u.int_in_range(0..=3u8)? {
+            0 => return Ok(Ipv6Addr::LOCALHOST),
+            1 => segments[..4].copy_from_slice(&[0xFE80, 0, 0, 0]),
+            2 => segments[0] = 0xFC00 | (segments[0] & 0x01FF),
+            _ => segments[..2].copy_from_slice(&[0x2001, 0xDB8]),
+        }
+    } else {
+        // global unicast addresses, excluding `2001::/16` which contains the documentation addresses
+        segments[0] = u.int_in_range(0x2002..=0x3FFF)?;
+    }
+
+    Ok(Ipv6Addr::from(segments))
+}
+
+#[allow(dead_code)]
+#[inline]
+fn port(u: &mut Unstructured<'_>, port: TriAllow) -> Result<Option<u16>> {
+    if tri_allow(u, port)? { Ok(Some(u.arbitrary()?)) } else { Ok(None) }
+}
+
+#[allow(dead_code)]
+#[inline]
+fn append_port(mut s: String, port: Option<u16>) -> String {
+    if let Some(port) = port {
+        s.push(':');
+        s.push_str(port.to_string().as_str());
+    }
+
+    s
+}
+
+/// Generate a domain, an IPv4 address, or a bracketed IPv6 address.
+#[allow(dead_code)]
+fn host_name(
+    u: &mut Unstructured<'_>,
+    local: TriAllow,
+    at_least_two_labels: TriAllow,
+) -> Result<String> {
+    let local = tri_allow(u, local)?;
+
+    // IP addresses are not allowed when at least two labels are disallowed
+    let kind = if at_least_two_labels.disallow() { 0 } else { u.int_in_range(0..=2u8)? };
+
+    Ok(match kind {
+        0 => domain_name(u, local, at_least_two_labels)?,
+        1 => ipv4_addr(u, local)?.to_string(),
+        _ => format!("[{}]", ipv6_addr(u, local)?),
+    })
+}
+
+/// Generate an input of the `domain` validator.
+#[cfg(feature = "domain")]
+pub fn domain(
+    u: &mut Unstructured<'_>,
+    ipv4: TriAllow,
+    local: TriAllow,
+    at_least_two_labels: TriAllow,
+    port: TriAllow,
+) -> Result<String> {
+    // IPv4 addresses are not allowed when at least two labels are disallowed
+    let is_ipv4 = !at_least_two_labels.disallow() && tri_allow(u, ipv4)?;
+    let local = tri_allow(u, local)?;
+
+    let s = if is_ipv4 {
+        ipv4_addr(u, local)?.to_string()
+    } else {
+        domain_name(u, local, at_least_two_labels)?
+    };
+
+    Ok(append_port(s, self::port(u, port)?))
+}
+
+/// Generate an input of the `host` validator.
+#[cfg(feature = "host")]
+#[inline]
+pub fn host(
+    u: &mut Unstructured<'_>,
+    local: TriAllow,
+    at_least_two_labels: TriAllow,
+    port: TriAllow,
+) -> Result<String> {
+    let s = host_name(u, local, at_least_two_labels)?;
+
+    Ok(append_port(s, self::port(u, port)?))
+}
+
+/// Generate an input of the `ipv4` validator.
+#[cfg(any(feature = "ip", feature = "ipv4"))]
+#[inline]
+pub fn ipv4(u: &mut Unstructured<'_>, local: TriAllow, port: TriAllow) -> Result<String> {
+    let local = tri_allow(u, local)?;
+    let s = ipv4_addr(u, local)?.to_string();
+
+    Ok(append_port(s, self::port(u, port)?))
+}
+
+/// Generate an input of the `ipv6` validator. The address is bracketed if it comes with a port.
+#[cfg(any(feature = "ip", feature = "ipv6"))]
+#[inline]
+pub fn ipv6(u: &mut Unstructured<'_>, local: TriAllow, port: TriAllow) -> Result<String> {
+    let local = tri_allow(u, local)?;
+    let ip = ipv6_addr(u, local)?;
+
+    Ok(match self::port(u, port)? {
+        Some(port) => format!("[{ip}]:{port}"),
+        None => ip.to_string(),
+    })
+}
+
+/// Generate an input of the `ip` validator.
+#[cfg(feature = "ip")]
+#[inline]
+pub fn ip(u: &mut Unstructured<'_>, local: TriAllow, port: TriAllow) -> Result<String> {
+    if u.arbitrary()? { ipv4(u, local, port) } else { ipv6(u, local, port) }
+}
+
+/// Generate an input of the `email` validator. Comments are never generated.
+#[cfg(feature = "email")]
+pub fn email(
+    u: &mut Unstructured<'_>,
+    ip: TriAllow,
+    local: TriAllow,
+    at_least_two_labels: TriAllow,
+    non_ascii: TriAllow,
+) -> Result<String> {
+    let mut s = String::new();
+
+    for i in 0..u.int_in_range(1..=16u8)? {
+        let c = match u.int_in_range(0..=15u8)? {
+            0 if i > 0 && !s.ends_with('.') => '.',
+            1 => *u.choose(&['_', '+', '-'])?,
+            2 if non_ascii.allow() => *u.choose(&['é', 'ß', '中'])?,
+            _ => *u.choose(b"abcdefghijklmnopqrstuvwxyz0123456789")? as char,
+        };
+
+        s.push(c);
+    }
+
+    if s.ends_with('.') {
+        s.pop();
+        s.push('_');
+    }
+
+    s.push('@');
+
+    // IP addresses are not allowed when at least two labels are disallowed
+    let is_ip = !at_least_two_labels.disallow() && tri_allow(u, ip)?;
+    let local = tri_allow(u, local)?;
+
+    if is_ip {
+        if u.arbitrary()? {
+            s.push_str(format!("[{}]", ipv4_addr(u, local)?).as_str());
+        } else {
+            s.push_str(format!("[IPv6:{}]", ipv6_addr(u, local)?).as_str());
+        }
+    } else {
+        s.push_str(domain_name(u, local, at_least_two_labels)?.as_str());
+    }
+
+    Ok(s)
+}
+
+/// Generate a mailbox from an input of the `email` validator, with a display name of words, quotes and non-ASCII characters if `display_name` decides so.
+#[cfg(feature = "email")]
+pub fn mailbox(u: &mut Unstructured<'_>, email: String, display_name: TriAllow) -> Result<String> {
+    if !tri_allow(u, display_name)? {
+        return Ok(email);
+    }
+
+    let mut name = String::new();
+
+    for i in 0..u.int_in_range(1..=3u8)? {
+        if i > 0 {
+            name.push(' ');
+        }
+
+        for _ in 0..u.int_in_range(1..=8u8)? {
+            let c = match u.int_in_range(0..=15u8)? {
+                0 => *u.choose(&['"', '.', ',', '\\'])?,
+                1 => *u.choose(&['é', 'ß', '中'])?,
+                _ => *u.choose(b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ")? as char,
+            };
+
+            name.push(c);
+        }
+    }
+
+    let mut s = String::new();
+
+    crate::functions::push_display_name(&mut s, &name);
+
+    s.push_str(" <");
+    s.push_str(&email);
+    s.push('>');
+
+    Ok(s)
+}
+
+/// Generate a URL of one of the schemes, with a random host, port, path, query and fragment.
+#[allow(dead_code)]
+fn url_with_schemes(u: &mut Unstructured<'_>, schemes: &[&str], local: TriAllow) -> Result<String> {
+    let scheme = *u.choose(schemes)?;
+    let host = host_name(u, local, TriAllow::Allow)?;
+
+    let mut s = append_port(format!("{scheme}://{host}"), self::port(u, TriAllow::Allow)?);
+
+    for _ in 0..u.int_in_range(0..=3u8)? {
+        s.push('/');
+        s.push_str(domain_name(u, false, TriAllow::Disallow)?.as_str());
+    }
+
+    if u.arbitrary()? {
+        s.push_str(
+            format!("?{}={}", domain_name(u, false, TriAllow::Disallow)?, u.arbitrary::<u32>()?)
+                .as_str(),
+        );
+    }
+
+    if u.arbitrary()? {
+        s.push('#');
+        s.push_str(domain_name(u, false, TriAllow::Disallow)?.as_str());
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `url` validator.
+#[cfg(feature = "url")]
+#[inline]
+pub fn url(u: &mut Unstructured<'_>) -> Result<String> {
+    url_with_schemes(u, &["http", "https", "ftp", "ws", "wss"], TriAllow::Allow)
+}
+
+/// Generate an input of the `http_url` validator.
+#[cfg(feature = "http_url")]
+#[inline]
+pub fn http_url(u: &mut Unstructured<'_>, local: TriAllow) -> Result<String> {
+    url_with_schemes(u, &["http", "https"], local)
+}
+
+/// Generate an input of the `http_ftp_url` validator.
+#[cfg(feature = "http_ftp_url")]
+#[inline]
+pub fn http_ftp_url(u: &mut Unstructured<'_>, local: TriAllow) -> Result<String> {
+    url_with_schemes(u, &["http", "https", "ftp"], local)
+}
@@ -0,0 +1,106 @@
+#[cfg(any(feature = "boolean", feature = "json", feature = "length"))]
+mod basic;
+#[cfg(any(feature = "boolean", feature = "json", feature = "length"))]
+pub use basic::*;
+
+#[cfg(any(
+    feature = "base32",
+    feature = "base32_decoded",
+    feature = "base64",
+    feature = "base64_decoded",
+    feature = "base64_url",
+    feature = "base64_url_decoded"
+))]
+mod base_xx;
+#[cfg(any(
+    feature = "base32",
+    feature = "base32_decoded",
+    feature = "base64",
+    feature = "base64_decoded",
+    feature = "base64_url",
+    feature = "base64_url_decoded"
+))]
+pub use base_xx::*;
+
+#[cfg(any(feature = "date", feature = "datetime", feature = "time"))]
+mod date_time;
+#[cfg(any(feature = "date", feature = "datetime", feature = "time"))]
+pub use date_time::*;
+
+#[cfg(any(
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "http_ftp_url",
+    feature = "ip",
+    feature = "ipv4",
+    feature = "ipv6",
+    feature = "url"
+))]
+mod host;
+#[cfg(any(
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "http_ftp_url",
+    feature = "ip",
+    feature = "ipv4",
+    feature = "ipv6",
+    feature = "url"
+))]
+pub use host::*;
+
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+mod hex_groups;
+#[cfg(any(feature = "mac_address", feature = "uuid"))]
+pub use hex_groups::*;
+
+#[cfg(any(
+    feature = "bit",
+    feature = "byte",
+    feature = "duration",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "unsigned_integer"
+))]
+mod number;
+#[cfg(any(
+    feature = "bit",
+    feature = "byte",
+    feature = "duration",
+    feature = "number",
+    feature = "signed_integer",
+    feature = "unsigned_integer"
+))]
+pub use number::*;
+
+#[cfg(feature = "phone")]
+mod phone;
+#[cfg(feature = "phone")]
+pub use phone::*;
+
+#[cfg(any(feature = "semver", feature = "semver_req"))]
+mod semver;
+#[cfg(any(feature = "semver", feature = "semver_req"))]
+pub use semver::*;
+
+#[cfg(any(feature = "line", feature = "text"))]
+mod utf8;
+#[cfg(any(feature = "line", feature = "text"))]
+pub use utf8::*;
+
+/// Decide whether something which must exist, is allowed, or is disallowed exists.
+#[allow(dead_code)]
+#[inline]
+fn tri_allow(
+    u: &mut arbitrary::Unstructured<'_>,
+    allow: crate::runtime::TriAllow,
+) -> arbitrary::Result<bool> {
+    match allow {
+        crate::runtime::TriAllow::Must => Ok(true),
+        crate::runtime::TriAllow::Allow => u.arbitrary(),
+        crate::runtime::TriAllow::Disallow => Ok(false),
+    }
+}
@@ -0,0 +1,271 @@
+#[cfg(feature = "duration")]
+use alloc::{format, string::String};
+use core::ops::RangeInclusive;
+
+use arbitrary::{Error, Result, Unstructured, unstructured::Int};
+
+use crate::runtime::RangeOption;
+#[cfg(feature = "number")]
+use crate::runtime::TriAllow;
+
+/// Integers which can be generated in a range.
+pub trait Integer: Int {
+    const MIN_VALUE: Self;
+    const MAX_VALUE: Self;
+
+    fn checked_inc(self) -> Option<Self>;
+
+    fn checked_dec(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const MAX_VALUE: Self = <$ty>::MAX;
+                const MIN_VALUE: Self = <$ty>::MIN;
+
+                #[inline]
+                fn checked_inc(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn checked_dec(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Generate an integer in `range`, which cannot exceed `bounds`.
+pub fn integer_within<T: Integer>(
+    u: &mut Unstructured<'_>,
+    range: RangeOption<T>,
+    bounds: RangeInclusive<T>,
+) -> Result<T> {
+    let (lower, upper) = bounds.into_inner();
+
+    let (min, max) = match range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => {
+            let max = match max {
+                Some(max) if inclusive => max,
+                Some(max) => max.checked_dec().ok_or(Error::IncorrectFormat)?,
+                None => T::MAX_VALUE,
+            };
+
+            (min.unwrap_or(T::MIN_VALUE), max)
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => {
+            // the values below `min` and the values above `max` are allowed
+            let below = min.and_then(T::checked_dec).map(|max| (T::MIN_VALUE, max));
+            let above = max
+                .and_then(|max| if inclusive { max.checked_inc() } else { Some(max) })
+                .map(|min| (min, T::MAX_VALUE));
+
+            match (below, above) {
+                (Some(below), Some(above)) => {
+                    if u.arbitrary()? {
+                        below
+                    } else {
+                        above
+                    }
+                },
+                (Some(side), None) | (None, Some(side)) => side,
+                // no side is allowed, or the range is empty
+                (None, None) if min.is_some() || max.is_some() => {
+                    return Err(Error::IncorrectFormat);
+                },
+                (None, None) => (T::MIN_VALUE, T::MAX_VALUE),
+            }
+        },
+        RangeOption::Unlimited => (T::MIN_VALUE, T::MAX_VALUE),
+    };
+
+    let (min, max) = (min.max(lower), max.min(upper));
+
+    if min > max {
+        return Err(Error::IncorrectFormat);
+    }
+
+    u.int_in_range(min..=max)
+}
+
+/// Generate an input of the `unsigned_integer`, `signed_integer`, `bit` or `byte` validator.
+#[inline]
+pub fn integer<T: Integer>(u: &mut Unstructured<'_>, range: RangeOption<T>) -> Result<T> {
+    integer_within(u, range, T::MIN_VALUE..=T::MAX_VALUE)
+}
+
+/// Generate a finite number in `range`, which cannot exceed `bounds`. The exclusive ends are stepped over in the precision of `T`.
+#[cfg(any(feature = "duration", feature = "number"))]
+fn float_within<T: Float>(
+    u: &mut Unstructured<'_>,
+    range: RangeOption<f64>,
+    bounds: RangeInclusive<f64>,
+) -> Result<f64> {
+    let (lower, upper) = bounds.into_inner();
+
+    let (min, max) = match range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => {
+            let max = match max {
+                Some(max) if inclusive => max,
+                Some(max) => T::next_down(max),
+                None => upper,
+            };
+
+            (min.unwrap_or(lower), max)
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => {
+            let below = min.map(|max| (lower, T::next_down(max)));
+            let above = max.map(|min| (if inclusive { T::next_up(min) } else { min }, upper));
+
+            match (below, above) {
+                (Some(below), Some(above)) => {
+                    if u.arbitrary()? {
+                        below
+                    } else {
+                        above
+                    }
+                },
+                (Some(side), None) | (None, Some(side)) => side,
+                (None, None) => (lower, upper),
+            }
+        },
+        RangeOption::Unlimited => (lower, upper),
+    };
+
+    let (min, max) = (min.max(lower), max.min(upper));
+
+    if min > max {
+        return Err(Error::IncorrectFormat);
+    }
+
+    // the bounds themselves are worth testing as well
+    Ok(match u.int_in_range(0..=7u8)? {
+        0 => min,
+        1 => max,
+        _ => {
+            let ratio = u.int_in_range(0..=u32::MAX)? as f64 / u32::MAX as f64;
+
+            // interpolate without computing `max - min`, which can overflow
+            (min * (1.0 - ratio) + max * ratio).clamp(min, max)
+        },
+    })
+}
+
+/// Floating-point numbers which can be generated in a range.
+#[cfg(any(feature = "duration", feature = "number"))]
+pub trait Float: Into<f64> {
+    const MIN: f64;
+    const MAX: f64;
+
+    /// The greatest number of `Self` which is less than `f`.
+    fn next_down(f: f64) -> f64;
+
+    /// The least number of `Self` which is greater than `f`.
+    fn next_up(f: f64) -> f64;
+}
+
+#[cfg(any(feature = "duration", feature = "number"))]
+impl Float for f32 {
+    const MAX: f64 = f32::MAX as f64;
+    const MIN: f64 = f32::MIN as f64;
+
+    #[inline]
+    fn next_down(f: f64) -> f64 {
+        (f as f32).next_down() as f64
+    }
+
+    #[inline]
+    fn next_up(f: f64) -> f64 {
+        (f as f32).next_up() as f64
+    }
+}
+
+#[cfg(any(feature = "duration", feature = "number"))]
+impl Float for f64 {
+    const MAX: f64 = f64::MAX;
+    const MIN: f64 = f64::MIN;
+
+    #[inline]
+    fn next_down(f: f64) -> f64 {
+        f.next_down()
+    }
+
+    #[inline]
+    fn next_up(f: f64) -> f64 {
+        f.next_up()
+    }
+}
+
+/// Generate an input of the `number` validator. Both `f32` and `f64` are generated as `f64`.
+#[cfg(feature = "number")]
+pub fn number<T: Float>(
+    u: &mut Unstructured<'_>,
+    nan: TriAllow,
+    range: RangeOption<T>,
+) -> Result<f64> {
+    let nan = match nan {
+        TriAllow::Must => true,
+        TriAllow::Allow => u.ratio(1u8, 8)?,
+        TriAllow::Disallow => false,
+    };
+
+    if nan {
+        return Ok(f64::NAN);
+    }
+
+    let range = match range {
+        RangeOption::Inside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Inside {
+            min: min.map(Into::into),
+            max: max.map(Into::into),
+            inclusive,
+        },
+        RangeOption::Outside {
+            min,
+            max,
+            inclusive,
+        } => RangeOption::Outside {
+            min: min.map(Into::into),
+            max: max.map(Into::into),
+            inclusive,
+        },
+        RangeOption::Unlimited => RangeOption::Unlimited,
+    };
+
+    float_within::<T>(u, range, T::MIN..=T::MAX)
+}
+
+/// Generate an input of the `duration` validator, which is a number of seconds such as `1.5s`.
+#[cfg(feature = "duration")]
+#[inline]
+pub fn duration(u: &mut Unstructured<'_>, range: RangeOption<f64>) -> Result<String> {
+    // a `Duration` holds `u64::MAX` seconds at most, which cannot be represented exactly by `f64`
+    let seconds = float_within::<f64>(u, range, 0.0..=1e18)?;
+
+    Ok(format!("{seconds}s"))
+}
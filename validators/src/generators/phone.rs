@@ -0,0 +1,68 @@
+use alloc::{format, string::String, vec::Vec};
+
+use arbitrary::{Error, Result, Unstructured};
+use phonenumber::{
+    country::Id,
+    metadata::{DATABASE, Metadata},
+};
+
+/// Generate an input of the `phone` validator, which is a phone number of one of `countries` in the international format. Any country is chosen if `countries` is empty.
+///
+/// The numbers are derived from the examples in the metadata of `phonenumber`, with the last digits replaced when the result is still valid.
+pub fn phone(u: &mut Unstructured<'_>, countries: &[Id]) -> Result<String> {
+    let (id, examples) = if countries.is_empty() {
+        let database = DATABASE.iter().collect::<Vec<_>>();
+
+        // a few regions have no example which is valid internationally
+        loop {
+            let (id, examples) = examples(u.choose(&database)?);
+
+            if !examples.is_empty() || u.is_empty() {
+                break (id, examples);
+            }
+        }
+    } else {
+        examples(DATABASE.by_id(u.choose(countries)?.as_ref()).ok_or(Error::IncorrectFormat)?)
+    };
+
+    let original = u.choose(&examples)?;
+
+    let mut s = String::from(&original[..original.len().saturating_sub(2)]);
+
+    for _ in 0..original.len() - s.len() {
+        s.push(char::from(b'0' + u.int_in_range(0..=9u8)?));
+    }
+
+    // an example is only known to be valid as it is
+    if is_valid(id, &s) { Ok(s) } else { Ok(original.clone()) }
+}
+
+/// Collect the examples of a region in the international format.
+fn examples(metadata: &Metadata) -> (Option<Id>, Vec<String>) {
+    let descriptors = metadata.descriptors();
+
+    let code = metadata.country_code();
+    let id = metadata.id().parse::<Id>().ok();
+
+    // some examples, such as the numbers of national toll-free services, cannot be dialed internationally
+    let examples = [
+        descriptors.fixed_line(),
+        descriptors.mobile(),
+        descriptors.toll_free(),
+        descriptors.premium_rate(),
+        descriptors.voip(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|descriptor| descriptor.example())
+    .map(|example| format!("+{code}{example}"))
+    .filter(|s| is_valid(id, s))
+    .collect();
+
+    (id, examples)
+}
+
+#[inline]
+fn is_valid(id: Option<Id>, s: &str) -> bool {
+    matches!(phonenumber::parse(id, s), Ok(number) if number.is_valid() && number.country().id() == id)
+}
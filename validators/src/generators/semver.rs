@@ -0,0 +1,72 @@
+use alloc::{format, string::String};
+
+use arbitrary::{Result, Unstructured};
+
+/// Generate identifiers of a pre-release or build metadata, such as `alpha.1`.
+#[cfg(feature = "semver")]
+fn identifiers(u: &mut Unstructured<'_>) -> Result<String> {
+    let mut s = String::new();
+
+    for i in 0..u.int_in_range(1..=3u8)? {
+        if i > 0 {
+            s.push('.');
+        }
+
+        if u.arbitrary()? {
+            s.push_str(format!("{}", u.int_in_range(0..=99u8)?).as_str());
+        } else {
+            s.push_str(u.choose(&["alpha", "beta", "rc", "x-y"])?);
+        }
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `semver` validator.
+#[cfg(feature = "semver")]
+pub fn semver(u: &mut Unstructured<'_>) -> Result<String> {
+    let mut s =
+        format!("{}.{}.{}", u.arbitrary::<u16>()?, u.arbitrary::<u16>()?, u.arbitrary::<u16>()?);
+
+    if u.arbitrary()? {
+        s.push('-');
+        s.push_str(identifiers(u)?.as_str());
+    }
+
+    if u.arbitrary()? {
+        s.push('+');
+        s.push_str(identifiers(u)?.as_str());
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `semver_req` validator, which is one or more comparators such as `>=1.2, <2`.
+#[cfg(feature = "semver_req")]
+pub fn semver_req(u: &mut Unstructured<'_>) -> Result<String> {
+    if u.ratio(1u8, 8)? {
+        return Ok(String::from("*"));
+    }
+
+    let mut s = String::new();
+
+    for i in 0..u.int_in_range(1..=3u8)? {
+        if i > 0 {
+            s.push_str(", ");
+        }
+
+        s.push_str(u.choose(&["", "=", ">", ">=", "<", "<=", "~", "^"])?);
+        s.push_str(format!("{}", u.int_in_range(0..=99u8)?).as_str());
+
+        // a partial version, such as `1.2`, is allowed as well
+        match u.int_in_range(0..=2u8)? {
+            0 => (),
+            1 => s.push_str(format!(".{}", u.int_in_range(0..=99u8)?).as_str()),
+            _ => s.push_str(
+                format!(".{}.{}", u.int_in_range(0..=99u8)?, u.int_in_range(0..=99u8)?).as_str(),
+            ),
+        }
+    }
+
+    Ok(s)
+}
@@ -0,0 +1,84 @@
+use alloc::string::String;
+
+use arbitrary::{Error, Result, Unstructured};
+
+/// Characters which are not ASCII, of two, three and four bytes in UTF-8.
+const NON_ASCII: [char; 6] = ['é', 'ß', 'Ж', '中', '文', '🦀'];
+
+/// The longest part of a string which is generated beyond its minimum length.
+const EXTRA_LENGTH: usize = 64;
+
+fn utf8(
+    u: &mut Unstructured<'_>,
+    whitespaces: &[char],
+    byte_length: bool,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<String> {
+    // no leading or trailing whitespaces are generated, so the trimmed length is the length
+    let min = min.unwrap_or(0).max(trimmed_min.unwrap_or(0));
+    let max = max.unwrap_or(usize::MAX).min(min.saturating_add(EXTRA_LENGTH));
+
+    if min > max {
+        return Err(Error::IncorrectFormat);
+    }
+
+    let length = u.int_in_range(min..=max)?;
+
+    let mut s = String::with_capacity(length);
+    let mut counter = 0;
+
+    while counter < length {
+        let c = match u.int_in_range(0..=9u8)? {
+            0 => *u.choose(whitespaces)?,
+            1 => {
+                let c = *u.choose(&NON_ASCII)?;
+
+                if byte_length && c.len_utf8() > length - counter { 'a' } else { c }
+            },
+            _ => u.int_in_range(b'!'..=b'~')? as char,
+        };
+
+        s.push(c);
+
+        counter += if byte_length { c.len_utf8() } else { 1 };
+    }
+
+    // the whitespaces are all one byte long
+    if s.starts_with(whitespaces) {
+        s.replace_range(..1, "a");
+    }
+
+    if s.ends_with(whitespaces) {
+        s.replace_range(s.len() - 1.., "a");
+    }
+
+    Ok(s)
+}
+
+/// Generate an input of the `text` validator. The lengths are counted in bytes if `byte_length` is `true`, or in characters otherwise.
+#[cfg(feature = "text")]
+#[inline]
+pub fn text(
+    u: &mut Unstructured<'_>,
+    byte_length: bool,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<String> {
+    utf8(u, &[' ', '\t', '\n', '\r'], byte_length, min, trimmed_min, max)
+}
+
+/// Generate an input of the `line` validator. The lengths are counted in bytes if `byte_length` is `true`, or in characters otherwise.
+#[cfg(feature = "line")]
+#[inline]
+pub fn line(
+    u: &mut Unstructured<'_>,
+    byte_length: bool,
+    min: Option<usize>,
+    trimmed_min: Option<usize>,
+    max: Option<usize>,
+) -> Result<String> {
+    utf8(u, &[' ', '\t'], byte_length, min, trimmed_min, max)
+}
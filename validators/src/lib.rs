@@ -18,7 +18,13 @@ default-features = false
 
 Certain validators do not require the use of the `std` library. However, if needed, you can explicitly enable the `std` feature.
 
-This library can support the Serde framework, the Rocket framework and the Axum framework by enabling the `serde`, `rocket` and `axum` features, respectively.
+This library can support the Serde framework, the Rocket framework, the Axum framework and the Actix Web framework by enabling the `serde`, `rocket`, `axum` and `actix-web` features, respectively. The `clap` feature lets validators be used as command-line arguments. See [Clap](#clap). The `sqlx` feature lets validators be stored in databases. See [SQLx](#sqlx). The `arbitrary` feature generates valid values for fuzzing and property testing. See [Arbitrary](#arbitrary). The `axum-problem-json` feature renders errors as `application/problem+json` responses. See [Problem Details](#problem-details).
+
+The `localization` feature translates the messages of the errors. See [Localization](#localization).
+
+The `schemars` feature implements the `JsonSchema` trait of the `schemars` crate for validators, and the `utoipa` feature implements the `ToSchema` trait of the `utoipa` crate. See [JSON Schema](#json-schema) and [OpenAPI](#openapi).
+
+The models of the `date`, `datetime` and `time` validators can be converted to the types of the `chrono` crate or the `time` crate by enabling the `chrono` or `time-dep` feature. The latter is not named `time` because that is the name of the `time` validator.
 
 ## Validators
 
@@ -228,6 +234,58 @@ assert_eq!(1000u64, AtLeastOneByte::parse_string("1KB").unwrap().0);
 * Traits: `ValidateString`, `ValidateUnsignedInteger`
 * By default, the range is unlimited and `ignore_case = true`
 
+#### date
+
+```rust
+# #[cfg(all(feature = "derive", feature = "date"))]
+# {
+use validators::prelude::*;
+use validators_prelude::Date;
+
+#[derive(Validator)]
+#[validator(date(range(min = "2000-01-01", max = "2099-12-31")))]
+pub struct BirthDate(Date);
+
+assert!(BirthDate::parse_string("2024-02-29").is_ok());
+assert!(BirthDate::parse_string("2023-02-29").is_err());
+assert!(BirthDate::parse_string("1999-12-31").is_err());
+assert!(BirthDate::parse_string("20240229").is_err()); // basic format
+assert_eq!(Date { year: 2024, month: 2, day: 29 }, BirthDate::parse_string("2024-02-29").unwrap().0);
+# }
+```
+
+* Traits: `ValidateString`
+* By default, `basic_format = Disallow` and the range is unlimited
+* The extended format is `YYYY-MM-DD` and the basic format is `YYYYMMDD`. The bounds of `range` are inclusive and must be written in the extended format.
+* With the `chrono` feature, `Date` can be converted to `chrono::NaiveDate`. With the `time-dep` feature, it can be converted to `time::Date`.
+
+#### datetime
+
+```rust
+# #[cfg(all(feature = "derive", feature = "datetime"))]
+# {
+use validators::prelude::*;
+use validators_prelude::DateTime;
+
+#[derive(Validator)]
+#[validator(datetime(timezone(Must), fraction(Disallow), range(min = "2000-01-01T00:00:00Z")))]
+pub struct Timestamp(DateTime);
+
+assert!(Timestamp::parse_string("2024-02-29T12:34:56Z").is_ok());
+assert!(Timestamp::parse_string("2024-02-29T12:34:56+08:00").is_ok());
+assert!(Timestamp::parse_string("2024-02-29T12:34:56").is_err()); // no timezone
+assert!(Timestamp::parse_string("2024-02-29T12:34:56.789Z").is_err()); // fraction
+assert!(Timestamp::parse_string("2000-01-01T07:59:59+08:00").is_err()); // too early
+assert_eq!("2024-02-29T12:34:56+08:00", Timestamp::parse_string("2024-02-29 12:34:56+08:00").unwrap().0.to_string());
+# }
+```
+
+* Traits: `ValidateString`
+* By default, `basic_format = Disallow`, `timezone = Allow`, `fraction = Allow` and the range is unlimited
+* The date and the time are joined by `T`, `t` or a space, and must be written in the same format. Offsets are `Z` or `±hh:mm` (`±hhmm` in the basic format).
+* The bounds of `range` are inclusive instants and must be written in the extended format. A date-time without a timezone is compared as UTC.
+* With the `chrono` feature, `DateTime` can be converted to `chrono::NaiveDateTime` and `chrono::DateTime<FixedOffset>`. With the `time-dep` feature, it can be converted to `time::PrimitiveDateTime` and `time::OffsetDateTime`.
+
 #### domain
 
 ```rust
@@ -254,8 +312,41 @@ assert_eq!(Some(8080), DomainAllowPort::parse_string("example.com:8080").unwrap(
 # }
 ```
 
-* Traits: `ValidateString`, `QualifyDomain`, `ToUriAuthorityString`
-* By default, `ipv4 = Allow, local = Allow, port = Allow, at_least_two_labels = Allow`
+* Traits: `ValidateString`, `QualifyDomain`, `ToUriAuthorityString`, `RegistrableDomain`
+* By default, `ipv4 = Allow, local = Allow, port = Allow, at_least_two_labels = Allow, registrable = Allow, known_tld = Allow`
+* `registrable(Must)` and `registrable(Disallow)` are supported, see [Public Suffix List](#public-suffix-list)
+* `known_tld(Must)` is supported, see [Known TLDs](#known-tlds)
+* `allowed_domains(...)` and `denied_domains(...)` are supported, see [Domain Lists](#domain-lists)
+
+#### duration
+
+```rust
+# #[cfg(all(feature = "derive", feature = "duration"))]
+# {
+use std::time::Duration;
+
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(duration(range(min = 1, max = 86400)))]
+pub struct Timeout(Duration);
+
+assert_eq!(Duration::from_secs(5400), Timeout::parse_string("PT1H30M").unwrap().0);
+assert_eq!(Duration::from_secs(5400), Timeout::parse_string("1h30m").unwrap().0);
+assert_eq!(Duration::from_secs(5400), Timeout::parse_string("1.5 hours").unwrap().0);
+assert_eq!(Duration::from_secs(90), Timeout::parse_string("90").unwrap().0);
+assert!(Timeout::parse_string("500ms").is_err()); // too small
+assert!(Timeout::parse_string("2 days").is_err()); // too large
+assert!(Timeout::parse_u64(60).is_ok());
+# }
+```
+
+* Traits: `ValidateString`, `ValidateUnsignedInteger`
+* By default, the range is unlimited and `ignore_case = true`
+* The bounds of `range` are numbers of seconds. Integers passed to `ValidateUnsignedInteger` are also seconds.
+* The ISO 8601 format supports the `W`, `D`, `H`, `M` and `S` designators; years and months are rejected because their lengths vary. The human format supports units from `ns` to `w` (`weeks`), and a bare number is in seconds.
+* If `ignore_case = false`, ISO 8601 designators must be upper-case and human units must be lower-case.
+* Durations are serialized in the ISO 8601 format, by `validators::models::Iso8601Duration`.
 
 #### email
 
@@ -288,11 +379,47 @@ pub struct EmailWithoutComment {
 }
 
 assert!(EmailWithoutComment::parse_string("(john)joke@example.com").is_err());
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Allow), local(Allow), at_least_two_labels(Allow), non_ascii(Allow), display_name(Allow)))]
+pub struct Mailbox {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: Host,
+    pub display_name: Option<String>,
+}
+
+let mailbox = Mailbox::parse_string("\"Doe, Jane\" <jane@example.com>").unwrap();
+
+assert_eq!(Some("Doe, Jane"), mailbox.display_name.as_deref());
+assert_eq!("jane@example.com", mailbox.to_email_string());
+assert_eq!("\"Doe, Jane\" <jane@example.com>", mailbox.to_mailbox_string());
+
+let mailbox = Mailbox::parse_string("=?UTF-8?Q?Andr=C3=A9?= <andre@example.com>").unwrap();
+
+assert_eq!(Some("André"), mailbox.display_name.as_deref());
+assert!(Mailbox::parse_string("jane@example.com").unwrap().display_name.is_none());
+
+let options = CanonicalEmailOptions::builder()
+    .lowercase_local_part(true)
+    .sub_address_separator(Some('+'))
+    .dot_insensitive_domain("gmail.com")
+    .build();
+
+let email = EmailWithoutComment::parse_string("Jane.Doe+News@GMail.com").unwrap();
+
+assert_eq!("janedoe@gmail.com", email.to_canonical_email(&options));
+assert_eq!("jane.doe@xn--fiq228c.tw", EmailWithoutComment::parse_string("Jane.Doe@中文.TW").unwrap().to_canonical_email(&options));
 # }
 ```
 
-* Traits: `ValidateString`, `ToEmailString`
-* By default, `comment = Allow, ip = Allow, local = Allow, at_least_two_labels = Allow, non_ascii = Allow`
+* Traits: `ValidateString`, `ToEmailString`, `ToCanonicalEmail`, `RegistrableDomain`
+* By default, `comment = Allow, ip = Allow, local = Allow, at_least_two_labels = Allow, non_ascii = Allow, display_name = Disallow, registrable = Allow, known_tld = Allow`
+* With `display_name(Allow)` or `display_name(Must)`, a `name-addr` of RFC 5322 such as `"Jane Doe" <jane@example.com>` is accepted and the struct needs a `display_name: Option<String>` field. The display name can be made of atoms, quoted strings and encoded words of RFC 2047, with comments between the words unless `comment = Disallow`. The comments are dropped. `to_mailbox_string` renders the display name back with the address, as encoded words if it has non-ASCII or control characters.
+* `to_canonical_email` builds a form for de-duplicating accounts, without comments and the display name. The domain part is always lowercased and encoded by IDNA, while lowercasing the local part, stripping sub-addressing tags and ignoring dots for some domains are enabled by `CanonicalEmailOptions`.
+* `allowed_domains(...)` and `denied_domains(...)` are supported, see [Domain Lists](#domain-lists)
+* `registrable(Must)` and `registrable(Disallow)` are supported, see [Public Suffix List](#public-suffix-list)
+* `known_tld(Must)` is supported, see [Known TLDs](#known-tlds)
 
 #### host
 
@@ -316,8 +443,10 @@ assert!(HostMustAtLeastTwoLabelsAllowPort::parse_string("example").is_err());
 # }
 ```
 
-* Traits: `ValidateString`, `ToUriAuthorityString`
-* By default, `local = Allow, port = Allow, at_least_two_labels = Allow`
+* Traits: `ValidateString`, `ToUriAuthorityString`, `RegistrableDomain`
+* By default, `local = Allow, port = Allow, at_least_two_labels = Allow, registrable = Allow`
+* `allowed_domains(...)` and `denied_domains(...)` are supported, see [Domain Lists](#domain-lists)
+* `registrable(Must)` and `registrable(Disallow)` are supported, see [Public Suffix List](#public-suffix-list)
 
 #### http_url
 
@@ -342,6 +471,7 @@ assert!(HttpURL::parse_string("ftp://example.org/").is_err());
 
 * Traits: `ValidateString`
 * By default, `local = Allow`
+* `allowed_domains(...)` and `denied_domains(...)` are supported, see [Domain Lists](#domain-lists)
 
 #### http_ftp_url
 
@@ -367,6 +497,7 @@ assert!(HttpFtpURL::parse_string("ftp://example.org/").is_ok());
 
 * Traits: `ValidateString`
 * By default, `local = Allow`
+* `allowed_domains(...)` and `denied_domains(...)` are supported, see [Domain Lists](#domain-lists)
 
 #### ip
 
@@ -730,6 +861,31 @@ assert!(TextNotAllowEmpty::parse_string("   ").is_err());
 * Traits: `ValidateLength`
 * By default, the length is unlimited
 
+#### time
+
+```rust
+# #[cfg(all(feature = "derive", feature = "time"))]
+# {
+use validators::prelude::*;
+use validators_prelude::Time;
+
+#[derive(Validator)]
+#[validator(time(basic_format(Allow), timezone(Disallow)))]
+pub struct LocalTime(Time);
+
+assert!(LocalTime::parse_string("12:34:56").is_ok());
+assert!(LocalTime::parse_string("123456.5").is_ok());
+assert!(LocalTime::parse_string("12:34:56Z").is_err());
+assert!(LocalTime::parse_string("24:00:00").is_err());
+assert_eq!(500_000_000, LocalTime::parse_string("12:34:56,5").unwrap().0.nanosecond);
+# }
+```
+
+* Traits: `ValidateString`
+* By default, `basic_format = Disallow`, `timezone = Allow` and `fraction = Allow`
+* The extended format is `hh:mm:ss` and the basic format is `hhmmss`, optionally followed by a fraction of up to nine digits and an offset. The second can be `60` for a leap second.
+* With the `chrono` feature, `Time` can be converted to `chrono::NaiveTime`. With the `time-dep` feature, it can be converted to `time::Time`.
+
 #### unsigned_integer
 
 ```rust
@@ -789,6 +945,412 @@ assert!(UUID::parse_string("A866664A-F9D3-4DDE-89CB-182015FA4F41").is_ok());
 * Traits: `ValidateString`, `ToUuidString`
 * By default, `case = Any, separator(Allow(b'-')`
 
+## Domain Lists
+
+The `email`, `domain`, `host`, `http_url` and `http_ftp_url` validators accept `allowed_domains(...)` and `denied_domains(...)`, which take domain literals or one expression of a `DomainSet`, such as the path to a `static` slice, array, `Vec`, `HashSet` or `BTreeSet`. A domain matches a listed domain if it is the same or its subdomain, case-insensitively. Write IDNs in Punycode (`xn--`).
+
+The checks happen after the other checks. A domain which is not in `allowed_domains` fails with `DomainNotAllowed`, and an IP never is in it. A domain in `denied_domains` fails with `DomainDenied`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email"))]
+# {
+use std::{collections::HashSet, sync::LazyLock};
+
+use validators::prelude::*;
+use validators::errors::EmailError;
+
+static DISPOSABLE: LazyLock<HashSet<&str>> = LazyLock::new(|| HashSet::from(["mailinator.com", "yopmail.com"]));
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), allowed_domains("example.com", "example.org"), denied_domains(DISPOSABLE)))]
+pub struct CorporateEmail {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+assert!(CorporateEmail::parse_string("jane@mail.example.com").is_ok());
+assert!(matches!(CorporateEmail::parse_string("jane@example.net"), Err(EmailError::DomainNotAllowed)));
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), denied_domains(DISPOSABLE)))]
+pub struct Email {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+assert!(Email::parse_string("jane@example.net").is_ok());
+assert!(matches!(Email::parse_string("jane@Mailinator.com"), Err(EmailError::DomainDenied)));
+# }
+```
+
+## Public Suffix List
+
+`at_least_two_labels` only counts the labels, so `co.uk` passes it. The `email`, `domain` and `host` validators accept `registrable(Must)`, with which a domain must be a registrable domain or its subdomain, such as `example.co.uk` or `www.example.co.uk`, and `registrable(Disallow)`, with which a domain must be a public suffix, such as `co.uk`. They fail with `RegistrableMust` and `RegistrableDisallow`. An IP is never registrable. The `RegistrableDomain` trait gives the public suffix (eTLD) and the registrable domain (eTLD+1) of a parsed domain, and the `public_suffix`, `registrable_domain` and `is_registrable_domain` functions do the same for strings.
+
+They use the [Public Suffix List](https://publicsuffix.org/) embedded in this crate, including its private section, which is taken at commit `1cbd6e71a9b83620b1d0b11e49d3d9ff48c27e22` of <https://github.com/publicsuffix/list> and compiled into sorted tables by `tools/update-data`, so it does not have to be parsed at runtime. The list is licensed under the Mozilla Public License 2.0. A newer list can be loaded by `PublicSuffixList::from_file` and installed by `PublicSuffixList::install` before any domain is checked.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "domain"))]
+# {
+use validators::prelude::*;
+use validators::errors::DomainError;
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), port(Disallow), registrable(Must)))]
+pub struct RegistrableDomainName(pub String);
+
+let domain = RegistrableDomainName::parse_string("www.Example.co.uk").unwrap();
+
+assert_eq!(Some("co.uk"), domain.get_public_suffix());
+assert_eq!(Some("example.co.uk"), domain.get_registrable_domain());
+assert!(matches!(RegistrableDomainName::parse_string("co.uk"), Err(DomainError::RegistrableMust)));
+
+assert_eq!(Some("example.com"), validators::functions::registrable_domain("mail.example.com."));
+assert_eq!(Some("github.io"), validators::functions::public_suffix("jane.github.io"));
+# }
+```
+
+## Known TLDs
+
+The `email` and `domain` validators accept `known_tld(Must)`, with which the last label of a domain must be a TLD delegated in the root zone, so a typo such as `jane@example.con` fails with `KnownTLDMust`. An IP is not checked. The `is_known_tld` function does the same for strings.
+
+They use the list of TLDs embedded in this crate, which is derived from the ICANN section of the [Public Suffix List](#public-suffix-list) at the same commit as the embedded suffixes, `1cbd6e71`, in the format of the IANA list. It carries no `# Version` line, so `TLDList::builtin().version()` is `None`. The IANA list can be embedded instead by `cargo run --manifest-path tools/update-data/Cargo.toml -- tlds tlds-alpha-by-domain.txt`, which keeps the file verbatim, so its `# Version YYYYMMDDHH` line records when it was taken. The `Update Data` workflow of the repository downloads the IANA list and the Public Suffix List and runs the tool on both. A newer list, such as <https://data.iana.org/TLD/tlds-alpha-by-domain.txt>, can also be loaded at runtime by `TLDList::from_file` and installed by `TLDList::install` before any domain is checked.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email"))]
+# {
+use validators::prelude::*;
+use validators::errors::EmailError;
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), known_tld(Must)))]
+pub struct Email {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: validators::models::Host,
+}
+
+assert!(Email::parse_string("jane@example.com").is_ok());
+assert!(Email::parse_string("jane@[127.0.0.1]").is_ok());
+assert!(matches!(Email::parse_string("jane@example.con"), Err(EmailError::KnownTLDMust)));
+
+assert!(validators::functions::is_known_tld("example.台灣"));
+assert!(!validators::functions::is_known_tld("localhost"));
+# }
+```
+
+## Error Codes
+
+Every error type implements the `ValidatorError` trait, which gives the name of the validator, a stable code for each variant, such as `text.too_long`, and the parameters relevant to the failure. API clients and translation tables can key off the codes instead of the messages.
+
+The errors themselves are small enums. The `*_detailed` methods of the validating traits, such as `validate_str_detailed` and `validate_u128_detailed`, return a `DetailedError` instead, which also carries the details of the failure as parameters, such as the configured `min` and `max` along with the rejected `value` or the measured `length`, or where an invalid input goes wrong, such as the byte `offset` of the first invalid character of a `text`, `line`, `base64` or `base64_url` string, or the index of the first invalid `label` of a `domain`. The details are measured only after the validation has failed.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer"))]
+# {
+use validators::{errors::UnsignedIntegerError, prelude::*};
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 100))))]
+pub struct Percentage(u8);
+
+let error = Percentage::parse_u8(101).unwrap_err();
+
+assert!(matches!(error, UnsignedIntegerError::TooLarge));
+assert_eq!("unsigned_integer", error.validator());
+assert_eq!("unsigned_integer.too_large", error.code());
+assert!(error.params().is_empty());
+
+let error = Percentage::validate_u128_detailed(101).unwrap_err();
+
+assert!(matches!(error.error(), UnsignedIntegerError::TooLarge));
+assert_eq!("unsigned_integer.too_large", error.code());
+assert_eq!(
+    vec![
+        ("max", ErrorParam::Unsigned(100)),
+        ("inclusive", ErrorParam::Bool(true)),
+        ("value", ErrorParam::Unsigned(101)),
+    ],
+    error.params()
+);
+# }
+```
+
+* `CustomizedError` delegates to the error of the built-in validator, and the code of a `Custom` error is `custom`, which has no details
+
+## Localization
+
+With the `localization` feature enabled, the `Localize` trait translates the message of an error by its code. The bundled `Builtin` catalog covers German, Spanish, French, Japanese, Simplified Chinese and Traditional Chinese. The messages of the errors themselves are in English.
+
+The languages can be a single language tag or the value of an `Accept-Language` header. `ValidationErrors` and `Problem` can be localized as a whole.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line", feature = "localization"))]
+# {
+use validators::{localization::{Builtin, MapCatalog}, prelude::*};
+
+#[derive(Debug, Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Name(String);
+
+let error = Name::validate_str_detailed("magiclen.org").unwrap_err();
+
+assert_eq!("ist zu lang (höchstens 8)", error.localize("de-CH, de;q=0.9, en;q=0.8"));
+assert_eq!("line is too long", error.localize("en-US"));
+
+// without the details, a template without the parameters is used
+assert_eq!("ist zu lang", Name::parse_str("magiclen.org").unwrap_err().localize("de"));
+
+// a catalog of your own, with the bundled one as the fallback
+let mut catalog = MapCatalog::new();
+
+catalog.insert("nl", "line.too_long", "is te lang (maximaal { $max } tekens)");
+
+assert_eq!("is te lang (maximaal 8 tekens)", error.localize_with(&(catalog, Builtin), "nl"));
+# }
+```
+
+* Implement the `Catalog` trait to load messages from other sources, such as Fluent files
+* `AcceptLanguage` reads the `Accept-Language` header as an Axum extractor or a Rocket request guard
+
+```rust,ignore
+use validators::{localization::AcceptLanguage, problem::Problem};
+
+async fn create_service(language: AcceptLanguage, Json(payload): Json<CreateService>) -> Result<String, Problem> {
+    let email = Email::parse_string(payload.email).map_err(|error| Problem::from(error).localize(language.as_str()))?;
+
+    Ok(email.local_part)
+}
+```
+
+## Custom Validation
+
+Every validator accepts a `custom = path::to::fn` parameter. The function is called with a reference to the parsed value after the built-in checks pass, and can reject it by returning an error which can be converted into `Box<dyn Error + Send + Sync>`, such as `&str`, `String` or any error type. The `Error` type of the implemented traits becomes `CustomizedError<E>`, where `E` is the error of the built-in validator.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line"))]
+# {
+use validators::{errors::CustomizedError, prelude::*};
+
+fn not_reserved(username: &Username) -> Result<(), &'static str> {
+    if username.0 == "admin" { Err("reserved username") } else { Ok(()) }
+}
+
+#[derive(Validator)]
+#[validator(line(char_length(trimmed_min = 1, max = 32), custom = not_reserved))]
+pub struct Username(pub String);
+
+assert!(Username::parse_string("len").is_ok());
+assert!(matches!(Username::parse_string(""), Err(CustomizedError::Builtin(_))));
+assert!(matches!(Username::parse_string("admin"), Err(CustomizedError::Custom(_))));
+# }
+```
+
+* The `validate_*` methods also call the function, except the `validate_collection` method of the `length` validator, which cannot create the parsed value from a reference
+* `const_new` only runs the built-in checks, because the function cannot be called in a const context
+* In the `#[validate(...)]` attribute of a field, the function is called with a reference to the value of the field
+
+## Field-level Validation
+
+If a struct does not have the `#[validator(validator_name)]` attribute, its named fields can be validated individually by applying the `#[validate(validator_name)]` attribute to them. The parameters of each validator are the same as above, and the same checking code is generated for them, but the fields keep their plain types.
+
+The `ValidateFields` trait is implemented for the struct. Its `validate` method checks every validated field and collects all failures into a `ValidationErrors` report, which records the path of the field, the name of the validator and the typed error of each failure along with its details. A field marked with `#[validate(nested)]` is validated by its own `ValidateFields` implementation, and the paths of its failures are prefixed with the name of the field. `Option<T>`, `Box<T>`, `Vec<T>`, `[T]` and `[T; N]` of a `ValidateFields` type also implement `ValidateFields`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email", feature = "line", feature = "unsigned_integer"))]
+# {
+use validators::{errors::UnsignedIntegerError, prelude::*};
+
+#[derive(Validator)]
+pub struct Address {
+    #[validate(line(char_length(trimmed_min = 1, max = 10)))]
+    pub zip: String,
+}
+
+#[derive(Validator)]
+pub struct SignUp {
+    #[validate(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Allow)))]
+    pub email: String,
+    #[validate(unsigned_integer(range(Inside(min = 18, max = 150))))]
+    pub age: u8,
+    #[validate(line(char_length(trimmed_min = 1, max = 32)))]
+    pub nickname: Option<String>, // `None` is not validated
+    #[validate(nested)]
+    pub addresses: Vec<Address>,
+    pub note: String, // not validated
+}
+
+let sign_up = SignUp {
+    email: String::from("len@magiclen.org"),
+    age: 17,
+    nickname: None,
+    addresses: vec![Address { zip: String::from("300") }, Address { zip: String::new() }],
+    note: String::new(),
+};
+
+let errors = sign_up.validate().unwrap_err();
+
+assert_eq!(2, errors.len());
+
+let error = errors.iter().next().unwrap();
+
+assert_eq!("age", error.path());
+assert_eq!("unsigned_integer", error.validator());
+assert_eq!("unsigned_integer.too_small", error.code());
+assert!(matches!(error.downcast_ref(), Some(UnsignedIntegerError::TooSmall)));
+assert_eq!(vec![("min", ErrorParam::Unsigned(18)), ("value", ErrorParam::Unsigned(17))], error.params());
+
+assert_eq!("addresses[1].zip", errors.iter().nth(1).unwrap().path());
+# }
+```
+
+* Fields of `bool`, integer and floating-point types are validated with `ValidateBoolean`, `ValidateSignedInteger`, `ValidateUnsignedInteger` or `ValidateNumber`, fields of the `length` validator with `ValidateLength`, and other fields with `ValidateString`
+* A field of a primitive type needs a validator of its kind: `boolean` for `bool`, `unsigned_integer` or `signed_integer` for unsigned integers (but not `signed_integer` for `u128`), `signed_integer` for signed integers, and `number` for floating-point numbers. `number` also accepts the integers of at most 32 bits, but not wider ones, which an `f64` cannot hold exactly. Other combinations fail to compile.
+
+```rust,compile_fail
+use validators::prelude::*;
+
+#[derive(Validator)]
+pub struct Settings {
+    #[validate(unsigned_integer(range(Inside(max = 100))))]
+    pub offset: i8, // error: the `unsigned_integer` validator cannot validate a field of `i8`
+}
+```
+* `ValidationErrors` can also be built by hand with its `push` and `extend_nested` methods, and implements `Serialize` if the `serde` feature is enabled
+
+## Enums of Alternatives
+
+An enum whose variants each wrap a validator type can also derive `Validator`. The `ValidateString` trait is implemented for it, and the variants are tried in order until one of them accepts the input. If none does, an error struct named with the `Error` suffix is returned, which has one field per variant (in snake case) telling why that variant failed. The error struct implements `Clone` and `ValidatorError` when the errors of the variants do, with the `alternatives.invalid` code and the code of each variant as a parameter named after its field.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "domain", feature = "email", feature = "ipv4"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Allow)))]
+pub struct Ipv4AllowPort {
+    pub ipv4: std::net::Ipv4Addr,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), port(Disallow), at_least_two_labels(Must)))]
+pub struct DomainWithoutPort(pub String);
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Allow)))]
+pub struct EmailWithoutIp {
+    pub local_part: String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+pub enum Target {
+    Ip(Ipv4AllowPort),
+    Domain(DomainWithoutPort),
+    Email(EmailWithoutIp),
+}
+
+assert!(matches!(Target::parse_str("127.0.0.1:8080"), Ok(Target::Ip(_))));
+assert!(matches!(Target::parse_str("magiclen.org"), Ok(Target::Domain(_))));
+assert!(matches!(Target::parse_str("len@magiclen.org"), Ok(Target::Email(_))));
+
+let error = Target::parse_str("len@").err().unwrap();
+
+assert!(matches!(error.email, validators::errors::EmailError::Invalid));
+assert_eq!("alternatives.invalid", error.code());
+# }
+```
+
+* Traits: `ValidateString`
+* The `#[validator(alternatives(serde(...), rocket(...)))]` attribute can be used to choose which `serde` and `rocket` traits to implement, like other validators. By default, all of them are implemented if the features are enabled. The `Deserialize` implementation accepts strings.
+
+## Borrowed Strings
+
+The `base32`, `base64`, `base64_url`, `line`, `regex` and `text` validators can also be derived for a struct with a single `&'a str` or `Cow<'a, str>` field. The `ValidateStr` trait is implemented for it, so `parse_borrowed_str` checks the input and borrows it without copying. The `Deserialize` implementation borrows from the input as well.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "line"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 32)))]
+pub struct Title<'a>(pub &'a str);
+
+let title = Title::parse_borrowed_str("Validators").unwrap();
+
+assert_eq!("Validators", title.0);
+# }
+```
+
+* A `&'a str` field can only be created from a borrowed string, so the `ValidateString` trait is not implemented and deserializing fails if the input has to be unescaped. With a `Cow<'a, str>` field, the `ValidateString` trait is implemented too and returns `Cow::Owned`.
+* The `domain` validator supports only the `Cow<'a, str>` field, which is borrowed when the input is already in ASCII form.
+* The `ValidateBytes` trait and the Rocket traits are not implemented for borrowed fields.
+
+## Compile-time Literals
+
+The `mac_address`, `signed_integer`, `unsigned_integer` and `uuid` validators generate a `const fn const_new(s: &str) -> Self`, which runs the same parsing logic in a const context. The `literal!` macro makes sure that it runs at compile time, so an invalid literal fails the build instead of panicking at startup.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "unsigned_integer"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(uuid(case(Lower), separator(Allow(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Port(u16);
+
+const NIL: Uuid = Uuid::const_new("00000000-0000-0000-0000-000000000000");
+
+let admin = validators::literal!(Uuid, "a866664a-f2cc-4d2b-a0a5-0c1b42c0ee7a");
+let port = validators::literal!(Port, "8080");
+
+assert_eq!(0, NIL.0);
+assert_eq!(0xa866664a_f2cc_4d2b_a0a5_0c1b42c0ee7a, admin.0);
+assert_eq!(8080, port.0);
+# }
+```
+
+```rust,ignore
+let port = validators::literal!(Port, "0"); // error: the literal is not a valid `Port`
+```
+
+Validators whose values need heap allocation or parsers from other crates, such as `email` and `semver`, have no `const_new`. Neither do validators with a `custom` function, which cannot be called in a const context.
+
+## Runtime Validators
+
+When the options of a validator are known only at runtime, for example from a configuration file, enable the `runtime` feature. The `runtime` module then provides a `*Validator` type for every validator which has options. Each of them is created by a builder whose defaults are the same as the derive macro, and returns the same errors.
+
+```rust
+# #[cfg(all(feature = "runtime", feature = "email"))]
+# {
+use validators::{
+    errors::EmailError,
+    runtime::{EmailValidator, TriAllow},
+};
+
+let validator = EmailValidator::builder().ip(TriAllow::Disallow).local(TriAllow::Disallow).build();
+
+let email = validator.parse_str("len@magiclen.org").unwrap();
+
+assert_eq!("len", email.local_part);
+
+assert!(matches!(validator.parse_str("len@[8.8.8.8]"), Err(EmailError::IPDisallow)));
+assert!(matches!(validator.parse_str("len@localhost"), Err(EmailError::LocalDisallow)));
+# }
+```
+
+Validators without options, such as `boolean`, `json`, `semver`, `semver_req` and `url`, have no runtime version. Neither do the `date`, `datetime`, `duration` and `time` validators yet.
+
 ## `validators::Result`
 
 When incorporating your validator type into another type, you might desire to obtain the original error instance provided by the validator.
@@ -930,6 +1492,295 @@ async fn handler(body: String) -> Result<String, validators::errors::EmailError>
 let app: Router = Router::new().route("/email", post(handler));
 # }
 ```
+
+### Problem Details
+
+The plain-text responses are not easy for programs to handle. With the `axum-problem-json` feature enabled, every validator error, `ValidationErrors` and the rejections of the `Json`, `Query` and `Path` extractors can be converted into `validators::problem::Problem`, which is rendered as `application/problem+json` ([RFC 9457](https://www.rfc-editor.org/rfc/rfc9457)) with the stable `code` of the `ValidatorError`, such as `email.ip_disallow`, the name of the `validator` and the `params` of the failure. Wrap an extractor in `WithProblem` to report its failures in the same format. If a validator fails while the extractor deserializes the request, the problem has the code of that validator and the `path` of the field, such as `user.email`, instead of a generic `extract.*` code.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email", feature = "axum-problem-json"))]
+# {
+use validators::prelude::*;
+use validators::problem::Problem;
+
+#[derive(Debug, Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+let problem = Problem::from(Email::parse_str("user@[127.0.0.1]").unwrap_err());
+
+assert_eq!(400, problem.status().as_u16());
+assert_eq!("email.ip_disallow", problem.code());
+assert_eq!(Some("email"), problem.validator());
+assert_eq!("must not use an IP", problem.detail());
+# }
+```
+
+```rust,ignore
+use axum::{extract::Json, routing::post, Router};
+use validators::problem::{Problem, WithProblem};
+
+// A validator which fails in the `Json` extractor becomes a problem with its code and the path of the field.
+// Other failures of the extractor, such as a syntax error, become `extract.*` problems.
+async fn create_service(WithProblem(Json(payload)): WithProblem<Json<CreateService>>) -> Result<String, Problem> {
+    // A validator error becomes a problem with the code of its variant.
+    let email = Email::parse_string(payload.email)?;
+
+    Ok(email.local_part)
+}
+
+let app: Router = Router::new().route("/services", post(create_service));
+```
+
+## Actix Web
+
+The `actix-web` feature also enables the `serde` feature, so validators can be used in the `Json`, `Query`, `Form` or `Path` extractors of Actix Web directly, and the validation is performed during deserialization. As with [Rocket forms](#validatorsresult), a `validators::Result` field captures its validation error instead of rejecting the whole request.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer", feature = "actix-web"))]
+# {
+use serde::Deserialize;
+use validators::actix_web::web::Query;
+use validators::prelude::*;
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+pub struct Port(u16);
+
+#[derive(Debug, Deserialize)]
+struct Service {
+    port:        Port,
+    backup_port: validators::Result<Port, validators::errors::UnsignedIntegerError>,
+}
+
+let service = Query::<Service>::from_query("port=8080&backup_port=0").unwrap().into_inner();
+
+assert_eq!(8080, service.port.0);
+assert!(service.backup_port.into_std_result().is_err());
+
+// An invalid field which is not wrapped in `validators::Result` rejects the query.
+assert!(Query::<Service>::from_query("port=0&backup_port=8080").is_err());
+# }
+```
+
+Every validator error type, `ValidationErrors` and `CustomizedError` implement Actix Web's `ResponseError` trait with the `400 Bad Request` status code, so handlers which perform the validation manually can return the error directly with the `?` operator. With the `localization` feature enabled, `AcceptLanguage` can be extracted from the request as well.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "email", feature = "actix-web"))]
+# {
+use validators::actix_web::{App, ResponseError, http::StatusCode, web};
+use validators::prelude::*;
+
+#[derive(Debug, Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+async fn handler(body: String) -> Result<String, validators::errors::EmailError> {
+    let email = Email::parse_string(body)?;
+
+    Ok(email.local_part)
+}
+
+let _app = App::new().route("/email", web::post().to(handler));
+
+assert_eq!(StatusCode::BAD_REQUEST, Email::parse_str("user@[127.0.0.1]").unwrap_err().status_code());
+# }
+```
+
+## Clap
+
+With the `clap` feature enabled, every validator which accepts strings implements the `ValueParserFactory` trait of the `clap` crate, so it can be used as a command-line argument with `value_parser!` or `#[arg(value_parser)]`. The error message of the validator is shown in the error output of `clap`, followed by the allowed range of the `unsigned_integer`, `signed_integer` and `number` validators. The words accepted by the `boolean` validator are listed as the possible values. Like other values parsed by `clap`, the validator needs to implement `Clone`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer", feature = "clap"))]
+# {
+use validators::clap::{Arg, Command, value_parser};
+use validators::prelude::*;
+
+#[derive(Debug, Clone, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+pub struct Port(u16);
+
+let command = Command::new("server").arg(Arg::new("port").long("port").value_parser(value_parser!(Port)));
+
+let port = command.clone().get_matches_from(["server", "--port", "8080"]).remove_one::<Port>("port").unwrap();
+
+assert_eq!(8080, port.0);
+
+let error = command.try_get_matches_from(["server", "--port", "0"]).unwrap_err();
+
+assert!(error.to_string().contains("invalid value '0' for '--port <port>': integer is too small, expected 1..=65535"));
+# }
+```
+
+With the derive macro of `clap`, it looks like this:
+
+```rust,ignore
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[arg(long, value_parser)]
+    port: Port,
+}
+```
+
+## SQLx
+
+With the `sqlx` feature enabled, the validators implement the `Type`, `Encode` and `Decode` traits of the `sqlx` crate for every database which supports their representations, so they can be bound to queries and read from rows directly. Decoding runs the validation again, so a corrupted row surfaces as a decode error instead of an invalid value.
+
+* The integer, number, boolean and decoded base32/base64 validators are stored as their inner values.
+* The `uuid` validator is stored as `sqlx::types::Uuid`, which is a native UUID column in PostgreSQL and a 16-byte blob in SQLite.
+* The `mac_address` validator is stored as a 64-bit signed integer.
+* The `ip`, `ipv4` and `ipv6` validators without a port are stored as `IpAddr`, which is `INET` in PostgreSQL. Other databases do not support them.
+* The other validators are stored as the strings which they serialize to, such as the `to_email_string` of an email or the ISO 8601 form of a duration.
+
+The `length` validator, enums of alternatives, validators borrowing their input and phone validators of more than one country are not supported.
+
+```rust,ignore
+use sqlx::SqlitePool;
+use validators::prelude::*;
+
+#[derive(Debug, Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+async fn add_user(pool: &SqlitePool, email: Email) -> sqlx::Result<()> {
+    sqlx::query("INSERT INTO users (email) VALUES (?)").bind(email).execute(pool).await?;
+
+    Ok(())
+}
+
+async fn emails(pool: &SqlitePool) -> sqlx::Result<Vec<Email>> {
+    // fails with `sqlx::Error::ColumnDecode` if a row is not a valid email
+    sqlx::query_scalar("SELECT email FROM users").fetch_all(pool).await
+}
+```
+
+## Arbitrary
+
+With the `arbitrary` feature enabled, the validators implement the `Arbitrary` trait of the `arbitrary` crate, so fuzzers such as `cargo fuzz` can generate values which satisfy their parameters, such as ranges, lengths, cases and whether a port or an IP address is allowed. An input is generated from the parameters and then parsed by the validator, so a value is always valid. If the validator still rejects the input, for instance because of a custom check, `arbitrary::Error::IncorrectFormat` is returned.
+
+Enums of alternatives choose one of their variants. The `length` validator generates its items with their own `Arbitrary` implementations. The `regex` validator and validators borrowing their input are not supported. With `proptest`, the values can be used through the `proptest-arbitrary-interop` crate.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer", feature = "arbitrary"))]
+# {
+use validators::arbitrary::{Arbitrary, Unstructured};
+use validators::prelude::*;
+
+#[derive(Debug, Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+pub struct Port(u16);
+
+let port = Port::arbitrary(&mut Unstructured::new(&[0x12, 0x34, 0x56])).unwrap();
+
+assert!(port.0 >= 1);
+# }
+```
+
+## JSON Schema
+
+With the `schemars` feature enabled, every validator implements the `JsonSchema` trait of the `schemars` crate. The schema is generated from the parameters of the validator, so API documentation stays in sync with the validation rules.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "text", feature = "unsigned_integer", feature = "email", feature = "schemars"))]
+# {
+use validators::prelude::*;
+use validators::schemars::schema_for;
+
+#[derive(Validator)]
+#[validator(text(char_length(min = 1, max = 32)))]
+pub struct Name(String);
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1024, max = 65535))))]
+pub struct Port(u16);
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Disallow)))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+let schema = schema_for!(Name);
+
+assert_eq!("string", schema.get("type").unwrap());
+assert_eq!(1, *schema.get("minLength").unwrap());
+assert_eq!(32, *schema.get("maxLength").unwrap());
+
+let schema = schema_for!(Port);
+
+assert_eq!("integer", schema.get("type").unwrap());
+assert_eq!(1024, *schema.get("minimum").unwrap());
+assert_eq!(65535, *schema.get("maximum").unwrap());
+
+assert_eq!("email", schema_for!(Email).get("format").unwrap());
+# }
+```
+
+The keywords which are generated:
+
+* `type` for every validator, except `json` (any value) and `length` (any collection)
+* `minimum`, `maximum` and `exclusiveMaximum` from the `range` parameter of the `bit`, `byte`, `duration`, `number`, `signed_integer` and `unsigned_integer` validators. An `Outside` range becomes a `not` schema
+* `minLength` and `maxLength` from the `char_length` parameter of the `line` and `text` validators, and `minItems` and `maxItems` from the `length` validator
+* `format` for the `date`, `datetime`, `domain`, `duration`, `email`, `http_url`, `http_ftp_url`, `ipv4`, `ipv6`, `time`, `url` and `uuid` validators, unless the parameters allow values which the format rejects (for example, a `port` which must exist)
+* `pattern` for the `base64_url`, `line`, `mac_address`, `text` and `uuid` validators, and for the `regex` validator if the regular expression is a string literal
+* `contentEncoding` for the `base32` and `base64` validators
+* `anyOf` for enums of alternatives
+
+Other conditions, such as the `trimmed_min` length or the `local` parameter, cannot be written in JSON Schema, so the generated schema can accept some values which the validator rejects.
+
+## OpenAPI
+
+With the `utoipa` feature enabled, every validator implements the `PartialSchema` and `ToSchema` traits of the `utoipa` crate, so it can be used in the request bodies and parameters of OpenAPI documents, such as the ones of the handlers of the `axum` feature. The schema has the same keywords as the [JSON Schema](#json-schema), except that an `Outside` range cannot be expressed, and that the `length` validator becomes an array schema.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "uuid", feature = "length", feature = "utoipa"))]
+# {
+use validators::prelude::*;
+use validators::utoipa::{
+    PartialSchema, ToSchema,
+    openapi::{RefOr, schema::Schema},
+};
+
+#[derive(Validator)]
+#[validator(uuid(case(Upper), separator(Must(b'-'))))]
+pub struct Uuid(u128);
+
+#[derive(Validator)]
+#[validator(length(min = 1, max = 8))]
+pub struct Tags(Vec<String>);
+
+assert_eq!("Uuid", Uuid::name());
+
+let RefOr::T(Schema::Object(object)) = Uuid::schema() else { unreachable!() };
+
+assert_eq!(
+    Some("^[0-9A-F]{8}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{12}$"),
+    object.pattern.as_deref()
+);
+
+let RefOr::T(Schema::Array(array)) = Tags::schema() else { unreachable!() };
+
+assert_eq!(Some(1), array.min_items);
+assert_eq!(Some(8), array.max_items);
+# }
+```
 */
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -937,10 +1788,18 @@ let app: Router = Router::new().route("/email", post(handler));
 
 extern crate alloc;
 
+#[cfg(feature = "actix-web")]
+pub extern crate actix_web;
+#[cfg(feature = "arbitrary")]
+pub extern crate arbitrary;
 #[cfg(feature = "axum")]
 pub extern crate axum;
 #[cfg(feature = "byte-unit")]
 pub extern crate byte_unit;
+#[cfg(feature = "chrono")]
+pub extern crate chrono;
+#[cfg(feature = "clap")]
+pub extern crate clap;
 extern crate core;
 #[cfg(feature = "data-encoding")]
 pub extern crate data_encoding;
@@ -952,28 +1811,51 @@ pub extern crate phonenumber;
 pub extern crate regex_dep as regex;
 #[cfg(feature = "rocket")]
 pub extern crate rocket;
+#[cfg(feature = "schemars")]
+pub extern crate schemars;
 #[cfg(feature = "semver-dep")]
 pub extern crate semver_dep as semver;
 #[cfg(feature = "serde")]
 pub extern crate serde;
 #[cfg(feature = "serde_json")]
 pub extern crate serde_json;
+#[cfg(feature = "sqlx")]
+pub extern crate sqlx;
 #[cfg(feature = "str-utils")]
 pub extern crate str_utils;
+#[cfg(feature = "time-dep")]
+pub extern crate time_dep as time;
 #[cfg(feature = "url-dep")]
 pub extern crate url_dep as url;
+#[cfg(feature = "utoipa")]
+pub extern crate utoipa;
 
 /// Errors from validators.
 pub mod errors;
 /// Functions with validators.
 pub mod functions;
+/// Generators of inputs which satisfy the configurations of validators, used by the `Arbitrary` implementations.
+#[cfg(feature = "arbitrary")]
+pub mod generators;
+/// Translated messages of the errors.
+#[cfg(feature = "localization")]
+pub mod localization;
 /// Models with validators.
 pub mod models;
+/// Problem details responses for Axum.
+#[cfg(feature = "axum-problem-json")]
+pub mod problem;
+/// Validators configured at runtime, without the derive macro.
+#[cfg(any(feature = "runtime", feature = "test", feature = "arbitrary"))]
+pub mod runtime;
 #[doc(hidden)]
-#[cfg(feature = "test")]
+#[cfg(any(feature = "test", feature = "arbitrary"))]
 pub mod test;
 /// Module of traits.
 pub mod traits;
+/// Value parsers of command-line arguments for clap.
+#[cfg(feature = "clap")]
+pub mod value_parser;
 
 mod result;
 
@@ -981,6 +1863,65 @@ pub use result::*;
 #[cfg(feature = "derive")]
 pub use validators_derive::Validator;
 
+/**
+Parse a literal at compile time with the `const_new` function of a validator type, so that an invalid literal fails the build. The result can be used in const contexts as well.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "unsigned_integer"))]
+# {
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Port(u16);
+
+const DEFAULT_PORT: Port = validators::literal!(Port, "8080");
+
+assert_eq!(8080, DEFAULT_PORT.0);
+# }
+```
+ */
+#[macro_export]
+macro_rules! literal {
+    ($t:ty, $s:expr $(,)?) => {{
+        const LITERAL: $t = <$t>::const_new($s);
+
+        LITERAL
+    }};
+}
+
+/**
+An invalid literal fails the build.
+
+```rust,compile_fail
+use validators::prelude::*;
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1))))]
+pub struct Port(u16);
+
+let port = validators::literal!(Port, "0");
+```
+
+A validator with a `custom` function has no `const_new`, because the function would be skipped.
+
+```rust,compile_fail
+use validators::prelude::*;
+
+fn not_http(v: &Port) -> Result<(), &'static str> {
+    if v.0 == 8080 { Err("reserved") } else { Ok(()) }
+}
+
+#[derive(Validator)]
+#[validator(unsigned_integer(range(Inside(min = 1)), custom = not_http))]
+pub struct Port(u16);
+
+let port = validators::literal!(Port, "8080");
+```
+ */
+#[cfg(all(doctest, feature = "derive", feature = "unsigned_integer"))]
+pub struct LiteralCompileFail;
+
 /**
 A convenience module appropriate for glob imports.
 
@@ -991,13 +1932,21 @@ use validators::prelude::*;
 pub mod prelude {
     #[doc(hidden)]
     pub mod validators_prelude {
-        pub use alloc::{borrow::Cow, format, string::String, vec::Vec};
+        pub use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
 
+        #[cfg(feature = "actix-web")]
+        pub use crate::actix_web;
+        #[cfg(feature = "arbitrary")]
+        pub use crate::arbitrary;
         #[cfg(feature = "byte-unit")]
         pub use crate::byte_unit;
+        #[cfg(feature = "clap")]
+        pub use crate::clap;
         #[cfg(feature = "data-encoding")]
         pub use crate::data_encoding;
         pub use crate::errors::*;
+        #[cfg(feature = "arbitrary")]
+        pub use crate::generators;
         #[cfg(feature = "idna")]
         pub use crate::idna;
         #[cfg(feature = "phonenumber")]
@@ -1006,18 +1955,26 @@ pub mod prelude {
         pub use crate::regex;
         #[cfg(feature = "rocket")]
         pub use crate::rocket;
+        #[cfg(feature = "schemars")]
+        pub use crate::schemars;
         #[cfg(feature = "semver-dep")]
         pub use crate::semver;
         #[cfg(feature = "serde")]
         pub use crate::serde;
         #[cfg(feature = "serde_json")]
         pub use crate::serde_json;
+        #[cfg(feature = "sqlx")]
+        pub use crate::sqlx;
         #[cfg(feature = "str-utils")]
         pub use crate::str_utils;
-        #[cfg(feature = "test")]
+        #[cfg(any(feature = "test", feature = "arbitrary"))]
         pub use crate::test::*;
         #[cfg(feature = "url")]
         pub use crate::url;
+        #[cfg(feature = "utoipa")]
+        pub use crate::utoipa;
+        #[cfg(feature = "clap")]
+        pub use crate::value_parser::ValidatorValueParser;
         #[allow(unused_imports)]
         pub use crate::{functions::*, models::*};
     }
@@ -0,0 +1,376 @@
+use alloc::string::String;
+
+use super::{Catalog, format_template, lookup};
+use crate::traits::ErrorParam;
+
+/// The bundled translations of the messages of the validators, in German (`de`), Spanish (`es`), French (`fr`), Japanese (`ja`), Simplified Chinese (`zh-Hans`, `zh-CN`, `zh-SG`) and Traditional Chinese (`zh-Hant`, `zh-TW`, `zh-HK`, `zh-MO`). A bare `zh` means Simplified Chinese.
+///
+/// English is not included because the messages of the errors are already in English.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Builtin;
+
+impl Catalog for Builtin {
+    #[inline]
+    fn message(
+        &self,
+        locale: &str,
+        code: &str,
+        params: &[(&'static str, ErrorParam)],
+    ) -> Option<String> {
+        let table: fn(&str) -> Option<&'static str> = match locale.to_ascii_lowercase().as_str() {
+            "de" => de,
+            "es" => es,
+            "fr" => fr,
+            "ja" => ja,
+            "zh" | "zh-hans" | "zh-cn" | "zh-sg" => zh_hans,
+            "zh-hant" | "zh-tw" | "zh-hk" | "zh-mo" => zh_hant,
+            _ => return None,
+        };
+
+        lookup(code, params, |key| table(generic_key(key)))
+            .map(|template| format_template(template, params))
+    }
+}
+
+/// Map the failures which mean the same thing to the invalid input.
+fn generic_key(key: &str) -> &str {
+    match key {
+        "parse_error"
+        | "parse_int_error"
+        | "parse_float_error"
+        | "serde_json_error"
+        | "invalid_json_value_error"
+        | "failure" => "invalid",
+        _ => key,
+    }
+}
+
+fn de(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "einige Felder sind ungültig",
+        "invalid" => "ungültiger Wert",
+        "decode" => "die Daten können nicht dekodiert werden",
+        "protocol_error" => "nicht unterstütztes Protokoll",
+        "padding_must" => "Padding ist erforderlich",
+        "padding_disallow" => "Padding ist nicht erlaubt",
+        "local_must" => "muss lokal sein",
+        "local_disallow" => "darf nicht lokal sein",
+        "port_must" => "ein Port ist erforderlich",
+        "port_disallow" => "ein Port ist nicht erlaubt",
+        "at_least_two_labels_must" => "muss mindestens zwei Labels haben",
+        "at_least_two_labels_disallow" => "darf nur ein Label haben",
+        "ipv4_must" => "muss eine IPv4-Adresse sein",
+        "ipv4_disallow" => "darf keine IPv4-Adresse sein",
+        "ip_must" => "muss eine IP-Adresse verwenden",
+        "ip_disallow" => "darf keine IP-Adresse verwenden",
+        "comment_disallow" => "Kommentare sind nicht erlaubt",
+        "display_name_must" => "ein Anzeigename ist erforderlich",
+        "display_name_disallow" => "ein Anzeigename ist nicht erlaubt",
+        "domain_not_allowed" => "die Domain ist nicht erlaubt",
+        "domain_denied" => "die Domain ist gesperrt",
+        "registrable_must" => "muss unter einer registrierbaren Domain liegen",
+        "registrable_disallow" => "muss ein öffentliches Suffix sein",
+        "known_tld_must" => "muss eine bekannte TLD verwenden",
+        "too_large" => "der Wert ist zu groß",
+        "too_small" => "muss mindestens {min} sein",
+        "too_small.bare" => "der Wert ist zu klein",
+        "forbidden" => "der Wert ist nicht erlaubt",
+        "nan_must" => "muss NaN sein",
+        "nan_disallow" => "darf nicht NaN sein",
+        "too_long" => "ist zu lang (höchstens {max})",
+        "too_long.bare" => "ist zu lang",
+        "too_short" => "ist zu kurz",
+        "length.too_large" => "darf höchstens {max} Elemente enthalten",
+        "length.too_large.bare" => "enthält zu viele Elemente",
+        "length.too_small" => "muss mindestens {min} Elemente enthalten",
+        "length.too_small.bare" => "enthält zu wenige Elemente",
+        "duration.too_small" => "muss mindestens {min} Sekunden betragen",
+        "too_early" => "darf nicht vor {min} liegen",
+        "too_early.bare" => "ist zu früh",
+        "too_late" => "darf nicht nach {max} liegen",
+        "too_late.bare" => "ist zu spät",
+        "basic_format_must" => "muss im Basisformat angegeben sein",
+        "basic_format_disallow" => "darf nicht im Basisformat angegeben sein",
+        "timezone_must" => "eine Zeitzone ist erforderlich",
+        "timezone_disallow" => "eine Zeitzone ist nicht erlaubt",
+        "fraction_disallow" => "Sekundenbruchteile sind nicht erlaubt",
+        "separator_must" => "Trennzeichen sind erforderlich",
+        "separator_disallow" => "Trennzeichen sind nicht erlaubt",
+        _ => return None,
+    })
+}
+
+fn es(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "algunos campos no son válidos",
+        "invalid" => "valor no válido",
+        "decode" => "no se pueden decodificar los datos",
+        "protocol_error" => "protocolo no admitido",
+        "padding_must" => "se requiere relleno",
+        "padding_disallow" => "no se permite relleno",
+        "local_must" => "debe ser local",
+        "local_disallow" => "no debe ser local",
+        "port_must" => "se requiere un puerto",
+        "port_disallow" => "no se permite un puerto",
+        "at_least_two_labels_must" => "debe tener al menos dos etiquetas",
+        "at_least_two_labels_disallow" => "debe tener una sola etiqueta",
+        "ipv4_must" => "debe ser una dirección IPv4",
+        "ipv4_disallow" => "no debe ser una dirección IPv4",
+        "ip_must" => "debe usar una dirección IP",
+        "ip_disallow" => "no debe usar una dirección IP",
+        "comment_disallow" => "no se permiten comentarios",
+        "display_name_must" => "se requiere un nombre para mostrar",
+        "display_name_disallow" => "no se permite un nombre para mostrar",
+        "domain_not_allowed" => "el dominio no está permitido",
+        "domain_denied" => "el dominio está bloqueado",
+        "registrable_must" => "debe estar bajo un dominio registrable",
+        "registrable_disallow" => "debe ser un sufijo público",
+        "known_tld_must" => "debe usar un TLD conocido",
+        "too_large" => "el valor es demasiado grande",
+        "too_small" => "debe ser como mínimo {min}",
+        "too_small.bare" => "el valor es demasiado pequeño",
+        "forbidden" => "el valor no está permitido",
+        "nan_must" => "debe ser NaN",
+        "nan_disallow" => "no debe ser NaN",
+        "too_long" => "es demasiado largo (máximo {max})",
+        "too_long.bare" => "es demasiado largo",
+        "too_short" => "es demasiado corto",
+        "length.too_large" => "debe contener como máximo {max} elementos",
+        "length.too_large.bare" => "contiene demasiados elementos",
+        "length.too_small" => "debe contener al menos {min} elementos",
+        "length.too_small.bare" => "contiene muy pocos elementos",
+        "duration.too_small" => "debe ser de al menos {min} segundos",
+        "too_early" => "no debe ser anterior a {min}",
+        "too_early.bare" => "es demasiado pronto",
+        "too_late" => "no debe ser posterior a {max}",
+        "too_late.bare" => "es demasiado tarde",
+        "basic_format_must" => "debe estar en el formato básico",
+        "basic_format_disallow" => "no debe estar en el formato básico",
+        "timezone_must" => "se requiere una zona horaria",
+        "timezone_disallow" => "no se permite una zona horaria",
+        "fraction_disallow" => "no se permiten fracciones de segundo",
+        "separator_must" => "se requieren separadores",
+        "separator_disallow" => "no se permiten separadores",
+        _ => return None,
+    })
+}
+
+fn fr(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "certains champs sont invalides",
+        "invalid" => "valeur invalide",
+        "decode" => "impossible de décoder les données",
+        "protocol_error" => "protocole non pris en charge",
+        "padding_must" => "le remplissage est obligatoire",
+        "padding_disallow" => "le remplissage n'est pas autorisé",
+        "local_must" => "doit être local",
+        "local_disallow" => "ne doit pas être local",
+        "port_must" => "un port est obligatoire",
+        "port_disallow" => "un port n'est pas autorisé",
+        "at_least_two_labels_must" => "doit comporter au moins deux libellés",
+        "at_least_two_labels_disallow" => "doit comporter un seul libellé",
+        "ipv4_must" => "doit être une adresse IPv4",
+        "ipv4_disallow" => "ne doit pas être une adresse IPv4",
+        "ip_must" => "doit utiliser une adresse IP",
+        "ip_disallow" => "ne doit pas utiliser d'adresse IP",
+        "comment_disallow" => "les commentaires ne sont pas autorisés",
+        "display_name_must" => "un nom d'affichage est requis",
+        "display_name_disallow" => "un nom d'affichage n'est pas autorisé",
+        "domain_not_allowed" => "le domaine n'est pas autorisé",
+        "domain_denied" => "le domaine est bloqué",
+        "registrable_must" => "doit relever d'un domaine enregistrable",
+        "registrable_disallow" => "doit être un suffixe public",
+        "known_tld_must" => "doit utiliser un TLD connu",
+        "too_large" => "la valeur est trop grande",
+        "too_small" => "doit être au moins {min}",
+        "too_small.bare" => "la valeur est trop petite",
+        "forbidden" => "la valeur n'est pas autorisée",
+        "nan_must" => "doit être NaN",
+        "nan_disallow" => "ne doit pas être NaN",
+        "too_long" => "est trop long (maximum {max})",
+        "too_long.bare" => "est trop long",
+        "too_short" => "est trop court",
+        "length.too_large" => "doit contenir au plus {max} éléments",
+        "length.too_large.bare" => "contient trop d'éléments",
+        "length.too_small" => "doit contenir au moins {min} éléments",
+        "length.too_small.bare" => "ne contient pas assez d'éléments",
+        "duration.too_small" => "doit durer au moins {min} secondes",
+        "too_early" => "ne doit pas être antérieur à {min}",
+        "too_early.bare" => "est trop tôt",
+        "too_late" => "ne doit pas être postérieur à {max}",
+        "too_late.bare" => "est trop tard",
+        "basic_format_must" => "doit être au format de base",
+        "basic_format_disallow" => "ne doit pas être au format de base",
+        "timezone_must" => "un fuseau horaire est obligatoire",
+        "timezone_disallow" => "un fuseau horaire n'est pas autorisé",
+        "fraction_disallow" => "les fractions de seconde ne sont pas autorisées",
+        "separator_must" => "les séparateurs sont obligatoires",
+        "separator_disallow" => "les séparateurs ne sont pas autorisés",
+        _ => return None,
+    })
+}
+
+fn ja(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "無効なフィールドがあります",
+        "invalid" => "無効な値です",
+        "decode" => "データをデコードできません",
+        "protocol_error" => "サポートされていないプロトコルです",
+        "padding_must" => "パディングが必要です",
+        "padding_disallow" => "パディングは使用できません",
+        "local_must" => "ローカルである必要があります",
+        "local_disallow" => "ローカルであってはいけません",
+        "port_must" => "ポートが必要です",
+        "port_disallow" => "ポートは指定できません",
+        "at_least_two_labels_must" => "少なくとも2つのラベルが必要です",
+        "at_least_two_labels_disallow" => "ラベルは1つだけにしてください",
+        "ipv4_must" => "IPv4アドレスである必要があります",
+        "ipv4_disallow" => "IPv4アドレスは使用できません",
+        "ip_must" => "IPアドレスを使用する必要があります",
+        "ip_disallow" => "IPアドレスは使用できません",
+        "comment_disallow" => "コメントは使用できません",
+        "display_name_must" => "表示名が必要です",
+        "display_name_disallow" => "表示名は使用できません",
+        "domain_not_allowed" => "このドメインは許可されていません",
+        "domain_denied" => "このドメインは禁止されています",
+        "registrable_must" => "登録可能なドメインである必要があります",
+        "registrable_disallow" => "パブリックサフィックスである必要があります",
+        "known_tld_must" => "既知のTLDを使用する必要があります",
+        "too_large" => "値が大きすぎます",
+        "too_small" => "{min}以上である必要があります",
+        "too_small.bare" => "値が小さすぎます",
+        "forbidden" => "この値は使用できません",
+        "nan_must" => "NaNである必要があります",
+        "nan_disallow" => "NaNは使用できません",
+        "too_long" => "長すぎます（最大{max}）",
+        "too_long.bare" => "長すぎます",
+        "too_short" => "短すぎます",
+        "length.too_large" => "要素は{max}個以下である必要があります",
+        "length.too_large.bare" => "要素が多すぎます",
+        "length.too_small" => "要素は{min}個以上である必要があります",
+        "length.too_small.bare" => "要素が少なすぎます",
+        "duration.too_small" => "{min}秒以上である必要があります",
+        "too_early" => "{min}より前にはできません",
+        "too_early.bare" => "早すぎます",
+        "too_late" => "{max}より後にはできません",
+        "too_late.bare" => "遅すぎます",
+        "basic_format_must" => "基本形式で記述する必要があります",
+        "basic_format_disallow" => "基本形式は使用できません",
+        "timezone_must" => "タイムゾーンが必要です",
+        "timezone_disallow" => "タイムゾーンは指定できません",
+        "fraction_disallow" => "秒の小数部は使用できません",
+        "separator_must" => "区切り文字が必要です",
+        "separator_disallow" => "区切り文字は使用できません",
+        _ => return None,
+    })
+}
+
+fn zh_hans(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "部分字段无效",
+        "invalid" => "无效的值",
+        "decode" => "无法解码数据",
+        "protocol_error" => "不支持的协议",
+        "padding_must" => "必须有填充",
+        "padding_disallow" => "不允许填充",
+        "local_must" => "必须是本地地址",
+        "local_disallow" => "不能是本地地址",
+        "port_must" => "必须指定端口",
+        "port_disallow" => "不允许指定端口",
+        "at_least_two_labels_must" => "必须至少有两个标签",
+        "at_least_two_labels_disallow" => "只能有一个标签",
+        "ipv4_must" => "必须是 IPv4 地址",
+        "ipv4_disallow" => "不能是 IPv4 地址",
+        "ip_must" => "必须使用 IP 地址",
+        "ip_disallow" => "不能使用 IP 地址",
+        "comment_disallow" => "不允许注释",
+        "display_name_must" => "必须包含显示名称",
+        "display_name_disallow" => "不允许显示名称",
+        "domain_not_allowed" => "不允许该域名",
+        "domain_denied" => "该域名已被禁止",
+        "registrable_must" => "必须属于可注册的域名",
+        "registrable_disallow" => "必须是公共后缀",
+        "known_tld_must" => "必须使用已知的顶级域名",
+        "too_large" => "值太大",
+        "too_small" => "必须至少为 {min}",
+        "too_small.bare" => "值太小",
+        "forbidden" => "不允许此值",
+        "nan_must" => "必须是 NaN",
+        "nan_disallow" => "不能是 NaN",
+        "too_long" => "太长（最多 {max}）",
+        "too_long.bare" => "太长",
+        "too_short" => "太短",
+        "length.too_large" => "最多只能有 {max} 个元素",
+        "length.too_large.bare" => "元素太多",
+        "length.too_small" => "至少需要 {min} 个元素",
+        "length.too_small.bare" => "元素太少",
+        "duration.too_small" => "必须至少为 {min} 秒",
+        "too_early" => "不能早于 {min}",
+        "too_early.bare" => "太早",
+        "too_late" => "不能晚于 {max}",
+        "too_late.bare" => "太晚",
+        "basic_format_must" => "必须使用基本格式",
+        "basic_format_disallow" => "不能使用基本格式",
+        "timezone_must" => "必须指定时区",
+        "timezone_disallow" => "不允许指定时区",
+        "fraction_disallow" => "不允许秒的小数部分",
+        "separator_must" => "必须有分隔符",
+        "separator_disallow" => "不允许分隔符",
+        _ => return None,
+    })
+}
+
+fn zh_hant(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "validation" => "部分欄位無效",
+        "invalid" => "無效的值",
+        "decode" => "無法解碼資料",
+        "protocol_error" => "不支援的協定",
+        "padding_must" => "必須有填充",
+        "padding_disallow" => "不允許填充",
+        "local_must" => "必須是本機位址",
+        "local_disallow" => "不能是本機位址",
+        "port_must" => "必須指定連接埠",
+        "port_disallow" => "不允許指定連接埠",
+        "at_least_two_labels_must" => "必須至少有兩個標籤",
+        "at_least_two_labels_disallow" => "只能有一個標籤",
+        "ipv4_must" => "必須是 IPv4 位址",
+        "ipv4_disallow" => "不能是 IPv4 位址",
+        "ip_must" => "必須使用 IP 位址",
+        "ip_disallow" => "不能使用 IP 位址",
+        "comment_disallow" => "不允許註解",
+        "display_name_must" => "必須包含顯示名稱",
+        "display_name_disallow" => "不允許顯示名稱",
+        "domain_not_allowed" => "不允許該網域",
+        "domain_denied" => "該網域已被禁止",
+        "registrable_must" => "必須屬於可註冊的網域",
+        "registrable_disallow" => "必須是公共後綴",
+        "known_tld_must" => "必須使用已知的頂級網域",
+        "too_large" => "值太大",
+        "too_small" => "必須至少為 {min}",
+        "too_small.bare" => "值太小",
+        "forbidden" => "不允許此值",
+        "nan_must" => "必須是 NaN",
+        "nan_disallow" => "不能是 NaN",
+        "too_long" => "太長（最多 {max}）",
+        "too_long.bare" => "太長",
+        "too_short" => "太短",
+        "length.too_large" => "最多只能有 {max} 個元素",
+        "length.too_large.bare" => "元素太多",
+        "length.too_small" => "至少需要 {min} 個元素",
+        "length.too_small.bare" => "元素太少",
+        "duration.too_small" => "必須至少為 {min} 秒",
+        "too_early" => "不能早於 {min}",
+        "too_early.bare" => "太早",
+        "too_late" => "不能晚於 {max}",
+        "too_late.bare" => "太晚",
+        "basic_format_must" => "必須使用基本格式",
+        "basic_format_disallow" => "不能使用基本格式",
+        "timezone_must" => "必須指定時區",
+        "timezone_disallow" => "不允許指定時區",
+        "fraction_disallow" => "不允許秒的小數部分",
+        "separator_must" => "必須有分隔符號",
+        "separator_disallow" => "不允許分隔符號",
+        _ => return None,
+    })
+}
@@ -0,0 +1,287 @@
+#[cfg(feature = "actix-web")]
+mod actix_web_traits;
+#[cfg(feature = "axum")]
+mod axum_traits;
+mod builtin;
+#[cfg(feature = "rocket")]
+mod rocket_traits;
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+pub use self::builtin::*;
+use crate::traits::ErrorParam;
+
+/// A source of translated messages, keyed by a locale and the code of an error.
+///
+/// Implement this trait to plug in your own catalogs, for example one backed by Fluent files loaded from disk. Catalogs can be layered with a tuple, `(yours, Builtin)`, which asks the first one and falls back to the second one.
+///
+/// ```rust,ignore
+/// use validators::{localization::Catalog, traits::ErrorParam};
+///
+/// struct FluentCatalog { /* bundles loaded with the `fluent` crate */ }
+///
+/// impl Catalog for FluentCatalog {
+///     fn message(&self, locale: &str, code: &str, params: &[(&'static str, ErrorParam)]) -> Option<String> {
+///         let bundle = self.bundles.get(locale)?;
+///         let message = bundle.get_message(&code.replace('.', "-"))?;
+///         /* format the message with the parameters */
+///     }
+/// }
+/// ```
+pub trait Catalog {
+    /// Get the message of the error `code` in `locale`, which is a language tag such as `de` or `zh-Hant`. Return `None` if there is no translation.
+    fn message(
+        &self,
+        locale: &str,
+        code: &str,
+        params: &[(&'static str, ErrorParam)],
+    ) -> Option<String>;
+}
+
+impl<C: Catalog + ?Sized> Catalog for &C {
+    #[inline]
+    fn message(
+        &self,
+        locale: &str,
+        code: &str,
+        params: &[(&'static str, ErrorParam)],
+    ) -> Option<String> {
+        (**self).message(locale, code, params)
+    }
+}
+
+impl<A: Catalog, B: Catalog> Catalog for (A, B) {
+    #[inline]
+    fn message(
+        &self,
+        locale: &str,
+        code: &str,
+        params: &[(&'static str, ErrorParam)],
+    ) -> Option<String> {
+        self.0.message(locale, code, params).or_else(|| self.1.message(locale, code, params))
+    }
+}
+
+/// A catalog of message templates held in memory.
+///
+/// A template is looked up by the full code of an error, such as `text.too_long`, and then by the part after the validator name, such as `too_long`, so one template can cover the same failure of every validator. Placeholders such as `{max}` (or `{ $max }` in the Fluent style) are replaced with the parameters of the error. A template is skipped if the error does not have all of its parameters, which happens unless the error comes from a `*_detailed` method, and then the template of the code with `.bare` appended, such as `too_long.bare`, is used.
+///
+/// ```rust
+/// use validators::{localization::MapCatalog, traits::ErrorParam};
+/// # use validators::localization::Catalog;
+///
+/// let mut catalog = MapCatalog::new();
+///
+/// catalog.insert("nl", "too_long", "is te lang (maximaal {max})");
+///
+/// assert_eq!(
+///     Some("is te lang (maximaal 8)".to_string()),
+///     catalog.message("nl", "text.too_long", &[(
+///         "max",
+///         ErrorParam::Unsigned(8)
+///     )])
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MapCatalog {
+    templates: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MapCatalog {
+    /// Create an empty catalog.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            templates: BTreeMap::new()
+        }
+    }
+
+    /// Add or replace the template of `code` in `locale`. Locales are compared case-insensitively.
+    #[inline]
+    pub fn insert(
+        &mut self,
+        locale: &str,
+        code: impl Into<String>,
+        template: impl Into<String>,
+    ) -> &mut Self {
+        self.templates
+            .entry(locale.to_ascii_lowercase())
+            .or_default()
+            .insert(code.into(), template.into());
+
+        self
+    }
+}
+
+impl Catalog for MapCatalog {
+    #[inline]
+    fn message(
+        &self,
+        locale: &str,
+        code: &str,
+        params: &[(&'static str, ErrorParam)],
+    ) -> Option<String> {
+        let templates = self.templates.get(&locale.to_ascii_lowercase())?;
+
+        lookup(code, params, |key| templates.get(key).map(String::as_str))
+            .map(|template| format_template(template, params))
+    }
+}
+
+/// Replace the placeholders of `template`, `{name}` or `{ $name }`, with the parameters. Unknown placeholders are kept as they are.
+///
+/// ```rust
+/// use validators::{localization::format_template, traits::ErrorParam};
+///
+/// assert_eq!(
+///     "must be at least 18",
+///     format_template("must be at least { $min }", &[(
+///         "min",
+///         ErrorParam::Unsigned(18)
+///     )])
+/// );
+/// ```
+pub fn format_template(template: &str, params: &[(&'static str, ErrorParam)]) -> String {
+    let mut s = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        s.push_str(&rest[..start]);
+
+        let placeholder = &rest[start..];
+
+        let Some(end) = placeholder.find('}') else {
+            break;
+        };
+
+        let name = placeholder[1..end].trim();
+        let name = name.strip_prefix('$').unwrap_or(name);
+
+        match params.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => s.push_str(&value.to_string()),
+            None => s.push_str(&placeholder[..=end]),
+        }
+
+        rest = &placeholder[end + 1..];
+    }
+
+    s.push_str(rest);
+
+    s
+}
+
+/// The value of the `Accept-Language` header of a request, which is the list of the languages preferred by the client. An empty string if the header is missing.
+///
+/// With the `axum` feature, it can be used as an extractor. With the `rocket` feature, it can be used as a request guard.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AcceptLanguage(pub String);
+
+impl AcceptLanguage {
+    /// The header value, which can be passed to `Localize::localize`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// Look up a template by the full code and then by the part after the validator name. Templates with a placeholder which is not in the parameters are skipped, and the template of `<code>.bare` is looked up in the same way instead, for the errors which are reported without their details.
+pub(crate) fn lookup<'a>(
+    code: &str,
+    params: &[(&'static str, ErrorParam)],
+    get: impl Fn(&str) -> Option<&'a str>,
+) -> Option<&'a str> {
+    let get = |key: &str| get(key).filter(|template| has_params(template, params));
+
+    let find =
+        |code: &str| get(code).or_else(|| code.split_once('.').and_then(|(_, suffix)| get(suffix)));
+
+    find(code).or_else(|| find(&format!("{code}.bare")))
+}
+
+/// Whether every placeholder of `template` is in the parameters.
+fn has_params(template: &str, params: &[(&'static str, ErrorParam)]) -> bool {
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let placeholder = &rest[start..];
+
+        let Some(end) = placeholder.find('}') else {
+            break;
+        };
+
+        let name = placeholder[1..end].trim();
+        let name = name.strip_prefix('$').unwrap_or(name);
+
+        if !params.iter().any(|(n, _)| *n == name) {
+            return false;
+        }
+
+        rest = &placeholder[end + 1..];
+    }
+
+    true
+}
+
+/// Find a message in the catalog for the first language of `languages` that has one.
+///
+/// `languages` is a single language tag or a list of them in the `Accept-Language` format, such as `fr-CH, fr;q=0.9, en;q=0.8`. Every tag is also tried with its subtags removed one by one (`zh-Hant-TW`, `zh-Hant`, `zh`). Since the messages of the errors are written in English, `None` is returned as soon as an English tag is reached, which means the message of the error should be used.
+pub(crate) fn translate<C: Catalog + ?Sized>(
+    catalog: &C,
+    languages: &str,
+    code: &str,
+    params: &[(&'static str, ErrorParam)],
+) -> Option<String> {
+    for language in parse_languages(languages) {
+        let mut tag = language;
+
+        loop {
+            if let Some(message) = catalog.message(tag, code, params) {
+                return Some(message);
+            }
+
+            match tag.rfind('-') {
+                Some(index) => tag = &tag[..index],
+                None => break,
+            }
+        }
+
+        if tag.eq_ignore_ascii_case("en") {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Parse a list of languages in the `Accept-Language` format, sorted by their quality values. Wildcards and languages with a quality of zero are skipped.
+fn parse_languages(languages: &str) -> Vec<&str> {
+    let mut list: Vec<(&str, f32)> = languages
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+
+            let tag = parts.next()?.trim();
+
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+
+            let quality = parts
+                .filter_map(|part| part.trim().strip_prefix("q="))
+                .next()
+                .map_or(1.0, |q| q.trim().parse::<f32>().unwrap_or(0.0));
+
+            if quality > 0.0 { Some((tag, quality)) } else { None }
+        })
+        .collect();
+
+    // the sorting is stable, so languages with the same quality keep their order
+    list.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    list.into_iter().map(|(tag, _)| tag).collect()
+}
@@ -0,0 +1,17 @@
+use core::convert::Infallible;
+
+use rocket::request::{FromRequest, Outcome, Request};
+
+use super::AcceptLanguage;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AcceptLanguage {
+    type Error = Infallible;
+
+    #[inline]
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let value = request.headers().get_one("Accept-Language").unwrap_or_default();
+
+        Outcome::Success(Self(value.into()))
+    }
+}
@@ -0,0 +1,320 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter, Write},
+};
+
+/// A calendar date of the proleptic Gregorian calendar, from `0000-01-01` to `9999-12-31`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year:  u16,
+    pub month: u8,
+    pub day:   u8,
+}
+
+/// A time of day. `offset` is the offset from UTC in minutes, which is `None` for a local time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Time {
+    pub hour:       u8,
+    pub minute:     u8,
+    pub second:     u8,
+    pub nanosecond: u32,
+    pub offset:     Option<i16>,
+}
+
+/// A date and a time of day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Time,
+}
+
+/// Determine whether the year is a leap year.
+#[inline]
+pub const fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Get the number of days in the month of the year. `0` is returned if the month is not from 1 to 12.
+#[inline]
+pub const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        },
+        _ => 0,
+    }
+}
+
+impl Date {
+    /// Determine whether the month and the day exist.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.year <= 9999 && self.day >= 1 && self.day <= days_in_month(self.year, self.month)
+    }
+
+    /// The number of days since `1970-01-01`.
+    pub const fn days_since_unix_epoch(&self) -> i64 {
+        // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = self.month as i64;
+        let year = if month <= 2 { self.year as i64 - 1 } else { self.year as i64 };
+
+        let era = if year >= 0 { year } else { year - 399 } / 400;
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+}
+
+impl Time {
+    /// Determine whether the time and the offset exist. The second can be `60` for a leap second.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.hour < 24
+            && self.minute < 60
+            && self.second <= 60
+            && self.nanosecond < 1_000_000_000
+            && match self.offset {
+                Some(offset) => offset > -1440 && offset < 1440,
+                None => true,
+            }
+    }
+
+    /// The number of seconds since midnight, ignoring the offset.
+    #[inline]
+    pub const fn seconds_since_midnight(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+}
+
+impl DateTime {
+    /// Determine whether the date-time exists.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        self.date.is_valid() && self.time.is_valid()
+    }
+
+    /// The number of seconds since `1970-01-01T00:00:00Z`. A local date-time is treated as UTC.
+    #[inline]
+    pub const fn unix_timestamp(&self) -> i64 {
+        let offset = match self.time.offset {
+            Some(offset) => offset as i64 * 60,
+            None => 0,
+        };
+
+        self.date.days_since_unix_epoch() * 86400 + self.time.seconds_since_midnight() as i64
+            - offset
+    }
+
+    /// Compare the instants of two date-times. A local date-time is treated as UTC.
+    #[inline]
+    pub fn cmp_instant(&self, other: &Self) -> Ordering {
+        (self.unix_timestamp(), self.time.nanosecond)
+            .cmp(&(other.unix_timestamp(), other.time.nanosecond))
+    }
+}
+
+impl Display for Date {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{:04}-{:02}-{:02}", self.year, self.month, self.day))
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second))?;
+
+        if self.nanosecond > 0 {
+            let mut nanosecond = self.nanosecond;
+            let mut width = 9;
+
+            while nanosecond.is_multiple_of(10) {
+                nanosecond /= 10;
+                width -= 1;
+            }
+
+            f.write_fmt(format_args!(".{nanosecond:0width$}"))?;
+        }
+
+        match self.offset {
+            Some(0) => f.write_char('Z'),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+
+                f.write_fmt(format_args!("{sign}{:02}:{:02}", offset / 60, offset % 60))
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for DateTime {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{}T{}", self.date, self.time))
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "date"))]
+impl TryFrom<Date> for chrono::NaiveDate {
+    type Error = crate::errors::DateError;
+
+    #[inline]
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        chrono::NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
+            .ok_or(crate::errors::DateError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+impl TryFrom<Time> for chrono::NaiveTime {
+    type Error = crate::errors::TimeError;
+
+    /// The offset is ignored.
+    #[inline]
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        // chrono represents a leap second with a nanosecond value over one second
+        let (second, nanosecond) = if time.second == 60 {
+            (59, time.nanosecond + 1_000_000_000)
+        } else {
+            (time.second, time.nanosecond)
+        };
+
+        chrono::NaiveTime::from_hms_nano_opt(
+            time.hour as u32,
+            time.minute as u32,
+            second as u32,
+            nanosecond,
+        )
+        .ok_or(crate::errors::TimeError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "datetime"))]
+impl TryFrom<DateTime> for chrono::NaiveDateTime {
+    type Error = crate::errors::DateTimeError;
+
+    /// The offset is ignored.
+    #[inline]
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let date = chrono::NaiveDate::from_ymd_opt(
+            date_time.date.year as i32,
+            date_time.date.month as u32,
+            date_time.date.day as u32,
+        )
+        .ok_or(crate::errors::DateTimeError::Invalid)?;
+
+        let time = &date_time.time;
+
+        let (second, nanosecond) = if time.second == 60 {
+            (59, time.nanosecond + 1_000_000_000)
+        } else {
+            (time.second, time.nanosecond)
+        };
+
+        date.and_hms_nano_opt(time.hour as u32, time.minute as u32, second as u32, nanosecond)
+            .ok_or(crate::errors::DateTimeError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "datetime"))]
+impl TryFrom<DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = crate::errors::DateTimeError;
+
+    /// `DateTimeError::TimezoneMust` is returned for a local date-time.
+    #[inline]
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let offset = date_time.time.offset.ok_or(crate::errors::DateTimeError::TimezoneMust)?;
+
+        let offset = chrono::FixedOffset::east_opt(offset as i32 * 60)
+            .ok_or(crate::errors::DateTimeError::Invalid)?;
+
+        chrono::NaiveDateTime::try_from(date_time)?
+            .and_local_timezone(offset)
+            .single()
+            .ok_or(crate::errors::DateTimeError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "time-dep", feature = "date"))]
+impl TryFrom<Date> for time_dep::Date {
+    type Error = crate::errors::DateError;
+
+    #[inline]
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let month =
+            time_dep::Month::try_from(date.month).map_err(|_| crate::errors::DateError::Invalid)?;
+
+        time_dep::Date::from_calendar_date(date.year as i32, month, date.day)
+            .map_err(|_| crate::errors::DateError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "time-dep", feature = "time"))]
+impl TryFrom<Time> for time_dep::Time {
+    type Error = crate::errors::TimeError;
+
+    /// The offset is ignored. The `time` crate does not support leap seconds, so a leap second becomes the last nanosecond of the minute.
+    #[inline]
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        let (second, nanosecond) =
+            if time.second == 60 { (59, 999_999_999) } else { (time.second, time.nanosecond) };
+
+        time_dep::Time::from_hms_nano(time.hour, time.minute, second, nanosecond)
+            .map_err(|_| crate::errors::TimeError::Invalid)
+    }
+}
+
+#[cfg(all(feature = "time-dep", feature = "datetime"))]
+impl TryFrom<DateTime> for time_dep::PrimitiveDateTime {
+    type Error = crate::errors::DateTimeError;
+
+    /// The offset is ignored. The `time` crate does not support leap seconds, so a leap second becomes the last nanosecond of the minute.
+    #[inline]
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let DateTime {
+            date,
+            time,
+        } = date_time;
+
+        let month = time_dep::Month::try_from(date.month)
+            .map_err(|_| crate::errors::DateTimeError::Invalid)?;
+
+        let date = time_dep::Date::from_calendar_date(date.year as i32, month, date.day)
+            .map_err(|_| crate::errors::DateTimeError::Invalid)?;
+
+        let (second, nanosecond) =
+            if time.second == 60 { (59, 999_999_999) } else { (time.second, time.nanosecond) };
+
+        let time = time_dep::Time::from_hms_nano(time.hour, time.minute, second, nanosecond)
+            .map_err(|_| crate::errors::DateTimeError::Invalid)?;
+
+        Ok(time_dep::PrimitiveDateTime::new(date, time))
+    }
+}
+
+#[cfg(all(feature = "time-dep", feature = "datetime"))]
+impl TryFrom<DateTime> for time_dep::OffsetDateTime {
+    type Error = crate::errors::DateTimeError;
+
+    /// `DateTimeError::TimezoneMust` is returned for a local date-time.
+    #[inline]
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let offset = date_time.time.offset.ok_or(crate::errors::DateTimeError::TimezoneMust)?;
+
+        let offset = time_dep::UtcOffset::from_whole_seconds(offset as i32 * 60)
+            .map_err(|_| crate::errors::DateTimeError::Invalid)?;
+
+        Ok(time_dep::PrimitiveDateTime::try_from(date_time)?.assume_offset(offset))
+    }
+}
@@ -0,0 +1,63 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+/// Formats a `Duration` in the ISO 8601 format, such as `P1DT1H30M` or `PT0.5S`. The largest designator is the day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Iso8601Duration(pub Duration);
+
+impl Display for Iso8601Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let seconds = self.0.as_secs();
+        let nanoseconds = self.0.subsec_nanos();
+
+        let days = seconds / 86400;
+        let hours = seconds % 86400 / 3600;
+        let minutes = seconds % 3600 / 60;
+        let seconds = seconds % 60;
+
+        f.write_str("P")?;
+
+        if days > 0 {
+            f.write_fmt(format_args!("{days}D"))?;
+        }
+
+        if hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+            return if days == 0 { f.write_str("T0S") } else { Ok(()) };
+        }
+
+        f.write_str("T")?;
+
+        if hours > 0 {
+            f.write_fmt(format_args!("{hours}H"))?;
+        }
+
+        if minutes > 0 {
+            f.write_fmt(format_args!("{minutes}M"))?;
+        }
+
+        if nanoseconds > 0 {
+            let mut nanoseconds = nanoseconds;
+            let mut width = 9;
+
+            while nanoseconds.is_multiple_of(10) {
+                nanoseconds /= 10;
+                width -= 1;
+            }
+
+            f.write_fmt(format_args!("{seconds}.{nanoseconds:0width$}S"))
+        } else if seconds > 0 {
+            f.write_fmt(format_args!("{seconds}S"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<Duration> for Iso8601Duration {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
@@ -3,7 +3,29 @@ mod host;
 #[cfg(any(feature = "email", feature = "host"))]
 pub use host::*;
 
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+mod public_suffix_list;
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+mod public_suffix_table;
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+pub use public_suffix_list::*;
+
+#[cfg(any(feature = "domain", feature = "email"))]
+mod tld_list;
+#[cfg(any(feature = "domain", feature = "email"))]
+pub use tld_list::*;
+
 #[cfg(feature = "http_ftp_url")]
 mod protocol;
 #[cfg(feature = "http_ftp_url")]
 pub use protocol::*;
+
+#[cfg(any(feature = "date", feature = "time", feature = "datetime"))]
+mod date_time;
+#[cfg(any(feature = "date", feature = "time", feature = "datetime"))]
+pub use date_time::*;
+
+#[cfg(feature = "duration")]
+mod duration;
+#[cfg(feature = "duration")]
+pub use duration::*;
@@ -0,0 +1,219 @@
+use std::{io, path::Path, string::String, sync::OnceLock, vec::Vec};
+
+use super::public_suffix_table;
+
+static GLOBAL: OnceLock<PublicSuffixList> = OnceLock::new();
+
+/// Rules in lowercase ASCII, with IDNs in Punycode, sorted for binary search.
+#[derive(Debug, Clone)]
+enum Rules {
+    /// A table generated by `tools/update-data`.
+    Static(&'static [&'static str]),
+    Owned(Vec<String>),
+}
+
+impl Default for Rules {
+    #[inline]
+    fn default() -> Self {
+        Self::Owned(Vec::new())
+    }
+}
+
+impl Rules {
+    #[inline]
+    fn from_vec(mut rules: Vec<String>) -> Self {
+        rules.sort_unstable();
+        rules.dedup();
+
+        Self::Owned(rules)
+    }
+
+    #[inline]
+    fn contains(&self, rule: &str) -> bool {
+        match self {
+            Self::Static(rules) => rules.binary_search(&rule).is_ok(),
+            Self::Owned(rules) => rules.binary_search_by(|e| e.as_str().cmp(rule)).is_ok(),
+        }
+    }
+}
+
+/// The [Public Suffix List](https://publicsuffix.org/), which decides the public suffixes (eTLDs) such as `com` and `co.uk`, under which domains can be registered. Both the ICANN and the private sections are used.
+///
+/// The list embedded in this crate is used unless another one is installed by `install`, such as a newer list loaded by `from_file`.
+#[derive(Debug, Clone, Default)]
+pub struct PublicSuffixList {
+    /// The normal rules, such as `co.uk`.
+    rules:      Rules,
+    /// The domains under which every label is a public suffix, such as `ck` for `*.ck`.
+    wildcards:  Rules,
+    /// The exceptions to the wildcards, such as `www.ck` for `!www.ck`.
+    exceptions: Rules,
+}
+
+impl PublicSuffixList {
+    /// Parse a list in the format of `public_suffix_list.dat`. Comments and the rules which cannot be encoded by IDNA are skipped.
+    pub fn parse(s: &str) -> Self {
+        let mut rules = Vec::new();
+        let mut wildcards = Vec::new();
+        let mut exceptions = Vec::new();
+
+        for line in s.lines() {
+            // a rule ends at the first whitespace
+            let Some(rule) = line.split_whitespace().next() else {
+                continue;
+            };
+
+            if rule.starts_with("//") {
+                continue;
+            }
+
+            let (table, rule) = if let Some(rule) = rule.strip_prefix('!') {
+                (&mut exceptions, rule)
+            } else if let Some(rule) = rule.strip_prefix("*.") {
+                (&mut wildcards, rule)
+            } else {
+                (&mut rules, rule)
+            };
+
+            let rule = if rule.is_ascii() {
+                rule.to_ascii_lowercase()
+            } else {
+                match idna::domain_to_ascii(rule) {
+                    Ok(rule) => rule,
+                    Err(_) => continue,
+                }
+            };
+
+            table.push(rule);
+        }
+
+        Self {
+            rules:      Rules::from_vec(rules),
+            wildcards:  Rules::from_vec(wildcards),
+            exceptions: Rules::from_vec(exceptions),
+        }
+    }
+
+    /// Load a list in the format of `public_suffix_list.dat` from a file, such as the latest one from <https://publicsuffix.org/list/public_suffix_list.dat>.
+    #[inline]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// The list embedded in this crate, which is a static table generated by `tools/update-data`, so it costs nothing to create.
+    #[inline]
+    pub const fn builtin() -> Self {
+        Self {
+            rules:      Rules::Static(public_suffix_table::RULES),
+            wildcards:  Rules::Static(public_suffix_table::WILDCARDS),
+            exceptions: Rules::Static(public_suffix_table::EXCEPTIONS),
+        }
+    }
+
+    /// Use this list for the `registrable` option, the `RegistrableDomain` trait and the functions of public suffixes. It has to be called before any of them is used, or `false` is returned and this list is dropped.
+    #[inline]
+    pub fn install(self) -> bool {
+        GLOBAL.set(self).is_ok()
+    }
+
+    /// The list which is installed, or the embedded one if no list has been installed.
+    #[inline]
+    pub fn global() -> &'static Self {
+        GLOBAL.get_or_init(Self::builtin)
+    }
+
+    /// Count the labels of the public suffix of a domain in lowercase ASCII without the trailing dot. A domain whose TLD is not in the list has its TLD as the public suffix.
+    fn count_suffix_labels(&self, domain: &str) -> usize {
+        let labels = domain.split('.').count();
+
+        let mut suffix = domain;
+
+        for i in 0..labels {
+            let suffix_labels = labels - i;
+
+            if self.exceptions.contains(suffix) {
+                return suffix_labels - 1;
+            }
+
+            if self.rules.contains(suffix) {
+                return suffix_labels;
+            }
+
+            let parent = suffix.split_once('.').map(|(_, parent)| parent);
+
+            if let Some(parent) = parent
+                && self.wildcards.contains(parent)
+            {
+                return suffix_labels;
+            }
+
+            match parent {
+                Some(parent) => suffix = parent,
+                None => break,
+            }
+        }
+
+        1
+    }
+
+    #[inline]
+    fn label_count(domain: &str) -> usize {
+        domain.split('.').count()
+    }
+
+    /// Get the last `labels` labels of a domain, or `None` if it does not have so many labels.
+    #[inline]
+    fn last_labels(domain: &str, labels: usize) -> Option<&str> {
+        if labels == 0 {
+            return None;
+        }
+
+        match domain.rmatch_indices('.').nth(labels - 1) {
+            Some((index, _)) => Some(&domain[(index + 1)..]),
+            None if Self::label_count(domain) == labels => Some(domain),
+            None => None,
+        }
+    }
+
+    /// Count the labels of the public suffix of a domain, which can be in Unicode but has no trailing dot. `None` is returned for an empty domain, an empty label or a domain which cannot be encoded by IDNA.
+    fn suffix_labels(&self, domain: &str) -> Option<usize> {
+        if domain.is_empty() || domain.split('.').any(|label| label.is_empty()) {
+            return None;
+        }
+
+        let count = if domain.is_ascii() {
+            self.count_suffix_labels(&domain.to_ascii_lowercase())
+        } else {
+            let ascii_domain = idna::domain_to_ascii(domain).ok()?;
+
+            // the labels must be the same after the encoding to be mapped back
+            if Self::label_count(&ascii_domain) != Self::label_count(domain) {
+                return None;
+            }
+
+            self.count_suffix_labels(&ascii_domain)
+        };
+
+        Some(count)
+    }
+
+    /// Get the public suffix (eTLD) of a domain, such as `co.uk` of `www.example.co.uk`. The domain can be in Unicode and can have a trailing dot, which is not included in the result.
+    #[inline]
+    pub fn public_suffix<'a>(&self, domain: &'a str) -> Option<&'a str> {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+        let labels = self.suffix_labels(domain)?;
+
+        Self::last_labels(domain, labels)
+    }
+
+    /// Get the registrable domain (eTLD+1) of a domain, such as `example.co.uk` of `www.example.co.uk`. `None` is returned if the domain is a public suffix itself. The domain can be in Unicode and can have a trailing dot, which is not included in the result.
+    #[inline]
+    pub fn registrable_domain<'a>(&self, domain: &'a str) -> Option<&'a str> {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+        let labels = self.suffix_labels(domain)?;
+
+        Self::last_labels(domain, labels + 1)
+    }
+}
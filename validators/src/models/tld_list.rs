@@ -0,0 +1,132 @@
+use std::{collections::HashSet, io, path::Path, string::String, sync::OnceLock};
+
+/// The embedded list, in the format of `tlds-alpha-by-domain.txt` of IANA.
+const BUILTIN: &str = include_str!("tlds-alpha-by-domain.txt");
+
+static GLOBAL: OnceLock<TLDList> = OnceLock::new();
+
+/// The top-level domains delegated in the [root zone](https://www.iana.org/domains/root/db), which decide whether the last label of a domain is a known TLD, so that a typo such as `example.con` can be found.
+///
+/// The list embedded in this crate is used unless another one is installed by `install`, such as a newer list loaded by `from_file`.
+#[derive(Debug, Clone, Default)]
+pub struct TLDList {
+    /// The TLDs in lowercase ASCII, with IDNs in Punycode.
+    tlds:    HashSet<String>,
+    /// The version of the list, from its `# Version` line.
+    version: Option<String>,
+}
+
+impl TLDList {
+    /// Parse a list in the format of [`tlds-alpha-by-domain.txt`](https://data.iana.org/TLD/tlds-alpha-by-domain.txt), with one TLD per line. Comments starting with `#` and the TLDs which cannot be encoded by IDNA are skipped, except that the version is taken from a comment such as `# Version 2024061000, Last Updated Mon Jun 10 07:07:01 2024 UTC`.
+    pub fn parse(s: &str) -> Self {
+        let mut list = Self::default();
+
+        for line in s.lines() {
+            let Some(tld) = line.split_whitespace().next() else {
+                continue;
+            };
+
+            if tld.starts_with('#') {
+                if list.version.is_none() {
+                    list.version = Self::parse_version(line);
+                }
+
+                continue;
+            }
+
+            if let Some(tld) = Self::to_ascii_tld(tld) {
+                list.tlds.insert(tld);
+            }
+        }
+
+        list
+    }
+
+    /// Load a list in the format of `tlds-alpha-by-domain.txt` from a file, such as the latest one from <https://data.iana.org/TLD/tlds-alpha-by-domain.txt>.
+    #[inline]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// The list embedded in this crate.
+    #[inline]
+    pub fn builtin() -> Self {
+        Self::parse(BUILTIN)
+    }
+
+    /// Use this list for the `known_tld` option and the `is_known_tld` function. It has to be called before any of them is used, or `false` is returned and this list is dropped.
+    #[inline]
+    pub fn install(self) -> bool {
+        GLOBAL.set(self).is_ok()
+    }
+
+    /// The list which is installed, or the embedded one if no list has been installed.
+    #[inline]
+    pub fn global() -> &'static Self {
+        GLOBAL.get_or_init(Self::builtin)
+    }
+
+    /// Get the version from a line such as `# Version 2024061000, Last Updated ...`.
+    #[inline]
+    fn parse_version(line: &str) -> Option<String> {
+        let version = line.strip_prefix('#')?.trim_start().strip_prefix("Version ")?;
+
+        let version = version.split(',').next().unwrap_or(version).trim();
+
+        if version.is_empty() { None } else { Some(String::from(version)) }
+    }
+
+    /// Convert a TLD to lowercase ASCII, with an IDN in Punycode. `None` is returned for an empty TLD or a TLD which cannot be encoded by IDNA.
+    #[inline]
+    fn to_ascii_tld(tld: &str) -> Option<String> {
+        if tld.is_empty() {
+            return None;
+        }
+
+        if tld.is_ascii() {
+            Some(tld.to_ascii_lowercase())
+        } else {
+            idna::domain_to_ascii(tld).ok()
+        }
+    }
+
+    /// Determine whether a TLD, such as `com` or `台灣`, is in this list. The TLD is case-insensitive and can have a trailing dot.
+    #[inline]
+    pub fn contains(&self, tld: &str) -> bool {
+        let tld = tld.strip_suffix('.').unwrap_or(tld);
+
+        if tld.contains('.') {
+            return false;
+        }
+
+        Self::to_ascii_tld(tld).is_some_and(|tld| self.tlds.contains(&tld))
+    }
+
+    /// Determine whether the last label of a domain, such as `com` of `www.example.com`, is in this list. The domain can be in Unicode and can have a trailing dot.
+    #[inline]
+    pub fn contains_tld_of(&self, domain: &str) -> bool {
+        let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+        let tld = domain.rsplit('.').next().unwrap_or(domain);
+
+        self.contains(tld)
+    }
+
+    /// The version of this list, such as `2024061000` (`YYYYMMDDHH`) of an IANA list. `None` is returned if the list has no `# Version` line, which is the case for the embedded list until it is replaced by an IANA one.
+    #[inline]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// The number of the TLDs in this list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tlds.len()
+    }
+
+    /// Determine whether this list has no TLD.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tlds.is_empty()
+    }
+}
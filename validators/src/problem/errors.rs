@@ -0,0 +1,12 @@
+use alloc::string::ToString;
+
+use super::Problem;
+use crate::traits::ValidatorError;
+
+impl<E: ValidatorError> From<E> for Problem {
+    #[inline]
+    fn from(error: E) -> Self {
+        Problem::validation(error.code(), error.validator(), error.to_string())
+            .with_params(error.params())
+    }
+}
@@ -0,0 +1,225 @@
+mod errors;
+mod rejection;
+
+use alloc::{string::String, vec::Vec};
+
+use axum::{
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+pub use self::rejection::*;
+use crate::{
+    errors::{ValidationErrors, validation_errors::serde_traits::Params},
+    traits::ErrorParam,
+};
+
+/// The media type of the responses rendered by `Problem`.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// An error response in the problem details format of [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457), rendered as `application/problem+json`.
+///
+/// Every `ValidatorError` can be converted into a `Problem`, so handlers can return `Result<_, Problem>` and use the `?` operator. Besides the standard members, the body has a stable `code` such as `email.ip_disallow`, the name of the `validator`, the `params` of the failure, the `path` of the field if the failure comes from an extractor, and the failures of every field if the error is a `ValidationErrors` report.
+///
+/// ```json
+/// {
+///     "type": "about:blank",
+///     "title": "Bad Request",
+///     "status": 400,
+///     "detail": "must not use an IP",
+///     "code": "email.ip_disallow",
+///     "validator": "email"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Problem {
+    status:    StatusCode,
+    code:      &'static str,
+    validator: Option<&'static str>,
+    detail:    String,
+    params:    Vec<(&'static str, ErrorParam)>,
+    path:      Option<String>,
+    errors:    Option<ValidationErrors>,
+}
+
+impl Problem {
+    /// Create a problem which is not related to a particular validator.
+    #[inline]
+    pub fn new(status: StatusCode, code: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            validator: None,
+            detail: detail.into(),
+            params: Vec::new(),
+            path: None,
+            errors: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn validation(
+        code: &'static str,
+        validator: &'static str,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            validator: Some(validator),
+            ..Self::new(StatusCode::BAD_REQUEST, code, detail)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_params(mut self, params: Vec<(&'static str, ErrorParam)>) -> Self {
+        self.params = params;
+
+        self
+    }
+
+    #[inline]
+    pub(crate) fn with_path(mut self, path: Option<String>) -> Self {
+        self.path = path;
+
+        self
+    }
+
+    /// Change the status code, which is `400 Bad Request` for validator errors.
+    #[inline]
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+
+        self
+    }
+
+    /// The HTTP status code.
+    #[inline]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The stable code of the problem, such as `email.ip_disallow`.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The name of the validator which rejected the input, such as `email`.
+    #[inline]
+    pub const fn validator(&self) -> Option<&'static str> {
+        self.validator
+    }
+
+    /// The human-readable explanation.
+    #[inline]
+    pub fn detail(&self) -> &str {
+        self.detail.as_str()
+    }
+
+    /// The parameters of the failure, such as the configured `max` of `TextError::TooLong`.
+    #[inline]
+    pub fn params(&self) -> &[(&'static str, ErrorParam)] {
+        self.params.as_slice()
+    }
+
+    /// The path of the field which a validator rejected while an extractor deserialized the request, such as `user.addresses[2].zip`.
+    #[inline]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// The failures of every field, if the problem comes from a `ValidationErrors` report.
+    #[inline]
+    pub fn errors(&self) -> Option<&ValidationErrors> {
+        self.errors.as_ref()
+    }
+
+    /// Translate the `detail` and the messages of the failures of every field with the bundled translations. See `Localize` for the format of `languages`.
+    #[cfg(feature = "localization")]
+    #[inline]
+    pub fn localize(self, languages: &str) -> Self {
+        self.localize_with(&crate::localization::Builtin, languages)
+    }
+
+    /// Translate the `detail` and the messages of the failures of every field with the given catalog. See `Localize` for the format of `languages`.
+    #[cfg(feature = "localization")]
+    pub fn localize_with<C: crate::localization::Catalog + ?Sized>(
+        mut self,
+        catalog: &C,
+        languages: &str,
+    ) -> Self {
+        if let Some(detail) =
+            crate::localization::translate(catalog, languages, self.code, &self.params)
+        {
+            self.detail = detail;
+        }
+
+        if let Some(errors) = self.errors.as_mut() {
+            errors.localize_with(catalog, languages);
+        }
+
+        self
+    }
+}
+
+impl Serialize for Problem {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = 5
+            + usize::from(self.validator.is_some())
+            + usize::from(!self.params.is_empty())
+            + usize::from(self.path.is_some())
+            + usize::from(self.errors.is_some());
+
+        let mut s = serializer.serialize_struct("Problem", len)?;
+
+        s.serialize_field("type", "about:blank")?;
+        s.serialize_field("title", self.status.canonical_reason().unwrap_or_default())?;
+        s.serialize_field("status", &self.status.as_u16())?;
+        s.serialize_field("detail", &self.detail)?;
+        s.serialize_field("code", self.code)?;
+
+        if let Some(validator) = self.validator {
+            s.serialize_field("validator", validator)?;
+        }
+
+        if !self.params.is_empty() {
+            s.serialize_field("params", &Params(&self.params))?;
+        }
+
+        if let Some(path) = self.path.as_ref() {
+            s.serialize_field("path", path)?;
+        }
+
+        if let Some(errors) = self.errors.as_ref() {
+            s.serialize_field("errors", errors)?;
+        }
+
+        s.end()
+    }
+}
+
+impl IntoResponse for Problem {
+    #[inline]
+    fn into_response(self) -> Response {
+        match serde_json::to_vec(&self) {
+            Ok(body) => (
+                self.status,
+                [(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON))],
+                body,
+            )
+                .into_response(),
+            Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+        }
+    }
+}
+
+impl From<ValidationErrors> for Problem {
+    #[inline]
+    fn from(errors: ValidationErrors) -> Self {
+        Self {
+            errors: Some(errors),
+            ..Self::new(StatusCode::BAD_REQUEST, "validation", "some fields are invalid")
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cell::RefCell,
+    error::Error,
+    future::{Future, poll_fn},
+    ops::{Deref, DerefMut},
+    pin::pin,
+};
+
+use axum::{
+    extract::{
+        FromRequest, FromRequestParts, Request,
+        path::ErrorKind,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+    },
+    http::request::Parts,
+};
+
+use super::Problem;
+use crate::traits::{ErrorParam, ValidatorError};
+
+/// The last error of a validator which failed while deserializing.
+struct Recorded {
+    code:      &'static str,
+    validator: &'static str,
+    detail:    String,
+    params:    Vec<(&'static str, ErrorParam)>,
+}
+
+std::thread_local! {
+    /// The slot of the extractor which `WithProblem` is polling in the current thread, or `None` if no extractor is being polled.
+    static RECORDED: RefCell<Option<Option<Recorded>>> = const { RefCell::new(None) };
+}
+
+/// Record the error of a validator which fails while deserializing, if an extractor wrapped by `WithProblem` is being polled in the current thread. Otherwise, nothing is done.
+#[inline]
+pub(crate) fn record<E: ValidatorError>(error: &E) {
+    RECORDED.with_borrow_mut(|slot| {
+        if let Some(slot) = slot {
+            *slot = Some(Recorded {
+                code:      error.code(),
+                validator: error.validator(),
+                detail:    error.to_string(),
+                params:    error.params(),
+            });
+        }
+    });
+}
+
+/// Call `f` with an empty slot for the errors of the validators. An extractor deserializes a request and returns its rejection in the same poll, so `f` polls the extractor and converts the rejection, while the error which caused it is still in the slot.
+#[inline]
+fn capture<T>(f: impl FnOnce() -> T) -> T {
+    let outer = RECORDED.with_borrow_mut(|slot| slot.replace(None));
+
+    let result = f();
+
+    RECORDED.with_borrow_mut(|slot| *slot = outer);
+
+    result
+}
+
+/// Take the recorded error if `is_from` confirms that the deserialization error is made from its message. Otherwise, the recorded error is stale, such as one which a `#[serde(untagged)]` enum has recovered from.
+fn take_recorded(is_from: impl FnOnce(&str) -> bool) -> Option<Recorded> {
+    RECORDED
+        .with_borrow_mut(|slot| slot.as_mut().and_then(Option::take))
+        .filter(|recorded| is_from(recorded.detail.as_str()))
+}
+
+/// Get the failure of a validator out of a deserialization error, which is wrapped by `serde_path_to_error` in the source of `error`.
+fn validator_problem<E: Error + 'static>(
+    error: &(dyn Error + 'static),
+    status: axum::http::StatusCode,
+    is_from: impl FnOnce(&E, &str) -> bool,
+) -> Option<Problem> {
+    let error = error.source()?.source()?.downcast_ref::<serde_path_to_error::Error<E>>()?;
+
+    let recorded = take_recorded(|detail| is_from(error.inner(), detail))?;
+
+    let path = error.path().to_string();
+
+    Some(recorded.into_problem(status, if path == "." { None } else { Some(path) }))
+}
+
+impl Recorded {
+    #[inline]
+    fn into_problem(self, status: axum::http::StatusCode, path: Option<String>) -> Problem {
+        Problem::validation(self.code, self.validator, self.detail)
+            .with_params(self.params)
+            .with_status(status)
+            .with_path(path)
+    }
+}
+
+impl From<JsonRejection> for Problem {
+    #[inline]
+    fn from(rejection: JsonRejection) -> Self {
+        if let JsonRejection::JsonDataError(error) = &rejection
+            && let Some(problem) =
+                validator_problem(error, rejection.status(), |error: &serde_json::Error, detail| {
+                    // the position is appended to a custom message
+                    if error.line() == 0 {
+                        error.to_string() == detail
+                    } else {
+                        error.to_string()
+                            == format!(
+                                "{detail} at line {} column {}",
+                                error.line(),
+                                error.column()
+                            )
+                    }
+                })
+        {
+            return problem;
+        }
+
+        let code = match &rejection {
+            JsonRejection::JsonDataError(_) => "extract.json_data",
+            JsonRejection::JsonSyntaxError(_) => "extract.json_syntax",
+            JsonRejection::MissingJsonContentType(_) => "extract.json_content_type",
+            _ => "extract.body",
+        };
+
+        Problem::new(rejection.status(), code, rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for Problem {
+    #[inline]
+    fn from(rejection: QueryRejection) -> Self {
+        if let QueryRejection::FailedToDeserializeQueryString(error) = &rejection
+            && let Some(problem) = validator_problem(
+                error,
+                rejection.status(),
+                |error: &serde_urlencoded::de::Error, detail| error.to_string() == detail,
+            )
+        {
+            return problem;
+        }
+
+        Problem::new(rejection.status(), "extract.query", rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for Problem {
+    #[inline]
+    fn from(rejection: PathRejection) -> Self {
+        if let PathRejection::FailedToDeserializePathParams(error) = &rejection {
+            let recorded = match error.kind() {
+                ErrorKind::DeserializeError {
+                    key,
+                    message,
+                    ..
+                } => take_recorded(|detail| message == detail)
+                    .map(|recorded| (recorded, Some(key.clone()))),
+                ErrorKind::Message(message) => {
+                    take_recorded(|detail| message == detail).map(|recorded| (recorded, None))
+                },
+                _ => None,
+            };
+
+            if let Some((recorded, path)) = recorded {
+                return recorded.into_problem(rejection.status(), path);
+            }
+        }
+
+        let code = match &rejection {
+            PathRejection::MissingPathParams(_) => "extract.path_missing",
+            _ => "extract.path",
+        };
+
+        Problem::new(rejection.status(), code, rejection.body_text())
+    }
+}
+
+/// Wrap an extractor, such as `Json`, `Query` or `Path`, so that its rejection is rendered as a `Problem`.
+///
+/// Validators are checked while the extractor deserializes the request. If one of them fails, the problem has its code, such as `email.ip_disallow`, its parameters, and the `path` of the field, such as `user.email`, with the status of the rejection. Other failures, and rejections which are converted without `WithProblem`, become `extract.*` problems.
+///
+/// ```rust,ignore
+/// use axum::extract::Json;
+/// use validators::problem::WithProblem;
+///
+/// async fn create_user(WithProblem(Json(user)): WithProblem<Json<User>>) { /* ... */ }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WithProblem<E>(pub E);
+
+impl<E> WithProblem<E> {
+    /// Unwrap the extractor.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> Deref for WithProblem<E> {
+    type Target = E;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E> DerefMut for WithProblem<E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Poll an extractor, recording the errors of the validators which fail in each poll, so that its rejection becomes a `Problem` with the code of the validator.
+#[allow(clippy::result_large_err)] // `Problem` is the rejection of `WithProblem` anyway
+async fn poll_extractor<E, R>(
+    extractor: impl Future<Output = Result<E, R>>,
+) -> Result<WithProblem<E>, Problem>
+where
+    Problem: From<R>, {
+    let mut extractor = pin!(extractor);
+
+    poll_fn(|cx| {
+        capture(|| {
+            extractor.as_mut().poll(cx).map(|result| result.map(WithProblem).map_err(Problem::from))
+        })
+    })
+    .await
+}
+
+impl<S, E> FromRequest<S> for WithProblem<E>
+where
+    S: Send + Sync,
+    E: FromRequest<S>,
+    Problem: From<E::Rejection>,
+{
+    type Rejection = Problem;
+
+    #[inline]
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        poll_extractor(E::from_request(req, state)).await
+    }
+}
+
+impl<S, E> FromRequestParts<S> for WithProblem<E>
+where
+    S: Send + Sync,
+    E: FromRequestParts<S>,
+    Problem: From<E::Rejection>,
+{
+    type Rejection = Problem;
+
+    #[inline]
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        poll_extractor(E::from_request_parts(parts, state)).await
+    }
+}
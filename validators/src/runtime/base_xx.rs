@@ -0,0 +1,189 @@
+use alloc::string::String;
+
+use super::TriAllow;
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[cfg(feature = "base32")]
+    #[derive(Validator)]
+    #[validator(base32(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base32(pub(super) validators_prelude::String);
+
+    #[cfg(feature = "base32_decoded")]
+    #[derive(Validator)]
+    #[validator(base32_decoded(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base32Decoded(pub(super) validators_prelude::Vec<u8>);
+
+    #[cfg(feature = "base64")]
+    #[derive(Validator)]
+    #[validator(base64(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base64(pub(super) validators_prelude::String);
+
+    #[cfg(feature = "base64_decoded")]
+    #[derive(Validator)]
+    #[validator(base64_decoded(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base64Decoded(pub(super) validators_prelude::Vec<u8>);
+
+    #[cfg(feature = "base64_url")]
+    #[derive(Validator)]
+    #[validator(base64_url(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base64Url(pub(super) validators_prelude::String);
+
+    #[cfg(feature = "base64_url_decoded")]
+    #[derive(Validator)]
+    #[validator(base64_url_decoded(padding(Allow), serde = false, rocket = false))]
+    pub(super) struct Base64UrlDecoded(pub(super) validators_prelude::Vec<u8>);
+}
+
+macro_rules! base_xx_validator {
+    (
+        $name:literal,
+        $validator:ident,
+        $builder:ident,
+        $builtin:ident,
+        $output:ty,
+        $block_length:literal,
+        $error:ident $(,)?
+    ) => {
+        #[doc = concat!("The runtime version of the `", $name, "` validator. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone)]
+        pub struct $validator {
+            padding: TriAllow,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            validator: $validator,
+        }
+
+        impl $builder {
+            /// Whether the padding must be, may be, or must not be included. Default: `Allow`.
+            #[inline]
+            pub const fn padding(mut self, padding: TriAllow) -> Self {
+                self.validator.padding = padding;
+                self
+            }
+
+            #[inline]
+            pub const fn build(self) -> $validator {
+                self.validator
+            }
+        }
+
+        impl Default for $validator {
+            #[inline]
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+
+        impl $validator {
+            #[inline]
+            pub const fn builder() -> $builder {
+                $builder {
+                    validator: $validator {
+                        padding: TriAllow::Allow
+                    },
+                }
+            }
+
+            #[inline]
+            pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<$output, crate::errors::$error> {
+                self.parse_str(s.into())
+            }
+
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<$output, crate::errors::$error> {
+                let s = s.as_ref();
+
+                // the length is checked before the content, like the derive macro does
+                if self.padding.must() && !s.is_empty() && s.len() % $block_length != 0 {
+                    return Err(crate::errors::$error::PaddingMust);
+                }
+
+                let builtin::$builtin(v) = <builtin::$builtin as crate::traits::ValidateString>::parse_str(s)?;
+
+                if self.padding.disallow() && s.ends_with('=') {
+                    return Err(crate::errors::$error::PaddingDisallow);
+                }
+
+                Ok(v)
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                self.parse_str(s)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "base32")]
+base_xx_validator!(
+    "base32",
+    Base32Validator,
+    Base32ValidatorBuilder,
+    Base32,
+    String,
+    8,
+    Base32Error
+);
+
+#[cfg(feature = "base32_decoded")]
+base_xx_validator!(
+    "base32_decoded",
+    Base32DecodedValidator,
+    Base32DecodedValidatorBuilder,
+    Base32Decoded,
+    alloc::vec::Vec<u8>,
+    8,
+    Base32DecodedError,
+);
+
+#[cfg(feature = "base64")]
+base_xx_validator!(
+    "base64",
+    Base64Validator,
+    Base64ValidatorBuilder,
+    Base64,
+    String,
+    4,
+    Base64Error
+);
+
+#[cfg(feature = "base64_decoded")]
+base_xx_validator!(
+    "base64_decoded",
+    Base64DecodedValidator,
+    Base64DecodedValidatorBuilder,
+    Base64Decoded,
+    alloc::vec::Vec<u8>,
+    4,
+    Base64DecodedError,
+);
+
+#[cfg(feature = "base64_url")]
+base_xx_validator!(
+    "base64_url",
+    Base64UrlValidator,
+    Base64UrlValidatorBuilder,
+    Base64Url,
+    String,
+    4,
+    Base64UrlError,
+);
+
+#[cfg(feature = "base64_url_decoded")]
+base_xx_validator!(
+    "base64_url_decoded",
+    Base64UrlDecodedValidator,
+    Base64UrlDecodedValidatorBuilder,
+    Base64UrlDecoded,
+    alloc::vec::Vec<u8>,
+    4,
+    Base64UrlDecodedError,
+);
@@ -0,0 +1,170 @@
+#[cfg(feature = "bit")]
+use byte_unit::Bit;
+#[cfg(feature = "byte")]
+use byte_unit::Byte;
+
+use super::{RangeOption, RangeViolation};
+
+/// Map a range violation. Only `Inside` ranges are supported by the derive macro, so `Outside` ranges report `TooSmall`, like a value below the lower bound.
+macro_rules! map_range_violation {
+    ($error:ident) => {
+        |violation| match violation {
+            RangeViolation::TooLarge => crate::errors::$error::TooLarge,
+            RangeViolation::TooSmall | RangeViolation::Forbidden => crate::errors::$error::TooSmall,
+        }
+    };
+}
+
+/// The runtime version of the `bit` validator. Create one with `BitValidator::builder()`.
+#[cfg(feature = "bit")]
+#[derive(Debug, Clone)]
+pub struct BitValidator {
+    range: RangeOption<Bit>,
+}
+
+/// Builder of `BitValidator`. The defaults are the same as the derive macro.
+#[cfg(feature = "bit")]
+#[derive(Debug, Clone)]
+pub struct BitValidatorBuilder {
+    validator: BitValidator,
+}
+
+#[cfg(feature = "bit")]
+impl BitValidatorBuilder {
+    /// The range of the bits. Default: `Unlimited`.
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<Bit>) -> Self {
+        self.validator.range = range;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> BitValidator {
+        self.validator
+    }
+}
+
+#[cfg(feature = "bit")]
+impl Default for BitValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(feature = "bit")]
+impl BitValidator {
+    #[inline]
+    pub const fn builder() -> BitValidatorBuilder {
+        BitValidatorBuilder {
+            validator: BitValidator {
+                range: RangeOption::Unlimited
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<Bit, crate::errors::BitError> {
+        self.parse_bit(Bit::parse_str(s.as_ref())?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::BitError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_u128(&self, u: u128) -> Result<Bit, crate::errors::BitError> {
+        self.parse_bit(Bit::from_u128(u).ok_or(crate::errors::BitError::TooLarge)?)
+    }
+
+    #[inline]
+    pub fn parse_bit(&self, bit: Bit) -> Result<Bit, crate::errors::BitError> {
+        self.range.check(&bit).map_err(map_range_violation!(BitError))?;
+
+        Ok(bit)
+    }
+}
+
+/// The runtime version of the `byte` validator. Create one with `ByteValidator::builder()`.
+#[cfg(feature = "byte")]
+#[derive(Debug, Clone)]
+pub struct ByteValidator {
+    range:       RangeOption<Byte>,
+    ignore_case: bool,
+}
+
+/// Builder of `ByteValidator`. The defaults are the same as the derive macro.
+#[cfg(feature = "byte")]
+#[derive(Debug, Clone)]
+pub struct ByteValidatorBuilder {
+    validator: ByteValidator,
+}
+
+#[cfg(feature = "byte")]
+impl ByteValidatorBuilder {
+    /// The range of the bytes. Default: `Unlimited`.
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<Byte>) -> Self {
+        self.validator.range = range;
+        self
+    }
+
+    /// Whether `b` in units is treated as bytes instead of bits. Default: `true`.
+    #[inline]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.validator.ignore_case = ignore_case;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> ByteValidator {
+        self.validator
+    }
+}
+
+#[cfg(feature = "byte")]
+impl Default for ByteValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(feature = "byte")]
+impl ByteValidator {
+    #[inline]
+    pub const fn builder() -> ByteValidatorBuilder {
+        ByteValidatorBuilder {
+            validator: ByteValidator {
+                range: RangeOption::Unlimited, ignore_case: true
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<Byte, crate::errors::ByteError> {
+        self.parse_byte(Byte::parse_str(s.as_ref(), self.ignore_case)?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::ByteError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_u128(&self, u: u128) -> Result<Byte, crate::errors::ByteError> {
+        self.parse_byte(Byte::from_u128(u).ok_or(crate::errors::ByteError::TooLarge)?)
+    }
+
+    #[inline]
+    pub fn parse_byte(&self, byte: Byte) -> Result<Byte, crate::errors::ByteError> {
+        self.range.check(&byte).map_err(map_range_violation!(ByteError))?;
+
+        Ok(byte)
+    }
+}
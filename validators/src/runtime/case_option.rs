@@ -1,3 +1,4 @@
+/// Which letter case of hexadecimal digits is accepted.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CaseOption {
     Any,
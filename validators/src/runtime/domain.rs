@@ -0,0 +1,181 @@
+use alloc::string::String;
+
+use super::{TriAllow, check_tri_allow};
+use crate::errors::DomainError;
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[derive(Validator)]
+    #[validator(domain(
+        ipv4(Allow),
+        local(Allow),
+        port(Allow),
+        at_least_two_labels(Allow),
+        serde = false,
+        rocket = false
+    ))]
+    pub(super) struct Domain {
+        pub(super) domain: validators_prelude::String,
+        pub(super) port:   Option<u16>,
+    }
+}
+
+/// A domain parsed by `DomainValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDomain {
+    pub domain:   String,
+    pub port:     Option<u16>,
+    pub is_ipv4:  bool,
+    pub is_local: bool,
+}
+
+/// The runtime version of the `domain` validator. Create one with `DomainValidator::builder()`.
+#[derive(Debug, Clone)]
+pub struct DomainValidator {
+    ipv4:                TriAllow,
+    local:               TriAllow,
+    port:                TriAllow,
+    at_least_two_labels: TriAllow,
+}
+
+/// Builder of `DomainValidator`. The defaults are the same as the derive macro.
+#[derive(Debug, Clone)]
+pub struct DomainValidatorBuilder {
+    validator: DomainValidator,
+}
+
+impl DomainValidatorBuilder {
+    /// Whether the domain must be, may be, or must not be an IPv4 address. Default: `Allow`.
+    #[inline]
+    pub const fn ipv4(mut self, ipv4: TriAllow) -> Self {
+        self.validator.ipv4 = ipv4;
+        self
+    }
+
+    /// Whether the domain must be, may be, or must not be local. Default: `Allow`.
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+        self
+    }
+
+    /// Whether a port must be, may be, or must not be given. Default: `Allow`.
+    #[inline]
+    pub const fn port(mut self, port: TriAllow) -> Self {
+        self.validator.port = port;
+        self
+    }
+
+    /// Whether the domain must have, may have, or must not have at least two labels. Default: `Allow`.
+    #[inline]
+    pub const fn at_least_two_labels(mut self, at_least_two_labels: TriAllow) -> Self {
+        self.validator.at_least_two_labels = at_least_two_labels;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> DomainValidator {
+        self.validator
+    }
+}
+
+impl Default for DomainValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl DomainValidator {
+    #[inline]
+    pub const fn builder() -> DomainValidatorBuilder {
+        DomainValidatorBuilder {
+            validator: DomainValidator {
+                ipv4:                TriAllow::Allow,
+                local:               TriAllow::Allow,
+                port:                TriAllow::Allow,
+                at_least_two_labels: TriAllow::Allow,
+            },
+        }
+    }
+
+    fn check(&self, domain: builtin::Domain) -> Result<ParsedDomain, DomainError> {
+        let builtin::Domain {
+            domain,
+            port,
+        } = domain;
+
+        let (is_ipv4, is_local) = match crate::functions::parse_ipv4_allow_an_ended_dot(&domain) {
+            Ok(ip) => {
+                if self.ipv4.disallow() {
+                    return Err(DomainError::IPv4Disallow);
+                }
+
+                if self.at_least_two_labels.disallow() {
+                    return Err(DomainError::AtLeastTwoLabelsDisallow);
+                }
+
+                (true, crate::functions::is_local_ipv4(ip))
+            },
+            Err(_) => {
+                if self.ipv4.must() {
+                    return Err(DomainError::IPv4Must);
+                }
+
+                let is_local = crate::functions::is_local_domain(&domain);
+
+                if !is_local {
+                    check_tri_allow(
+                        self.at_least_two_labels,
+                        crate::functions::is_at_least_two_labels_domain(&domain),
+                        DomainError::AtLeastTwoLabelsMust,
+                        DomainError::AtLeastTwoLabelsDisallow,
+                    )?;
+                }
+
+                (false, is_local)
+            },
+        };
+
+        check_tri_allow(self.local, is_local, DomainError::LocalMust, DomainError::LocalDisallow)?;
+
+        Ok(ParsedDomain {
+            domain,
+            port,
+            is_ipv4,
+            is_local,
+        })
+    }
+
+    #[inline]
+    pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<ParsedDomain, DomainError> {
+        self.parse_str(s.into())
+    }
+
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<ParsedDomain, DomainError> {
+        let s = s.as_ref();
+
+        // the port is checked before the domain, like the derive macro does
+        if !s.is_empty() {
+            check_tri_allow(
+                self.port,
+                s.contains(':'),
+                DomainError::PortMust,
+                DomainError::PortDisallow,
+            )?;
+        }
+
+        let domain = <builtin::Domain as crate::traits::ValidateString>::parse_str(s)?;
+
+        self.check(domain)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), DomainError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use alloc::string::String;
+
+use super::{TriAllow, check_tri_allow};
+use crate::{errors::EmailError, models::Host};
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Allow),
+        ip(Allow),
+        local(Allow),
+        at_least_two_labels(Allow),
+        non_ascii(Allow),
+        serde = false,
+        rocket = false
+    ))]
+    pub(super) struct Email {
+        pub(super) local_part:                 validators_prelude::String,
+        pub(super) need_quoted:                bool,
+        pub(super) domain_part:                validators_prelude::Host,
+        pub(super) comment_before_local_part:  Option<validators_prelude::String>,
+        pub(super) comment_after_local_part:   Option<validators_prelude::String>,
+        pub(super) comment_before_domain_part: Option<validators_prelude::String>,
+        pub(super) comment_after_domain_part:  Option<validators_prelude::String>,
+    }
+}
+
+/// An email address parsed by `EmailValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedEmail {
+    pub local_part:                 String,
+    pub need_quoted:                bool,
+    pub domain_part:                Host,
+    pub comment_before_local_part:  Option<String>,
+    pub comment_after_local_part:   Option<String>,
+    pub comment_before_domain_part: Option<String>,
+    pub comment_after_domain_part:  Option<String>,
+    pub is_local:                   bool,
+}
+
+/// The runtime version of the `email` validator. Create one with `EmailValidator::builder()`.
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    comment:             bool,
+    ip:                  TriAllow,
+    local:               TriAllow,
+    at_least_two_labels: TriAllow,
+    non_ascii:           bool,
+}
+
+/// Builder of `EmailValidator`. The defaults are the same as the derive macro.
+#[derive(Debug, Clone)]
+pub struct EmailValidatorBuilder {
+    validator: EmailValidator,
+}
+
+impl EmailValidatorBuilder {
+    /// Whether comments are allowed. Default: `true`.
+    #[inline]
+    pub const fn comment(mut self, allow: bool) -> Self {
+        self.validator.comment = allow;
+        self
+    }
+
+    /// Whether the domain part must be, may be, or must not be an IP. Default: `Allow`.
+    #[inline]
+    pub const fn ip(mut self, ip: TriAllow) -> Self {
+        self.validator.ip = ip;
+        self
+    }
+
+    /// Whether the domain part must be, may be, or must not be local. Default: `Allow`.
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+        self
+    }
+
+    /// Whether the domain part must have, may have, or must not have at least two labels. Default: `Allow`.
+    #[inline]
+    pub const fn at_least_two_labels(mut self, at_least_two_labels: TriAllow) -> Self {
+        self.validator.at_least_two_labels = at_least_two_labels;
+        self
+    }
+
+    /// Whether non-ASCII characters are allowed in the local part. Default: `true`.
+    #[inline]
+    pub const fn non_ascii(mut self, allow: bool) -> Self {
+        self.validator.non_ascii = allow;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> EmailValidator {
+        self.validator
+    }
+}
+
+impl Default for EmailValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl EmailValidator {
+    #[inline]
+    pub const fn builder() -> EmailValidatorBuilder {
+        EmailValidatorBuilder {
+            validator: EmailValidator {
+                comment:             true,
+                ip:                  TriAllow::Allow,
+                local:               TriAllow::Allow,
+                at_least_two_labels: TriAllow::Allow,
+                non_ascii:           true,
+            },
+        }
+    }
+
+    fn check(&self, email: builtin::Email) -> Result<ParsedEmail, EmailError> {
+        let builtin::Email {
+            local_part,
+            need_quoted,
+            domain_part,
+            comment_before_local_part,
+            comment_after_local_part,
+            comment_before_domain_part,
+            comment_after_domain_part,
+        } = email;
+
+        if !self.non_ascii && !local_part.is_ascii() {
+            return Err(EmailError::Invalid);
+        }
+
+        if !self.comment
+            && (comment_before_local_part.is_some()
+                || comment_after_local_part.is_some()
+                || comment_before_domain_part.is_some())
+        {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        let is_local = match &domain_part {
+            Host::Domain(domain) => {
+                if self.ip.must() {
+                    return Err(EmailError::IPMust);
+                }
+
+                let is_local = crate::functions::is_local_domain(domain);
+
+                if !is_local {
+                    check_tri_allow(
+                        self.at_least_two_labels,
+                        crate::functions::is_at_least_two_labels_domain(domain),
+                        EmailError::AtLeastTwoLabelsMust,
+                        EmailError::AtLeastTwoLabelsDisallow,
+                    )?;
+                }
+
+                is_local
+            },
+            Host::IPv4(ip) => {
+                self.check_ip()?;
+
+                crate::functions::is_local_ipv4(*ip)
+            },
+            Host::IPv6(ip) => {
+                self.check_ip()?;
+
+                crate::functions::is_local_ipv6(*ip)
+            },
+        };
+
+        check_tri_allow(self.local, is_local, EmailError::LocalMust, EmailError::LocalDisallow)?;
+
+        if !self.comment && comment_after_domain_part.is_some() {
+            return Err(EmailError::CommentDisallow);
+        }
+
+        Ok(ParsedEmail {
+            local_part,
+            need_quoted,
+            domain_part,
+            comment_before_local_part,
+            comment_after_local_part,
+            comment_before_domain_part,
+            comment_after_domain_part,
+            is_local,
+        })
+    }
+
+    #[inline]
+    fn check_ip(&self) -> Result<(), EmailError> {
+        if self.ip.disallow() {
+            return Err(EmailError::IPDisallow);
+        }
+
+        if self.at_least_two_labels.disallow() {
+            return Err(EmailError::AtLeastTwoLabelsDisallow);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<ParsedEmail, EmailError> {
+        self.parse_str(s.into())
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<ParsedEmail, EmailError> {
+        let email = <builtin::Email as crate::traits::ValidateString>::parse_str(s)?;
+
+        self.check(email)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), EmailError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use super::{CaseOption, SeparatorOption};
+
+/// Why a string of hexadecimal groups is rejected.
+enum HexGroupsViolation {
+    Invalid,
+    SeparatorMust,
+    SeparatorDisallow,
+}
+
+/// Decode hexadecimal digits split into groups of the given lengths, in the same way as the derive macro does for `mac_address` and `uuid`.
+fn decode_hex_groups(
+    s: &str,
+    groups: &[usize],
+    separator: SeparatorOption,
+    case: CaseOption,
+) -> Result<u128, HexGroupsViolation> {
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    let digits_length: usize = groups.iter().sum();
+    let separated_length = digits_length + groups.len() - 1;
+
+    let mut decoded = 0u128;
+
+    let mut decode = |e: u8| -> Result<(), HexGroupsViolation> {
+        decoded <<= 4;
+
+        decoded |= u128::from(match e {
+            b'0'..=b'9' => e - b'0',
+            b'a'..=b'f' if case.lower() => e - (b'a' - 10),
+            b'A'..=b'F' if case.upper() => e - (b'A' - 10),
+            _ => return Err(HexGroupsViolation::Invalid),
+        });
+
+        Ok(())
+    };
+
+    match separator {
+        SeparatorOption::Disallow => {
+            if length != digits_length {
+                return Err(HexGroupsViolation::SeparatorDisallow);
+            }
+
+            for &e in bytes {
+                decode(e)?;
+            }
+        },
+        SeparatorOption::Must(separator) => {
+            if length != separated_length {
+                return Err(HexGroupsViolation::SeparatorMust);
+            }
+
+            let mut index = 0;
+
+            for (i, &group) in groups.iter().enumerate() {
+                if i > 0 {
+                    if bytes[index] != separator {
+                        return Err(HexGroupsViolation::Invalid);
+                    }
+
+                    index += 1;
+                }
+
+                for &e in &bytes[index..(index + group)] {
+                    decode(e)?;
+                }
+
+                index += group;
+            }
+        },
+        SeparatorOption::Allow(separator) => {
+            if !(digits_length..=separated_length).contains(&length) {
+                return Err(HexGroupsViolation::Invalid);
+            }
+
+            let mut index = 0;
+
+            for (i, &group) in groups.iter().enumerate() {
+                if i > 0 && bytes.get(index) == Some(&separator) {
+                    index += 1;
+                }
+
+                // the last group takes the rest of the string, which must fit exactly
+                let end = if i == groups.len() - 1 { length } else { index + group };
+
+                if end > length || end - index != group {
+                    return Err(HexGroupsViolation::Invalid);
+                }
+
+                for &e in &bytes[index..end] {
+                    decode(e)?;
+                }
+
+                index = end;
+            }
+        },
+    }
+
+    Ok(decoded)
+}
+
+macro_rules! hex_groups_validator {
+    (
+        $name:literal,
+        $validator:ident,
+        $builder:ident,
+        $groups:expr,
+        $default_separator:literal,
+        $output:ty,
+        $error:ident $(,)?
+    ) => {
+        #[doc = concat!("The runtime version of the `", $name, "` validator. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone)]
+        pub struct $validator {
+            case:      CaseOption,
+            separator: SeparatorOption,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            validator: $validator,
+        }
+
+        impl $builder {
+            /// Which letter case of hexadecimal digits is accepted. Default: `Any`.
+            #[inline]
+            pub const fn case(mut self, case: CaseOption) -> Self {
+                self.validator.case = case;
+                self
+            }
+
+            #[doc = concat!("Whether the groups are separated. Default: `Allow(", stringify!($default_separator), ")`.")]
+            #[inline]
+            pub const fn separator(mut self, separator: SeparatorOption) -> Self {
+                self.validator.separator = separator;
+                self
+            }
+
+            #[inline]
+            pub const fn build(self) -> $validator {
+                self.validator
+            }
+        }
+
+        impl Default for $validator {
+            #[inline]
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+
+        impl $validator {
+            #[inline]
+            pub const fn builder() -> $builder {
+                $builder {
+                    validator: $validator {
+                        case:      CaseOption::Any,
+                        separator: SeparatorOption::Allow($default_separator),
+                    },
+                }
+            }
+
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<$output, crate::errors::$error> {
+                match decode_hex_groups(s.as_ref(), &$groups, self.separator, self.case) {
+                    #[allow(clippy::unnecessary_cast)]
+                    Ok(decoded) => Ok(decoded as $output),
+                    Err(HexGroupsViolation::Invalid) => Err(crate::errors::$error::Invalid),
+                    Err(HexGroupsViolation::SeparatorMust) => Err(crate::errors::$error::SeparatorMust),
+                    Err(HexGroupsViolation::SeparatorDisallow) => {
+                        Err(crate::errors::$error::SeparatorDisallow)
+                    },
+                }
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                self.parse_str(s)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "mac_address")]
+hex_groups_validator!(
+    "mac_address",
+    MacAddressValidator,
+    MacAddressValidatorBuilder,
+    [2, 2, 2, 2, 2, 2],
+    b':',
+    u64,
+    MacAddressError,
+);
+
+#[cfg(feature = "uuid")]
+hex_groups_validator!(
+    "uuid",
+    UuidValidator,
+    UuidValidatorBuilder,
+    [8, 4, 4, 4, 12],
+    b'-',
+    u128,
+    UuidError,
+);
@@ -0,0 +1,159 @@
+use alloc::string::String;
+
+use super::{TriAllow, check_tri_allow};
+use crate::{errors::HostError, models::Host};
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[derive(Validator)]
+    #[validator(host(
+        local(Allow),
+        port(Allow),
+        at_least_two_labels(Allow),
+        serde = false,
+        rocket = false
+    ))]
+    pub(super) struct Host {
+        pub(super) host: validators_prelude::Host,
+        pub(super) port: Option<u16>,
+    }
+}
+
+/// A host parsed by `HostValidator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedHost {
+    pub host:     Host,
+    pub port:     Option<u16>,
+    pub is_local: bool,
+}
+
+/// The runtime version of the `host` validator. Create one with `HostValidator::builder()`.
+#[derive(Debug, Clone)]
+pub struct HostValidator {
+    local:               TriAllow,
+    port:                TriAllow,
+    at_least_two_labels: TriAllow,
+}
+
+/// Builder of `HostValidator`. The defaults are the same as the derive macro.
+#[derive(Debug, Clone)]
+pub struct HostValidatorBuilder {
+    validator: HostValidator,
+}
+
+impl HostValidatorBuilder {
+    /// Whether the host must be, may be, or must not be local. Default: `Allow`.
+    #[inline]
+    pub const fn local(mut self, local: TriAllow) -> Self {
+        self.validator.local = local;
+        self
+    }
+
+    /// Whether a port must be, may be, or must not be given. Default: `Allow`.
+    #[inline]
+    pub const fn port(mut self, port: TriAllow) -> Self {
+        self.validator.port = port;
+        self
+    }
+
+    /// Whether the host must have, may have, or must not have at least two labels. IP addresses count as having at least two labels. Default: `Allow`.
+    #[inline]
+    pub const fn at_least_two_labels(mut self, at_least_two_labels: TriAllow) -> Self {
+        self.validator.at_least_two_labels = at_least_two_labels;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> HostValidator {
+        self.validator
+    }
+}
+
+impl Default for HostValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl HostValidator {
+    #[inline]
+    pub const fn builder() -> HostValidatorBuilder {
+        HostValidatorBuilder {
+            validator: HostValidator {
+                local:               TriAllow::Allow,
+                port:                TriAllow::Allow,
+                at_least_two_labels: TriAllow::Allow,
+            },
+        }
+    }
+
+    fn check(&self, host: builtin::Host) -> Result<ParsedHost, HostError> {
+        let builtin::Host {
+            host,
+            port,
+        } = host;
+
+        check_tri_allow(self.port, port.is_some(), HostError::PortMust, HostError::PortDisallow)?;
+
+        let is_local = match &host {
+            Host::Domain(domain) => {
+                let is_local = crate::functions::is_local_domain(domain);
+
+                if !is_local {
+                    check_tri_allow(
+                        self.at_least_two_labels,
+                        crate::functions::is_at_least_two_labels_domain(domain),
+                        HostError::AtLeastTwoLabelsMust,
+                        HostError::AtLeastTwoLabelsDisallow,
+                    )?;
+                }
+
+                is_local
+            },
+            Host::IPv4(ip) => {
+                if self.at_least_two_labels.disallow() {
+                    return Err(HostError::AtLeastTwoLabelsDisallow);
+                }
+
+                crate::functions::is_local_ipv4(*ip)
+            },
+            Host::IPv6(ip) => {
+                if self.at_least_two_labels.disallow() {
+                    return Err(HostError::AtLeastTwoLabelsDisallow);
+                }
+
+                crate::functions::is_local_ipv6(*ip)
+            },
+        };
+
+        check_tri_allow(self.local, is_local, HostError::LocalMust, HostError::LocalDisallow)?;
+
+        Ok(ParsedHost {
+            host,
+            port,
+            is_local,
+        })
+    }
+
+    #[inline]
+    pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<ParsedHost, HostError> {
+        self.parse_str(s.into())
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<ParsedHost, HostError> {
+        let host = <builtin::Host as crate::traits::ValidateString>::parse_str(s)?;
+
+        self.check(host)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), HostError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::string::String;
+
+use super::{TriAllow, check_tri_allow};
+use crate::url::{Host, Url};
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[cfg(feature = "http_url")]
+    #[derive(Validator)]
+    #[validator(http_url(local(Allow), serde = false, rocket = false))]
+    pub(super) struct HttpUrl {
+        pub(super) url:      validators_prelude::url::Url,
+        pub(super) is_https: bool,
+    }
+
+    #[cfg(feature = "http_ftp_url")]
+    #[derive(Validator)]
+    #[validator(http_ftp_url(local(Allow), serde = false, rocket = false))]
+    pub(super) struct HttpFtpUrl {
+        pub(super) url:      validators_prelude::url::Url,
+        pub(super) protocol: validators_prelude::Protocol,
+    }
+}
+
+#[inline]
+fn is_local_url(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => crate::functions::is_local_domain(domain),
+        Some(Host::Ipv4(ip)) => crate::functions::is_local_ipv4(ip),
+        Some(Host::Ipv6(ip)) => crate::functions::is_local_ipv6(ip),
+        None => false,
+    }
+}
+
+macro_rules! http_xx_url_validator {
+    (
+        $name:literal,
+        $validator:ident,
+        $builder:ident,
+        $parsed:ident,
+        $builtin:ident,
+        $field:ident: $ty:ty,
+        $error:ident $(,)?
+    ) => {
+        #[doc = concat!("A URL parsed by `", stringify!($validator), "`.")]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $parsed {
+            pub url:    Url,
+            pub $field: $ty,
+        }
+
+        #[doc = concat!("The runtime version of the `", $name, "` validator. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone)]
+        pub struct $validator {
+            local: TriAllow,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            validator: $validator,
+        }
+
+        impl $builder {
+            /// Whether the host must be, may be, or must not be local. Default: `Allow`.
+            #[inline]
+            pub const fn local(mut self, local: TriAllow) -> Self {
+                self.validator.local = local;
+                self
+            }
+
+            #[inline]
+            pub const fn build(self) -> $validator {
+                self.validator
+            }
+        }
+
+        impl Default for $validator {
+            #[inline]
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+
+        impl $validator {
+            #[inline]
+            pub const fn builder() -> $builder {
+                $builder {
+                    validator: $validator {
+                        local: TriAllow::Allow
+                    },
+                }
+            }
+
+            #[inline]
+            pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<$parsed, crate::errors::$error> {
+                self.check(<builtin::$builtin as crate::traits::ValidateString>::parse_string(s)?)
+            }
+
+            #[inline]
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<$parsed, crate::errors::$error> {
+                self.check(<builtin::$builtin as crate::traits::ValidateString>::parse_str(s)?)
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                self.parse_str(s)?;
+
+                Ok(())
+            }
+
+            fn check(&self, url: builtin::$builtin) -> Result<$parsed, crate::errors::$error> {
+                let builtin::$builtin {
+                    url,
+                    $field,
+                } = url;
+
+                if self.local != TriAllow::Allow {
+                    check_tri_allow(
+                        self.local,
+                        is_local_url(&url),
+                        crate::errors::$error::LocalMust,
+                        crate::errors::$error::LocalDisallow,
+                    )?;
+                }
+
+                Ok($parsed {
+                    url,
+                    $field,
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "http_url")]
+http_xx_url_validator!(
+    "http_url",
+    HttpUrlValidator,
+    HttpUrlValidatorBuilder,
+    ParsedHttpUrl,
+    HttpUrl,
+    is_https: bool,
+    HttpURLError,
+);
+
+#[cfg(feature = "http_ftp_url")]
+http_xx_url_validator!(
+    "http_ftp_url",
+    HttpFtpUrlValidator,
+    HttpFtpUrlValidatorBuilder,
+    ParsedHttpFtpUrl,
+    HttpFtpUrl,
+    protocol: crate::models::Protocol,
+    HttpFtpURLError,
+);
@@ -0,0 +1,182 @@
+use alloc::string::String;
+
+use super::{TriAllow, check_tri_allow};
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[cfg(feature = "ip")]
+    #[derive(Validator)]
+    #[validator(ip(local(Allow), port(Allow), serde = false, rocket = false))]
+    pub(super) struct Ip {
+        pub(super) ip:   ::std::net::IpAddr,
+        pub(super) port: Option<u16>,
+    }
+
+    #[cfg(feature = "ipv4")]
+    #[derive(Validator)]
+    #[validator(ipv4(local(Allow), port(Allow), serde = false, rocket = false))]
+    pub(super) struct Ipv4 {
+        pub(super) ipv4: ::std::net::Ipv4Addr,
+        pub(super) port: Option<u16>,
+    }
+
+    #[cfg(feature = "ipv6")]
+    #[derive(Validator)]
+    #[validator(ipv6(local(Allow), port(Allow), serde = false, rocket = false))]
+    pub(super) struct Ipv6 {
+        pub(super) ipv6: ::std::net::Ipv6Addr,
+        pub(super) port: Option<u16>,
+    }
+}
+
+macro_rules! ip_xx_validator {
+    (
+        $name:literal,
+        $validator:ident,
+        $builder:ident,
+        $parsed:ident,
+        $builtin:ident,
+        $field:ident: $ty:ty,
+        $error:ident,
+        $is_local:path $(,)?
+    ) => {
+        #[doc = concat!("An address parsed by `", stringify!($validator), "`.")]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $parsed {
+            pub $field:   $ty,
+            pub port:     Option<u16>,
+            pub is_local: bool,
+        }
+
+        #[doc = concat!("The runtime version of the `", $name, "` validator. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone)]
+        pub struct $validator {
+            local: TriAllow,
+            port:  TriAllow,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            validator: $validator,
+        }
+
+        impl $builder {
+            /// Whether the address must be, may be, or must not be local. Default: `Allow`.
+            #[inline]
+            pub const fn local(mut self, local: TriAllow) -> Self {
+                self.validator.local = local;
+                self
+            }
+
+            /// Whether a port must be, may be, or must not be given. Default: `Allow`.
+            #[inline]
+            pub const fn port(mut self, port: TriAllow) -> Self {
+                self.validator.port = port;
+                self
+            }
+
+            #[inline]
+            pub const fn build(self) -> $validator {
+                self.validator
+            }
+        }
+
+        impl Default for $validator {
+            #[inline]
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+
+        impl $validator {
+            #[inline]
+            pub const fn builder() -> $builder {
+                $builder {
+                    validator: $validator {
+                        local: TriAllow::Allow, port: TriAllow::Allow
+                    },
+                }
+            }
+
+            #[inline]
+            pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<$parsed, crate::errors::$error> {
+                self.parse_str(s.into())
+            }
+
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<$parsed, crate::errors::$error> {
+                let builtin::$builtin {
+                    $field,
+                    port,
+                } = <builtin::$builtin as crate::traits::ValidateString>::parse_str(s)?;
+
+                check_tri_allow(
+                    self.port,
+                    port.is_some(),
+                    crate::errors::$error::PortMust,
+                    crate::errors::$error::PortDisallow,
+                )?;
+
+                let is_local = $is_local($field);
+
+                check_tri_allow(
+                    self.local,
+                    is_local,
+                    crate::errors::$error::LocalMust,
+                    crate::errors::$error::LocalDisallow,
+                )?;
+
+                Ok($parsed {
+                    $field,
+                    port,
+                    is_local,
+                })
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                self.parse_str(s)?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ip")]
+ip_xx_validator!(
+    "ip",
+    IpValidator,
+    IpValidatorBuilder,
+    ParsedIp,
+    Ip,
+    ip: std::net::IpAddr,
+    IpError,
+    crate::functions::is_local_ip,
+);
+
+#[cfg(feature = "ipv4")]
+ip_xx_validator!(
+    "ipv4",
+    Ipv4Validator,
+    Ipv4ValidatorBuilder,
+    ParsedIpv4,
+    Ipv4,
+    ipv4: std::net::Ipv4Addr,
+    Ipv4Error,
+    crate::functions::is_local_ipv4,
+);
+
+#[cfg(feature = "ipv6")]
+ip_xx_validator!(
+    "ipv6",
+    Ipv6Validator,
+    Ipv6ValidatorBuilder,
+    ParsedIpv6,
+    Ipv6,
+    ipv6: std::net::Ipv6Addr,
+    Ipv6Error,
+    crate::functions::is_local_ipv6,
+);
@@ -0,0 +1,106 @@
+use alloc::vec;
+
+use crate::{
+    errors::DetailedError,
+    traits::{CollectionLength, ErrorParam},
+};
+
+/// The runtime version of the `length` validator. Create one with `LengthValidator::builder()`.
+#[derive(Debug, Clone, Default)]
+pub struct LengthValidator {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+/// Builder of `LengthValidator`. The defaults are the same as the derive macro.
+#[derive(Debug, Clone)]
+pub struct LengthValidatorBuilder {
+    validator: LengthValidator,
+}
+
+impl LengthValidatorBuilder {
+    /// The minimum length of the collection. Default: unlimited.
+    #[inline]
+    pub const fn min(mut self, min: usize) -> Self {
+        self.validator.min = Some(min);
+        self
+    }
+
+    /// The maximum length of the collection. Default: unlimited.
+    #[inline]
+    pub const fn max(mut self, max: usize) -> Self {
+        self.validator.max = Some(max);
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> LengthValidator {
+        self.validator
+    }
+}
+
+impl LengthValidator {
+    #[inline]
+    pub const fn builder() -> LengthValidatorBuilder {
+        LengthValidatorBuilder {
+            validator: LengthValidator {
+                min: None, max: None
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_collection<T: CollectionLength>(
+        &self,
+        v: T,
+    ) -> Result<T, crate::errors::LengthError> {
+        self.validate_collection(&v)?;
+
+        Ok(v)
+    }
+
+    pub fn validate_collection<T: CollectionLength>(
+        &self,
+        v: &T,
+    ) -> Result<(), crate::errors::LengthError> {
+        let length = v.len();
+
+        if let Some(min) = self.min
+            && length < min
+        {
+            return Err(crate::errors::LengthError::TooSmall);
+        }
+
+        if let Some(max) = self.max
+            && length > max
+        {
+            return Err(crate::errors::LengthError::TooLarge);
+        }
+
+        Ok(())
+    }
+
+    /// The same as `validate_collection`, but the error also carries the details of the failure, such as the configured limit and the measured length.
+    pub fn validate_collection_detailed<T: CollectionLength>(
+        &self,
+        v: &T,
+    ) -> Result<(), DetailedError<crate::errors::LengthError>> {
+        self.validate_collection(v).map_err(|error| {
+            let length = ("length", ErrorParam::Unsigned(v.len() as u128));
+
+            let details = match error {
+                crate::errors::LengthError::TooSmall => {
+                    vec![("min", ErrorParam::Unsigned(self.min.unwrap_or(0) as u128)), length]
+                },
+                crate::errors::LengthError::TooLarge => {
+                    vec![
+                        ("max", ErrorParam::Unsigned(self.max.unwrap_or(usize::MAX) as u128)),
+                        length,
+                    ]
+                },
+            };
+
+            DetailedError::new(error).with_params(details)
+        })
+    }
+}
@@ -0,0 +1,129 @@
+mod case_option;
+mod range_option;
+mod separator_option;
+mod tri_allow;
+
+pub use case_option::*;
+pub use range_option::*;
+pub use separator_option::*;
+pub use tri_allow::*;
+
+#[cfg(all(
+    feature = "runtime",
+    any(
+        feature = "base32",
+        feature = "base32_decoded",
+        feature = "base64",
+        feature = "base64_decoded",
+        feature = "base64_url",
+        feature = "base64_url_decoded"
+    )
+))]
+mod base_xx;
+#[cfg(all(
+    feature = "runtime",
+    any(
+        feature = "base32",
+        feature = "base32_decoded",
+        feature = "base64",
+        feature = "base64_decoded",
+        feature = "base64_url",
+        feature = "base64_url_decoded"
+    )
+))]
+pub use base_xx::*;
+
+#[cfg(all(feature = "runtime", any(feature = "bit", feature = "byte")))]
+mod byte_unit;
+#[cfg(all(feature = "runtime", any(feature = "bit", feature = "byte")))]
+pub use self::byte_unit::*;
+
+#[cfg(all(feature = "runtime", feature = "domain"))]
+mod domain;
+#[cfg(all(feature = "runtime", feature = "domain"))]
+pub use domain::*;
+
+#[cfg(all(feature = "runtime", feature = "email"))]
+mod email;
+#[cfg(all(feature = "runtime", feature = "email"))]
+pub use email::*;
+
+#[cfg(all(feature = "runtime", feature = "host"))]
+mod host;
+#[cfg(all(feature = "runtime", feature = "host"))]
+pub use host::*;
+
+#[cfg(all(feature = "runtime", any(feature = "http_url", feature = "http_ftp_url")))]
+mod http_xx_url;
+#[cfg(all(feature = "runtime", any(feature = "http_url", feature = "http_ftp_url")))]
+pub use http_xx_url::*;
+
+#[cfg(all(feature = "runtime", any(feature = "ip", feature = "ipv4", feature = "ipv6")))]
+mod ip_xx;
+#[cfg(all(feature = "runtime", any(feature = "ip", feature = "ipv4", feature = "ipv6")))]
+pub use ip_xx::*;
+
+#[cfg(all(feature = "runtime", feature = "length"))]
+mod length;
+#[cfg(all(feature = "runtime", feature = "length"))]
+pub use length::*;
+
+#[cfg(all(feature = "runtime", any(feature = "line", feature = "text")))]
+mod utf8;
+#[cfg(all(feature = "runtime", any(feature = "line", feature = "text")))]
+pub use utf8::*;
+
+#[cfg(all(feature = "runtime", any(feature = "mac_address", feature = "uuid")))]
+mod hex_groups;
+#[cfg(all(feature = "runtime", any(feature = "mac_address", feature = "uuid")))]
+pub use hex_groups::*;
+
+#[cfg(all(
+    feature = "runtime",
+    any(feature = "number", feature = "signed_integer", feature = "unsigned_integer")
+))]
+mod number;
+#[cfg(all(
+    feature = "runtime",
+    any(feature = "number", feature = "signed_integer", feature = "unsigned_integer")
+))]
+pub use number::*;
+
+#[cfg(all(feature = "runtime", feature = "phone"))]
+mod phone;
+#[cfg(all(feature = "runtime", feature = "phone"))]
+pub use phone::*;
+
+#[cfg(all(feature = "runtime", feature = "regex"))]
+mod regex;
+#[cfg(all(feature = "runtime", feature = "regex"))]
+pub use regex::*;
+
+/// Check a value which must, may, or must not have some property.
+#[cfg(all(
+    feature = "runtime",
+    any(
+        feature = "domain",
+        feature = "email",
+        feature = "host",
+        feature = "http_url",
+        feature = "http_ftp_url",
+        feature = "ip",
+        feature = "ipv4",
+        feature = "ipv6",
+        feature = "number"
+    )
+))]
+#[inline]
+pub(crate) fn check_tri_allow<E>(
+    option: TriAllow,
+    actual: bool,
+    must: E,
+    disallow: E,
+) -> Result<(), E> {
+    match option {
+        TriAllow::Must if !actual => Err(must),
+        TriAllow::Disallow if actual => Err(disallow),
+        _ => Ok(()),
+    }
+}
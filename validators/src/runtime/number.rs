@@ -0,0 +1,235 @@
+#[cfg(any(feature = "signed_integer", feature = "unsigned_integer"))]
+use core::{num::ParseIntError, str::FromStr};
+
+#[cfg(feature = "number")]
+use super::TriAllow;
+use super::{RangeOption, RangeViolation};
+use crate::{errors::DetailedError, traits::ErrorParam};
+
+#[cfg(any(feature = "signed_integer", feature = "unsigned_integer"))]
+macro_rules! integer_validator {
+    ($name:literal, $validator:ident, $builder:ident, $error:ident, $wide:ty, $param:ident $(,)?) => {
+        #[doc = concat!("The runtime version of the `", $name, "` validator for the integer type `T`. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone)]
+        pub struct $validator<T> {
+            range: RangeOption<T>,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder<T> {
+            validator: $validator<T>,
+        }
+
+        impl<T> $builder<T> {
+            /// The range of the integer. Default: `Unlimited`.
+            #[inline]
+            pub fn range(mut self, range: RangeOption<T>) -> Self {
+                self.validator.range = range;
+                self
+            }
+
+            #[inline]
+            pub fn build(self) -> $validator<T> {
+                self.validator
+            }
+        }
+
+        impl<T> Default for $validator<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+
+        impl<T> $validator<T> {
+            #[inline]
+            pub const fn builder() -> $builder<T> {
+                $builder {
+                    validator: $validator {
+                        range: RangeOption::Unlimited
+                    },
+                }
+            }
+
+            #[inline]
+            fn range_error(violation: RangeViolation) -> crate::errors::$error {
+                match violation {
+                    RangeViolation::TooSmall => crate::errors::$error::TooSmall,
+                    RangeViolation::TooLarge => crate::errors::$error::TooLarge,
+                    RangeViolation::Forbidden => crate::errors::$error::Forbidden,
+                }
+            }
+        }
+
+        impl<T: FromStr<Err = ParseIntError> + PartialOrd> $validator<T> {
+            #[inline]
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<T, crate::errors::$error> {
+                self.parse_integer(s.as_ref().parse()?)
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                self.parse_str(s)?;
+
+                Ok(())
+            }
+
+            #[inline]
+            pub fn parse_integer(&self, i: T) -> Result<T, crate::errors::$error> {
+                self.validate_integer(&i)?;
+
+                Ok(i)
+            }
+
+            #[inline]
+            pub fn validate_integer(&self, i: &T) -> Result<(), crate::errors::$error> {
+                self.range.check(i).map_err(Self::range_error)
+            }
+        }
+
+        impl<T: FromStr<Err = ParseIntError> + PartialOrd + Copy + TryInto<$wide>> $validator<T> {
+            /// The same as `validate_integer`, but the error also carries the details of the failure, such as the violated bound and the rejected value.
+            pub fn validate_integer_detailed(&self, i: &T) -> Result<(), DetailedError<crate::errors::$error>> {
+                self.range.check(i).map_err(|violation| {
+                    // every primitive integer fits in the parameters of the error
+                    let details = self.range.details(violation, *i, |v| {
+                        ErrorParam::$param(v.try_into().ok().unwrap_or(<$wide>::MAX))
+                    });
+
+                    DetailedError::new(Self::range_error(violation)).with_params(details)
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "signed_integer")]
+integer_validator!(
+    "signed_integer",
+    SignedIntegerValidator,
+    SignedIntegerValidatorBuilder,
+    SignedIntegerError,
+    i128,
+    Signed,
+);
+
+#[cfg(feature = "unsigned_integer")]
+integer_validator!(
+    "unsigned_integer",
+    UnsignedIntegerValidator,
+    UnsignedIntegerValidatorBuilder,
+    UnsignedIntegerError,
+    u128,
+    Unsigned,
+);
+
+/// The runtime version of the `number` validator. Create one with `NumberValidator::builder()`.
+#[cfg(feature = "number")]
+#[derive(Debug, Clone)]
+pub struct NumberValidator {
+    range: RangeOption<f64>,
+    nan:   TriAllow,
+}
+
+/// Builder of `NumberValidator`. The defaults are the same as the derive macro.
+#[cfg(feature = "number")]
+#[derive(Debug, Clone)]
+pub struct NumberValidatorBuilder {
+    validator: NumberValidator,
+}
+
+#[cfg(feature = "number")]
+impl NumberValidatorBuilder {
+    /// The range of the number. NaN is never out of range. Default: `Unlimited`.
+    #[inline]
+    pub const fn range(mut self, range: RangeOption<f64>) -> Self {
+        self.validator.range = range;
+        self
+    }
+
+    /// Whether the number must be, may be, or must not be NaN. Default: `Allow`.
+    #[inline]
+    pub const fn nan(mut self, nan: TriAllow) -> Self {
+        self.validator.nan = nan;
+        self
+    }
+
+    #[inline]
+    pub const fn build(self) -> NumberValidator {
+        self.validator
+    }
+}
+
+#[cfg(feature = "number")]
+impl Default for NumberValidator {
+    #[inline]
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[cfg(feature = "number")]
+impl NumberValidator {
+    #[inline]
+    pub const fn builder() -> NumberValidatorBuilder {
+        NumberValidatorBuilder {
+            validator: NumberValidator {
+                range: RangeOption::Unlimited, nan: TriAllow::Allow
+            },
+        }
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<f64, crate::errors::NumberError> {
+        self.parse_f64(s.as_ref().parse()?)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::NumberError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_f64(&self, f: f64) -> Result<f64, crate::errors::NumberError> {
+        self.validate_f64(f)?;
+
+        Ok(f)
+    }
+
+    pub fn validate_f64(&self, f: f64) -> Result<(), crate::errors::NumberError> {
+        self.range.check(&f).map_err(Self::range_error)?;
+
+        super::check_tri_allow(
+            self.nan,
+            f.is_nan(),
+            crate::errors::NumberError::NaNMust,
+            crate::errors::NumberError::NaNDisallow,
+        )
+    }
+
+    /// The same as `validate_f64`, but the error also carries the details of the failure, such as the violated bound and the rejected value.
+    pub fn validate_f64_detailed(
+        &self,
+        f: f64,
+    ) -> Result<(), DetailedError<crate::errors::NumberError>> {
+        if let Err(violation) = self.range.check(&f) {
+            let details = self.range.details(violation, f, ErrorParam::Float);
+
+            return Err(DetailedError::new(Self::range_error(violation)).with_params(details));
+        }
+
+        self.validate_f64(f).map_err(DetailedError::new)
+    }
+
+    #[inline]
+    fn range_error(violation: RangeViolation) -> crate::errors::NumberError {
+        match violation {
+            RangeViolation::TooSmall => crate::errors::NumberError::TooSmall,
+            RangeViolation::TooLarge => crate::errors::NumberError::TooLarge,
+            RangeViolation::Forbidden => crate::errors::NumberError::Forbidden,
+        }
+    }
+}
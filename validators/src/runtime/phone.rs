@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use phonenumber::{PhoneNumber, country::Id};
+
+/// The runtime version of the `phone` validator. Create one with `PhoneValidator::builder()`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneValidator {
+    countries: Vec<Id>,
+}
+
+/// Builder of `PhoneValidator`. The defaults are the same as the derive macro.
+#[derive(Debug, Clone)]
+pub struct PhoneValidatorBuilder {
+    validator: PhoneValidator,
+}
+
+impl PhoneValidatorBuilder {
+    /// The countries the phone number may belong to. Default: any country, in which case the number must be in the international format.
+    #[inline]
+    pub fn countries<I: IntoIterator<Item = Id>>(mut self, countries: I) -> Self {
+        self.validator.countries = countries.into_iter().collect();
+        self
+    }
+
+    #[inline]
+    pub fn build(self) -> PhoneValidator {
+        self.validator
+    }
+}
+
+impl PhoneValidator {
+    #[inline]
+    pub const fn builder() -> PhoneValidatorBuilder {
+        PhoneValidatorBuilder {
+            validator: PhoneValidator {
+                countries: Vec::new()
+            },
+        }
+    }
+
+    /// Parse a phone number. With several countries, the number of the first country it is valid in is returned.
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<PhoneNumber, crate::errors::PhoneError> {
+        let s = s.as_ref();
+
+        if self.countries.is_empty() {
+            let phonenumber = phonenumber::parse(None, s)?;
+
+            return if phonenumber.is_valid() {
+                Ok(phonenumber)
+            } else {
+                Err(crate::errors::PhoneError::Invalid)
+            };
+        }
+
+        for &country in self.countries.iter() {
+            let phonenumber = phonenumber::parse(Some(country), s)?;
+
+            if phonenumber.country().id() == Some(country) && phonenumber.is_valid() {
+                return Ok(phonenumber);
+            }
+        }
+
+        Err(crate::errors::PhoneError::Invalid)
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::PhoneError> {
+        self.parse_str(s)?;
+
+        Ok(())
+    }
+}
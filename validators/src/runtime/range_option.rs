@@ -0,0 +1,137 @@
+/// The range a number must be inside or outside of. Without `min` or `max`, that side is unbounded. `inclusive` decides whether `max` belongs to the range.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RangeOption<T> {
+    Inside { min: Option<T>, max: Option<T>, inclusive: bool },
+    Outside { min: Option<T>, max: Option<T>, inclusive: bool },
+    Unlimited,
+}
+
+#[cfg(all(
+    feature = "runtime",
+    any(
+        feature = "bit",
+        feature = "byte",
+        feature = "number",
+        feature = "signed_integer",
+        feature = "unsigned_integer"
+    )
+))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RangeViolation {
+    TooSmall,
+    TooLarge,
+    Forbidden,
+}
+
+#[cfg(all(
+    feature = "runtime",
+    any(
+        feature = "bit",
+        feature = "byte",
+        feature = "number",
+        feature = "signed_integer",
+        feature = "unsigned_integer"
+    )
+))]
+impl<T: PartialOrd> RangeOption<T> {
+    /// Check `v` in the same way as the `range` parameter of the derive macro.
+    pub(crate) fn check(&self, v: &T) -> Result<(), RangeViolation> {
+        match self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+            } => {
+                if let Some(min) = min
+                    && v < min
+                {
+                    return Err(RangeViolation::TooSmall);
+                }
+
+                if let Some(max) = max
+                    && (if *inclusive { v > max } else { v >= max })
+                {
+                    return Err(RangeViolation::TooLarge);
+                }
+            },
+            Self::Outside {
+                min,
+                max,
+                inclusive,
+            } => {
+                let forbidden = match (min, max) {
+                    (Some(min), Some(max)) => {
+                        v >= min && (if *inclusive { v <= max } else { v < max })
+                    },
+                    (Some(min), None) => v >= min,
+                    (None, Some(max)) => {
+                        if *inclusive {
+                            v <= max
+                        } else {
+                            v < max
+                        }
+                    },
+                    (None, None) => false,
+                };
+
+                if forbidden {
+                    return Err(RangeViolation::Forbidden);
+                }
+            },
+            Self::Unlimited => (),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    feature = "runtime",
+    any(feature = "number", feature = "signed_integer", feature = "unsigned_integer")
+))]
+impl<T: Copy> RangeOption<T> {
+    /// The details of a violation of this range by `v`, which are the same as the ones given by the derive macro.
+    pub(crate) fn details(
+        &self,
+        violation: RangeViolation,
+        v: T,
+        param: impl Fn(T) -> crate::traits::ErrorParam,
+    ) -> alloc::vec::Vec<(&'static str, crate::traits::ErrorParam)> {
+        use crate::traits::ErrorParam;
+
+        let (min, max, inclusive) = match *self {
+            Self::Inside {
+                min,
+                max,
+                inclusive,
+            }
+            | Self::Outside {
+                min,
+                max,
+                inclusive,
+            } => (min, max, inclusive),
+            Self::Unlimited => (None, None, true),
+        };
+
+        let mut details = alloc::vec::Vec::with_capacity(4);
+
+        match violation {
+            RangeViolation::TooSmall => {
+                details.extend(min.map(|min| ("min", param(min))));
+            },
+            RangeViolation::TooLarge => {
+                details.extend(max.map(|max| ("max", param(max))));
+                details.push(("inclusive", ErrorParam::Bool(inclusive)));
+            },
+            RangeViolation::Forbidden => {
+                details.extend(min.map(|min| ("min", param(min))));
+                details.extend(max.map(|max| ("max", param(max))));
+                details.push(("inclusive", ErrorParam::Bool(inclusive)));
+            },
+        }
+
+        details.push(("value", param(v)));
+
+        details
+    }
+}
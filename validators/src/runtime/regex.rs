@@ -0,0 +1,50 @@
+use alloc::string::String;
+
+use crate::regex::Regex;
+
+/// The runtime version of the `regex` validator, for a regular expression known only at runtime. Create one with `RegexValidator::new(regex)`.
+#[derive(Debug, Clone)]
+pub struct RegexValidator {
+    regex: Regex,
+}
+
+impl RegexValidator {
+    #[inline]
+    pub const fn new(regex: Regex) -> Self {
+        RegexValidator {
+            regex,
+        }
+    }
+
+    #[inline]
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    #[inline]
+    pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<String, crate::errors::RegexError> {
+        let s = s.into();
+
+        self.validate_str(&s)?;
+
+        Ok(s)
+    }
+
+    #[inline]
+    pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<String, crate::errors::RegexError> {
+        let s = s.as_ref();
+
+        self.validate_str(s)?;
+
+        Ok(String::from(s))
+    }
+
+    #[inline]
+    pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::RegexError> {
+        if !self.regex.is_match(s.as_ref()) {
+            return Err(crate::errors::RegexError);
+        }
+
+        Ok(())
+    }
+}
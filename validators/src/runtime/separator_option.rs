@@ -1,3 +1,4 @@
+/// Whether the groups must be, are allowed to be, or must not be separated by the given byte.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum SeparatorOption {
     Must(u8),
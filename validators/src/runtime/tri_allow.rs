@@ -1,3 +1,4 @@
+/// Whether something must exist, is allowed, or is disallowed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TriAllow {
     Must,
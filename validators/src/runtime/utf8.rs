@@ -0,0 +1,205 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{errors::DetailedError, traits::ErrorParam};
+
+#[allow(dead_code)]
+mod builtin {
+    use crate::prelude::*;
+
+    #[cfg(feature = "line")]
+    #[derive(Validator)]
+    #[validator(line(serde = false, rocket = false))]
+    pub(super) struct Line(pub(super) validators_prelude::String);
+
+    #[cfg(feature = "text")]
+    #[derive(Validator)]
+    #[validator(text(serde = false, rocket = false))]
+    pub(super) struct Text(pub(super) validators_prelude::String);
+}
+
+/// The limits of a length. `trimmed_min` is the minimum length after leading and trailing whitespaces are removed.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct LengthOption {
+    pub min:         Option<usize>,
+    pub trimmed_min: Option<usize>,
+    pub max:         Option<usize>,
+}
+
+/// Which of the limits is exceeded.
+enum LengthViolation {
+    TooLong,
+    TooShort,
+}
+
+impl LengthOption {
+    fn check(
+        &self,
+        length: usize,
+        trimmed_length: impl FnOnce() -> usize,
+    ) -> Result<(), LengthViolation> {
+        if let Some(max) = self.max
+            && length > max
+        {
+            return Err(LengthViolation::TooLong);
+        }
+
+        if let Some(min) = self.min
+            && length < min
+        {
+            return Err(LengthViolation::TooShort);
+        }
+
+        if let Some(trimmed_min) = self.trimmed_min
+            && trimmed_length() < trimmed_min
+        {
+            return Err(LengthViolation::TooShort);
+        }
+
+        Ok(())
+    }
+
+    /// The details of a violation, which are the same as the ones given by the derive macro.
+    fn details(
+        &self,
+        violation: &LengthViolation,
+        length: usize,
+        trimmed_length: usize,
+    ) -> Vec<(&'static str, ErrorParam)> {
+        match violation {
+            LengthViolation::TooLong => vec![
+                ("max", ErrorParam::Unsigned(self.max.unwrap_or(usize::MAX) as u128)),
+                ("length", ErrorParam::Unsigned(length as u128)),
+            ],
+            LengthViolation::TooShort => vec![
+                ("min", ErrorParam::Unsigned(self.min.unwrap_or(0) as u128)),
+                ("trimmed_min", ErrorParam::Unsigned(self.trimmed_min.unwrap_or(0) as u128)),
+                ("length", ErrorParam::Unsigned(length as u128)),
+                ("trimmed_length", ErrorParam::Unsigned(trimmed_length as u128)),
+            ],
+        }
+    }
+}
+
+macro_rules! utf8_validator {
+    ($name:literal, $validator:ident, $builder:ident, $builtin:ident, $error:ident $(,)?) => {
+        #[doc = concat!("The runtime version of the `", $name, "` validator. Create one with `", stringify!($validator), "::builder()`.")]
+        #[derive(Debug, Clone, Default)]
+        pub struct $validator {
+            char_length: LengthOption,
+            byte_length: LengthOption,
+        }
+
+        #[doc = concat!("Builder of `", stringify!($validator), "`. The defaults are the same as the derive macro.")]
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            validator: $validator,
+        }
+
+        impl $builder {
+            /// The limits of the number of characters. Default: unlimited.
+            #[inline]
+            pub const fn char_length(mut self, char_length: LengthOption) -> Self {
+                self.validator.char_length = char_length;
+                self
+            }
+
+            /// The limits of the number of bytes. Default: unlimited.
+            #[inline]
+            pub const fn byte_length(mut self, byte_length: LengthOption) -> Self {
+                self.validator.byte_length = byte_length;
+                self
+            }
+
+            #[inline]
+            pub const fn build(self) -> $validator {
+                self.validator
+            }
+        }
+
+        impl $validator {
+            #[inline]
+            pub const fn builder() -> $builder {
+                $builder {
+                    validator: $validator {
+                        char_length: LengthOption {
+                            min: None, trimmed_min: None, max: None
+                        },
+                        byte_length: LengthOption {
+                            min: None, trimmed_min: None, max: None
+                        },
+                    },
+                }
+            }
+
+            fn check(&self, s: &str) -> Result<(), crate::errors::$error> {
+                self.char_length
+                    .check(s.chars().count(), || s.trim().chars().count())
+                    .and_then(|()| self.byte_length.check(s.len(), || s.trim().len()))
+                    .map_err(|violation| Self::length_error(&violation))
+            }
+
+            #[inline]
+            fn length_error(violation: &LengthViolation) -> crate::errors::$error {
+                match violation {
+                    LengthViolation::TooLong => crate::errors::$error::TooLong,
+                    LengthViolation::TooShort => crate::errors::$error::TooShort,
+                }
+            }
+
+            #[inline]
+            pub fn parse_string<S: Into<String>>(&self, s: S) -> Result<String, crate::errors::$error> {
+                let builtin::$builtin(s) = <builtin::$builtin as crate::traits::ValidateString>::parse_string(s)?;
+
+                self.check(&s)?;
+
+                Ok(s)
+            }
+
+            #[inline]
+            pub fn parse_str<S: AsRef<str>>(&self, s: S) -> Result<String, crate::errors::$error> {
+                let builtin::$builtin(s) = <builtin::$builtin as crate::traits::ValidateString>::parse_str(s)?;
+
+                self.check(&s)?;
+
+                Ok(s)
+            }
+
+            #[inline]
+            pub fn validate_str<S: AsRef<str>>(&self, s: S) -> Result<(), crate::errors::$error> {
+                let s = s.as_ref();
+
+                <builtin::$builtin as crate::traits::ValidateString>::validate_str(s)?;
+
+                self.check(s)
+            }
+
+            /// The same as `validate_str`, but the error also carries the details of the failure, such as the configured limit and the measured length.
+            pub fn validate_str_detailed<S: AsRef<str>>(&self, s: S) -> Result<(), DetailedError<crate::errors::$error>> {
+                let s = s.as_ref();
+
+                <builtin::$builtin as crate::traits::ValidateString>::validate_str_detailed(s)?;
+
+                let lengths = [
+                    (&self.char_length, s.chars().count(), s.trim().chars().count()),
+                    (&self.byte_length, s.len(), s.trim().len()),
+                ];
+
+                for (option, length, trimmed_length) in lengths {
+                    if let Err(violation) = option.check(length, || trimmed_length) {
+                        let details = option.details(&violation, length, trimmed_length);
+
+                        return Err(DetailedError::new(Self::length_error(&violation)).with_params(details));
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "line")]
+utf8_validator!("line", LineValidator, LineValidatorBuilder, Line, LineError);
+
+#[cfg(feature = "text")]
+utf8_validator!("text", TextValidator, TextValidatorBuilder, Text, TextError);
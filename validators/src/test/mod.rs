@@ -1,9 +1 @@
-mod case_option;
-mod range_option;
-mod separator_option;
-mod tri_allow;
-
-pub use case_option::*;
-pub use range_option::*;
-pub use separator_option::*;
-pub use tri_allow::*;
+pub use crate::runtime::{CaseOption, RangeOption, SeparatorOption, TriAllow};
@@ -0,0 +1,80 @@
+use alloc::collections::BTreeSet;
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// A set of domains for the `allowed_domains` and `denied_domains` parameters. A domain is in the set if it is one of the domains or a subdomain of one of them, so `example.com` covers `mail.example.com` but not `myexample.com`.
+///
+/// The domains in the set are written in ASCII, with IDNs in Punycode (`xn--`). Slices, arrays and `Vec`s compare them case-insensitively, while `HashSet`s and `BTreeSet`s look them up in lowercase.
+pub trait DomainSet {
+    /// Determine whether the domain is in this set.
+    fn contains_domain(&self, domain: &str) -> bool;
+}
+
+/// Call `f` with the domain without the trailing dot, in the ASCII form of IDNA if it has non-ASCII characters.
+#[inline]
+fn with_ascii_domain(domain: &str, f: impl Fn(&str) -> bool) -> bool {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+
+    #[cfg(feature = "idna")]
+    if !domain.is_ascii() {
+        return idna::domain_to_ascii(domain).is_ok_and(|domain| f(&domain));
+    }
+
+    f(domain)
+}
+
+/// The domain itself and the domains it is a subdomain of, such as `a.example.com`, `example.com` and `com`.
+#[inline]
+fn suffixes(domain: &str) -> impl Iterator<Item = &str> {
+    core::iter::once(domain).chain(domain.match_indices('.').map(|(i, _)| &domain[(i + 1)..]))
+}
+
+#[inline]
+fn is_domain_or_subdomain(domain: &str, entry: &str) -> bool {
+    let entry = entry.strip_suffix('.').unwrap_or(entry);
+
+    if entry.is_empty() || domain.len() < entry.len() {
+        return false;
+    }
+
+    let start = domain.len() - entry.len();
+
+    domain.is_char_boundary(start)
+        && domain[start..].eq_ignore_ascii_case(entry)
+        && (start == 0 || domain.as_bytes()[start - 1] == b'.')
+}
+
+impl<S: AsRef<str>> DomainSet for [S] {
+    #[inline]
+    fn contains_domain(&self, domain: &str) -> bool {
+        with_ascii_domain(domain, |domain| {
+            self.iter().any(|entry| is_domain_or_subdomain(domain, entry.as_ref()))
+        })
+    }
+}
+
+impl<S: Borrow<str> + Ord> DomainSet for BTreeSet<S> {
+    #[inline]
+    fn contains_domain(&self, domain: &str) -> bool {
+        with_ascii_domain(domain, |domain| {
+            let domain = domain.to_ascii_lowercase();
+
+            suffixes(&domain).any(|suffix| self.contains(suffix))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: Borrow<str> + Hash + Eq, H: BuildHasher> DomainSet for HashSet<S, H> {
+    #[inline]
+    fn contains_domain(&self, domain: &str) -> bool {
+        with_ascii_domain(domain, |domain| {
+            let domain = domain.to_ascii_lowercase();
+
+            suffixes(&domain).any(|suffix| self.contains(suffix))
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use alloc::string::{String, ToString};
+
+use super::ValidatorError;
+use crate::localization::{Builtin, Catalog, translate};
+
+/// Translate the message of an error by its code.
+///
+/// `languages` is a single language tag, such as `de`, or the value of an `Accept-Language` header, such as `fr-CH, fr;q=0.9, en;q=0.8`. The first language which has a translation is used. If none has, or English is preferred, the message of the error is returned.
+///
+/// ```rust
+/// use validators::{errors::EmailError, prelude::*};
+///
+/// assert_eq!("ungültiger Wert", EmailError::Invalid.localize("de-DE"));
+/// assert_eq!("invalid Email", EmailError::Invalid.localize("en, de;q=0.5"));
+/// ```
+pub trait Localize {
+    /// Translate the message with the bundled translations.
+    #[inline]
+    fn localize(&self, languages: &str) -> String {
+        self.localize_with(&Builtin, languages)
+    }
+
+    /// Translate the message with the given catalog.
+    fn localize_with<C: Catalog + ?Sized>(&self, catalog: &C, languages: &str) -> String;
+}
+
+impl<E: ValidatorError + ?Sized> Localize for E {
+    #[inline]
+    fn localize_with<C: Catalog + ?Sized>(&self, catalog: &C, languages: &str) -> String {
+        translate(catalog, languages, self.code(), &self.params())
+            .unwrap_or_else(|| self.to_string())
+    }
+}
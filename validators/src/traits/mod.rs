@@ -2,27 +2,60 @@ mod collection_length;
 mod validate_boolean;
 mod validate_bytes;
 mod validate_char;
+mod validate_fields;
 mod validate_length;
 mod validate_number;
 mod validate_signed_integer;
+mod validate_str;
 mod validate_string;
 mod validate_unsigned_integer;
+mod validator_error;
 
 pub use collection_length::*;
 pub use validate_boolean::*;
 pub use validate_bytes::*;
 pub use validate_char::*;
+pub use validate_fields::*;
 pub use validate_length::*;
 pub use validate_number::*;
 pub use validate_signed_integer::*;
+pub use validate_str::*;
 pub use validate_string::*;
 pub use validate_unsigned_integer::*;
+pub use validator_error::*;
 
 #[cfg(feature = "serde_json")]
 mod validate_json_value;
 #[cfg(feature = "serde_json")]
 pub use validate_json_value::*;
 
+#[cfg(feature = "localization")]
+mod localize;
+#[cfg(feature = "localization")]
+pub use localize::*;
+
+#[cfg(any(
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "http_ftp_url",
+))]
+mod domain_set;
+#[cfg(any(
+    feature = "domain",
+    feature = "email",
+    feature = "host",
+    feature = "http_url",
+    feature = "http_ftp_url",
+))]
+pub use domain_set::*;
+
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+mod registrable_domain;
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+pub use registrable_domain::*;
+
 #[cfg(feature = "domain")]
 mod qualify_domain;
 #[cfg(feature = "domain")]
@@ -45,9 +78,13 @@ mod to_uri_authority_string;
 ))]
 pub use to_uri_authority_string::*;
 
+#[cfg(feature = "email")]
+mod to_canonical_email;
 #[cfg(feature = "email")]
 mod to_email_string;
 #[cfg(feature = "email")]
+pub use to_canonical_email::*;
+#[cfg(feature = "email")]
 pub use to_email_string::*;
 
 #[cfg(feature = "json")]
@@ -0,0 +1,8 @@
+/// The `domain`, `host` and `email` validators will implement this for their types, by the installed `PublicSuffixList`.
+pub trait RegistrableDomain {
+    /// Get the public suffix (eTLD) of the domain, such as `co.uk` of `www.example.co.uk`. It is `None` for an IP.
+    fn get_public_suffix(&self) -> Option<&str>;
+
+    /// Get the registrable domain (eTLD+1) of the domain, such as `example.co.uk` of `www.example.co.uk`. It is `None` for an IP or a public suffix.
+    fn get_registrable_domain(&self) -> Option<&str>;
+}
@@ -0,0 +1,58 @@
+use alloc::{string::String, vec::Vec};
+
+/// Options of `ToCanonicalEmail`. Create one with `CanonicalEmailOptions::builder()`.
+///
+/// The domain part is always lowercased and encoded by IDNA. The other rules are off by default.
+#[derive(Debug, Clone, Default)]
+pub struct CanonicalEmailOptions {
+    pub(crate) lowercase_local_part:    bool,
+    pub(crate) sub_address_separator:   Option<char>,
+    pub(crate) dot_insensitive_domains: Vec<String>,
+}
+
+/// Builder of `CanonicalEmailOptions`.
+#[derive(Debug, Clone, Default)]
+pub struct CanonicalEmailOptionsBuilder {
+    options: CanonicalEmailOptions,
+}
+
+impl CanonicalEmailOptionsBuilder {
+    /// Whether to lowercase the local part. Default: `false`.
+    #[inline]
+    pub const fn lowercase_local_part(mut self, lowercase: bool) -> Self {
+        self.options.lowercase_local_part = lowercase;
+        self
+    }
+
+    /// The separator of sub-addressing tags, such as `'+'` for `user+tag@example.com`. The tag is stripped from the first separator to the end of the local part. Default: `None`.
+    #[inline]
+    pub const fn sub_address_separator(mut self, separator: Option<char>) -> Self {
+        self.options.sub_address_separator = separator;
+        self
+    }
+
+    /// Add a domain whose mailboxes ignore the dots in the local part, such as `gmail.com`.
+    #[inline]
+    pub fn dot_insensitive_domain<S: Into<String>>(mut self, domain: S) -> Self {
+        self.options.dot_insensitive_domains.push(domain.into());
+        self
+    }
+
+    #[inline]
+    pub fn build(self) -> CanonicalEmailOptions {
+        self.options
+    }
+}
+
+impl CanonicalEmailOptions {
+    #[inline]
+    pub fn builder() -> CanonicalEmailOptionsBuilder {
+        CanonicalEmailOptionsBuilder::default()
+    }
+}
+
+/// The `email` validator will implement this for its types.
+pub trait ToCanonicalEmail {
+    /// Retrieve the canonical form of the email for comparing identities, without comments and the display name.
+    fn to_canonical_email(&self, options: &CanonicalEmailOptions) -> String;
+}
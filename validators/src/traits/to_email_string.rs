@@ -4,4 +4,10 @@ use alloc::string::String;
 pub trait ToEmailString {
     /// Retrieve the email as a string.
     fn to_email_string(&self) -> String;
+
+    /// Retrieve the full mailbox as a string, which is `Display Name <local@domain>` if there is a display name and the same as `to_email_string` otherwise.
+    #[inline]
+    fn to_mailbox_string(&self) -> String {
+        self.to_email_string()
+    }
 }
@@ -0,0 +1,62 @@
+use alloc::{boxed::Box, format, vec::Vec};
+
+use crate::errors::ValidationErrors;
+
+/// Validate every field of a value and report all failures.
+///
+/// The `Validator` derive implements this trait for structs whose fields have the `#[validate(...)]` attribute. Fields marked with `#[validate(nested)]` must implement this trait.
+pub trait ValidateFields {
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+impl<T: ValidateFields + ?Sized> ValidateFields for &T {
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(*self)
+    }
+}
+
+impl<T: ValidateFields + ?Sized> ValidateFields for Box<T> {
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self.as_ref())
+    }
+}
+
+impl<T: ValidateFields> ValidateFields for Option<T> {
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Some(v) => v.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidateFields> ValidateFields for [T] {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        for (i, v) in self.iter().enumerate() {
+            if let Err(e) = v.validate() {
+                errors.extend_nested(&format!("[{i}]"), e);
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl<T: ValidateFields, const N: usize> ValidateFields for [T; N] {
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
+
+impl<T: ValidateFields> ValidateFields for Vec<T> {
+    #[inline]
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.as_slice().validate()
+    }
+}
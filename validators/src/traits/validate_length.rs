@@ -1,4 +1,4 @@
-use crate::traits::CollectionLength;
+use crate::{errors::DetailedError, traits::CollectionLength};
 
 /// Validate the length of collections.
 pub trait ValidateLength<T: CollectionLength>: Sized {
@@ -7,4 +7,10 @@ pub trait ValidateLength<T: CollectionLength>: Sized {
     fn parse_collection(v: T) -> Result<Self, Self::Error>;
 
     fn validate_collection(v: &T) -> Result<(), Self::Error>;
+
+    /// The same as `validate_collection`, but the error also carries the details of the failure, such as the configured limit and the measured length.
+    #[inline]
+    fn validate_collection_detailed(v: &T) -> Result<(), DetailedError<Self::Error>> {
+        Self::validate_collection(v).map_err(DetailedError::new)
+    }
 }
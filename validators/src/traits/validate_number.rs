@@ -1,3 +1,5 @@
+use crate::errors::DetailedError;
+
 /// Validate and deserialize (floating) numbers.
 pub trait ValidateNumber: Sized {
     type Error;
@@ -6,6 +8,12 @@ pub trait ValidateNumber: Sized {
 
     fn validate_f64(f: f64) -> Result<(), Self::Error>;
 
+    /// The same as `validate_f64`, but the error also carries the details of the failure, such as the configured bound and the rejected value.
+    #[inline]
+    fn validate_f64_detailed(f: f64) -> Result<(), DetailedError<Self::Error>> {
+        Self::validate_f64(f).map_err(DetailedError::new)
+    }
+
     #[inline]
     fn parse_f32(f: f32) -> Result<Self, Self::Error> {
         Self::parse_f64(f as f64)
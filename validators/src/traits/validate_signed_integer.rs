@@ -1,3 +1,5 @@
+use crate::errors::DetailedError;
+
 /// Validate and deserialize signed integers.
 pub trait ValidateSignedInteger: Sized {
     type Error;
@@ -6,6 +8,12 @@ pub trait ValidateSignedInteger: Sized {
 
     fn validate_i128(i: i128) -> Result<(), Self::Error>;
 
+    /// The same as `validate_i128`, but the error also carries the details of the failure, such as the configured bound and the rejected value.
+    #[inline]
+    fn validate_i128_detailed(i: i128) -> Result<(), DetailedError<Self::Error>> {
+        Self::validate_i128(i).map_err(DetailedError::new)
+    }
+
     #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "16",)))]
     #[inline]
     fn parse_isize(i: isize) -> Result<Self, Self::Error> {
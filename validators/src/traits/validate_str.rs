@@ -0,0 +1,6 @@
+/// Validate strings and borrow them instead of copying them.
+pub trait ValidateStr<'a>: Sized {
+    type Error;
+
+    fn parse_borrowed_str(s: &'a str) -> Result<Self, Self::Error>;
+}
@@ -1,5 +1,7 @@
 use alloc::string::String;
 
+use crate::errors::DetailedError;
+
 /// Validate and deserialize strings.
 pub trait ValidateString: Sized {
     type Error;
@@ -9,4 +11,10 @@ pub trait ValidateString: Sized {
     fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, Self::Error>;
 
     fn validate_str<S: AsRef<str>>(s: S) -> Result<(), Self::Error>;
+
+    /// The same as `validate_str`, but the error also carries the details of the failure, such as the configured limit and the measured length.
+    #[inline]
+    fn validate_str_detailed<S: AsRef<str>>(s: S) -> Result<(), DetailedError<Self::Error>> {
+        Self::validate_str(s).map_err(DetailedError::new)
+    }
 }
@@ -1,3 +1,5 @@
+use crate::errors::DetailedError;
+
 /// Validate and deserialize unsigned integers.
 pub trait ValidateUnsignedInteger: Sized {
     type Error;
@@ -6,6 +8,12 @@ pub trait ValidateUnsignedInteger: Sized {
 
     fn validate_u128(u: u128) -> Result<(), Self::Error>;
 
+    /// The same as `validate_u128`, but the error also carries the details of the failure, such as the configured bound and the rejected value.
+    #[inline]
+    fn validate_u128_detailed(u: u128) -> Result<(), DetailedError<Self::Error>> {
+        Self::validate_u128(u).map_err(DetailedError::new)
+    }
+
     #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "16",)))]
     #[inline]
     fn parse_usize(u: usize) -> Result<Self, Self::Error> {
@@ -0,0 +1,75 @@
+use alloc::{string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// A parameter of a validation failure, such as the configured maximum length.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorParam {
+    Bool(bool),
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+    String(String),
+}
+
+impl Display for ErrorParam {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::Bool(v) => Display::fmt(v, f),
+            Self::Unsigned(v) => Display::fmt(v, f),
+            Self::Signed(v) => Display::fmt(v, f),
+            Self::Float(v) => Display::fmt(v, f),
+            Self::String(v) => f.write_str(v),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ErrorParam {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::Unsigned(v) => serializer.serialize_u128(*v),
+            Self::Signed(v) => serializer.serialize_i128(*v),
+            Self::Float(v) => serializer.serialize_f64(*v),
+            Self::String(v) => serializer.serialize_str(v),
+        }
+    }
+}
+
+/// Every error of the validators implements this trait, so that API clients and translation tables can key off a stable code instead of the message.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "text"))]
+/// # {
+/// use validators::{errors::TextError, prelude::*};
+///
+/// #[derive(Debug, Validator)]
+/// #[validator(text(char_length(max = 4)))]
+/// pub struct Name(String);
+///
+/// let error = Name::parse_str("too long").unwrap_err();
+///
+/// assert!(matches!(error, TextError::TooLong));
+/// assert_eq!("text", error.validator());
+/// assert_eq!("text.too_long", error.code());
+/// assert!(error.params().is_empty());
+/// # }
+/// ```
+pub trait ValidatorError: Error {
+    /// The name of the validator, which is also the name of its feature, such as `text`.
+    fn validator(&self) -> &'static str;
+
+    /// The stable code of the failure, which is the name of the validator and the name of the variant in snake case, such as `text.too_long`.
+    fn code(&self) -> &'static str;
+
+    /// The parameters relevant to the failure. The errors themselves have none, except where an invalid input is, but a `DetailedError` adds the details of the failure, such as the configured `max` of `TextError::TooLong`.
+    #[inline]
+    fn params(&self) -> Vec<(&'static str, ErrorParam)> {
+        Vec::new()
+    }
+}
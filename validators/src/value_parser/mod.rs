@@ -0,0 +1,126 @@
+use alloc::{boxed::Box, format};
+use core::{error::Error, marker::PhantomData};
+use std::ffi::OsStr;
+
+use clap::{
+    Arg, Command,
+    builder::{PossibleValue, TypedValueParser},
+};
+
+use crate::traits::ValidateString;
+
+/// The `clap` value parser of a validator, returned by the `ValueParserFactory` implementation which is derived for every validator accepting strings. Use `#[arg(value_parser)]` to pick it up.
+///
+/// The error message of the validator is shown in the error output of `clap`, followed by the hint if any, such as the allowed range of an `unsigned_integer` validator. Like other values parsed by `clap`, the validator needs to implement `Clone`.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "unsigned_integer", feature = "clap"))]
+/// # {
+/// use validators::{
+///     clap::{Arg, Command, builder::TypedValueParser},
+///     prelude::*,
+///     value_parser::ValidatorValueParser,
+/// };
+///
+/// #[derive(Debug, Clone, Validator)]
+/// #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+/// pub struct Port(u16);
+///
+/// let parser = ValidatorValueParser::<Port>::new().hint("1..=65535");
+/// let error = parser.parse_ref(&Command::new("server"), None, "0".as_ref()).unwrap_err();
+///
+/// assert!(error.to_string().contains("integer is too small, expected 1..=65535"));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ValidatorValueParser<T> {
+    hint:            Option<&'static str>,
+    possible_values: &'static [(&'static str, &'static [&'static str])],
+    _marker:         PhantomData<fn() -> T>,
+}
+
+impl<T> ValidatorValueParser<T> {
+    /// Create a parser without a hint or possible values.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            hint: None, possible_values: &[], _marker: PhantomData
+        }
+    }
+
+    /// Append the hint to the error message of the validator, such as `1..=65535`.
+    #[inline]
+    pub const fn hint(mut self, hint: &'static str) -> Self {
+        self.hint = Some(hint);
+
+        self
+    }
+
+    /// Set the values listed in the help output of `clap`. Each value comes with the aliases which are accepted as well but not listed.
+    #[inline]
+    pub const fn possible_values(
+        mut self,
+        possible_values: &'static [(&'static str, &'static [&'static str])],
+    ) -> Self {
+        self.possible_values = possible_values;
+
+        self
+    }
+}
+
+impl<T> Default for ValidatorValueParser<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ValidatorValueParser<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ValidatorValueParser<T> {}
+
+impl<T> TypedValueParser for ValidatorValueParser<T>
+where
+    T: ValidateString + Clone + Send + Sync + 'static,
+    T::Error: Error + Send + Sync + 'static,
+{
+    type Value = T;
+
+    #[inline]
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let hint = self.hint;
+
+        // `clap` formats the error of a function parser, which is not possible to construct from outside
+        let parse = move |s: &str| {
+            T::parse_str(s).map_err(|error| -> Box<dyn Error + Send + Sync> {
+                match hint {
+                    Some(hint) => format!("{error}, expected {hint}").into(),
+                    None => Box::new(error),
+                }
+            })
+        };
+
+        parse.parse_ref(cmd, arg, value)
+    }
+
+    #[inline]
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        if self.possible_values.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.possible_values.iter().map(|(value, aliases)| {
+                PossibleValue::new(*value).aliases(aliases.iter().copied())
+            })))
+        }
+    }
+}
@@ -0,0 +1,193 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "actix-web"))]
+
+use validators::{
+    actix_web::{
+        App,
+        http::StatusCode,
+        rt::System,
+        test::{TestRequest, call_service, init_service, read_body},
+        web,
+    },
+    prelude::*,
+};
+
+#[cfg(feature = "email")]
+#[test]
+fn response_error() {
+    use validators::errors::EmailError;
+
+    #[derive(Debug, Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Disallow)
+    ))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    async fn handler(body: String) -> Result<String, EmailError> {
+        let email = Email::parse_string(body)?;
+
+        Ok(email.local_part)
+    }
+
+    System::new().block_on(async {
+        let app = init_service(App::new().route("/email", web::post().to(handler))).await;
+
+        let response = call_service(
+            &app,
+            TestRequest::post().uri("/email").set_payload("user@example.com").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("user", read_body(response).await);
+
+        let response = call_service(
+            &app,
+            TestRequest::post().uri("/email").set_payload("user@[127.0.0.1]").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!("must not use an IP", read_body(response).await);
+    });
+}
+
+#[cfg(all(feature = "line", feature = "email"))]
+#[test]
+fn validation_errors() {
+    use validators::errors::{EmailError, LineError, ValidationErrors};
+
+    async fn handler() -> Result<String, ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        errors.push("name", "line", LineError::TooLong);
+        errors.push("email", "email", EmailError::Invalid);
+
+        Err(errors)
+    }
+
+    System::new().block_on(async {
+        let app = init_service(App::new().route("/users", web::post().to(handler))).await;
+
+        let response = call_service(&app, TestRequest::post().uri("/users").to_request()).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!("name: line is too long\nemail: invalid Email", read_body(response).await);
+    });
+}
+
+#[cfg(all(feature = "ipv4", feature = "unsigned_integer"))]
+#[test]
+fn alternatives() {
+    #[derive(Validator)]
+    #[validator(ipv4(local(Allow), port(Disallow)))]
+    pub struct Ipv4(pub std::net::Ipv4Addr);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+    pub struct Port(u16);
+
+    #[derive(Validator)]
+    pub enum Target {
+        Ip(Ipv4),
+        Port(Port),
+    }
+
+    async fn handler(body: String) -> Result<String, TargetError> {
+        match Target::parse_string(body)? {
+            Target::Ip(ip) => Ok(ip.0.to_string()),
+            Target::Port(port) => Ok(port.0.to_string()),
+        }
+    }
+
+    System::new().block_on(async {
+        let app = init_service(App::new().route("/targets", web::post().to(handler))).await;
+
+        let response = call_service(
+            &app,
+            TestRequest::post().uri("/targets").set_payload("8080").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("8080", read_body(response).await);
+
+        let response =
+            call_service(&app, TestRequest::post().uri("/targets").set_payload("0").to_request())
+                .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!(
+            "does not match any alternative (Ip: invalid IPv4; Port: integer is too small)",
+            read_body(response).await
+        );
+    });
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn query() {
+    use serde::Deserialize;
+    use validators::errors::UnsignedIntegerError;
+
+    #[derive(Debug, Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+    pub struct Port(u16);
+
+    #[derive(Debug, Deserialize)]
+    struct Service {
+        port:        Port,
+        backup_port: validators::Result<Port, UnsignedIntegerError>,
+    }
+
+    async fn handler(service: web::Query<Service>) -> String {
+        let service = service.into_inner();
+
+        match service.backup_port.into_std_result() {
+            Ok(backup_port) => format!("{} {}", service.port.0, backup_port.0),
+            Err(error) => format!("{} ({error})", service.port.0),
+        }
+    }
+
+    System::new().block_on(async {
+        let app = init_service(App::new().route("/services", web::get().to(handler))).await;
+
+        let response = call_service(
+            &app,
+            TestRequest::get().uri("/services?port=8080&backup_port=8081").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("8080 8081", read_body(response).await);
+
+        let response = call_service(
+            &app,
+            TestRequest::get().uri("/services?port=8080&backup_port=0").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("8080 (integer is too small)", read_body(response).await);
+
+        let response = call_service(
+            &app,
+            TestRequest::get().uri("/services?port=0&backup_port=8080").to_request(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert!(
+            String::from_utf8(read_body(response).await.to_vec())
+                .unwrap()
+                .contains("integer is too small")
+        );
+    });
+}
@@ -0,0 +1,176 @@
+#![cfg(all(
+    feature = "test",
+    feature = "derive",
+    feature = "domain",
+    feature = "email",
+    feature = "ipv4"
+))]
+
+use std::net::Ipv4Addr;
+
+use validators::{
+    errors::{DomainError, EmailError, Ipv4Error},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(ipv4(local(Allow), port(Allow)))]
+pub struct Ipv4AllowPort {
+    pub ipv4: Ipv4Addr,
+    pub port: Option<u16>,
+}
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Disallow), port(Disallow), at_least_two_labels(Must)))]
+pub struct DomainWithoutPort(pub String);
+
+#[derive(Validator)]
+#[validator(email(
+    comment(Disallow),
+    ip(Disallow),
+    local(Disallow),
+    at_least_two_labels(Must),
+    non_ascii(Allow)
+))]
+pub struct EmailWithoutIp {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[derive(Validator)]
+pub enum Target {
+    Ip(Ipv4AllowPort),
+    Domain(DomainWithoutPort),
+    EmailAddress(EmailWithoutIp),
+}
+
+#[test]
+fn parse() {
+    match Target::parse_string("127.0.0.1:8080").unwrap() {
+        Target::Ip(ip) => {
+            assert_eq!(Ipv4Addr::LOCALHOST, ip.ipv4);
+            assert_eq!(Some(8080), ip.port);
+        },
+        _ => panic!("expect Ip"),
+    }
+
+    match Target::parse_str("magiclen.org").unwrap() {
+        Target::Domain(domain) => assert_eq!("magiclen.org", domain.0),
+        _ => panic!("expect Domain"),
+    }
+
+    match Target::parse_string("len@magiclen.org").unwrap() {
+        Target::EmailAddress(email) => assert_eq!("len", email.local_part),
+        _ => panic!("expect EmailAddress"),
+    }
+
+    assert!(Target::validate_str("magiclen.org:8080").is_err());
+    assert!(Target::validate_str("127.0.0.1").is_ok());
+}
+
+#[test]
+fn error() {
+    let error = Target::parse_str("len@localhost").err().unwrap();
+
+    assert!(matches!(error.ip, Ipv4Error::Invalid));
+    assert!(matches!(error.domain, DomainError::Invalid));
+    assert!(matches!(error.email_address, EmailError::LocalDisallow));
+
+    assert_eq!(
+        "does not match any alternative (Ip: invalid IPv4; Domain: invalid domain; EmailAddress: \
+         must not be local)",
+        error.to_string()
+    );
+}
+
+#[test]
+fn field_names() {
+    #[derive(Validator)]
+    pub enum Names {
+        IPv4(Ipv4AllowPort),
+        Type(DomainWithoutPort),
+        HTTPServer(EmailWithoutIp),
+    }
+
+    let error = Names::parse_str("len@localhost").err().unwrap();
+
+    assert!(matches!(error.ipv4, Ipv4Error::Invalid));
+    assert!(matches!(error.r#type, DomainError::Invalid));
+    assert!(matches!(error.http_server, EmailError::LocalDisallow));
+    assert!(format!("{error:?}").starts_with("NamesError { ipv4: "));
+}
+
+#[test]
+fn generics() {
+    #[derive(Validator)]
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    type IpOrDomain = Either<Ipv4AllowPort, DomainWithoutPort>;
+
+    assert!(matches!(IpOrDomain::parse_str("127.0.0.1:80").unwrap(), Either::Left(_)));
+    assert!(matches!(IpOrDomain::parse_str("magiclen.org").unwrap(), Either::Right(_)));
+
+    let error = IpOrDomain::parse_str("localhost").err().unwrap();
+
+    assert!(matches!(error.left, Ipv4Error::Invalid));
+    assert!(matches!(error.right, DomainError::LocalDisallow));
+}
+
+#[test]
+fn validator_error() {
+    use validators::errors::ValidationErrors;
+
+    let error = Target::parse_str("len@").err().unwrap().clone();
+
+    assert_eq!("alternatives", error.validator());
+    assert_eq!("alternatives.invalid", error.code());
+
+    let params = error
+        .params()
+        .into_iter()
+        .map(|(name, param)| match param {
+            ErrorParam::String(code) => (name, code),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            ("ip", "ipv4.invalid".to_string()),
+            ("domain", "domain.invalid".to_string()),
+            ("email_address", "email.invalid".to_string())
+        ],
+        params
+    );
+
+    let mut errors = ValidationErrors::new();
+
+    errors.push("target", error.validator(), error);
+
+    assert_eq!("alternatives.invalid", errors.iter().next().unwrap().code());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde() {
+    #[derive(Validator)]
+    #[validator(alternatives(serde(Deserialize)))]
+    pub enum DeserializeOnly {
+        Domain(DomainWithoutPort),
+    }
+
+    let target: Target = validators::serde_json::from_str("\"magiclen.org\"").unwrap();
+
+    assert!(matches!(target, Target::Domain(_)));
+    assert_eq!("\"magiclen.org\"", validators::serde_json::to_string(&target).unwrap());
+
+    assert!(validators::serde_json::from_str::<Target>("\"len@\"").is_err());
+    assert!(matches!(
+        validators::serde_json::from_str("\"magiclen.org\""),
+        Ok(DeserializeOnly::Domain(DomainWithoutPort(domain))) if domain == "magiclen.org"
+    ));
+}
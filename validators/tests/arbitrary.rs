@@ -0,0 +1,285 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "arbitrary"))]
+
+use std::{collections::HashSet, fmt::Debug};
+
+use validators::{
+    arbitrary::{Arbitrary, Unstructured},
+    prelude::*,
+};
+
+/// Generate values from pseudo-random buffers, which all have to be valid.
+fn generate<T>() -> Vec<T>
+where
+    T: for<'a> Arbitrary<'a> + Debug, {
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+
+    (0..256)
+        .map(|_| {
+            let data = (0..256)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+
+                    state as u8
+                })
+                .collect::<Vec<u8>>();
+
+            T::arbitrary(&mut Unstructured::new(&data)).unwrap()
+        })
+        .collect()
+}
+
+/// Generate values and make sure that they are not all the same.
+fn generate_distinct<T>() -> Vec<T>
+where
+    T: for<'a> Arbitrary<'a> + Debug, {
+    let values = generate::<T>();
+
+    let distinct = values.iter().map(|v| format!("{v:?}")).collect::<HashSet<_>>();
+
+    assert!(distinct.len() > 1, "{values:?}");
+
+    values
+}
+
+#[cfg(feature = "text")]
+#[test]
+fn text() {
+    #[derive(Debug, Validator)]
+    #[validator(text(char_length(trimmed_min = 2, max = 10)))]
+    pub struct Name(String);
+
+    for name in generate_distinct::<Name>() {
+        let length = name.0.chars().count();
+
+        assert!((2..=10).contains(&length), "{name:?}");
+        assert!(name.0.trim().chars().count() >= 2, "{name:?}");
+    }
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn line() {
+    #[derive(Debug, Validator)]
+    #[validator(line(byte_length(min = 1, max = 8)))]
+    pub struct Line(String);
+
+    for line in generate_distinct::<Line>() {
+        assert!((1..=8).contains(&line.0.len()), "{line:?}");
+        assert!(!line.0.contains('\n'), "{line:?}");
+    }
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Debug, Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+    pub struct Port(u16);
+
+    assert!(generate_distinct::<Port>().iter().all(|port| port.0 >= 1));
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Debug, Validator)]
+    #[validator(signed_integer(range(Inside(min = -3, max = 3, inclusive = false))))]
+    pub struct Offset(i64);
+
+    #[derive(Debug, Validator)]
+    #[validator(signed_integer(range(Outside(min = -100, max = 100))))]
+    pub struct Extreme(i8);
+
+    assert!(generate_distinct::<Offset>().iter().all(|v| (-3..3).contains(&v.0)));
+    assert!(generate_distinct::<Extreme>().iter().all(|v| !(-100..=100).contains(&v.0)));
+}
+
+#[cfg(feature = "number")]
+#[test]
+fn number() {
+    #[derive(Debug, Validator)]
+    #[validator(number(nan(Disallow), range(Inside(min = 0.0, max = 1.0))))]
+    pub struct Ratio(f64);
+
+    #[derive(Debug, Validator)]
+    #[validator(number(nan(Must)))]
+    pub struct NaN(f32);
+
+    assert!(generate_distinct::<Ratio>().iter().all(|v| (0.0..=1.0).contains(&v.0)));
+    assert!(generate::<NaN>().iter().all(|v| v.0.is_nan()));
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn domain() {
+    #[derive(Debug, Validator)]
+    #[validator(domain(ipv4(Allow), local(Disallow), at_least_two_labels(Must), port(Must)))]
+    pub struct DomainWithPort {
+        domain: String,
+        port:   u16,
+    }
+
+    for v in generate_distinct::<DomainWithPort>() {
+        assert!(v.domain.contains('.'), "{v:?}");
+    }
+}
+
+#[cfg(feature = "host")]
+#[test]
+fn host() {
+    #[derive(Debug, Validator)]
+    #[validator(host(local(Must), port(Disallow)))]
+    pub struct LocalHost(validators::models::Host);
+
+    generate_distinct::<LocalHost>();
+}
+
+#[cfg(feature = "ip")]
+#[test]
+fn ip() {
+    #[derive(Debug, Validator)]
+    #[validator(ip(local(Disallow), port(Allow)))]
+    pub struct GlobalIp {
+        ip:   std::net::IpAddr,
+        port: Option<u16>,
+    }
+
+    let values = generate_distinct::<GlobalIp>();
+
+    assert!(values.iter().any(|v| v.ip.is_ipv4()));
+    assert!(values.iter().any(|v| v.ip.is_ipv6()));
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    #[derive(Debug, Validator)]
+    #[validator(email(ip(Must), local(Allow), at_least_two_labels(Allow), non_ascii(Allow)))]
+    pub struct IpEmail {
+        local_part:                 String,
+        need_quoted:                bool,
+        domain_part:                std::net::IpAddr,
+        comment_before_local_part:  Option<String>,
+        comment_after_local_part:   Option<String>,
+        comment_before_domain_part: Option<String>,
+        comment_after_domain_part:  Option<String>,
+    }
+
+    assert!(generate_distinct::<IpEmail>().iter().all(|v| !v.local_part.is_empty()));
+
+    #[allow(dead_code)]
+    #[derive(Debug, Validator)]
+    #[validator(email(comment(Disallow), non_ascii(Disallow), display_name(Must)))]
+    pub struct Mailbox {
+        local_part:   String,
+        need_quoted:  bool,
+        domain_part:  validators::models::Host,
+        display_name: Option<String>,
+    }
+
+    assert!(generate_distinct::<Mailbox>().iter().all(|v| v.display_name.is_some()));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    #[derive(Debug, Validator)]
+    #[validator(uuid(case(Upper), separator(Disallow)))]
+    pub struct Uuid(u128);
+
+    generate_distinct::<Uuid>();
+}
+
+#[cfg(feature = "mac_address")]
+#[test]
+fn mac_address() {
+    #[derive(Debug, Validator)]
+    #[validator(mac_address(case(Lower), separator(Must(b':'))))]
+    pub struct MacAddress(u64);
+
+    assert!(generate_distinct::<MacAddress>().iter().all(|v| v.0 >> 48 == 0));
+}
+
+#[cfg(feature = "datetime")]
+#[test]
+fn datetime() {
+    #[derive(Debug, Validator)]
+    #[validator(datetime(
+        timezone(Must),
+        range(min = "2000-01-01T00:00:00Z", max = "2000-01-02T00:00:00.5Z")
+    ))]
+    pub struct Instant(validators::models::DateTime);
+
+    for v in generate_distinct::<Instant>() {
+        let timestamp = v.0.unix_timestamp();
+
+        assert!((946684800..=946771200).contains(&timestamp), "{v:?}");
+    }
+}
+
+#[cfg(feature = "date")]
+#[test]
+fn date() {
+    #[derive(Debug, Validator)]
+    #[validator(date(basic_format(Must), range(min = "1999-12-31", max = "2000-02-29")))]
+    pub struct Day(validators::models::Date);
+
+    for v in generate_distinct::<Day>() {
+        assert!(matches!((v.0.year, v.0.month), (1999, 12) | (2000, 1..=2)), "{v:?}");
+    }
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64() {
+    #[derive(Debug, Validator)]
+    #[validator(base64(padding(Must)))]
+    pub struct Base64(String);
+
+    assert!(generate_distinct::<Base64>().iter().all(|v| v.0.len() % 4 == 0));
+}
+
+#[cfg(feature = "phone")]
+#[test]
+fn phone() {
+    #[derive(Debug, Validator)]
+    #[validator(phone(countries(TW)))]
+    pub struct TWPhone(validators::phonenumber::PhoneNumber);
+
+    generate_distinct::<TWPhone>();
+}
+
+#[cfg(feature = "length")]
+#[test]
+fn length() {
+    #[derive(Debug, Validator)]
+    #[validator(length(min = 1, max = 3))]
+    pub struct NonEmptyVec(Vec<u8>);
+
+    assert!(generate_distinct::<NonEmptyVec>().iter().all(|v| (1..=3).contains(&v.0.len())));
+}
+
+#[cfg(all(feature = "semver", feature = "url"))]
+#[test]
+fn alternatives() {
+    #[derive(Debug, Validator)]
+    #[validator(semver)]
+    pub struct Version(validators::semver::Version);
+
+    #[derive(Debug, Validator)]
+    #[validator(url)]
+    pub struct Url(validators::url::Url);
+
+    #[derive(Debug, Validator)]
+    pub enum VersionOrUrl {
+        Version(Version),
+        Url(Url),
+    }
+
+    let values = generate_distinct::<VersionOrUrl>();
+
+    assert!(values.iter().any(|v| matches!(v, VersionOrUrl::Version(_))));
+    assert!(values.iter().any(|v| matches!(v, VersionOrUrl::Url(_))));
+}
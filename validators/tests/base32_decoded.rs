@@ -83,3 +83,20 @@ fn decode() {
 
     assert_eq!(b"123456789", base32_decoded.0.as_slice());
 }
+
+#[test]
+fn trailing_bits() {
+    #[derive(Validator)]
+    #[validator(base32_decoded(padding(Allow)))]
+    pub struct Base32Decoded(pub Vec<u8>);
+
+    for s in ["ME======", "ME", "GEZDGNBV"] {
+        assert!(Base32Decoded::parse_str(s).is_ok());
+        assert!(Base32Decoded::validate_str(s).is_ok());
+    }
+
+    for s in ["MF======", "MF", "GEZDGNBVM"] {
+        assert!(Base32Decoded::parse_str(s).is_err());
+        assert!(Base32Decoded::validate_str(s).is_err());
+    }
+}
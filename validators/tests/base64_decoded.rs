@@ -83,3 +83,20 @@ fn decode() {
 
     assert_eq!(b"1234567890", base64_decoded.0.as_slice());
 }
+
+#[test]
+fn trailing_bits() {
+    #[derive(Validator)]
+    #[validator(base64_decoded(padding(Allow)))]
+    pub struct Base64Decoded(pub Vec<u8>);
+
+    for s in ["MTIzNDU2Nzg5MA==", "MTIzNDU2Nzg5MA", "MTIzNDU2Nzg5MDE"] {
+        assert!(Base64Decoded::parse_str(s).is_ok());
+        assert!(Base64Decoded::validate_str(s).is_ok());
+    }
+
+    for s in ["MTIzNDU2Nzg5MB==", "MTIzNDU2Nzg5MB", "MTIzNDU2Nzg5MDF", "MTIzN"] {
+        assert!(Base64Decoded::parse_str(s).is_err());
+        assert!(Base64Decoded::validate_str(s).is_err());
+    }
+}
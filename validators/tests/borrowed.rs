@@ -0,0 +1,82 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "domain", feature = "line"))]
+
+use std::borrow::Cow;
+
+use validators::{
+    errors::{CustomizedError, DomainError, LineError},
+    prelude::*,
+};
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct Line<'a>(pub &'a str);
+
+#[derive(Validator)]
+#[validator(line(char_length(max = 8)))]
+pub struct CowLine<'a>(pub Cow<'a, str>);
+
+#[derive(Validator)]
+#[validator(line(custom = not_admin))]
+pub struct Username<'a>(pub &'a str);
+
+#[derive(Validator)]
+#[validator(domain(ipv4(Disallow), local(Allow), at_least_two_labels(Allow), port(Disallow)))]
+pub struct Domain<'a>(pub Cow<'a, str>);
+
+fn not_admin(username: &Username) -> Result<(), &'static str> {
+    if username.0 == "admin" { Err("reserved username") } else { Ok(()) }
+}
+
+#[test]
+fn borrowed_str() {
+    let s = String::from("magiclen");
+
+    let line = Line::parse_borrowed_str(&s).unwrap();
+
+    assert!(std::ptr::eq(s.as_str(), line.0));
+
+    assert!(matches!(Line::parse_borrowed_str("magic\nlen"), Err(LineError::Invalid)));
+    assert!(matches!(Line::parse_borrowed_str("magiclen.org"), Err(LineError::TooLong)));
+
+    assert!(Username::parse_borrowed_str("len").is_ok());
+    assert!(matches!(Username::parse_borrowed_str("admin"), Err(CustomizedError::Custom(_))));
+}
+
+#[test]
+fn cow_str() {
+    assert!(matches!(
+        CowLine::parse_borrowed_str("magiclen").unwrap().0,
+        Cow::Borrowed("magiclen")
+    ));
+    assert!(matches!(CowLine::parse_str("magiclen").unwrap().0, Cow::Owned(_)));
+    assert!(matches!(CowLine::parse_string("magic\nlen"), Err(LineError::Invalid)));
+    assert!(CowLine::validate_str("magiclen").is_ok());
+
+    assert!(matches!(Domain::parse_borrowed_str("magiclen.org").unwrap().0, Cow::Borrowed(_)));
+    assert!(
+        matches!(Domain::parse_borrowed_str("MagicLen.org").unwrap().0, Cow::Owned(s) if s == "magiclen.org")
+    );
+    assert!(matches!(Domain::parse_borrowed_str("127.0.0.1"), Err(DomainError::IPv4Disallow)));
+    assert!(!Domain::parse_borrowed_str("magiclen.org").unwrap().is_fully_qualified());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn deserialize() {
+    let json = String::from("\"magiclen\"");
+
+    let line: Line = validators::serde_json::from_str(&json).unwrap();
+
+    assert_eq!("magiclen", line.0);
+    assert!(validators::serde_json::from_str::<Line>("\"magic\\nlen\"").is_err());
+
+    let line: CowLine = validators::serde_json::from_str(&json).unwrap();
+
+    assert!(matches!(line.0, Cow::Borrowed("magiclen")));
+
+    let line: CowLine = validators::serde_json::from_str("\"magic\\u006cen\"").unwrap();
+
+    assert!(matches!(line.0, Cow::Owned(_)));
+
+    assert_eq!("\"magiclen\"", validators::serde_json::to_string(&Line("magiclen")).unwrap());
+}
@@ -0,0 +1,134 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "clap"))]
+
+use std::fmt::Debug;
+
+use validators::{
+    clap::{
+        Arg, Command,
+        builder::{TypedValueParser, ValueParserFactory},
+        value_parser,
+    },
+    prelude::*,
+};
+
+fn command<T>() -> Command
+where
+    T: ValueParserFactory + Clone + Send + Sync + 'static,
+    T::Parser: TypedValueParser<Value = T>, {
+    Command::new("app").arg(Arg::new("value").long("value").value_parser(value_parser!(T)))
+}
+
+fn parse<T>(value: &str) -> Result<T, String>
+where
+    T: ValueParserFactory + Debug + Clone + Send + Sync + 'static,
+    T::Parser: TypedValueParser<Value = T>, {
+    command::<T>()
+        .try_get_matches_from(["app".into(), format!("--value={value}")])
+        .map(|mut matches| matches.remove_one::<T>("value").unwrap())
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Debug, Clone, Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+    pub struct Port(u16);
+
+    assert_eq!(8080, parse::<Port>("8080").unwrap().0);
+
+    let error = parse::<Port>("0").unwrap_err();
+
+    assert!(
+        error.starts_with(
+            "error: invalid value '0' for '--value <value>': integer is too small, expected \
+             1..=65535"
+        ),
+        "{error}"
+    );
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Debug, Clone, Validator)]
+    #[validator(signed_integer(range(Outside(min = -9, max = 9))))]
+    pub struct NotDigit(i8);
+
+    #[derive(Debug, Clone, Validator)]
+    #[validator(signed_integer(range(Inside(min = 0, max = 10, inclusive = false))))]
+    pub struct Index(i8);
+
+    #[derive(Debug, Clone, Validator)]
+    #[validator(signed_integer(range(Unlimited)))]
+    pub struct Offset(i64);
+
+    assert_eq!(-10, parse::<NotDigit>("-10").unwrap().0);
+    assert!(
+        parse::<NotDigit>("0").unwrap_err().contains("integer is forbidden, expected not -9..=9")
+    );
+    assert!(parse::<Index>("10").unwrap_err().contains("integer is too large, expected 0..10"));
+    assert!(!parse::<Offset>("x").unwrap_err().contains("expected"));
+}
+
+#[cfg(feature = "number")]
+#[test]
+fn number() {
+    #[derive(Debug, Clone, Validator)]
+    #[validator(number(range(Inside(min = 0.5))))]
+    pub struct Ratio(f64);
+
+    assert_eq!(0.75, parse::<Ratio>("0.75").unwrap().0);
+    assert!(parse::<Ratio>("0.25").unwrap_err().contains("number is too small, expected 0.5.."));
+}
+
+#[cfg(feature = "boolean")]
+#[test]
+fn boolean() {
+    #[derive(Debug, Clone, Validator)]
+    #[validator(boolean)]
+    pub struct Flag(bool);
+
+    assert!(parse::<Flag>("yes").unwrap().0);
+    assert!(!parse::<Flag>("OFF").unwrap().0);
+    assert!(parse::<Flag>("maybe").unwrap_err().contains("invalid boolean"));
+
+    let command = command::<Flag>();
+    let values = command.get_arguments().next().unwrap().get_possible_values();
+
+    assert_eq!(
+        ["true", "false", "yes", "no", "on", "off", "1", "0"],
+        values.iter().map(|v| v.get_name()).collect::<Vec<_>>().as_slice()
+    );
+    assert!(values[0].matches("t", false));
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    #[derive(Debug, Clone, Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Disallow)
+    ))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    let email = parse::<Email>("len@magiclen.org").unwrap();
+
+    assert_eq!("len", email.local_part);
+    assert!(!email.need_quoted);
+    assert_eq!("magiclen.org", email.domain_part);
+
+    assert!(
+        parse::<Email>("len@[127.0.0.1]")
+            .unwrap_err()
+            .contains("'len@[127.0.0.1]' for '--value <value>': must not use an IP")
+    );
+}
@@ -0,0 +1,150 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "email", feature = "line"))]
+
+use std::fmt::{self, Display, Formatter};
+
+use validators::{
+    errors::{CustomizedError, EmailError, LineError},
+    prelude::*,
+};
+
+fn not_reserved(username: &Username) -> Result<(), &'static str> {
+    if username.0 == "admin" { Err("reserved username") } else { Ok(()) }
+}
+
+#[derive(Debug, Validator)]
+#[validator(line(char_length(trimmed_min = 1, max = 32), custom = not_reserved))]
+pub struct Username(pub String);
+
+#[derive(Debug)]
+pub struct BlockedDomain;
+
+impl Display for BlockedDomain {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("blocked domain")
+    }
+}
+
+impl std::error::Error for BlockedDomain {}
+
+fn not_blocked(email: &Email) -> Result<(), BlockedDomain> {
+    if email.domain_part.eq_ignore_ascii_case("example.com") { Err(BlockedDomain) } else { Ok(()) }
+}
+
+#[derive(Validator)]
+#[validator(email(comment(Disallow), ip(Disallow), local(Disallow), at_least_two_labels(Must), non_ascii(Allow), custom = not_blocked))]
+pub struct Email {
+    pub local_part:  String,
+    pub need_quoted: bool,
+    pub domain_part: String,
+}
+
+#[test]
+fn struct_level() {
+    assert_eq!("len", Username::parse_str("len").unwrap().0);
+    assert!(Username::validate_str("len").is_ok());
+
+    assert!(matches!(
+        Username::parse_str("  "),
+        Err(CustomizedError::Builtin(LineError::TooShort))
+    ));
+    assert!(matches!(Username::validate_str("  "), Err(CustomizedError::Builtin(_))));
+
+    match Username::parse_string("admin") {
+        Err(CustomizedError::Custom(error)) => assert_eq!("reserved username", error.to_string()),
+        _ => panic!("expect a custom error"),
+    }
+
+    assert!(matches!(Username::validate_str("admin"), Err(CustomizedError::Custom(_))));
+
+    assert!(Email::parse_str("len@magiclen.org").is_ok());
+    assert!(matches!(
+        Email::parse_str("len@localhost"),
+        Err(CustomizedError::Builtin(EmailError::LocalDisallow))
+    ));
+
+    match Email::parse_str("len@example.com") {
+        Err(CustomizedError::Custom(error)) => {
+            assert!(error.downcast_ref::<BlockedDomain>().is_some())
+        },
+        _ => panic!("expect a custom error"),
+    }
+
+    assert_eq!("len@magiclen.org", Email::parse_str("len@magiclen.org").unwrap().to_email_string());
+}
+
+fn not_admin(name: &String) -> Result<(), &'static str> {
+    if name == "admin" { Err("reserved username") } else { Ok(()) }
+}
+
+#[derive(Validator)]
+pub struct SignUp {
+    #[validate(line(char_length(trimmed_min = 1), custom = not_admin))]
+    pub name: String,
+}
+
+#[test]
+fn field_level() {
+    assert!(
+        SignUp {
+            name: String::from("len")
+        }
+        .validate()
+        .is_ok()
+    );
+
+    let errors = SignUp {
+        name: String::from("admin")
+    }
+    .validate()
+    .unwrap_err();
+
+    let error = errors.iter().next().unwrap();
+
+    assert_eq!("name", error.path());
+    assert!(matches!(error.downcast_ref(), Some(CustomizedError::<LineError>::Custom(_))));
+
+    let errors = SignUp {
+        name: String::new()
+    }
+    .validate()
+    .unwrap_err();
+
+    assert!(matches!(
+        errors.iter().next().unwrap().downcast_ref(),
+        Some(CustomizedError::<LineError>::Builtin(LineError::TooShort))
+    ));
+}
+
+#[test]
+fn alternatives() {
+    // the errors are not `Clone`, so neither is the error of the enum
+    #[derive(Validator)]
+    pub enum Account {
+        Email(Email),
+        Username(Username),
+    }
+
+    assert!(matches!(Account::parse_str("len@magiclen.org"), Ok(Account::Email(_))));
+
+    let error = Account::parse_str("admin").err().unwrap();
+
+    assert!(matches!(error.email, CustomizedError::Builtin(EmailError::Invalid)));
+    assert!(matches!(error.username, CustomizedError::Custom(_)));
+    assert_eq!("alternatives.invalid", error.code());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde() {
+    assert_eq!("len", validators::serde_json::from_str::<Username>("\"len\"").unwrap().0);
+    assert!(
+        validators::serde_json::from_str::<Username>("\"admin\"")
+            .unwrap_err()
+            .to_string()
+            .starts_with("reserved username")
+    );
+    assert_eq!(
+        "\"len\"",
+        validators::serde_json::to_string(&Username(String::from("len"))).unwrap()
+    );
+}
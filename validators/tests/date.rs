@@ -0,0 +1,167 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "date"))]
+
+use validators::{errors::DateError, prelude::*};
+use validators_prelude::Date;
+
+#[test]
+fn basic() {
+    macro_rules! test_case {
+        ($test:ident, $validator:ident) => {
+            type Validator = $validator;
+            let test = $test;
+
+            test("", false);
+            test("2024-1-1", false);
+            test("2024-01-01T00:00:00", false);
+            test("2024/01/01", false);
+            test("2024-00-01", false);
+            test("2024-13-01", false);
+            test("2024-01-00", false);
+            test("2024-01-32", false);
+            test("2023-02-29", false);
+            test("1900-02-29", false);
+            test("2024-01-01", !Validator::V_BASIC_FORMAT.must());
+            test("2024-02-29", !Validator::V_BASIC_FORMAT.must());
+            test("2000-02-29", !Validator::V_BASIC_FORMAT.must());
+            test("20240101", Validator::V_BASIC_FORMAT.allow());
+            test("20230229", false);
+        };
+    }
+
+    macro_rules! test_inner {
+        ( @unit $parameters:expr, $validator:ident) => {
+            {
+                fn test(s: &str, is_ok: bool) {
+                    let panic = match $validator::validate_str(s) {
+                        Ok(_) => !is_ok,
+                        Err(_) if !is_ok => false,
+                        Err(err) => {
+                            eprintln!("{}", err);
+
+                            true
+                        }
+                    };
+
+                    if panic {
+                        panic!("{:?}: {} {} expect {}", s, stringify!($validator), $parameters, is_ok);
+                    }
+                }
+
+                test_case!(test, $validator);
+            }
+        };
+        ( $parameters:expr ; $($validator:ident),* $(,)*) => {
+            $(
+                test_inner!(
+                    @unit
+                    $parameters,
+                    $validator
+                );
+            )*
+        };
+    }
+
+    macro_rules! test {
+        ($( { $( $p:meta => $v:meta ),* $(,)* } ),* $(,)* ) => {
+            $(
+                {
+                    #[derive(Validator)]
+                    #[validator(date($($p($v),)*))]
+                    pub struct DateValidator(pub Date);
+
+                    test_inner!(
+                        stringify! {
+                            $(
+                                $p = $v,
+                            )*
+                        };
+                        DateValidator,
+                    );
+                }
+            )*
+        }
+    }
+
+    test! {
+        {
+            basic_format => Allow,
+        },
+        {
+            basic_format => Must,
+        },
+        {
+            basic_format => Disallow,
+        }
+    }
+}
+
+#[test]
+fn range() {
+    #[derive(Validator)]
+    #[validator(date(range(min = "2000-01-01", max = "2000-12-31")))]
+    pub struct Year2000(pub Date);
+
+    assert!(matches!(Year2000::parse_str("1999-12-31"), Err(DateError::TooEarly)));
+    assert!(Year2000::parse_str("2000-01-01").is_ok());
+    assert!(Year2000::parse_str("2000-12-31").is_ok());
+    assert!(matches!(Year2000::parse_str("2001-01-01"), Err(DateError::TooLate)));
+
+    assert_eq!(
+        Some(Date {
+            year: 2000, month: 1, day: 1
+        }),
+        Year2000::V_MIN
+    );
+}
+
+#[test]
+fn model() {
+    #[derive(Validator)]
+    #[validator(date(basic_format(Allow)))]
+    pub struct AnyDate(pub Date);
+
+    let date = AnyDate::parse_str("19700102").unwrap().0;
+
+    assert_eq!(
+        Date {
+            year: 1970, month: 1, day: 2
+        },
+        date
+    );
+    assert_eq!("1970-01-02", date.to_string());
+    assert_eq!(1, date.days_since_unix_epoch());
+    assert_eq!(
+        -719528,
+        Date {
+            year: 0, month: 1, day: 1
+        }
+        .days_since_unix_epoch()
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    let date = Date {
+        year: 2024, month: 2, day: 29
+    };
+
+    assert_eq!(
+        validators::chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        date.try_into().unwrap()
+    );
+}
+
+#[cfg(feature = "time-dep")]
+#[test]
+fn time() {
+    let date = Date {
+        year: 2024, month: 2, day: 29
+    };
+
+    assert_eq!(
+        validators::time::Date::from_calendar_date(2024, validators::time::Month::February, 29)
+            .unwrap(),
+        date.try_into().unwrap()
+    );
+}
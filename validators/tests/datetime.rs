@@ -0,0 +1,211 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "datetime"))]
+
+use validators::{errors::DateTimeError, prelude::*};
+use validators_prelude::DateTime;
+
+#[test]
+fn basic() {
+    macro_rules! test_case {
+        ($test:ident, $validator:ident) => {
+            type Validator = $validator;
+            let test = $test;
+
+            let extended = !Validator::V_BASIC_FORMAT.must();
+            let basic = Validator::V_BASIC_FORMAT.allow();
+            let local = !Validator::V_TIMEZONE.must();
+            let zoned = Validator::V_TIMEZONE.allow();
+            let fraction = Validator::V_FRACTION.allow();
+
+            test("", false);
+            test("2024-02-29", false);
+            test("2024-02-29T", false);
+            test("2024-02-29_12:34:56", false);
+            test("2023-02-29T12:34:56", false);
+            test("2024-02-29T24:00:00", false);
+            test("2024-02-29T123456", false);
+            test("20240229T12:34:56", false);
+            test("2024-02-29T12:34:56+0800", false);
+            test("2024-02-29T12:34:56", extended && local);
+            test("2024-02-29t12:34:56", extended && local);
+            test("2024-02-29 12:34:56", extended && local);
+            test("2024-02-29T12:34:56.789", extended && local && fraction);
+            test("2024-02-29T12:34:56Z", extended && zoned);
+            test("2024-02-29T12:34:56.789+08:00", extended && zoned && fraction);
+            test("20240229T123456", basic && local);
+            test("20240229T123456,5", basic && local && fraction);
+            test("20240229T123456-0130", basic && zoned);
+        };
+    }
+
+    macro_rules! test_inner {
+        ( @unit $parameters:expr, $validator:ident) => {
+            {
+                fn test(s: &str, is_ok: bool) {
+                    let panic = match $validator::validate_str(s) {
+                        Ok(_) => !is_ok,
+                        Err(_) if !is_ok => false,
+                        Err(err) => {
+                            eprintln!("{}", err);
+
+                            true
+                        }
+                    };
+
+                    if panic {
+                        panic!("{:?}: {} {} expect {}", s, stringify!($validator), $parameters, is_ok);
+                    }
+                }
+
+                test_case!(test, $validator);
+            }
+        };
+        ( $parameters:expr ; $($validator:ident),* $(,)*) => {
+            $(
+                test_inner!(
+                    @unit
+                    $parameters,
+                    $validator
+                );
+            )*
+        };
+    }
+
+    macro_rules! test {
+        ($( { $( $p:meta => $v:meta ),* $(,)* } ),* $(,)* ) => {
+            $(
+                {
+                    #[derive(Validator)]
+                    #[validator(datetime($($p($v),)*))]
+                    pub struct DateTimeValidator(pub DateTime);
+
+                    test_inner!(
+                        stringify! {
+                            $(
+                                $p = $v,
+                            )*
+                        };
+                        DateTimeValidator,
+                    );
+                }
+            )*
+        }
+    }
+
+    test! {
+        {
+            basic_format => Allow,
+            timezone => Allow,
+            fraction => Allow,
+        },
+        {
+            basic_format => Must,
+            timezone => Must,
+            fraction => Disallow,
+        },
+        {
+            basic_format => Disallow,
+            timezone => Disallow,
+            fraction => Allow,
+        },
+        {
+            basic_format => Allow,
+            timezone => Must,
+            fraction => Disallow,
+        },
+        {
+            basic_format => Disallow,
+            timezone => Allow,
+            fraction => Disallow,
+        },
+    }
+}
+
+#[test]
+fn range() {
+    #[derive(Validator)]
+    #[validator(datetime(range(
+        min = "2000-01-01T00:00:00Z",
+        max = "2000-01-01T23:59:59.5+08:00"
+    )))]
+    pub struct Day(pub DateTime);
+
+    assert!(matches!(Day::parse_str("1999-12-31T23:59:59.999Z"), Err(DateTimeError::TooEarly)));
+    assert!(matches!(Day::parse_str("2000-01-01T07:59:59+08:00"), Err(DateTimeError::TooEarly)));
+    assert!(Day::parse_str("2000-01-01T08:00:00+08:00").is_ok());
+    assert!(Day::parse_str("2000-01-01T00:00:00").is_ok());
+    assert!(Day::parse_str("2000-01-01T15:59:59.5Z").is_ok());
+    assert!(matches!(Day::parse_str("2000-01-01T15:59:59.6Z"), Err(DateTimeError::TooLate)));
+
+    assert_eq!("2000-01-01T00:00:00Z", Day::V_MIN.unwrap().to_string());
+    assert_eq!("2000-01-01T23:59:59.5+08:00", Day::V_MAX.unwrap().to_string());
+}
+
+#[test]
+fn model() {
+    #[derive(Validator)]
+    #[validator(datetime(basic_format(Allow)))]
+    pub struct AnyDateTime(pub DateTime);
+
+    let date_time = AnyDateTime::parse_str("19700101T000001-0100").unwrap().0;
+
+    assert_eq!(3601, date_time.unix_timestamp());
+    assert_eq!("1970-01-01T00:00:01-01:00", date_time.to_string());
+
+    let date_time = AnyDateTime::parse_str("2024-02-29T12:34:56.000100").unwrap().0;
+
+    assert_eq!(1709210096, date_time.unix_timestamp());
+    assert_eq!("2024-02-29T12:34:56.0001", date_time.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    #[derive(Validator)]
+    #[validator(datetime(timezone(Must)))]
+    pub struct Timestamp(pub DateTime);
+
+    let timestamp: Timestamp = serde_json::from_str("\"2024-02-29 12:34:56z\"").unwrap();
+
+    assert_eq!("\"2024-02-29T12:34:56Z\"", serde_json::to_string(&timestamp).unwrap());
+    assert!(serde_json::from_str::<Timestamp>("\"2024-02-29T12:34:56\"").is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use validators::chrono::{FixedOffset, NaiveDateTime};
+
+    let date_time = validators_prelude::parse_date_time("2024-02-29T12:34:56.789+08:00").unwrap().0;
+
+    let naive: NaiveDateTime = date_time.try_into().unwrap();
+
+    assert_eq!("2024-02-29 12:34:56.789", naive.to_string());
+
+    let zoned: validators::chrono::DateTime<FixedOffset> = date_time.try_into().unwrap();
+
+    assert_eq!(1709181296, zoned.timestamp());
+
+    let local = validators_prelude::parse_date_time("2024-02-29T12:34:56").unwrap().0;
+
+    assert!(matches!(
+        validators::chrono::DateTime::<FixedOffset>::try_from(local),
+        Err(DateTimeError::TimezoneMust)
+    ));
+}
+
+#[cfg(feature = "time-dep")]
+#[test]
+fn time() {
+    use validators::time::{OffsetDateTime, PrimitiveDateTime};
+
+    let date_time = validators_prelude::parse_date_time("2024-02-29T12:34:56.789-08:00").unwrap().0;
+
+    let primitive: PrimitiveDateTime = date_time.try_into().unwrap();
+
+    assert_eq!(56, primitive.second());
+
+    let offset: OffsetDateTime = date_time.try_into().unwrap();
+
+    assert_eq!(1709238896, offset.unix_timestamp());
+    assert_eq!(date_time.unix_timestamp(), offset.unix_timestamp());
+}
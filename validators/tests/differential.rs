@@ -0,0 +1,171 @@
+#![cfg(all(feature = "derive", feature = "all-validators"))]
+
+//! Run the checks of the fuzz targets over a deterministic corpus, so that they are exercised without `cargo fuzz`.
+
+#[allow(dead_code)]
+#[path = "../../fuzz/src/lib.rs"]
+mod fuzz;
+
+const ALPHABET: &[&str] = &[
+    "a", "Z", "0", "9", "-", ".", "@", ":", "[", "]", "\"", "\\", " ", "\t", "\n", "\r", "\x00",
+    "\x7F", "(", ")", "%", "+", "=", "/", "_", "x", "f", "é", "中", "xn--", "::", "255", "65536",
+];
+
+/// Mutate the seeds with up to three random edits each, by a xorshift generator.
+fn corpus(seeds: &[&str]) -> Vec<Vec<u8>> {
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+
+    let mut next = |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        (state % n as u64) as usize
+    };
+
+    let mut corpus = Vec::new();
+
+    for seed in seeds {
+        corpus.push(seed.as_bytes().to_vec());
+
+        for _ in 0..2000 {
+            let mut s = seed.to_string();
+
+            for _ in 0..next(4) {
+                let mut p = next(s.len() + 1);
+
+                while !s.is_char_boundary(p) {
+                    p -= 1;
+                }
+
+                let piece = ALPHABET[next(ALPHABET.len())];
+
+                match next(3) {
+                    0 => s.insert_str(p, piece),
+                    1 => {
+                        if p < s.len() {
+                            s.remove(p);
+                        }
+
+                        s.insert_str(p, piece);
+                    },
+                    _ => {
+                        if p < s.len() {
+                            s.remove(p);
+                        }
+                    },
+                }
+            }
+
+            corpus.push(s.into_bytes());
+        }
+    }
+
+    // the fuzz targets also get invalid UTF-8
+    corpus.push(vec![0xFF, b'a', 0xC0]);
+
+    corpus
+}
+
+fn run(check: fn(&[u8]), seeds: &[&str]) {
+    for data in corpus(seeds) {
+        check(&data);
+    }
+}
+
+#[test]
+fn base_xx() {
+    let seeds = ["", "MZXW6===", "MZXW6YTBOI======", "Zm9vYmFy", "Zm9vYg==", "Zm9vYg", "-_-_"];
+
+    run(fuzz::base32, &seeds);
+    run(fuzz::base32_decoded, &seeds);
+    run(fuzz::base64, &seeds);
+    run(fuzz::base64_decoded, &seeds);
+    run(fuzz::base64_url, &seeds);
+    run(fuzz::base64_url_decoded, &seeds);
+}
+
+#[test]
+fn units() {
+    run(fuzz::bit, &["100 Mb", "1.5 GiB", "0", "12 kbps"]);
+    run(fuzz::byte, &["100 MB", "1.5 GiB", "0", "12 kB"]);
+    run(fuzz::duration, &["1s", "1.5 h", "20 ms", "3d"]);
+    run(fuzz::boolean, &["true", "false", "yes", "0", "on"]);
+}
+
+#[test]
+fn date_time() {
+    run(fuzz::date, &["2000-02-29", "20000229", "1999-12-31"]);
+    run(fuzz::datetime, &[
+        "2000-01-01T00:00:00Z",
+        "2000-01-01T00:00:00.5+08:00",
+        "20000101T000000",
+    ]);
+    run(fuzz::time, &["00:00:00", "23:59:60.5", "1230"]);
+}
+
+#[test]
+fn domain() {
+    run(fuzz::domain, &[
+        "example.com",
+        "a-b.example.",
+        "localhost",
+        "127.0.0.1",
+        "xn--fiq228c.tw",
+        "中文.tw",
+    ]);
+}
+
+#[test]
+fn email() {
+    run(fuzz::email, &[
+        "user@example.com",
+        "first.last@[127.0.0.1]",
+        "\"quoted\\ string\"@[IPv6:::1]",
+        "(comment)user(comment)@(comment)example.com(comment)",
+        "用户@例子.广告",
+    ]);
+}
+
+#[test]
+fn host() {
+    run(fuzz::host, &["example.com:8080", "127.0.0.1", "[::1]:443", "localhost", "a.b.c.d"]);
+}
+
+#[test]
+fn ip() {
+    run(fuzz::ip, &[
+        "127.0.0.1",
+        "255.255.255.255:65535",
+        "::1",
+        "[fe80::1]:80",
+        "::ffff:1.2.3.4",
+        "1:2:3:4:5:6:7:8",
+    ]);
+}
+
+#[test]
+fn text() {
+    let seeds = ["Hello, world!", "tab\there", "line\r\nbreak", "中文"];
+
+    run(fuzz::text, &seeds);
+    run(fuzz::line, &seeds);
+}
+
+#[test]
+fn others() {
+    run(fuzz::http_url, &["https://example.com/path?q=1", "http://[::1]:80/"]);
+    run(fuzz::http_ftp_url, &["ftp://example.com/file", "https://example.com"]);
+    run(fuzz::url, &["mailto:user@example.com", "file:///tmp"]);
+    run(fuzz::json, &["{\"a\": [1, 2.5, null]}", "\"s\"", "true"]);
+    run(fuzz::length, &["", "a", "0123456789abcdef", "0123456789abcdefg"]);
+    run(fuzz::mac_address, &["08:00:27:b2:46:c3", "080027B246C3", "08-00-27-b2-46-c3"]);
+    run(fuzz::number, &["1.5", "-0", "NaN", "1e308", "inf"]);
+    run(fuzz::phone, &["+886912345678", "+1 650-253-0000"]);
+    run(fuzz::regex, &["0123abcDEF", "xyz"]);
+    run(fuzz::semver, &["1.2.3", "1.0.0-alpha+build.1"]);
+    run(fuzz::semver_req, &[">=1.2, <2", "^0.1", "*"]);
+    run(fuzz::signed_integer, &["-9223372036854775808", "42", "+1"]);
+    run(fuzz::unsigned_integer, &["18446744073709551615", "0", "007"]);
+    run(fuzz::uuid, &["a866664a-f2cc-4d2b-a0a5-0c1b42c0ee7a", "A866664AF2CC4D2BA0A50C1B42C0EE7A"]);
+}
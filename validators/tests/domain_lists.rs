@@ -0,0 +1,186 @@
+#![cfg(feature = "derive")]
+
+#[allow(unused_imports)]
+use validators::prelude::*;
+
+#[cfg(any(feature = "domain", feature = "email", feature = "host"))]
+static DISPOSABLE: [&str; 2] = ["mailinator.com", "xn--fiq228c.tw"];
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    use std::{collections::HashSet, sync::LazyLock};
+
+    use validators::errors::EmailError;
+
+    static CORPORATE: LazyLock<HashSet<&str>> =
+        LazyLock::new(|| HashSet::from(["example.com", "example.org"]));
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Disallow),
+        allowed_domains(CORPORATE),
+        denied_domains("legacy.example.com")
+    ))]
+    pub struct CorporateEmail {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), denied_domains = DISPOSABLE))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    assert!(CorporateEmail::parse_str("jane@example.com").is_ok());
+    assert!(CorporateEmail::parse_str("jane@Mail.Example.ORG").is_ok());
+    assert!(matches!(
+        CorporateEmail::parse_str("jane@myexample.com"),
+        Err(EmailError::DomainNotAllowed)
+    ));
+    assert!(matches!(
+        CorporateEmail::parse_str("jane@example.com.evil"),
+        Err(EmailError::DomainNotAllowed)
+    ));
+    assert!(matches!(
+        CorporateEmail::parse_str("jane@[127.0.0.1]"),
+        Err(EmailError::DomainNotAllowed)
+    ));
+    assert!(matches!(
+        CorporateEmail::parse_str("jane@a.legacy.example.com"),
+        Err(EmailError::DomainDenied)
+    ));
+    assert!(matches!(CorporateEmail::parse_str("jane@@example.com"), Err(EmailError::Invalid)));
+
+    assert!(Email::parse_str("jane@example.com").is_ok());
+    assert!(Email::parse_str("jane@[127.0.0.1]").is_ok());
+    assert!(matches!(Email::parse_str("jane@MAILINATOR.com"), Err(EmailError::DomainDenied)));
+    assert!(matches!(Email::parse_str("jane@x.mailinator.com"), Err(EmailError::DomainDenied)));
+    assert!(matches!(Email::parse_str("jane@中文.tw"), Err(EmailError::DomainDenied)));
+    assert!(Email::parse_str("jane@中文.com").is_ok());
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn domain() {
+    use validators::errors::DomainError;
+
+    #[derive(Validator)]
+    #[validator(domain(
+        port(Allow),
+        allowed_domains("example.com", "example.net."),
+        denied_domains(DISPOSABLE)
+    ))]
+    pub struct Domain {
+        pub domain: String,
+        pub port:   Option<u16>,
+    }
+
+    assert!(Domain::parse_str("example.com").is_ok());
+    assert!(Domain::parse_str("a.b.example.net:8080").is_ok());
+    assert!(Domain::parse_str("example.net.").is_ok());
+    assert!(matches!(Domain::parse_str("example.org"), Err(DomainError::DomainNotAllowed)));
+    assert!(matches!(Domain::parse_str("127.0.0.1"), Err(DomainError::DomainNotAllowed)));
+    assert!(matches!(Domain::parse_str("a_b.example.com"), Err(DomainError::Invalid)));
+
+    #[derive(Validator)]
+    #[validator(domain(port(Disallow), denied_domains(DISPOSABLE)))]
+    pub struct DomainWithoutPort(pub String);
+
+    assert!(DomainWithoutPort::parse_str("127.0.0.1").is_ok());
+    assert!(matches!(DomainWithoutPort::parse_str("中文.TW"), Err(DomainError::DomainDenied)));
+}
+
+#[cfg(feature = "host")]
+#[test]
+fn host() {
+    use validators::errors::HostError;
+
+    #[derive(Validator)]
+    #[validator(host(port(Allow), denied_domains(DISPOSABLE)))]
+    pub struct Host {
+        pub host: validators::models::Host,
+        pub port: Option<u16>,
+    }
+
+    assert!(Host::parse_str("example.com:80").is_ok());
+    assert!(Host::parse_str("[::1]:80").is_ok());
+    assert!(matches!(Host::parse_str("mailinator.com:25"), Err(HostError::DomainDenied)));
+
+    #[derive(Validator)]
+    #[validator(host(port(Disallow), allowed_domains("localhost")))]
+    pub struct LocalHost(pub validators::models::Host);
+
+    assert!(LocalHost::parse_str("localhost").is_ok());
+    assert!(matches!(LocalHost::parse_str("127.0.0.1"), Err(HostError::DomainNotAllowed)));
+}
+
+#[cfg(all(feature = "http_url", feature = "http_ftp_url"))]
+#[test]
+fn http_url() {
+    use validators::errors::{HttpFtpURLError, HttpURLError};
+
+    static INTERNAL: &[&str] = &["internal.example.com"];
+
+    const PUBLIC: [&str; 1] = ["example.org"];
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(http_url(allowed_domains(INTERNAL)))]
+    pub struct InternalUrl {
+        pub url:      validators::url::Url,
+        pub is_https: bool,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(http_ftp_url(denied_domains(PUBLIC)))]
+    pub struct Url {
+        pub url:      validators::url::Url,
+        pub protocol: validators::models::Protocol,
+    }
+
+    assert!(InternalUrl::parse_str("https://api.internal.example.com/v1").is_ok());
+    assert!(matches!(
+        InternalUrl::parse_str("https://example.com/"),
+        Err(HttpURLError::DomainNotAllowed)
+    ));
+    assert!(matches!(InternalUrl::parse_str("http://[::1]/"), Err(HttpURLError::DomainNotAllowed)));
+
+    assert!(Url::parse_str("ftp://example.com/file").is_ok());
+    assert!(matches!(Url::parse_str("FTP://www.EXAMPLE.org/"), Err(HttpFtpURLError::DomainDenied)));
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn domain_set() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let list = ["Example.com", "xn--fiq228c.tw."];
+
+    assert!(list.contains_domain("example.com"));
+    assert!(list.contains_domain("a.EXAMPLE.com."));
+    assert!(list.contains_domain("中文.tw"));
+    assert!(list.contains_domain("a.中文.tw"));
+    assert!(!list.contains_domain("example.co"));
+    assert!(!list.contains_domain("xample.com"));
+    assert!(!list.contains_domain("com"));
+    assert!(!Vec::from([String::from("example.com")]).contains_domain("example.org"));
+
+    let set = HashSet::from(["example.com", "xn--fiq228c.tw"]);
+
+    assert!(set.contains_domain("A.Example.com"));
+    assert!(set.contains_domain("中文.tw"));
+    assert!(!set.contains_domain("myexample.com"));
+
+    let set = BTreeSet::from([String::from("example.com")]);
+
+    assert!(set.contains_domain("a.b.example.com."));
+    assert!(!set.contains_domain("example.com.a"));
+}
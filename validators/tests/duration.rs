@@ -0,0 +1,216 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "duration"))]
+
+use std::time::Duration;
+
+use validators::{
+    errors::DurationError,
+    prelude::{validators_prelude::RangeOption, *},
+};
+
+fn check_range(v: f64, range: RangeOption<f64>) -> bool {
+    if let RangeOption::Inside {
+        max,
+        min,
+        inclusive,
+    } = range
+    {
+        if let Some(min) = min
+            && v < min
+        {
+            return false;
+        }
+
+        if let Some(max) = max {
+            if inclusive {
+                if v > max {
+                    return false;
+                }
+            } else if v >= max {
+                return false;
+            }
+        }
+
+        true
+    } else {
+        false
+    }
+}
+
+#[test]
+fn basic() {
+    macro_rules! test_case {
+        ($test:ident, $validator:ident) => {
+            type Validator = $validator;
+            let test = $test;
+
+            let upper = Validator::V_IGNORE_CASE;
+
+            test("", false);
+            test("P", false);
+            test("PT", false);
+            test("P1DT", false);
+            test("P1Y", false);
+            test("P1M", false);
+            test("PT1S1M", false);
+            test("PT1.5M30S", false);
+            test("1 s", check_range(1.0, Validator::V_RANGE));
+            test("1.", false);
+            test("1x", false);
+            test("h", false);
+            test("1h30", false);
+            test(" 1s", false);
+            test("0", check_range(0.0, Validator::V_RANGE));
+            test("PT0S", check_range(0.0, Validator::V_RANGE));
+            test("90", check_range(90.0, Validator::V_RANGE));
+            test("90s", check_range(90.0, Validator::V_RANGE));
+            test("PT90S", check_range(90.0, Validator::V_RANGE));
+            test("pt90s", upper && check_range(90.0, Validator::V_RANGE));
+            test("PT1M30S", check_range(90.0, Validator::V_RANGE));
+            test("PT1.5M", check_range(90.0, Validator::V_RANGE));
+            test("1m30s", check_range(90.0, Validator::V_RANGE));
+            test("1 min 30 secs", check_range(90.0, Validator::V_RANGE));
+            test("1.5 Minutes", upper && check_range(90.0, Validator::V_RANGE));
+            test("500ms", check_range(0.5, Validator::V_RANGE));
+            test("1.5 days", check_range(129600.0, Validator::V_RANGE));
+            test("P1DT12H", check_range(129600.0, Validator::V_RANGE));
+            test("P1W", check_range(604800.0, Validator::V_RANGE));
+        };
+    }
+
+    macro_rules! test_inner {
+        ( @unit $parameters:expr, $validator:ident) => {
+            {
+                fn test(s: &str, is_ok: bool) {
+                    let panic = match $validator::validate_str(s) {
+                        Ok(_) => !is_ok,
+                        Err(_) if !is_ok => false,
+                        Err(err) => {
+                            eprintln!("{}", err);
+
+                            true
+                        }
+                    };
+
+                    if panic {
+                        panic!("{:?}: {} {} expect {}", s, stringify!($validator), $parameters, is_ok);
+                    }
+                }
+
+                test_case!(test, $validator);
+            }
+        };
+        ( $parameters:expr ; $($validator:ident),* $(,)*) => {
+            $(
+                test_inner!(
+                    @unit
+                    $parameters,
+                    $validator
+                );
+            )*
+        };
+    }
+
+    macro_rules! test {
+        ($( { $( $p:meta ),* $(,)* } ),* $(,)* ) => {
+            $(
+                {
+                    #[allow(dead_code)]
+                    #[derive(Validator)]
+                    #[validator(duration($($p,)*))]
+                    pub struct DurationValidator(Duration);
+
+                    test_inner!(
+                        stringify! {
+                            $(
+                                $p,
+                            )*
+                        };
+                        DurationValidator,
+                    );
+                }
+            )*
+        }
+    }
+
+    test! {
+        {
+        },
+        {
+            range(min = 1),
+        },
+        {
+            range(max = 90),
+        },
+        {
+            range(min = 0.5, max = 90, inclusive = false),
+        },
+        {
+            ignore_case = false,
+        },
+    }
+}
+
+#[test]
+fn parse() {
+    #[derive(Validator)]
+    #[validator(duration(range(min = 0.25, max = 3600)))]
+    pub struct Timeout(Duration);
+
+    assert_eq!(Duration::from_millis(1500), Timeout::parse_str("PT1.5S").unwrap().0);
+    assert_eq!(Duration::from_millis(1500), Timeout::parse_str("1,5s").unwrap().0);
+    assert_eq!(Duration::from_micros(250_001), Timeout::parse_str("250ms 1µs").unwrap().0);
+    assert_eq!(Duration::from_secs(3600), Timeout::parse_str("1 hour").unwrap().0);
+    assert!(matches!(Timeout::parse_str("249ms"), Err(DurationError::TooSmall)));
+    assert!(matches!(Timeout::parse_str("1h 1ns"), Err(DurationError::TooLarge)));
+    assert!(matches!(Timeout::parse_str("1 fortnight"), Err(DurationError::Invalid)));
+
+    assert!(Timeout::parse_u64(3600).is_ok());
+    assert!(matches!(Timeout::parse_u64(0), Err(DurationError::TooSmall)));
+    assert!(matches!(Timeout::parse_u128(u128::MAX), Err(DurationError::TooLarge)));
+}
+
+#[test]
+fn overflow() {
+    #[derive(Validator)]
+    #[validator(duration)]
+    pub struct AnyDuration(Duration);
+
+    assert_eq!(Duration::MAX, AnyDuration::parse_str("18446744073709551615.999999999s").unwrap().0);
+    assert!(matches!(
+        AnyDuration::parse_str("18446744073709551616s"),
+        Err(DurationError::TooLarge)
+    ));
+    assert!(matches!(
+        AnyDuration::parse_str("999999999999999999999999999999999999999999w"),
+        Err(DurationError::TooLarge)
+    ));
+}
+
+#[test]
+fn iso8601() {
+    use validators::models::Iso8601Duration;
+
+    assert_eq!("PT0S", Iso8601Duration(Duration::ZERO).to_string());
+    assert_eq!("P1D", Iso8601Duration(Duration::from_secs(86400)).to_string());
+    assert_eq!("PT1H30M", Iso8601Duration(Duration::from_secs(5400)).to_string());
+    assert_eq!("P1DT0.25S", Iso8601Duration(Duration::from_millis(86_400_250)).to_string());
+    assert_eq!("PT0.000000001S", Iso8601Duration(Duration::from_nanos(1)).to_string());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde() {
+    #[derive(Validator)]
+    #[validator(duration(range(max = 172800)))]
+    pub struct Retention(Duration);
+
+    let retention: Retention = validators::serde_json::from_str("\"1.5 days\"").unwrap();
+
+    assert_eq!("\"P1DT12H\"", validators::serde_json::to_string(&retention).unwrap());
+
+    let retention: Retention = validators::serde_json::from_str("60").unwrap();
+
+    assert_eq!("\"PT1M\"", validators::serde_json::to_string(&retention).unwrap());
+
+    assert!(validators::serde_json::from_str::<Retention>("172801").is_err());
+}
@@ -465,3 +465,309 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn local_part_edges() {
+    #[derive(Validator)]
+    #[validator(email(ip(Disallow), local(Allow), at_least_two_labels(Allow), non_ascii(Allow)))]
+    pub struct Email {
+        pub local_part:                 String,
+        pub need_quoted:                bool,
+        pub domain_part:                String,
+        pub comment_before_local_part:  Option<String>,
+        pub comment_after_local_part:   Option<String>,
+        pub comment_before_domain_part: Option<String>,
+        pub comment_after_domain_part:  Option<String>,
+    }
+
+    for s in ["a.@example.com", "a.(comment)@example.com"] {
+        assert!(Email::parse_str(s).is_err());
+        assert!(Email::validate_str(s).is_err());
+    }
+
+    for s in ["\"\\ \"@example.com", "\"a\\ b\"@example.com"] {
+        assert!(Email::parse_str(s).is_ok());
+        assert!(Email::validate_str(s).is_ok());
+    }
+}
+
+#[test]
+fn display_name() {
+    use validators::errors::EmailError;
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), display_name(Allow)))]
+    pub struct Mailbox {
+        pub local_part:   String,
+        pub need_quoted:  bool,
+        pub domain_part:  validators::models::Host,
+        pub display_name: Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), non_ascii(Disallow), display_name(Must)))]
+    pub struct NamedMailbox {
+        pub local_part:   String,
+        pub need_quoted:  bool,
+        pub domain_part:  validators::models::Host,
+        pub display_name: Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), display_name(Disallow)))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    assert!(Mailbox::V_DISPLAY_NAME.allow() && !Mailbox::V_DISPLAY_NAME.must());
+
+    for (s, display_name, email) in [
+        ("jane@example.com", None, "jane@example.com"),
+        ("<jane@example.com>", None, "jane@example.com"),
+        ("Jane Doe <jane@example.com>", Some("Jane Doe"), "jane@example.com"),
+        ("Jane \t Q.  Doe<jane@example.com>", Some("Jane Q. Doe"), "jane@example.com"),
+        ("\"Doe, Jane\" <jane@example.com>", Some("Doe, Jane"), "jane@example.com"),
+        ("\"Jane \\\"JD\\\" Doe\" <jane@example.com>", Some("Jane \"JD\" Doe"), "jane@example.com"),
+        ("=?UTF-8?B?5Lit5paH?= <jane@example.com>", Some("中文"), "jane@example.com"),
+        ("=?utf-8?q?Andr=C3=A9_Doe?= <andre@example.com>", Some("André Doe"), "andre@example.com"),
+        (
+            "=?ISO-8859-1?Q?Andr=E9?= =?UTF-8?B?IERvZQ==?= <andre@example.com>",
+            Some("André Doe"),
+            "andre@example.com",
+        ),
+        ("Mr. =?UTF-8?Q?Andr=C3=A9?= <andre@example.com>", Some("Mr. André"), "andre@example.com"),
+        ("=?x-unknown?Q?abc?= <a@example.com>", Some("=?x-unknown?Q?abc?="), "a@example.com"),
+    ] {
+        let mailbox = Mailbox::parse_str(s).unwrap_or_else(|err| panic!("{s:?}: {err}"));
+
+        assert_eq!(display_name, mailbox.display_name.as_deref(), "{s:?}");
+        assert_eq!(email, mailbox.to_email_string(), "{s:?}");
+
+        // rendering the mailbox and parsing it again keeps the same display name
+        let again = Mailbox::parse_string(mailbox.to_mailbox_string()).unwrap();
+
+        assert_eq!(mailbox.display_name, again.display_name, "{s:?}");
+        assert_eq!(email, again.to_email_string(), "{s:?}");
+    }
+
+    for s in [
+        "Jane Doe jane@example.com>",
+        "Jane Doe <>",
+        "Jane Doe <jane@example.com",
+        "Jane Doe <jane@example.com> ",
+        "Jane (Doe) <jane@example.com>",
+        "Jane, Doe <jane@example.com>",
+        "\"Jane Doe <jane@example.com>",
+        "Jane Doe <<jane@example.com>>",
+        "Jane Doe <jane@example>com>",
+    ] {
+        assert!(Mailbox::parse_str(s).is_err(), "{s:?}");
+    }
+
+    assert_eq!(
+        "Jane Doe <jane@example.com>",
+        Mailbox::parse_str("Jane   Doe<jane@example.com>").unwrap().to_mailbox_string()
+    );
+    assert_eq!(
+        "\"Doe, Jane\" <jane@example.com>",
+        Mailbox::parse_str("\"Doe, Jane\"<jane@example.com>").unwrap().to_mailbox_string()
+    );
+    assert_eq!(
+        "=?UTF-8?B?5Lit5paH?= <jane@example.com>",
+        Mailbox::parse_str("=?UTF-8?Q?=E4=B8=AD=E6=96=87?= <jane@example.com>")
+            .unwrap()
+            .to_mailbox_string()
+    );
+    assert_eq!(
+        "jane@example.com",
+        Mailbox::parse_str("<jane@example.com>").unwrap().to_mailbox_string()
+    );
+
+    // non-ASCII characters are still allowed in encoded words
+    assert_eq!(
+        Some("中文"),
+        NamedMailbox::parse_str("=?UTF-8?B?5Lit5paH?= <jane@example.com>")
+            .unwrap()
+            .display_name
+            .as_deref()
+    );
+    assert!(NamedMailbox::parse_str("中文 <jane@example.com>").is_err());
+    assert!(matches!(
+        NamedMailbox::parse_str("jane@example.com"),
+        Err(EmailError::DisplayNameMust)
+    ));
+    assert!(matches!(
+        NamedMailbox::parse_str("<jane@example.com>"),
+        Err(EmailError::DisplayNameMust)
+    ));
+    assert!(matches!(
+        NamedMailbox::parse_str("Jane (Doe) <jane@example.com>"),
+        Err(EmailError::Invalid)
+    ));
+
+    assert!(Email::parse_str("jane@example.com").is_ok());
+    assert!(matches!(
+        Email::parse_str("Jane Doe <jane@example.com>"),
+        Err(EmailError::DisplayNameDisallow)
+    ));
+    assert!(matches!(Email::parse_str("<jane@example.com>"), Err(EmailError::DisplayNameDisallow)));
+    assert_eq!(
+        "jane@example.com",
+        Email::parse_str("jane@example.com").unwrap().to_mailbox_string()
+    );
+}
+
+#[test]
+fn display_name_comment() {
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(display_name(Allow)))]
+    pub struct Mailbox {
+        pub local_part:                 String,
+        pub need_quoted:                bool,
+        pub domain_part:                validators::models::Host,
+        pub comment_before_local_part:  Option<String>,
+        pub comment_after_local_part:   Option<String>,
+        pub comment_before_domain_part: Option<String>,
+        pub comment_after_domain_part:  Option<String>,
+        pub display_name:               Option<String>,
+    }
+
+    for (s, display_name) in [
+        ("Jane (Doe) <jane@example.com>", Some("Jane")),
+        ("Jane(Doe)Doe <jane@example.com>", Some("Jane Doe")),
+        ("(Doe) <jane@example.com>", None),
+        ("Jane (a (nested) \\) comment) Doe <jane@example.com>", Some("Jane Doe")),
+        ("\"Jane\" (Doe)<jane@example.com>", Some("Jane")),
+    ] {
+        let mailbox = Mailbox::parse_str(s).unwrap_or_else(|err| panic!("{s:?}: {err}"));
+
+        assert_eq!(display_name, mailbox.display_name.as_deref(), "{s:?}");
+        assert_eq!("jane@example.com", mailbox.to_email_string(), "{s:?}");
+    }
+
+    for s in [
+        "Jane (Doe <jane@example.com>",
+        "Jane (Doe)) <jane@example.com>",
+        "Jane (Do\re) <jane@example.com>",
+    ] {
+        assert!(Mailbox::parse_str(s).is_err(), "{s:?}");
+    }
+}
+
+#[test]
+fn display_name_control() {
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), display_name(Allow)))]
+    pub struct Mailbox {
+        pub local_part:   String,
+        pub need_quoted:  bool,
+        pub domain_part:  validators::models::Host,
+        pub display_name: Option<String>,
+    }
+
+    let mailbox = Mailbox {
+        local_part:   String::from("jane"),
+        need_quoted:  false,
+        domain_part:  validators::models::Host::Domain(String::from("example.com")),
+        display_name: Some(String::from("x\r\nBcc: y")),
+    };
+
+    let s = mailbox.to_mailbox_string();
+
+    // CR and LF are encoded, so they cannot start another header field
+    assert!(!s.contains(['\r', '\n']), "{s:?}");
+    assert_eq!("=?UTF-8?B?eA0KQmNjOiB5?= <jane@example.com>", s);
+
+    let mailbox = Mailbox {
+        display_name: Some(String::from("Jane\tDoe")),
+        ..mailbox
+    };
+
+    assert_eq!("\"Jane\tDoe\" <jane@example.com>", mailbox.to_mailbox_string());
+}
+
+#[test]
+fn canonical() {
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(display_name(Allow)))]
+    pub struct Mailbox {
+        pub local_part:                 String,
+        pub need_quoted:                bool,
+        pub domain_part:                validators::models::Host,
+        pub comment_before_local_part:  Option<String>,
+        pub comment_after_local_part:   Option<String>,
+        pub comment_before_domain_part: Option<String>,
+        pub comment_after_domain_part:  Option<String>,
+        pub display_name:               Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), ip(Disallow)))]
+    pub struct EmailNonIP {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), ip(Must)))]
+    pub struct EmailIP {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: std::net::IpAddr,
+    }
+
+    let default = CanonicalEmailOptions::default();
+
+    let options = CanonicalEmailOptions::builder()
+        .lowercase_local_part(true)
+        .sub_address_separator(Some('+'))
+        .dot_insensitive_domain("GMail.com")
+        .dot_insensitive_domain("例子.广告")
+        .build();
+
+    for (s, by_default, by_options) in [
+        ("Jane@Example.COM", "Jane@example.com", "jane@example.com"),
+        ("Jane.Doe+news@example.com", "Jane.Doe+news@example.com", "jane.doe@example.com"),
+        ("Jane.Doe+news+more@gmail.com", "Jane.Doe+news+more@gmail.com", "janedoe@gmail.com"),
+        ("(a)J.D(b)@(c)GMAIL.com(d)", "J.D@gmail.com", "jd@gmail.com"),
+        ("\"Jane Doe\" <jane.doe@Gmail.com>", "jane.doe@gmail.com", "janedoe@gmail.com"),
+        ("+tag@example.com", "+tag@example.com", "+tag@example.com"),
+        ("用户.名@例子.广告", "用户.名@xn--fsqu00a.xn--4rr70v", "用户名@xn--fsqu00a.xn--4rr70v"),
+        ("\"a b\"@example.com", "\"a b\"@example.com", "\"a b\"@example.com"),
+        ("\"a\\\"B+c\"@example.com", "\"a\\\"B+c\"@example.com", "\"a\\\"b\"@example.com"),
+        ("\"a..b\"@gmail.com", "\"a..b\"@gmail.com", "ab@gmail.com"),
+        ("\"..\"@gmail.com", "\"..\"@gmail.com", "\"..\"@gmail.com"),
+        ("Jane@[127.0.0.1]", "Jane@[127.0.0.1]", "jane@[127.0.0.1]"),
+        ("Jane@[IPv6:::1]", "Jane@[IPv6:::1]", "jane@[IPv6:::1]"),
+    ] {
+        let email = Mailbox::parse_str(s).unwrap_or_else(|err| panic!("{s:?}: {err}"));
+
+        assert_eq!(by_default, email.to_canonical_email(&default), "{s:?}");
+        assert_eq!(by_options, email.to_canonical_email(&options), "{s:?}");
+
+        // the canonical form is a valid email which is canonical already
+        let again = Mailbox::parse_string(email.to_canonical_email(&options)).unwrap();
+
+        assert_eq!(by_options, again.to_canonical_email(&options), "{s:?}");
+    }
+
+    assert_eq!(
+        EmailNonIP::parse_str("A.B+c@GMAIL.COM").unwrap().to_canonical_email(&options),
+        EmailNonIP::parse_str("ab@gmail.com").unwrap().to_canonical_email(&options)
+    );
+    assert_eq!(
+        "jane@[IPv6:::1]",
+        EmailIP::parse_str("Jane+x@[IPv6:::1]").unwrap().to_canonical_email(&options)
+    );
+}
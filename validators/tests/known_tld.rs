@@ -0,0 +1,82 @@
+#![cfg(any(feature = "domain", feature = "email"))]
+
+#[allow(unused_imports)]
+use validators::prelude::*;
+use validators::{functions::is_known_tld, models::TLDList};
+
+#[test]
+fn functions() {
+    assert!(is_known_tld("com"));
+    assert!(is_known_tld("COM"));
+    assert!(is_known_tld("example.com"));
+    assert!(is_known_tld("www.example.co.uk."));
+    assert!(is_known_tld("example.台灣"));
+    assert!(is_known_tld("example.xn--kpry57d"));
+    assert!(is_known_tld("example.рф"));
+
+    assert!(!is_known_tld("example.con"));
+    assert!(!is_known_tld("localhost"));
+    assert!(!is_known_tld("example.local"));
+    assert!(!is_known_tld("example."));
+    assert!(!is_known_tld(""));
+}
+
+#[test]
+fn parse() {
+    let list = TLDList::parse("# Version 2099010100\nCOM\nXN--KPRY57D\n中文\n\n");
+
+    assert_eq!(3, list.len());
+    assert_eq!(Some("2099010100"), list.version());
+    assert!(list.contains("com"));
+    assert!(list.contains("台灣"));
+    assert!(list.contains("xn--fiq228c."));
+    assert!(!list.contains("org"));
+    assert!(!list.contains("example.com"));
+    assert!(list.contains_tld_of("www.example.中文"));
+    assert!(!list.contains_tld_of("example.org"));
+
+    assert_eq!(
+        Some("2099010100"),
+        TLDList::parse("# Version 2099010100, Last Updated Thu Jan  1 00:00:00 2099 UTC\nCOM\n")
+            .version()
+    );
+    assert_eq!(None, TLDList::parse("# Some comment\nCOM\n").version());
+
+    assert!(TLDList::builtin().len() > 1000);
+    assert!(TLDList::default().is_empty());
+}
+
+#[cfg(all(feature = "derive", feature = "domain"))]
+#[test]
+fn domain() {
+    use validators::errors::DomainError;
+
+    #[derive(Validator)]
+    #[validator(domain(ipv4(Allow), port(Disallow), known_tld(Must)))]
+    pub struct Domain(pub String);
+
+    assert!(Domain::parse_str("example.com").is_ok());
+    assert!(Domain::parse_str("example.org.").is_ok());
+    assert!(Domain::parse_str("127.0.0.1").is_ok());
+    assert!(matches!(Domain::parse_str("example.con"), Err(DomainError::KnownTLDMust)));
+    assert!(matches!(Domain::parse_str("localhost"), Err(DomainError::KnownTLDMust)));
+}
+
+#[cfg(all(feature = "derive", feature = "email"))]
+#[test]
+fn email() {
+    use validators::errors::EmailError;
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), ip(Disallow), known_tld(Must)))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    assert!(Email::parse_str("jane@example.com").is_ok());
+    assert!(Email::parse_str("jane@中文.台灣").is_ok());
+    assert!(matches!(Email::parse_str("jane@example.con"), Err(EmailError::KnownTLDMust)));
+}
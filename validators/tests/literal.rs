@@ -0,0 +1,84 @@
+#![cfg(feature = "derive")]
+
+use validators::prelude::*;
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1))))]
+    pub struct Port(u16);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Outside(min = 10, max = 20))))]
+    pub struct Sparse(u128);
+
+    const PORT: Port = validators::literal!(Port, "8080");
+    const SPARSE: Sparse = Sparse::const_new("21");
+
+    assert_eq!(8080, PORT.0);
+    assert_eq!(21, SPARSE.0);
+
+    assert!(Sparse::parse_str("15").is_err());
+    assert!(Sparse::parse_str("9").is_ok());
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Validator)]
+    #[validator(signed_integer(range(Inside(min = -12, max = 14))))]
+    pub struct UtcOffset(i8);
+
+    const OFFSET: UtcOffset = validators::literal!(UtcOffset, "-8");
+
+    assert_eq!(-8, OFFSET.0);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    #[derive(Validator)]
+    #[validator(uuid(case(Any), separator(Allow(b'-'))))]
+    pub struct Uuid(u128);
+
+    const ID: Uuid = validators::literal!(Uuid, "A866664A-f2cc4d2b-a0a5-0c1b42c0ee7a");
+
+    assert_eq!(Uuid::parse_str("A866664A-f2cc4d2b-a0a5-0c1b42c0ee7a").unwrap().0, ID.0);
+}
+
+#[cfg(feature = "mac_address")]
+#[test]
+fn mac_address() {
+    #[derive(Validator)]
+    #[validator(mac_address(case(Lower), separator(Must(b'-'))))]
+    pub struct MacAddress(u64);
+
+    const MAC_ADDRESS: MacAddress = validators::literal!(MacAddress, "08-00-27-b2-46-c3");
+
+    assert_eq!(0x080027B246C3, MAC_ADDRESS.0);
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn custom() {
+    use validators::errors::{CustomizedError, UnsignedIntegerError};
+
+    fn not_well_known(port: &UnprivilegedPort) -> Result<(), &'static str> {
+        if port.0 == 8080 { Err("reserved port") } else { Ok(()) }
+    }
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1024)), custom = not_well_known))]
+    pub struct UnprivilegedPort(u16);
+
+    // the custom function cannot run in a const context, so there is no `const_new` and `literal!` cannot be used
+
+    assert!(UnprivilegedPort::parse_str("3000").is_ok());
+    assert!(matches!(UnprivilegedPort::parse_str("8080"), Err(CustomizedError::Custom(_))));
+    assert!(matches!(UnprivilegedPort::validate_u16(8080), Err(CustomizedError::Custom(_))));
+    assert!(matches!(
+        UnprivilegedPort::parse_u64(80),
+        Err(CustomizedError::Builtin(UnsignedIntegerError::TooSmall))
+    ));
+}
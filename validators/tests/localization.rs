@@ -0,0 +1,179 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "localization"))]
+
+use validators::{
+    errors::*,
+    localization::{Builtin, Catalog, MapCatalog},
+    prelude::*,
+};
+
+#[cfg(feature = "text")]
+#[test]
+fn builtin() {
+    #[derive(Debug, Validator)]
+    #[validator(text(char_length(max = 4)))]
+    pub struct Name(String);
+
+    let error = Name::validate_str_detailed("magiclen").unwrap_err();
+
+    assert_eq!("ist zu lang (höchstens 4)", error.localize("de"));
+    assert_eq!("est trop long (maximum 4)", error.localize("fr-CA"));
+    assert_eq!("太長（最多 4）", error.localize("zh-TW"));
+    assert_eq!("太長（最多 4）", error.localize("zh-Hant-HK"));
+    assert_eq!("太长（最多 4）", error.localize("zh"));
+    assert_eq!("text is too long", error.localize("en"));
+    assert_eq!("text is too long", error.localize("ko"));
+
+    // without the details, a template without placeholders is used
+    let error = Name::parse_str("magiclen").unwrap_err();
+
+    assert_eq!("ist zu lang", error.localize("de"));
+    assert_eq!("太长", error.localize("zh"));
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn accept_language() {
+    let error = EmailError::IPDisallow;
+
+    assert_eq!("IPアドレスは使用できません", error.localize("ko;q=0.9, ja;q=0.8, en;q=0.7"));
+    assert_eq!("must not use an IP", error.localize("en-GB, de;q=0.9"));
+    assert_eq!("ne doit pas utiliser d'adresse IP", error.localize("de;q=0, *, fr;q=0.1"));
+    assert_eq!("must not use an IP", error.localize(""));
+}
+
+#[cfg(feature = "length")]
+#[test]
+fn map_catalog() {
+    let mut catalog = MapCatalog::new();
+
+    catalog.insert("pt-BR", "too_small", "deve ter pelo menos { $min } itens");
+    catalog.insert("de", "length.too_small", "zu wenige Elemente");
+
+    let error = DetailedError::new(LengthError::TooSmall)
+        .with_params(vec![("min", ErrorParam::Unsigned(2)), ("length", ErrorParam::Unsigned(1))]);
+
+    assert_eq!("deve ter pelo menos 2 itens", error.localize_with(&catalog, "pt-br"));
+    assert_eq!("zu wenige Elemente", error.localize_with(&(&catalog, Builtin), "de"));
+    assert_eq!(
+        "muss mindestens 2 Elemente enthalten",
+        error.localize_with(&(Builtin, &catalog), "de")
+    );
+    assert_eq!(None, catalog.message("es", "length.too_small", &[]));
+    assert_eq!(None, catalog.message("pt-BR", "length.too_small", &[]));
+    assert_eq!(
+        "enthält zu wenige Elemente",
+        LengthError::TooSmall.localize_with(&(Builtin, &catalog), "de")
+    );
+}
+
+#[cfg(all(feature = "line", feature = "email"))]
+#[test]
+fn validation_errors() {
+    let mut errors = ValidationErrors::new();
+
+    errors.push_detailed(
+        "name",
+        "line",
+        DetailedError::new(LineError::TooLong).with_params(vec![
+            ("max", ErrorParam::Unsigned(32)),
+            ("length", ErrorParam::Unsigned(40)),
+        ]),
+    );
+    errors.push("email", "email", EmailError::Invalid);
+
+    errors.localize("es");
+
+    assert_eq!("name: es demasiado largo (máximo 32)\nemail: valor no válido", errors.to_string());
+    assert_eq!("valor no válido", errors.iter().nth(1).unwrap().message());
+
+    errors.localize("en");
+
+    assert_eq!("name: line is too long\nemail: invalid Email", errors.to_string());
+}
+
+#[cfg(all(feature = "line", feature = "axum-problem-json"))]
+#[test]
+fn problem() {
+    use validators::{
+        problem::Problem,
+        serde_json::{self, json},
+    };
+
+    let mut errors = ValidationErrors::new();
+
+    errors.push_detailed(
+        "name",
+        "line",
+        DetailedError::new(LineError::TooLong).with_params(vec![
+            ("max", ErrorParam::Unsigned(32)),
+            ("length", ErrorParam::Unsigned(40)),
+        ]),
+    );
+
+    let problem = Problem::from(errors).localize("ja");
+
+    assert_eq!("無効なフィールドがあります", problem.detail());
+    assert_eq!(
+        json!([
+            {
+                "path": "name",
+                "validator": "line",
+                "code": "line.too_long",
+                "message": "長すぎます（最大32）",
+                "params": { "max": 32, "length": 40 },
+            },
+        ]),
+        serde_json::to_value(&problem).unwrap()["errors"]
+    );
+
+    let problem = Problem::from(LineError::Invalid).localize("en, ja");
+
+    assert_eq!("invalid line", problem.detail());
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn axum_extractor() {
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use validators::{
+        axum::{extract::FromRequestParts, http::Request},
+        localization::AcceptLanguage,
+    };
+
+    let (mut parts, _) = Request::builder()
+        .header("Accept-Language", "fr-CH, fr;q=0.9")
+        .body(())
+        .unwrap()
+        .into_parts();
+
+    let future = pin!(AcceptLanguage::from_request_parts(&mut parts, &()));
+
+    // the extractor never waits
+    let Poll::Ready(Ok(language)) = future.poll(&mut Context::from_waker(Waker::noop())) else {
+        unreachable!()
+    };
+
+    assert_eq!("fr-CH, fr;q=0.9", language.as_str());
+}
+
+#[cfg(feature = "actix-web")]
+#[test]
+fn actix_web_extractor() {
+    use validators::{
+        actix_web::{FromRequest, test::TestRequest},
+        localization::AcceptLanguage,
+    };
+
+    let request = TestRequest::default()
+        .insert_header(("Accept-Language", "fr-CH, fr;q=0.9"))
+        .to_http_request();
+
+    // the extractor never waits
+    let language = AcceptLanguage::extract(&request).into_inner().unwrap();
+
+    assert_eq!("fr-CH, fr;q=0.9", language.as_str());
+}
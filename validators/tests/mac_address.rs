@@ -128,3 +128,15 @@ fn basic() {
         },
     }
 }
+
+#[test]
+fn too_few_separators() {
+    #[derive(Validator)]
+    #[validator(mac_address(case(Any), separator(Allow(b':'))))]
+    pub struct MacAddress(pub u64);
+
+    for s in ["08:00:27:b2:46", "08:00:27:b2:4", "08:00:27b2:46"] {
+        assert!(MacAddress::parse_str(s).is_err());
+        assert!(MacAddress::validate_str(s).is_err());
+    }
+}
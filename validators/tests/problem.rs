@@ -0,0 +1,213 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "axum-problem-json"))]
+
+use validators::{
+    axum::{
+        http::{StatusCode, header},
+        response::IntoResponse,
+    },
+    errors::*,
+    prelude::*,
+    problem::{PROBLEM_JSON, Problem},
+    serde_json::{self, json},
+};
+
+#[cfg(feature = "email")]
+#[test]
+fn validator_error() {
+    let problem = Problem::from(EmailError::IPDisallow);
+
+    assert_eq!(StatusCode::BAD_REQUEST, problem.status());
+    assert_eq!("email.ip_disallow", problem.code());
+    assert_eq!(Some("email"), problem.validator());
+
+    assert_eq!(
+        json!({
+            "type": "about:blank",
+            "title": "Bad Request",
+            "status": 400,
+            "detail": "must not use an IP",
+            "code": "email.ip_disallow",
+            "validator": "email",
+        }),
+        serde_json::to_value(&problem).unwrap()
+    );
+
+    let response = problem.into_response();
+
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    assert_eq!(PROBLEM_JSON, response.headers()[header::CONTENT_TYPE]);
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn derived() {
+    #[derive(Debug, Validator)]
+    #[validator(line(char_length(max = 4)))]
+    pub struct Name(String);
+
+    let problem = Problem::from(Name::parse_str("too long").unwrap_err());
+
+    assert_eq!("line.too_long", problem.code());
+    assert_eq!(Some("line"), problem.validator());
+    assert!(problem.params().is_empty());
+
+    let problem = Problem::from(Name::validate_str_detailed("too long").unwrap_err());
+
+    assert_eq!(
+        [("max", ErrorParam::Unsigned(4)), ("length", ErrorParam::Unsigned(8))],
+        problem.params()
+    );
+    assert_eq!(json!({ "max": 4, "length": 8 }), serde_json::to_value(&problem).unwrap()["params"]);
+
+    fn not_admin(v: &Username) -> Result<(), &'static str> {
+        if v.0 == "admin" { Err("reserved") } else { Ok(()) }
+    }
+
+    #[derive(Debug, Validator)]
+    #[validator(line(custom = not_admin))]
+    pub struct Username(String);
+
+    let problem = Problem::from(Username::parse_str("admin").unwrap_err());
+
+    assert_eq!("custom", problem.code());
+    assert_eq!(Some("custom"), problem.validator());
+    assert_eq!("reserved", problem.detail());
+
+    let problem = Problem::from(Username::parse_str("a\nb").unwrap_err());
+
+    assert_eq!("line.invalid", problem.code());
+}
+
+#[cfg(all(feature = "line", feature = "email"))]
+#[test]
+fn validation_errors() {
+    let mut errors = ValidationErrors::new();
+
+    errors.push("name", "line", LineError::TooLong);
+    errors.push("email", "email", EmailError::Invalid);
+
+    let problem = Problem::from(errors).with_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+    assert_eq!("validation", problem.code());
+    assert_eq!(2, problem.errors().unwrap().len());
+
+    assert_eq!(
+        json!({
+            "type": "about:blank",
+            "title": "Unprocessable Entity",
+            "status": 422,
+            "detail": "some fields are invalid",
+            "code": "validation",
+            "errors": [
+                { "path": "name", "validator": "line", "code": "line.too_long", "message": "line is too long" },
+                { "path": "email", "validator": "email", "code": "email.invalid", "message": "invalid Email" },
+            ],
+        }),
+        serde_json::to_value(&problem).unwrap()
+    );
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn rejection() {
+    use std::{
+        collections::BTreeMap,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use validators::{
+        axum::{
+            body::Body,
+            extract::{FromRequest, FromRequestParts, Json, Query, Request},
+        },
+        problem::WithProblem,
+    };
+
+    /// The extractors are ready at once, because the bodies are in memory.
+    fn ready<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    fn json<T>(body: &'static str) -> Problem
+    where
+        Json<T>: FromRequest<(), Rejection = validators::axum::extract::rejection::JsonRejection>,
+    {
+        let request = Request::builder()
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap();
+
+        ready(WithProblem::<Json<T>>::from_request(request, &())).err().unwrap()
+    }
+
+    fn query<T>(uri: &'static str) -> Problem
+    where
+        Query<T>:
+            FromRequestParts<(), Rejection = validators::axum::extract::rejection::QueryRejection>,
+    {
+        let (mut parts, _) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+
+        ready(WithProblem::<Query<T>>::from_request_parts(&mut parts, &())).err().unwrap()
+    }
+
+    #[derive(Debug, Validator)]
+    #[validator(line(char_length(max = 4)))]
+    pub struct Name(String);
+
+    let problem = json::<BTreeMap<String, Vec<Name>>>(r#"{ "names": ["jane", "too long"] }"#);
+
+    assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, problem.status());
+    assert_eq!("line.too_long", problem.code());
+    assert_eq!(Some("line"), problem.validator());
+    assert_eq!(Some("names[1]"), problem.path());
+
+    assert_eq!(
+        json!({
+            "type": "about:blank",
+            "title": "Unprocessable Entity",
+            "status": 422,
+            "detail": "line is too long",
+            "code": "line.too_long",
+            "validator": "line",
+            "path": "names[1]",
+        }),
+        serde_json::to_value(&problem).unwrap()
+    );
+
+    let problem = json::<Name>(r#""too long""#);
+
+    assert_eq!("line.too_long", problem.code());
+    assert_eq!(None, problem.path());
+
+    let problem = json::<BTreeMap<String, Name>>(r#"{ "name": 1 }"#);
+
+    assert_eq!("extract.json_data", problem.code());
+    assert_eq!(None, problem.validator());
+
+    // an error of a validator outside the extractor is not recorded
+    assert!(serde_json::from_str::<Name>(r#""too long""#).is_err());
+
+    let problem = json::<BTreeMap<String, Name>>(r#"{ "name": "#);
+
+    assert_eq!("extract.json_syntax", problem.code());
+
+    // a rejection which is converted without `WithProblem` keeps the generic code
+    let problem = Problem::from(Json::<Name>::from_bytes(br#""too long""#).unwrap_err());
+
+    assert_eq!("extract.json_data", problem.code());
+    assert_eq!(None, problem.path());
+
+    let problem = query::<BTreeMap<String, Name>>("/users?name=a%0Ab");
+
+    assert_eq!(StatusCode::BAD_REQUEST, problem.status());
+    assert_eq!("line.invalid", problem.code());
+    assert_eq!(Some("name"), problem.path());
+
+    let problem = query::<BTreeMap<String, u8>>("/users?name=jane&age=x");
+
+    assert_eq!("extract.query", problem.code());
+}
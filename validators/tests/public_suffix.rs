@@ -0,0 +1,174 @@
+#![cfg(any(feature = "domain", feature = "email", feature = "host"))]
+
+#[allow(unused_imports)]
+use validators::prelude::*;
+use validators::{
+    functions::{is_registrable_domain, public_suffix, registrable_domain},
+    models::PublicSuffixList,
+};
+
+#[test]
+fn functions() {
+    assert_eq!(Some("com"), public_suffix("example.com"));
+    assert_eq!(Some("example.com"), registrable_domain("example.com"));
+    assert_eq!(Some("co.uk"), public_suffix("www.example.co.uk"));
+    assert_eq!(Some("example.co.uk"), registrable_domain("www.example.co.uk"));
+    assert_eq!(Some("Example.CO.UK"), registrable_domain("WWW.Example.CO.UK"));
+    assert_eq!(Some("example.co.uk"), registrable_domain("example.co.uk."));
+    assert_eq!(None, registrable_domain("co.uk"));
+    assert_eq!(Some("co.uk"), public_suffix("co.uk."));
+
+    // a wildcard and its exception
+    assert_eq!(Some("example.ck"), public_suffix("www.example.ck"));
+    assert_eq!(Some("www.example.ck"), registrable_domain("www.example.ck"));
+    assert_eq!(Some("ck"), public_suffix("www.ck"));
+    assert_eq!(Some("www.ck"), registrable_domain("a.www.ck"));
+
+    // the private section
+    assert_eq!(Some("github.io"), public_suffix("jane.github.io"));
+    assert_eq!(Some("jane.github.io"), registrable_domain("a.jane.github.io"));
+
+    // a TLD which is not in the list
+    assert_eq!(Some("unknown"), public_suffix("example.unknown"));
+    assert_eq!(Some("example.unknown"), registrable_domain("www.example.unknown"));
+
+    assert_eq!(Some("中文.tw"), registrable_domain("www.中文.tw"));
+    assert_eq!(Some("tw"), public_suffix("中文.tw"));
+
+    assert_eq!(None, public_suffix(""));
+    assert_eq!(None, public_suffix("example..com"));
+
+    assert!(is_registrable_domain("example.com"));
+    assert!(is_registrable_domain("www.example.com"));
+    assert!(!is_registrable_domain("com"));
+    assert!(!is_registrable_domain("co.uk"));
+    assert!(!is_registrable_domain("localhost"));
+}
+
+#[test]
+fn parse() {
+    let list = PublicSuffixList::parse(
+        "// comment\n\nexample\n*.wild.example\n!keep.wild.example\n中文 // trailing text\n",
+    );
+
+    assert_eq!(Some("example"), list.public_suffix("a.example"));
+    assert_eq!(Some("a.wild.example"), list.public_suffix("b.a.wild.example"));
+    assert_eq!(Some("wild.example"), list.public_suffix("keep.wild.example"));
+    assert_eq!(Some("keep.wild.example"), list.registrable_domain("a.keep.wild.example"));
+    assert_eq!(Some("a.中文"), list.registrable_domain("b.a.中文"));
+    assert_eq!(Some("uk"), list.public_suffix("example.co.uk"));
+}
+
+#[test]
+fn builtin() {
+    let builtin = PublicSuffixList::builtin();
+
+    assert_eq!(Some("ck"), builtin.public_suffix("www.ck"));
+    assert_eq!(Some("a.ck"), builtin.public_suffix("www.a.ck"));
+    assert_eq!(Some("公司.cn"), builtin.public_suffix("example.公司.cn"));
+    assert_eq!(Some("example.公司.cn"), builtin.registrable_domain("WWW.example.公司.cn."));
+    assert_eq!(Some("github.io"), builtin.public_suffix("magiclen.github.io"));
+}
+
+#[cfg(all(feature = "derive", feature = "domain"))]
+#[test]
+fn domain() {
+    use validators::errors::DomainError;
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(domain(
+        ipv4(Allow),
+        local(Allow),
+        port(Allow),
+        at_least_two_labels(Must),
+        registrable(Must)
+    ))]
+    pub struct RegistrableDomainName {
+        pub domain:   String,
+        pub is_ipv4:  bool,
+        pub is_local: bool,
+        pub port:     Option<u16>,
+    }
+
+    let domain = RegistrableDomainName::parse_str("www.example.co.uk:443").unwrap();
+
+    assert_eq!(Some("co.uk"), domain.get_public_suffix());
+    assert_eq!(Some("example.co.uk"), domain.get_registrable_domain());
+    assert!(RegistrableDomainName::parse_str("example.com.").is_ok());
+    assert!(matches!(RegistrableDomainName::parse_str("co.uk"), Err(DomainError::RegistrableMust)));
+    assert!(matches!(
+        RegistrableDomainName::parse_str("127.0.0.1"),
+        Err(DomainError::RegistrableMust)
+    ));
+
+    #[derive(Validator)]
+    #[validator(domain(ipv4(Disallow), port(Disallow), registrable(Disallow)))]
+    pub struct PublicSuffix(pub String);
+
+    let suffix = PublicSuffix::parse_str("co.uk").unwrap();
+
+    assert_eq!(Some("co.uk"), suffix.get_public_suffix());
+    assert_eq!(None, suffix.get_registrable_domain());
+    assert!(matches!(
+        PublicSuffix::parse_str("example.co.uk"),
+        Err(DomainError::RegistrableDisallow)
+    ));
+
+    #[derive(Validator)]
+    #[validator(domain(ipv4(Allow), port(Disallow)))]
+    pub struct DomainOrIPv4(pub String);
+
+    assert_eq!(None, DomainOrIPv4::parse_str("127.0.0.1").unwrap().get_registrable_domain());
+}
+
+#[cfg(all(feature = "derive", feature = "host"))]
+#[test]
+fn host() {
+    use validators::errors::HostError;
+
+    #[derive(Validator)]
+    #[validator(host(port(Disallow), registrable(Must)))]
+    pub struct Host(pub validators::models::Host);
+
+    let host = Host::parse_str("jane.github.io").unwrap();
+
+    assert_eq!(Some("github.io"), host.get_public_suffix());
+    assert_eq!(Some("jane.github.io"), host.get_registrable_domain());
+    assert!(matches!(Host::parse_str("github.io"), Err(HostError::RegistrableMust)));
+    assert!(matches!(Host::parse_str("[::1]"), Err(HostError::RegistrableMust)));
+}
+
+#[cfg(all(feature = "derive", feature = "email"))]
+#[test]
+fn email() {
+    use validators::errors::EmailError;
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), registrable(Must)))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: validators::models::Host,
+    }
+
+    let email = Email::parse_str("jane@mail.example.co.uk").unwrap();
+
+    assert_eq!(Some("example.co.uk"), email.get_registrable_domain());
+    assert!(matches!(Email::parse_str("jane@co.uk"), Err(EmailError::RegistrableMust)));
+    assert!(matches!(Email::parse_str("jane@[127.0.0.1]"), Err(EmailError::RegistrableMust)));
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(comment(Disallow), ip(Disallow)))]
+    pub struct EmailWithoutIP {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    let email = EmailWithoutIP::parse_str("jane@中文.tw").unwrap();
+
+    assert_eq!(Some("tw"), email.get_public_suffix());
+}
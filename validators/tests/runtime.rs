@@ -0,0 +1,96 @@
+#![cfg(all(
+    feature = "runtime",
+    feature = "domain",
+    feature = "email",
+    feature = "line",
+    feature = "mac_address",
+    feature = "signed_integer"
+))]
+
+use validators::{
+    errors::{DomainError, EmailError, LineError, MacAddressError, SignedIntegerError},
+    runtime::*,
+};
+
+#[test]
+fn email() {
+    let validator = EmailValidator::default();
+
+    assert!(validator.parse_str("len@magiclen.org").is_ok());
+    assert!(validator.parse_str("len@[127.0.0.1]").unwrap().is_local);
+    assert!(matches!(validator.parse_str("len@"), Err(EmailError::Invalid)));
+
+    let validator = EmailValidator::builder()
+        .ip(TriAllow::Disallow)
+        .local(TriAllow::Disallow)
+        .at_least_two_labels(TriAllow::Must)
+        .build();
+
+    let email = validator.parse_str("len@magiclen.org").unwrap();
+
+    assert_eq!("len", email.local_part);
+    assert!(!email.is_local);
+
+    assert!(matches!(validator.parse_str("len@[8.8.8.8]"), Err(EmailError::IPDisallow)));
+    assert!(matches!(validator.parse_str("len@localhost"), Err(EmailError::LocalDisallow)));
+    assert!(matches!(validator.parse_str("len@magiclen"), Err(EmailError::AtLeastTwoLabelsMust)));
+}
+
+#[test]
+fn domain() {
+    let validator = DomainValidator::builder().port(TriAllow::Must).build();
+
+    let domain = validator.parse_str("magiclen.org:8080").unwrap();
+
+    assert_eq!("magiclen.org", domain.domain);
+    assert_eq!(Some(8080), domain.port);
+
+    assert!(matches!(validator.parse_str("magiclen.org"), Err(DomainError::PortMust)));
+}
+
+#[test]
+fn line() {
+    let validator = LineValidator::builder()
+        .char_length(LengthOption {
+            min:         Some(2),
+            trimmed_min: Some(2),
+            max:         Some(4),
+        })
+        .build();
+
+    assert_eq!("len", validator.parse_str("len").unwrap());
+    assert!(matches!(validator.parse_str("magiclen"), Err(LineError::TooLong)));
+    assert!(matches!(validator.parse_str(" l "), Err(LineError::TooShort)));
+    assert!(matches!(validator.parse_str("l\nen"), Err(LineError::Invalid)));
+}
+
+#[test]
+fn mac_address() {
+    let validator = MacAddressValidator::default();
+
+    assert_eq!(0x080027B246C3, validator.parse_str("08:00:27:b2:46:c3").unwrap());
+    assert_eq!(0x080027B246C3, validator.parse_str("080027B246C3").unwrap());
+
+    let validator = MacAddressValidator::builder()
+        .case(CaseOption::Lower)
+        .separator(SeparatorOption::Must(b'-'))
+        .build();
+
+    assert!(validator.parse_str("08-00-27-b2-46-c3").is_ok());
+    assert!(matches!(validator.parse_str("08-00-27-B2-46-C3"), Err(MacAddressError::Invalid)));
+    assert!(matches!(validator.parse_str("080027b246c3"), Err(MacAddressError::SeparatorMust)));
+}
+
+#[test]
+fn signed_integer() {
+    let validator = SignedIntegerValidator::<i16>::builder()
+        .range(RangeOption::Outside {
+            min: Some(-10), max: Some(10), inclusive: false
+        })
+        .build();
+
+    assert_eq!(-11, validator.parse_str("-11").unwrap());
+    assert_eq!(10, validator.parse_integer(10).unwrap());
+    assert!(matches!(validator.parse_str("0"), Err(SignedIntegerError::Forbidden)));
+    assert!(matches!(validator.parse_str("x"), Err(SignedIntegerError::ParseIntError(_))));
+}
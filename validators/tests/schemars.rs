@@ -0,0 +1,294 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "schemars"))]
+
+use validators::{
+    prelude::*,
+    schemars::{JsonSchema, Schema, SchemaGenerator, json_schema},
+};
+
+fn schema<T: JsonSchema>() -> Schema {
+    T::json_schema(&mut SchemaGenerator::default())
+}
+
+#[cfg(feature = "text")]
+#[test]
+fn text() {
+    #[derive(Validator)]
+    #[validator(text(char_length(trimmed_min = 1, min = 1, max = 32)))]
+    pub struct Name(String);
+
+    #[derive(Validator)]
+    #[validator(text(byte_length(max = 32)))]
+    pub struct Bytes(String);
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^[^\\x00-\\x08\\x0C\\x0E-\\x1F\\x7F]*$",
+            "minLength": 1,
+            "maxLength": 32,
+        }),
+        schema::<Name>()
+    );
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^[^\\x00-\\x08\\x0C\\x0E-\\x1F\\x7F]*$",
+        }),
+        schema::<Bytes>()
+    );
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1024, max = 65535))))]
+    pub struct Port(u16);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(max = 100, inclusive = false))))]
+    pub struct Percentage(u8);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 18446744073709551616))))]
+    pub struct Huge(u128);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Unlimited)))]
+    pub struct Any(u64);
+
+    assert_eq!(
+        json_schema!({
+            "type": "integer",
+            "minimum": 1024,
+            "maximum": 65535,
+        }),
+        schema::<Port>()
+    );
+
+    assert_eq!(
+        json_schema!({
+            "type": "integer",
+            "exclusiveMaximum": 100,
+        }),
+        schema::<Percentage>()
+    );
+
+    assert_eq!(
+        json_schema!({
+            "type": "integer",
+            "minimum": 18446744073709551616f64,
+        }),
+        schema::<Huge>()
+    );
+
+    assert_eq!(json_schema!({ "type": "integer" }), schema::<Any>());
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Validator)]
+    #[validator(signed_integer(range(Outside(min = -9, max = 9))))]
+    pub struct NotDigit(i8);
+
+    assert_eq!(
+        json_schema!({
+            "type": "integer",
+            "not": {
+                "minimum": -9,
+                "maximum": 9,
+            },
+        }),
+        schema::<NotDigit>()
+    );
+}
+
+#[cfg(feature = "number")]
+#[test]
+fn number() {
+    #[derive(Validator)]
+    #[validator(number(range(Inside(min = -1.5, max = 1.5))))]
+    pub struct Ratio(f64);
+
+    assert_eq!(
+        json_schema!({
+            "type": "number",
+            "minimum": -1.5,
+            "maximum": 1.5,
+        }),
+        schema::<Ratio>()
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+    #[derive(Validator)]
+    #[validator(regex(regex("^[0-9a-f]+$")))]
+    pub struct Hex(String);
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^[0-9a-f]+$",
+        }),
+        schema::<Hex>()
+    );
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Disallow)
+    ))]
+    pub struct AsciiEmail {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Allow)
+    ))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    assert_eq!(json_schema!({ "type": "string", "format": "email" }), schema::<AsciiEmail>());
+    assert_eq!(json_schema!({ "type": "string", "format": "idn-email" }), schema::<Email>());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    #[derive(Validator)]
+    #[validator(uuid(case(Lower), separator(Must(b'-'))))]
+    pub struct Uuid(u128);
+
+    #[derive(Validator)]
+    #[validator(uuid(separator(Disallow)))]
+    pub struct SimpleUuid(u128);
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$",
+            "format": "uuid",
+        }),
+        schema::<Uuid>()
+    );
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^[0-9A-Fa-f]{8}[0-9A-Fa-f]{4}[0-9A-Fa-f]{4}[0-9A-Fa-f]{4}[0-9A-Fa-f]{12}$",
+        }),
+        schema::<SimpleUuid>()
+    );
+}
+
+#[cfg(feature = "mac_address")]
+#[test]
+fn mac_address() {
+    #[derive(Validator)]
+    #[validator(mac_address(case(Upper), separator(Allow(b'.'))))]
+    pub struct MacAddress(u64);
+
+    assert_eq!(
+        json_schema!({
+            "type": "string",
+            "pattern": "^(?:[0-9A-F]{2}\\.[0-9A-F]{2}\\.[0-9A-F]{2}\\.[0-9A-F]{2}\\.[0-9A-F]{2}\\.[0-9A-F]{2}|[0-9A-F]{2}[0-9A-F]{2}[0-9A-F]{2}[0-9A-F]{2}[0-9A-F]{2}[0-9A-F]{2})$",
+        }),
+        schema::<MacAddress>()
+    );
+}
+
+#[cfg(feature = "ipv4")]
+#[test]
+fn ipv4() {
+    #[derive(Validator)]
+    #[validator(ipv4(port(Disallow)))]
+    pub struct Ipv4(std::net::Ipv4Addr);
+
+    #[derive(Validator)]
+    #[validator(ipv4(port(Must)))]
+    pub struct Ipv4WithPort {
+        pub ipv4: std::net::Ipv4Addr,
+        pub port: u16,
+    }
+
+    assert_eq!(json_schema!({ "type": "string", "format": "ipv4" }), schema::<Ipv4>());
+    assert_eq!(json_schema!({ "type": "string" }), schema::<Ipv4WithPort>());
+}
+
+#[cfg(all(feature = "line", feature = "unsigned_integer"))]
+#[test]
+fn alternatives() {
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 8)))]
+    pub struct Name(String);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(max = 9))))]
+    pub struct Digit(u8);
+
+    #[derive(Validator)]
+    pub enum NameOrDigit {
+        Name(Name),
+        Digit(Digit),
+    }
+
+    let mut generator = SchemaGenerator::default();
+
+    assert_eq!(
+        json_schema!({
+            "anyOf": [
+                { "$ref": "#/$defs/Name" },
+                { "$ref": "#/$defs/Digit" },
+            ],
+        }),
+        NameOrDigit::json_schema(&mut generator)
+    );
+
+    assert_eq!(Some(&schema::<Name>().to_value()), generator.definitions().get("Name"));
+    assert_eq!(Some(&schema::<Digit>().to_value()), generator.definitions().get("Digit"));
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn custom_and_borrowed() {
+    use std::borrow::Cow;
+
+    fn not_admin(v: &Username) -> Result<(), &'static str> {
+        if v.0 == "admin" { Err("reserved") } else { Ok(()) }
+    }
+
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 16), custom = not_admin))]
+    pub struct Username(String);
+
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 16)))]
+    pub struct BorrowedLine<'a>(Cow<'a, str>);
+
+    assert_eq!("Username", Username::schema_name());
+    assert_eq!("BorrowedLine", BorrowedLine::schema_name());
+    assert_eq!(schema::<Username>(), schema::<BorrowedLine>());
+}
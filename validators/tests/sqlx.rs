@@ -0,0 +1,259 @@
+#![cfg(all(feature = "test-sqlx", feature = "derive"))]
+
+use std::{
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+};
+
+use validators::{
+    prelude::*,
+    sqlx::{Connection, Decode, Encode, Sqlite, SqliteConnection, Type},
+};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// The SQLite driver runs on its own thread, so parking the current thread until it is woken up is enough.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+
+        thread::park();
+    }
+}
+
+/// Store `value` in an in-memory database and read it back.
+fn round_trip<T>(value: T) -> Result<T, validators::sqlx::Error>
+where
+    T: for<'q> Encode<'q, Sqlite> + for<'r> Decode<'r, Sqlite> + Type<Sqlite> + Send + Unpin, {
+    block_on(async {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await?;
+
+        validators::sqlx::query_scalar("SELECT ?").bind(value).fetch_one(&mut connection).await
+    })
+}
+
+/// Read `sql`, which produces a raw value bypassing the validator, as `T`.
+fn select<T>(sql: &str) -> Result<T, validators::sqlx::Error>
+where
+    T: for<'r> Decode<'r, Sqlite> + Type<Sqlite> + Send + Unpin, {
+    block_on(async {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await?;
+
+        validators::sqlx::query_scalar(sql).fetch_one(&mut connection).await
+    })
+}
+
+#[cfg(feature = "text")]
+#[test]
+fn text() {
+    #[derive(Debug, Validator)]
+    #[validator(text(char_length(trimmed_min = 1, max = 8)))]
+    pub struct Name(String);
+
+    assert_eq!("magic", round_trip(Name::parse_str("magic").unwrap()).unwrap().0);
+
+    // a corrupted row surfaces as a decode error
+    let error = select::<Name>("SELECT 'magiclen.org'").unwrap_err();
+
+    assert!(matches!(error, validators::sqlx::Error::ColumnDecode { .. }));
+    assert!(error.to_string().contains("text is too long"), "{error}");
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Debug, Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1, max = 65535))))]
+    pub struct Port(u16);
+
+    assert_eq!(8080, round_trip(Port::parse_u16(8080).unwrap()).unwrap().0);
+    assert!(select::<Port>("SELECT 0").is_err());
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Debug, Validator)]
+    #[validator(signed_integer(range(Outside(min = -9, max = 9))))]
+    pub struct NotDigit(i64);
+
+    assert_eq!(-10, round_trip(NotDigit::parse_i64(-10).unwrap()).unwrap().0);
+    assert!(select::<NotDigit>("SELECT 5").is_err());
+}
+
+#[cfg(feature = "number")]
+#[test]
+fn number() {
+    #[derive(Debug, Validator)]
+    #[validator(number(nan(Disallow), range(Inside(min = 0, max = 1))))]
+    pub struct Ratio(f32);
+
+    assert_eq!(0.5, round_trip(Ratio::parse_f32(0.5).unwrap()).unwrap().0);
+    assert!(select::<Ratio>("SELECT 1.5").is_err());
+}
+
+#[cfg(feature = "boolean")]
+#[test]
+fn boolean() {
+    #[derive(Debug, Validator)]
+    #[validator(boolean)]
+    pub struct Flag(bool);
+
+    assert!(round_trip(Flag::parse_str("yes").unwrap()).unwrap().0);
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    #[derive(Debug, Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Disallow)
+    ))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    let email = round_trip(Email::parse_str("len@magiclen.org").unwrap()).unwrap();
+
+    assert_eq!("len", email.local_part);
+    assert!(!email.need_quoted);
+    assert_eq!("magiclen.org", email.domain_part);
+
+    assert!(select::<Email>("SELECT 'len@localhost'").is_err());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    #[derive(Debug, Validator)]
+    #[validator(uuid(case(Upper), separator(Allow(b'-'))))]
+    pub struct Uuid(u128);
+
+    let uuid = Uuid::parse_str("A866664A-F2F2-4C67-9A79-A5F0EA4C5E5F").unwrap();
+
+    assert_eq!(uuid.0, round_trip(Uuid(uuid.0)).unwrap().0);
+
+    // stored as a 16-byte blob by SQLite
+    assert_eq!(uuid.0, select::<Uuid>("SELECT x'A866664AF2F24C679A79A5F0EA4C5E5F'").unwrap().0);
+}
+
+#[cfg(feature = "mac_address")]
+#[test]
+fn mac_address() {
+    #[derive(Debug, Validator)]
+    #[validator(mac_address(case(Lower), separator(Allow(b':'))))]
+    pub struct MacAddress(u64);
+
+    let mac_address = MacAddress::parse_str("08:00:27:b2:46:c3").unwrap();
+
+    assert_eq!(0x080027B246C3, round_trip(mac_address).unwrap().0);
+    assert!(select::<MacAddress>("SELECT -1").is_err());
+    assert!(select::<MacAddress>("SELECT 281474976710656").is_err());
+}
+
+#[cfg(feature = "host")]
+#[test]
+fn host() {
+    #[derive(Debug, Validator)]
+    #[validator(host(local(Allow), at_least_two_labels(Allow), port(Allow)))]
+    pub struct Host {
+        pub host: validators::models::Host,
+        pub port: Option<u16>,
+    }
+
+    let host = round_trip(Host::parse_str("magiclen.org:8080").unwrap()).unwrap();
+
+    assert_eq!(Some(8080), host.port);
+    assert!(select::<Host>("SELECT 'magiclen.org:http'").is_err());
+}
+
+#[cfg(feature = "ipv4")]
+#[test]
+fn ipv4() {
+    #[derive(Debug, Validator)]
+    #[validator(ipv4(local(Allow), port(Must)))]
+    pub struct Ipv4WithPort {
+        pub ipv4: std::net::Ipv4Addr,
+        pub port: u16,
+    }
+
+    let ipv4 = round_trip(Ipv4WithPort::parse_str("127.0.0.1:8080").unwrap()).unwrap();
+
+    assert_eq!(8080, ipv4.port);
+    assert!(ipv4.ipv4.is_loopback());
+}
+
+#[cfg(feature = "duration")]
+#[test]
+fn duration() {
+    use std::time::Duration;
+
+    #[derive(Debug, Validator)]
+    #[validator(duration(range(min = 0.25, max = 3600)))]
+    pub struct Timeout(Duration);
+
+    assert_eq!(
+        Duration::from_millis(1500),
+        round_trip(Timeout::parse_str("1.5s").unwrap()).unwrap().0
+    );
+    assert!(select::<Timeout>("SELECT 'PT2H'").is_err());
+}
+
+#[cfg(feature = "byte")]
+#[test]
+fn byte() {
+    #[derive(Debug, Validator)]
+    #[validator(byte(range(max = 1048576)))]
+    pub struct Quota(validators::byte_unit::Byte);
+
+    assert_eq!(1500, round_trip(Quota::parse_str("1.5 KB").unwrap()).unwrap().0.as_u64());
+    assert!(select::<Quota>("SELECT '2 MiB'").is_err());
+}
+
+#[cfg(feature = "phone")]
+#[test]
+fn phone() {
+    #[derive(Debug, Validator)]
+    #[validator(phone(countries(TW)))]
+    pub struct TWPhone(validators::phonenumber::PhoneNumber);
+
+    let phone = round_trip(TWPhone::parse_str("0912345678").unwrap()).unwrap();
+
+    // stored in the E.164 format
+    assert_eq!(886, phone.0.code().value());
+    assert_eq!(912345678, phone.0.national().value());
+    assert!(select::<TWPhone>("SELECT '+14155552671'").is_err());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json() {
+    #[derive(Debug, Validator)]
+    #[validator(json)]
+    pub struct Json(validators::serde_json::Value);
+
+    let json = round_trip(Json::parse_str(r#"{ "a": [1, 2] }"#).unwrap()).unwrap();
+
+    assert_eq!(validators::serde_json::json!({ "a": [1, 2] }), json.0);
+    assert!(select::<Json>("SELECT '{'").is_err());
+}
@@ -0,0 +1,170 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "time"))]
+
+use validators::prelude::*;
+use validators_prelude::Time;
+
+#[test]
+fn basic() {
+    macro_rules! test_case {
+        ($test:ident, $validator:ident) => {
+            type Validator = $validator;
+            let test = $test;
+
+            let extended = !Validator::V_BASIC_FORMAT.must();
+            let basic = Validator::V_BASIC_FORMAT.allow();
+            let local = !Validator::V_TIMEZONE.must();
+            let zoned = Validator::V_TIMEZONE.allow();
+            let fraction = Validator::V_FRACTION.allow();
+
+            test("", false);
+            test("12:34", false);
+            test("12:34:5", false);
+            test("24:00:00", false);
+            test("12:60:00", false);
+            test("12:34:61", false);
+            test("12:34:56.", false);
+            test("12:34:56.1234567890", false);
+            test("12:34:56+0800", false);
+            test("12:34:56+08", false);
+            test("12:34:56+24:00", false);
+            test("123456+08:00", false);
+            test("12:34:56", extended && local);
+            test("23:59:60", extended && local);
+            test("12:34:56.789", extended && local && fraction);
+            test("12:34:56,123456789", extended && local && fraction);
+            test("12:34:56Z", extended && zoned);
+            test("12:34:56z", extended && zoned);
+            test("12:34:56-05:30", extended && zoned);
+            test("12:34:56.5+08:00", extended && zoned && fraction);
+            test("123456", basic && local);
+            test("123456.5", basic && local && fraction);
+            test("123456Z", basic && zoned);
+            test("123456+0800", basic && zoned);
+        };
+    }
+
+    macro_rules! test_inner {
+        ( @unit $parameters:expr, $validator:ident) => {
+            {
+                fn test(s: &str, is_ok: bool) {
+                    let panic = match $validator::validate_str(s) {
+                        Ok(_) => !is_ok,
+                        Err(_) if !is_ok => false,
+                        Err(err) => {
+                            eprintln!("{}", err);
+
+                            true
+                        }
+                    };
+
+                    if panic {
+                        panic!("{:?}: {} {} expect {}", s, stringify!($validator), $parameters, is_ok);
+                    }
+                }
+
+                test_case!(test, $validator);
+            }
+        };
+        ( $parameters:expr ; $($validator:ident),* $(,)*) => {
+            $(
+                test_inner!(
+                    @unit
+                    $parameters,
+                    $validator
+                );
+            )*
+        };
+    }
+
+    macro_rules! test {
+        ($( { $( $p:meta => $v:meta ),* $(,)* } ),* $(,)* ) => {
+            $(
+                {
+                    #[derive(Validator)]
+                    #[validator(time($($p($v),)*))]
+                    pub struct TimeValidator(pub Time);
+
+                    test_inner!(
+                        stringify! {
+                            $(
+                                $p = $v,
+                            )*
+                        };
+                        TimeValidator,
+                    );
+                }
+            )*
+        }
+    }
+
+    test! {
+        {
+            basic_format => Allow,
+            timezone => Allow,
+            fraction => Allow,
+        },
+        {
+            basic_format => Must,
+            timezone => Must,
+            fraction => Disallow,
+        },
+        {
+            basic_format => Disallow,
+            timezone => Disallow,
+            fraction => Allow,
+        },
+        {
+            basic_format => Allow,
+            timezone => Must,
+            fraction => Disallow,
+        },
+        {
+            basic_format => Disallow,
+            timezone => Allow,
+            fraction => Disallow,
+        },
+    }
+}
+
+#[test]
+fn model() {
+    #[derive(Validator)]
+    #[validator(time)]
+    pub struct AnyTime(pub Time);
+
+    let time = AnyTime::parse_str("01:02:03.04-09:30").unwrap().0;
+
+    assert_eq!(
+        Time {
+            hour:       1,
+            minute:     2,
+            second:     3,
+            nanosecond: 40_000_000,
+            offset:     Some(-570),
+        },
+        time
+    );
+    assert_eq!("01:02:03.04-09:30", time.to_string());
+    assert_eq!("01:02:03Z", AnyTime::parse_str("01:02:03+00:00").unwrap().0.to_string());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    let time = validators_prelude::parse_time("23:59:60.5").unwrap().0;
+
+    let time: validators::chrono::NaiveTime = time.try_into().unwrap();
+
+    assert_eq!(validators::chrono::NaiveTime::from_hms_milli_opt(23, 59, 59, 1500).unwrap(), time);
+}
+
+#[cfg(feature = "time-dep")]
+#[test]
+fn time() {
+    let time = validators_prelude::parse_time("12:34:56.789").unwrap().0;
+
+    assert_eq!(
+        validators::time::Time::from_hms_milli(12, 34, 56, 789).unwrap(),
+        time.try_into().unwrap()
+    );
+}
@@ -0,0 +1,250 @@
+#![cfg(all(feature = "test", feature = "derive", feature = "utoipa"))]
+
+use validators::{
+    prelude::*,
+    utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{
+            Ref, RefOr,
+            schema::{
+                AnyOfBuilder, ArrayBuilder, KnownFormat, ObjectBuilder, Schema, SchemaFormat,
+                SchemaType, Type,
+            },
+        },
+    },
+};
+
+fn object(builder: ObjectBuilder) -> RefOr<Schema> {
+    RefOr::T(Schema::Object(builder.build()))
+}
+
+// `utoipa` implements `Debug` for its schemas only with its `debug` feature, so `assert_eq!` cannot be used
+#[track_caller]
+fn assert_schema_eq(expected: RefOr<Schema>, actual: RefOr<Schema>) {
+    assert!(expected == actual, "the schema is not the expected one");
+}
+
+#[cfg(feature = "text")]
+#[test]
+fn text() {
+    #[derive(Validator)]
+    #[validator(text(char_length(trimmed_min = 1, min = 1, max = 32)))]
+    pub struct Name(String);
+
+    assert_eq!("Name", Name::name());
+
+    assert_schema_eq(
+        object(
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .pattern(Some("^[^\\x00-\\x08\\x0C\\x0E-\\x1F\\x7F]*$"))
+                .min_length(Some(1))
+                .max_length(Some(32)),
+        ),
+        Name::schema(),
+    );
+}
+
+#[cfg(feature = "unsigned_integer")]
+#[test]
+fn unsigned_integer() {
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(min = 1024, max = 65535))))]
+    pub struct Port(u16);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(max = 100, inclusive = false))))]
+    pub struct Percentage(u8);
+
+    assert_schema_eq(
+        object(
+            ObjectBuilder::new()
+                .schema_type(Type::Integer)
+                .minimum(Some(1024u64))
+                .maximum(Some(65535u64)),
+        ),
+        Port::schema(),
+    );
+
+    assert_schema_eq(
+        object(ObjectBuilder::new().schema_type(Type::Integer).exclusive_maximum(Some(100u64))),
+        Percentage::schema(),
+    );
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Validator)]
+    #[validator(signed_integer(range(Outside(min = -9, max = 9))))]
+    pub struct NotDigit(i8);
+
+    assert_schema_eq(object(ObjectBuilder::new().schema_type(Type::Integer)), NotDigit::schema());
+}
+
+#[cfg(feature = "byte")]
+#[test]
+fn byte() {
+    #[derive(Validator)]
+    #[validator(byte(range(max = 1024)))]
+    pub struct Size(validators::byte_unit::Byte);
+
+    assert_schema_eq(
+        object(
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Array(vec![Type::String, Type::Integer]))
+                .maximum(Some(1024u64)),
+        ),
+        Size::schema(),
+    );
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn email() {
+    #[allow(dead_code)]
+    #[derive(Validator)]
+    #[validator(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Allow)
+    ))]
+    pub struct Email {
+        pub local_part:  String,
+        pub need_quoted: bool,
+        pub domain_part: String,
+    }
+
+    assert_schema_eq(
+        object(
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::KnownFormat(KnownFormat::IdnEmail))),
+        ),
+        Email::schema(),
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    #[derive(Validator)]
+    #[validator(uuid(case(Upper), separator(Must(b'-'))))]
+    pub struct Uuid(u128);
+
+    assert_schema_eq(
+        object(
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::Custom(String::from("uuid"))))
+                .pattern(Some("^[0-9A-F]{8}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{12}$")),
+        ),
+        Uuid::schema(),
+    );
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64() {
+    #[derive(Validator)]
+    #[validator(base64)]
+    pub struct Base64(String);
+
+    assert_schema_eq(
+        object(ObjectBuilder::new().schema_type(Type::String).content_encoding("base64")),
+        Base64::schema(),
+    );
+}
+
+#[cfg(feature = "length")]
+#[test]
+fn length() {
+    #[derive(Validator)]
+    #[validator(length(min = 1, max = 8))]
+    pub struct Tags(Vec<String>);
+
+    #[derive(Validator)]
+    #[validator(length)]
+    pub struct Any(Vec<String>);
+
+    assert_schema_eq(
+        RefOr::T(Schema::Array(
+            ArrayBuilder::new()
+                .items(ObjectBuilder::new().schema_type(SchemaType::AnyValue))
+                .min_items(Some(1))
+                .max_items(Some(8))
+                .build(),
+        )),
+        Tags::schema(),
+    );
+
+    assert_schema_eq(object(ObjectBuilder::new().schema_type(SchemaType::AnyValue)), Any::schema());
+}
+
+#[cfg(all(feature = "line", feature = "unsigned_integer"))]
+#[test]
+fn alternatives() {
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 8)))]
+    pub struct Name(String);
+
+    #[derive(Validator)]
+    #[validator(unsigned_integer(range(Inside(max = 9))))]
+    pub struct Digit(u8);
+
+    #[derive(Validator)]
+    pub enum NameOrDigit {
+        Name(Name),
+        Digit(Digit),
+    }
+
+    assert_schema_eq(
+        RefOr::T(Schema::AnyOf(
+            AnyOfBuilder::new()
+                .item(Ref::from_schema_name("Name"))
+                .item(Ref::from_schema_name("Digit"))
+                .build(),
+        )),
+        NameOrDigit::schema(),
+    );
+
+    let mut schemas = Vec::new();
+
+    NameOrDigit::schemas(&mut schemas);
+
+    assert_eq!(2, schemas.len());
+
+    let (name, schema) = schemas.remove(0);
+
+    assert_eq!("Name", name);
+    assert_schema_eq(Name::schema(), schema);
+
+    let (name, schema) = schemas.remove(0);
+
+    assert_eq!("Digit", name);
+    assert_schema_eq(Digit::schema(), schema);
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn custom_and_borrowed() {
+    use std::borrow::Cow;
+
+    fn not_admin(v: &Username) -> Result<(), &'static str> {
+        if v.0 == "admin" { Err("reserved") } else { Ok(()) }
+    }
+
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 16), custom = not_admin))]
+    pub struct Username(String);
+
+    #[derive(Validator)]
+    #[validator(line(char_length(max = 16)))]
+    pub struct BorrowedLine<'a>(Cow<'a, str>);
+
+    assert_eq!("Username", Username::name());
+    assert_eq!("BorrowedLine", BorrowedLine::name());
+    assert_schema_eq(Username::schema(), BorrowedLine::schema());
+}
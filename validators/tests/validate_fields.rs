@@ -0,0 +1,199 @@
+#![cfg(all(
+    feature = "test",
+    feature = "derive",
+    feature = "email",
+    feature = "length",
+    feature = "line",
+    feature = "signed_integer",
+    feature = "unsigned_integer"
+))]
+
+use validators::{
+    errors::{EmailError, LineError, UnsignedIntegerError, ValidationErrors},
+    prelude::*,
+};
+
+#[derive(Validator)]
+pub struct SignUp {
+    #[validate(email(
+        comment(Disallow),
+        ip(Disallow),
+        local(Disallow),
+        at_least_two_labels(Must),
+        non_ascii(Allow)
+    ))]
+    pub email:    String,
+    #[validate(unsigned_integer(range(Inside(min = 18, max = 150))))]
+    pub age:      u8,
+    #[validate(line(char_length(trimmed_min = 1, max = 32)))]
+    pub name:     String,
+    #[validate(line(char_length(trimmed_min = 1)))]
+    pub nickname: Option<String>,
+    #[validate(signed_integer(range(Inside(min = -12, max = 14))))]
+    pub timezone: i8,
+    #[validate(length(max = 3))]
+    pub tags:     Vec<String>,
+    pub note:     String,
+}
+
+fn sign_up() -> SignUp {
+    SignUp {
+        email:    String::from("len@magiclen.org"),
+        age:      18,
+        name:     String::from("Magic Len"),
+        nickname: None,
+        timezone: 8,
+        tags:     vec![String::from("rust")],
+        note:     String::new(),
+    }
+}
+
+#[test]
+fn valid() {
+    assert!(sign_up().validate().is_ok());
+
+    let mut s = sign_up();
+    s.nickname = Some(String::from("len"));
+    s.note = String::from("\0");
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn invalid() {
+    let mut s = sign_up();
+    s.email = String::from("len@[127.0.0.1]");
+
+    let errors = s.validate().unwrap_err();
+
+    assert_eq!(1, errors.len());
+
+    let error = errors.iter().next().unwrap();
+
+    assert_eq!("email", error.path());
+    assert_eq!("email", error.validator());
+    assert!(matches!(error.downcast_ref(), Some(EmailError::IPDisallow)));
+
+    let mut s = sign_up();
+    s.age = 17;
+    s.name = String::from("  ");
+    s.nickname = Some(String::new());
+    s.timezone = -13;
+    s.tags = vec![String::new(); 4];
+
+    let errors = s.validate().unwrap_err();
+
+    assert_eq!(
+        ["age", "name", "nickname", "timezone", "tags"],
+        errors.iter().map(|e| e.path()).collect::<Vec<_>>().as_slice()
+    );
+    assert!(matches!(
+        errors.get("age").next().unwrap().downcast_ref(),
+        Some(UnsignedIntegerError::TooSmall)
+    ));
+    assert_eq!("tags: collection is too large", errors.get("tags").next().unwrap().to_string());
+}
+
+#[derive(Validator)]
+pub struct Address {
+    #[validate(line(char_length(trimmed_min = 1, max = 10)))]
+    pub zip: String,
+}
+
+#[derive(Validator)]
+pub struct User {
+    #[validate(line(char_length(trimmed_min = 1)))]
+    pub name:      String,
+    #[validate(nested)]
+    pub address:   Option<Address>,
+    #[validate(nested)]
+    pub addresses: Vec<Address>,
+}
+
+#[derive(Validator)]
+pub struct Form {
+    #[validate(nested)]
+    pub user: User,
+}
+
+#[test]
+fn nested() {
+    let address = |zip: &str| Address {
+        zip: String::from(zip)
+    };
+
+    let form = Form {
+        user: User {
+            name:      String::from("len"),
+            address:   Some(address("300")),
+            addresses: vec![address("300"), address("100")],
+        },
+    };
+
+    assert!(form.validate().is_ok());
+
+    let form = Form {
+        user: User {
+            name:      String::new(),
+            address:   Some(address("")),
+            addresses: vec![address("300"), address("100"), address("")],
+        },
+    };
+
+    let errors = form.validate().unwrap_err();
+
+    assert_eq!(
+        ["user.name", "user.address.zip", "user.addresses[2].zip"],
+        errors.iter().map(|e| e.path()).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!("line", errors.get("user.addresses[2].zip").next().unwrap().validator());
+}
+
+#[test]
+fn similar_field_names() {
+    #[allow(non_snake_case)]
+    #[derive(Validator)]
+    pub struct Names {
+        #[validate(line(char_length(max = 4)))]
+        pub foo_bar: String,
+        #[validate(line(char_length(max = 8)))]
+        pub fooBar:  String,
+    }
+
+    let names = Names {
+        foo_bar: String::from("abcdef"), fooBar: String::from("abcdef")
+    };
+
+    let errors = names.validate().unwrap_err();
+
+    assert_eq!(1, errors.len());
+    assert_eq!("foo_bar", errors.iter().next().unwrap().path());
+}
+
+#[test]
+fn hand_written() {
+    let mut errors = ValidationErrors::new();
+
+    assert!(errors.is_empty());
+
+    errors.push("zip", "line", LineError::Invalid);
+
+    let mut parent = ValidationErrors::new();
+
+    parent.extend_nested("addresses[0]", errors);
+
+    assert_eq!("addresses[0].zip: invalid line", parent.to_string());
+    assert!(parent.into_result().is_err());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serialize() {
+    let mut s = sign_up();
+    s.age = 17;
+
+    assert_eq!(
+        r#"[{"path":"age","validator":"unsigned_integer","code":"unsigned_integer.too_small","message":"integer is too small","params":{"min":18,"value":17}}]"#,
+        validators::serde_json::to_string(&s.validate().unwrap_err()).unwrap()
+    );
+}
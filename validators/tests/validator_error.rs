@@ -0,0 +1,205 @@
+#![cfg(all(feature = "test", feature = "derive"))]
+
+use validators::{errors::*, prelude::*};
+
+#[cfg(feature = "text")]
+#[test]
+fn text() {
+    #[derive(Debug, Validator)]
+    #[validator(text(byte_length(trimmed_min = 1, max = 8)))]
+    pub struct Name(String);
+
+    let error = Name::validate_str_detailed("magiclen.org").unwrap_err();
+
+    assert!(matches!(error.error(), TextError::TooLong));
+    assert_eq!(Some(12), error.length());
+    assert_eq!("text.too_long", error.code());
+
+    let error = Name::validate_str_detailed(" ").unwrap_err();
+
+    assert!(matches!(error.error(), TextError::TooShort));
+    assert_eq!("text.too_short", error.code());
+    assert_eq!(
+        vec![
+            ("min", ErrorParam::Unsigned(0)),
+            ("trimmed_min", ErrorParam::Unsigned(1)),
+            ("length", ErrorParam::Unsigned(1)),
+            ("trimmed_length", ErrorParam::Unsigned(0)),
+        ],
+        error.params()
+    );
+
+    let error = Name::validate_str_detailed("ab\x07c").unwrap_err();
+
+    assert!(matches!(error.error(), TextError::Invalid));
+    assert_eq!(Some(2), error.offset());
+    assert_eq!(None, error.length());
+
+    assert!(Name::validate_str_detailed("magiclen").is_ok());
+}
+
+#[cfg(feature = "signed_integer")]
+#[test]
+fn signed_integer() {
+    #[derive(Debug, Validator)]
+    #[validator(signed_integer(range(Outside(min = -9, max = 9))))]
+    pub struct NotDigit(i8);
+
+    let error = NotDigit::validate_i128_detailed(0).unwrap_err();
+
+    assert!(matches!(error.error(), SignedIntegerError::Forbidden));
+    assert_eq!("signed_integer.forbidden", error.code());
+    assert_eq!(
+        vec![
+            ("min", ErrorParam::Signed(-9)),
+            ("max", ErrorParam::Signed(9)),
+            ("inclusive", ErrorParam::Bool(true)),
+            ("value", ErrorParam::Signed(0)),
+        ],
+        error.params()
+    );
+
+    // out of the range of the type
+    let error = NotDigit::validate_i128_detailed(-129).unwrap_err();
+
+    assert!(matches!(error.error(), SignedIntegerError::TooSmall));
+    assert_eq!(
+        vec![("min", ErrorParam::Signed(-128)), ("value", ErrorParam::Signed(-129))],
+        error.params()
+    );
+
+    let error = NotDigit::validate_str_detailed("300").unwrap_err();
+
+    assert!(matches!(error.error(), SignedIntegerError::ParseIntError(_)));
+    assert!(error.params().is_empty());
+}
+
+#[cfg(feature = "length")]
+#[test]
+fn length() {
+    #[derive(Debug, Validator)]
+    #[validator(length(min = 2, max = 2))]
+    pub struct Pair(Vec<u8>);
+
+    let error = Pair::validate_collection_detailed(&vec![1, 2, 3]).unwrap_err();
+
+    assert!(matches!(error.error(), LengthError::TooLarge));
+    assert_eq!(
+        vec![("max", ErrorParam::Unsigned(2)), ("length", ErrorParam::Unsigned(3))],
+        error.params()
+    );
+    assert_eq!(Some(3), error.length());
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64() {
+    #[derive(Debug, Validator)]
+    #[validator(base64(padding(Allow)))]
+    pub struct Base64(String);
+
+    let error = Base64::validate_str_detailed("bWFn!WNsZW4=").unwrap_err();
+
+    assert!(matches!(error.error(), Base64Error::Invalid));
+    assert_eq!(Some(4), error.offset());
+    assert_eq!(vec![("offset", ErrorParam::Unsigned(4))], error.params());
+
+    assert_eq!(Some(11), Base64::validate_str_detailed("bWFnaWNsZW=x").unwrap_err().offset());
+    assert_eq!(Some(9), Base64::validate_str_detailed("bWFnaWNsZ=4=").unwrap_err().offset());
+}
+
+#[cfg(feature = "base64_url")]
+#[test]
+fn base64_url() {
+    #[derive(Debug, Validator)]
+    #[validator(base64_url(padding(Disallow)))]
+    pub struct Base64Url(String);
+
+    assert_eq!(Some(3), Base64Url::validate_str_detailed("bWF+aWNsZW4").unwrap_err().offset());
+
+    let error = Base64Url::validate_str_detailed("bWFnaWNsZW4=").unwrap_err();
+
+    assert!(matches!(error.error(), Base64UrlError::PaddingDisallow));
+    assert!(error.params().is_empty());
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn domain() {
+    #[derive(Debug, Validator)]
+    #[validator(domain(ipv4(Allow), local(Allow), at_least_two_labels(Allow), port(Allow)))]
+    pub struct Domain {
+        pub domain: String,
+        pub port:   Option<u16>,
+    }
+
+    let error = Domain::validate_str_detailed("www.-magiclen.org").unwrap_err();
+
+    assert!(matches!(error.error(), DomainError::Invalid));
+    assert_eq!(vec![("label", ErrorParam::Unsigned(1))], error.params());
+
+    assert_eq!(
+        Some(2),
+        Domain::validate_str_detailed("www.magiclen..org:8080").unwrap_err().label()
+    );
+    assert_eq!(None, Domain::validate_str_detailed("magiclen.org:http").unwrap_err().label());
+}
+
+#[cfg(feature = "email")]
+#[test]
+fn without_params() {
+    let error = EmailError::CommentDisallow;
+
+    assert_eq!("email", error.validator());
+    assert_eq!("email.comment_disallow", error.code());
+    assert!(error.params().is_empty());
+}
+
+#[cfg(feature = "line")]
+#[test]
+fn customized() {
+    fn not_admin(v: &Username) -> Result<(), &'static str> {
+        if v.0 == "admin" { Err("reserved") } else { Ok(()) }
+    }
+
+    #[derive(Debug, Validator)]
+    #[validator(line(char_length(max = 16), custom = not_admin))]
+    pub struct Username(String);
+
+    let error = Username::validate_str_detailed("administrator of magiclen.org").unwrap_err();
+
+    assert_eq!("line", error.validator());
+    assert_eq!("line.too_long", error.code());
+    assert_eq!(
+        vec![("max", ErrorParam::Unsigned(16)), ("length", ErrorParam::Unsigned(29))],
+        error.params()
+    );
+
+    let error = Username::validate_str_detailed("admin").unwrap_err();
+
+    assert_eq!("custom", error.validator());
+    assert_eq!("custom", error.code());
+    assert!(error.params().is_empty());
+}
+
+#[cfg(all(feature = "runtime", feature = "unsigned_integer"))]
+#[test]
+fn runtime() {
+    use validators::runtime::{RangeOption, UnsignedIntegerValidator};
+
+    let validator = UnsignedIntegerValidator::<u16>::builder()
+        .range(RangeOption::Inside {
+            min:       Some(1024),
+            max:       Some(65535),
+            inclusive: true,
+        })
+        .build();
+
+    let error = validator.validate_integer_detailed(&80).unwrap_err();
+
+    assert!(matches!(error.error(), UnsignedIntegerError::TooSmall));
+    assert_eq!(
+        vec![("min", ErrorParam::Unsigned(1024)), ("value", ErrorParam::Unsigned(80))],
+        error.params()
+    );
+}